@@ -0,0 +1,104 @@
+//! GitHub App authentication
+//!
+//! Builds Octocrab clients that authenticate as a GitHub App installation
+//! instead of with a personal access token. Octocrab caches the short-lived
+//! installation token and requests a new one before it expires.
+
+use anyhow::{Context, Result};
+use log::{debug, info};
+use octocrab::models::{AppId, InstallationId};
+use octocrab::Octocrab;
+use std::path::PathBuf;
+
+/// Credentials of a GitHub App used to authenticate as one of its installations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppCredentials {
+    /// GitHub App ID
+    pub app_id: u64,
+    /// Path to the App's private key (PEM)
+    pub private_key_path: PathBuf,
+    /// Installation to authenticate as (None = the App's first installation)
+    pub installation_id: Option<u64>,
+}
+
+impl AppCredentials {
+    /// Create credentials for the App's first installation
+    pub fn new(app_id: u64, private_key_path: impl Into<PathBuf>) -> Self {
+        Self {
+            app_id,
+            private_key_path: private_key_path.into(),
+            installation_id: None,
+        }
+    }
+
+    /// Authenticate as a specific installation
+    pub fn with_installation_id(mut self, installation_id: Option<u64>) -> Self {
+        self.installation_id = installation_id;
+        self
+    }
+
+    /// Build an Octocrab client authenticated as the App installation
+    ///
+    /// # Arguments
+    ///
+    /// * `base_uri` - API base URL for GitHub Enterprise hosts (None = github.com)
+    pub(crate) async fn build_octocrab(&self, base_uri: Option<&str>) -> Result<Octocrab> {
+        let pem = std::fs::read(&self.private_key_path).with_context(|| {
+            format!(
+                "Failed to read GitHub App private key from {}",
+                self.private_key_path.display()
+            )
+        })?;
+        let key = jsonwebtoken::EncodingKey::from_rsa_pem(&pem)
+            .context("Invalid GitHub App private key")?;
+
+        let mut builder = Octocrab::builder().app(AppId(self.app_id), key);
+        if let Some(uri) = base_uri {
+            builder = builder.base_uri(uri).context("Failed to set base URI")?;
+        }
+        let app = builder
+            .build()
+            .context("Failed to build GitHub App client")?;
+
+        let installation_id = match self.installation_id {
+            Some(id) => id,
+            None => {
+                debug!("Discovering installation for GitHub App {}", self.app_id);
+                let installations = app
+                    .apps()
+                    .installations()
+                    .send()
+                    .await
+                    .context("Failed to list GitHub App installations")?;
+                installations
+                    .items
+                    .first()
+                    .map(|installation| installation.id.0)
+                    .with_context(|| format!("GitHub App {} has no installations", self.app_id))?
+            }
+        };
+
+        info!(
+            "Authenticating as GitHub App {} installation {}",
+            self.app_id, installation_id
+        );
+        app.installation(InstallationId(installation_id))
+            .context("Failed to create installation client")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_credentials_builder() {
+        let creds = AppCredentials::new(42, "/tmp/app.pem");
+        assert_eq!(creds.app_id, 42);
+        assert_eq!(creds.private_key_path, PathBuf::from("/tmp/app.pem"));
+        assert_eq!(creds.installation_id, None);
+
+        let creds = creds.with_installation_id(Some(7));
+        assert_eq!(creds.installation_id, Some(7));
+    }
+}
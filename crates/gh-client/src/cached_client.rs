@@ -23,8 +23,9 @@ fn is_volatile_endpoint(url: &str) -> bool {
         .any(|pattern| url.contains(pattern))
 }
 use crate::types::{
-    CheckRun, CheckStatus, CiStatus, MergeMethod, MergeResult, PullRequest, ReviewComment,
-    ReviewDecision, ReviewEvent, SortPrsExt, WorkflowRun,
+    CheckRun, CheckStatus, CiStatus, DraftReviewComment, MergeMethod, MergeResult, PrCommit,
+    PrStateFilter, PullRequest, ReviewComment, ReviewDecision, ReviewEvent, SortPrsExt,
+    WorkflowRun,
 };
 use async_trait::async_trait;
 use gh_api_cache::{ApiCache, CachedResponse};
@@ -207,6 +208,61 @@ impl<C: GitHubClient + Clone> GitHubClient for CachedGitHubClient<C> {
         Ok(prs)
     }
 
+    async fn fetch_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_branch: Option<&str>,
+        state: PrStateFilter,
+        per_page: u8,
+        page: u32,
+    ) -> anyhow::Result<Vec<PullRequest>> {
+        let url = format!("/repos/{}/{}/pulls", owner, repo);
+        let per_page_str = per_page.to_string();
+        let page_str = page.to_string();
+        let mut params: Vec<(&str, &str)> = vec![
+            ("state", state.as_str()),
+            ("per_page", &per_page_str),
+            ("page", &page_str),
+        ];
+        if let Some(branch) = base_branch {
+            params.push(("base", branch));
+        }
+
+        // Try cache first
+        if let Some(cached_body) = self.try_cache_get("GET", &url, &params) {
+            match serde_json::from_str::<Vec<PullRequest>>(&cached_body) {
+                Ok(prs) => {
+                    debug!(
+                        "Cache HIT for {}/{} page {}: {} PRs",
+                        owner,
+                        repo,
+                        page,
+                        prs.len()
+                    );
+                    return Ok(prs);
+                }
+                Err(e) => {
+                    debug!("Failed to parse cached PR page: {}", e);
+                    // Fall through to fetch fresh data
+                }
+            }
+        }
+
+        // Fetch from API
+        let prs = self
+            .inner
+            .fetch_pull_requests_page(owner, repo, base_branch, state, per_page, page)
+            .await?;
+
+        // Cache the result
+        if let Ok(json) = serde_json::to_string(&prs) {
+            self.cache_set("GET", &url, &params, &json);
+        }
+
+        Ok(prs)
+    }
+
     async fn fetch_pull_request(
         &self,
         owner: &str,
@@ -375,6 +431,29 @@ impl<C: GitHubClient + Clone> GitHubClient for CachedGitHubClient<C> {
             .await
     }
 
+    async fn create_review_with_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        commit_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+        comments: &[DraftReviewComment],
+    ) -> anyhow::Result<()> {
+        let result = self
+            .inner
+            .create_review_with_comments(owner, repo, pr_number, commit_id, event, body, comments)
+            .await;
+
+        // On success, invalidate cached comments for this repo
+        if result.is_ok() {
+            self.cache_invalidate_comments(owner, repo);
+        }
+
+        result
+    }
+
     async fn close_pull_request(
         &self,
         owner: &str,
@@ -385,11 +464,82 @@ impl<C: GitHubClient + Clone> GitHubClient for CachedGitHubClient<C> {
         self.inner.close_pull_request(owner, repo, pr_number).await
     }
 
+    async fn mark_ready_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        // Mutations are never cached - pass through directly
+        self.inner
+            .mark_ready_for_review(owner, repo, pr_number)
+            .await
+    }
+
+    async fn convert_to_draft(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        // Mutations are never cached - pass through directly
+        self.inner.convert_to_draft(owner, repo, pr_number).await
+    }
+
+    async fn set_file_viewed(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        path: &str,
+        viewed: bool,
+    ) -> anyhow::Result<()> {
+        // Mutations are never cached - pass through directly
+        self.inner
+            .set_file_viewed(owner, repo, pr_number, path, viewed)
+            .await
+    }
+
     async fn rerun_failed_jobs(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()> {
         // Mutations are never cached - pass through directly
         self.inner.rerun_failed_jobs(owner, repo, run_id).await
     }
 
+    async fn rerun_workflow(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()> {
+        // Mutations are never cached - pass through directly
+        self.inner.rerun_workflow(owner, repo, run_id).await
+    }
+
+    async fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> anyhow::Result<()> {
+        // Mutations are never cached - pass through directly
+        self.inner.cancel_workflow_run(owner, repo, run_id).await
+    }
+
+    async fn download_job_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> anyhow::Result<String> {
+        // Logs can be large and are only fetched on demand - never cached
+        self.inner.download_job_logs(owner, repo, job_id).await
+    }
+
+    async fn repository_exists(&self, owner: &str, repo: &str) -> anyhow::Result<bool> {
+        // Must reflect the current access rights - never cached
+        self.inner.repository_exists(owner, repo).await
+    }
+
+    async fn fetch_viewer_login(&self) -> anyhow::Result<String> {
+        // Depends on the token, not the URL - never cached
+        self.inner.fetch_viewer_login().await
+    }
+
     async fn fetch_workflow_runs(
         &self,
         owner: &str,
@@ -556,6 +706,45 @@ impl<C: GitHubClient + Clone> GitHubClient for CachedGitHubClient<C> {
         Ok(comments)
     }
 
+    async fn fetch_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<Vec<PrCommit>> {
+        let url = format!("/repos/{}/{}/pulls/{}/commits", owner, repo, pr_number);
+        let params: &[(&str, &str)] = &[("per_page", "100")];
+
+        // Try cache first
+        if let Some(cached_body) = self.try_cache_get("GET", &url, params) {
+            match serde_json::from_str::<Vec<PrCommit>>(&cached_body) {
+                Ok(commits) => {
+                    debug!(
+                        "Cache HIT for {}/{} PR #{} commits: {} commits",
+                        owner,
+                        repo,
+                        pr_number,
+                        commits.len()
+                    );
+                    return Ok(commits);
+                }
+                Err(e) => {
+                    debug!("Failed to parse cached commits: {}", e);
+                }
+            }
+        }
+
+        // Fetch from API
+        let commits = self.inner.fetch_pr_commits(owner, repo, pr_number).await?;
+
+        // Cache the result
+        if let Ok(json) = serde_json::to_string(&commits) {
+            self.cache_set("GET", &url, params, &json);
+        }
+
+        Ok(commits)
+    }
+
     async fn fetch_review_decision(
         &self,
         owner: &str,
@@ -599,233 +788,7 @@ impl<C: GitHubClient + Clone> GitHubClient for CachedGitHubClient<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{CheckState, CiState, MergeableState};
-    use chrono::Utc;
-
-    /// Mock client for testing
-    #[derive(Debug, Clone)]
-    struct MockClient {
-        prs: Vec<PullRequest>,
-        call_count: Arc<Mutex<usize>>,
-    }
-
-    impl MockClient {
-        fn new(prs: Vec<PullRequest>) -> Self {
-            Self {
-                prs,
-                call_count: Arc::new(Mutex::new(0)),
-            }
-        }
-
-        fn call_count(&self) -> usize {
-            *self.call_count.lock().unwrap()
-        }
-    }
-
-    #[async_trait]
-    impl GitHubClient for MockClient {
-        async fn fetch_pull_requests(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _base_branch: Option<&str>,
-        ) -> anyhow::Result<Vec<PullRequest>> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(self.prs.clone())
-        }
-
-        async fn fetch_pull_request(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            pr_number: u64,
-        ) -> anyhow::Result<PullRequest> {
-            *self.call_count.lock().unwrap() += 1;
-            self.prs
-                .iter()
-                .find(|pr| pr.number == pr_number)
-                .cloned()
-                .ok_or_else(|| anyhow::anyhow!("PR not found"))
-        }
-
-        async fn fetch_check_runs(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _commit_sha: &str,
-        ) -> anyhow::Result<Vec<CheckRun>> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(vec![])
-        }
-
-        async fn fetch_commit_status(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _commit_sha: &str,
-        ) -> anyhow::Result<CheckStatus> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(CheckStatus {
-                state: CheckState::Success,
-                total_count: 0,
-                statuses: vec![],
-            })
-        }
-
-        async fn merge_pull_request(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-            _merge_method: MergeMethod,
-            _commit_title: Option<&str>,
-            _commit_message: Option<&str>,
-        ) -> anyhow::Result<MergeResult> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(MergeResult {
-                merged: true,
-                sha: Some("abc123".to_string()),
-                message: "Merged".to_string(),
-            })
-        }
-
-        async fn update_pull_request_branch(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-        ) -> anyhow::Result<()> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(())
-        }
-
-        async fn create_review(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-            _event: ReviewEvent,
-            _body: Option<&str>,
-        ) -> anyhow::Result<()> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(())
-        }
-
-        async fn close_pull_request(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-        ) -> anyhow::Result<()> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(())
-        }
-
-        async fn rerun_failed_jobs(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _run_id: u64,
-        ) -> anyhow::Result<()> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(())
-        }
-
-        async fn fetch_workflow_runs(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _head_sha: &str,
-        ) -> anyhow::Result<Vec<WorkflowRun>> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(vec![])
-        }
-
-        async fn fetch_ci_status(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _head_sha: &str,
-        ) -> anyhow::Result<CiStatus> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(CiStatus {
-                state: CiState::Success,
-                total_checks: 0,
-                passed: 0,
-                failed: 0,
-                pending: 0,
-            })
-        }
-
-        async fn create_review_comment(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-            _commit_id: &str,
-            _path: &str,
-            _line: u32,
-            _side: &str,
-            _body: &str,
-        ) -> anyhow::Result<u64> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(12345) // Mock comment ID
-        }
-
-        async fn delete_review_comment(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _comment_id: u64,
-        ) -> anyhow::Result<()> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(())
-        }
-
-        async fn fetch_review_comments(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-        ) -> anyhow::Result<Vec<ReviewComment>> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(vec![]) // Empty list by default
-        }
-
-        async fn fetch_review_decision(
-            &self,
-            _owner: &str,
-            _repo: &str,
-            _pr_number: u64,
-        ) -> anyhow::Result<ReviewDecision> {
-            *self.call_count.lock().unwrap() += 1;
-            Ok(ReviewDecision::Pending)
-        }
-    }
-
-    fn create_test_pr(number: u64) -> PullRequest {
-        use crate::types::MaturityState;
-
-        PullRequest {
-            number,
-            title: format!("Test PR {}", number),
-            body: None,
-            author: "testuser".to_string(),
-            comments: 0,
-            head_sha: "abc123".to_string(),
-            base_branch: "main".to_string(),
-            head_branch: "feature".to_string(),
-            mergeable: Some(true),
-            mergeable_state: Some(MergeableState::Clean),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            html_url: "https://github.com/test/repo/pull/1".to_string(),
-            additions: 100,
-            deletions: 50,
-            maturity: MaturityState::Ready,
-            review_decision: ReviewDecision::Pending,
-        }
-    }
+    use crate::mock_client::{test_pr as create_test_pr, MockClient};
 
     #[tokio::test]
     async fn test_cache_mode_none_skips_cache() {
@@ -935,6 +898,39 @@ mod tests {
         assert_eq!(mock2.call_count(), 0); // Never called, used cache
     }
 
+    #[tokio::test]
+    async fn test_pull_request_pages_cached_separately() {
+        let mock = MockClient::new((1..=3).map(create_test_pr).collect());
+        let cache = Arc::new(Mutex::new(ApiCache::default()));
+        let client = CachedGitHubClient::new(mock.clone(), cache, CacheMode::ReadWrite);
+
+        let page1 = client
+            .fetch_pull_requests_page("owner", "repo", None, PrStateFilter::Open, 2, 1)
+            .await
+            .unwrap();
+        let page2 = client
+            .fetch_pull_requests_page("owner", "repo", None, PrStateFilter::Open, 2, 2)
+            .await
+            .unwrap();
+        assert_eq!(page1.len(), 2);
+        assert_eq!(page2.len(), 1);
+        assert_eq!(mock.call_count(), 2);
+
+        // Same page again - served from cache
+        client
+            .fetch_pull_requests_page("owner", "repo", None, PrStateFilter::Open, 2, 2)
+            .await
+            .unwrap();
+        assert_eq!(mock.call_count(), 2);
+
+        // Different state - separate cache entry
+        client
+            .fetch_pull_requests_page("owner", "repo", None, PrStateFilter::All, 2, 1)
+            .await
+            .unwrap();
+        assert_eq!(mock.call_count(), 3);
+    }
+
     #[tokio::test]
     async fn test_with_mode_creates_new_client() {
         let mock = MockClient::new(vec![create_test_pr(1)]);
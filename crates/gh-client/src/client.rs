@@ -5,8 +5,8 @@
 //! controlling caching behavior.
 
 use crate::types::{
-    CheckRun, CheckStatus, CiStatus, MergeMethod, MergeResult, PullRequest, ReviewEvent,
-    WorkflowRun,
+    CheckRun, CheckStatus, CiStatus, DraftReviewComment, MergeMethod, MergeResult, PrStateFilter,
+    PullRequest, ReviewEvent, WorkflowRun,
 };
 use async_trait::async_trait;
 
@@ -87,6 +87,29 @@ pub trait GitHubClient: Send + Sync {
         base_branch: Option<&str>,
     ) -> anyhow::Result<Vec<PullRequest>>;
 
+    /// Fetch a single page of pull requests for a repository
+    ///
+    /// Unlike `fetch_pull_requests`, this does not follow pagination, so callers
+    /// can process pages incrementally. A page shorter than `per_page` is the last one.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner (user or organization)
+    /// * `repo` - Repository name
+    /// * `base_branch` - Optional base branch filter (e.g., "main")
+    /// * `state` - Which pull requests to list (open, closed, all)
+    /// * `per_page` - Page size (max 100)
+    /// * `page` - Page number (1-based)
+    async fn fetch_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_branch: Option<&str>,
+        state: PrStateFilter,
+        per_page: u8,
+        page: u32,
+    ) -> anyhow::Result<Vec<PullRequest>>;
+
     /// Fetch a single pull request by number
     ///
     /// This returns full PR details including additions/deletions
@@ -215,6 +238,35 @@ pub trait GitHubClient: Send + Sync {
         body: Option<&str>,
     ) -> anyhow::Result<()>;
 
+    /// Create a review with line comments on a pull request
+    ///
+    /// All comments are submitted atomically as part of a single review.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `pr_number` - Pull request number
+    /// * `commit_id` - SHA of the commit the comment positions refer to
+    /// * `event` - Review event (approve, request changes, or comment)
+    /// * `body` - Optional review body
+    /// * `comments` - Line comments, anchored by diff position
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    #[allow(clippy::too_many_arguments)]
+    async fn create_review_with_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        commit_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+        comments: &[DraftReviewComment],
+    ) -> anyhow::Result<()>;
+
     /// Close a pull request without merging
     ///
     /// # Arguments
@@ -233,6 +285,67 @@ pub trait GitHubClient: Send + Sync {
         pr_number: u64,
     ) -> anyhow::Result<()>;
 
+    /// Mark a draft pull request as ready for review
+    ///
+    /// Uses the GraphQL `markPullRequestReadyForReview` mutation, as the
+    /// REST API has no endpoint for changing the draft state.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `pr_number` - Pull request number
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    async fn mark_ready_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()>;
+
+    /// Convert a pull request back to a draft
+    ///
+    /// Uses the GraphQL `convertPullRequestToDraft` mutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `pr_number` - Pull request number
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    async fn convert_to_draft(&self, owner: &str, repo: &str, pr_number: u64)
+        -> anyhow::Result<()>;
+
+    /// Mark (or unmark) a file of a pull request as viewed
+    ///
+    /// Uses the GraphQL `markFileAsViewed` / `unmarkFileAsViewed` mutations.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `pr_number` - Pull request number
+    /// * `path` - File path relative to the repository root
+    /// * `viewed` - true to mark as viewed, false to unmark
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    async fn set_file_viewed(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        path: &str,
+        viewed: bool,
+    ) -> anyhow::Result<()>;
+
     // === CI Operations ===
 
     /// Rerun failed workflow jobs for a specific run
@@ -248,6 +361,51 @@ pub trait GitHubClient: Send + Sync {
     /// Ok(()) on success, error on failure
     async fn rerun_failed_jobs(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()>;
 
+    /// Rerun all jobs of a workflow run, including the ones that passed
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `run_id` - Workflow run ID
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    async fn rerun_workflow(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()>;
+
+    /// Cancel a queued or in-progress workflow run
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `run_id` - Workflow run ID
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, error on failure
+    async fn cancel_workflow_run(&self, owner: &str, repo: &str, run_id: u64)
+        -> anyhow::Result<()>;
+
+    /// Download the complete raw log of a workflow job
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `job_id` - Workflow job ID
+    ///
+    /// # Returns
+    ///
+    /// The plain-text log of the job
+    async fn download_job_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> anyhow::Result<String>;
+
     /// Fetch workflow runs for a commit
     ///
     /// # Arguments
@@ -358,6 +516,24 @@ pub trait GitHubClient: Send + Sync {
         pr_number: u64,
     ) -> anyhow::Result<Vec<crate::types::ReviewComment>>;
 
+    /// Fetch the commits of a pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    /// * `pr_number` - Pull request number
+    ///
+    /// # Returns
+    ///
+    /// The PR's commits, oldest first (at most 250, GitHub's limit for this endpoint)
+    async fn fetch_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<Vec<crate::types::PrCommit>>;
+
     /// Fetch review decision for a pull request
     ///
     /// This fetches all reviews for a PR and determines the overall review state:
@@ -380,6 +556,26 @@ pub trait GitHubClient: Send + Sync {
         repo: &str,
         pr_number: u64,
     ) -> anyhow::Result<crate::types::ReviewDecision>;
+
+    /// Check whether a repository exists and is accessible
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Repository owner
+    /// * `repo` - Repository name
+    ///
+    /// # Returns
+    ///
+    /// `false` if the repository is not found (or not visible with the
+    /// current credentials), error on any other failure
+    async fn repository_exists(&self, owner: &str, repo: &str) -> anyhow::Result<bool>;
+
+    /// Fetch the login of the authenticated user
+    ///
+    /// # Returns
+    ///
+    /// The GitHub username the client's token belongs to
+    async fn fetch_viewer_login(&self) -> anyhow::Result<String>;
 }
 
 #[cfg(test)]
@@ -3,23 +3,82 @@
 //! Manages GitHub API clients for different hosts (github.com, GitHub Enterprise).
 //! Clients are lazily initialized and cached per host.
 
-use crate::{ApiCache, CacheMode, CachedGitHubClient, OctocrabClient, DEFAULT_HOST};
+use crate::{
+    api_base_url, default_host, ApiCache, AppCredentials, CacheMode, CachedGitHubClient,
+    OctocrabClient, DEFAULT_HOST,
+};
 use anyhow::{Context, Result};
 use log::{debug, info};
 use octocrab::Octocrab;
 use std::collections::HashMap;
+use std::env::VarError;
+use std::fmt;
+use std::io;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Number of `gh auth token` runs before giving up on unexpected failures
+const GH_TOKEN_ATTEMPTS: u32 = 3;
+/// Delay before retrying `gh auth token`, doubled for every further retry
+const GH_TOKEN_BACKOFF: Duration = Duration::from_millis(200);
+
+/// No token could be resolved for a host
+///
+/// Returned (wrapped in `anyhow::Error`) when none of the token sources
+/// yields a token, so callers can tell "not authenticated yet" apart from
+/// other client errors via `downcast_ref`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTokenError {
+    /// The host a token was looked up for
+    pub host: String,
+    /// The host-specific env var that was checked
+    pub env_key: String,
+    /// Why each token source failed, in resolution order
+    pub attempts: Vec<String>,
+}
+
+impl fmt::Display for MissingTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No token found for host '{}'", self.host)?;
+        if !self.attempts.is_empty() {
+            write!(f, " ({})", self.attempts.join("; "))?;
+        }
+        write!(
+            f,
+            ". Set {} or run 'gh auth login --hostname {}'",
+            self.env_key, self.host
+        )
+    }
+}
+
+impl std::error::Error for MissingTokenError {}
+
+/// Whether an API error means GitHub rejected the token (HTTP 401)
+///
+/// Typically an expired or revoked token, which only shows on the first
+/// API call since resolving a token doesn't validate it.
+pub fn is_unauthorized(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<octocrab::Error>(),
+            Some(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 401
+        )
+    })
+}
 
 /// Resolves GitHub tokens for different hosts
 ///
 /// Tries multiple sources in order:
 /// 1. Host-specific env var (e.g., `GITHUB_TOKEN_GHE_EXAMPLE_COM`)
 /// 2. `gh auth token --hostname {host}` command
-/// 3. Generic `GITHUB_TOKEN` or `GH_TOKEN` (github.com only)
+/// 3. Generic `GITHUB_TOKEN` or `GH_TOKEN` (default host only)
+///
+/// Every source reports why it failed, so a [`MissingTokenError`] tells
+/// apart e.g. "gh is not installed" from "gh is not logged in".
 #[derive(Debug, Clone)]
 pub struct TokenResolver {
-    /// Cached default token from GITHUB_TOKEN/GH_TOKEN
-    default_token: Option<String>,
+    /// Cached default token from GITHUB_TOKEN/GH_TOKEN (Err = why there is none)
+    default_token: std::result::Result<String, String>,
 }
 
 impl Default for TokenResolver {
@@ -31,9 +90,9 @@ impl Default for TokenResolver {
 impl TokenResolver {
     /// Create a new token resolver
     pub fn new() -> Self {
-        let default_token = std::env::var("GITHUB_TOKEN")
-            .or_else(|_| std::env::var("GH_TOKEN"))
-            .ok();
+        let default_token = env_token("GITHUB_TOKEN").or_else(|github_token| {
+            env_token("GH_TOKEN").map_err(|gh_token| format!("{}, {}", github_token, gh_token))
+        });
 
         Self { default_token }
     }
@@ -42,63 +101,133 @@ impl TokenResolver {
     ///
     /// # Arguments
     ///
-    /// * `host` - The GitHub host (None = github.com)
+    /// * `host` - The GitHub host (None = default host)
     ///
     /// # Token Resolution Order
     ///
     /// 1. `GITHUB_TOKEN_{HOST}` env var (e.g., `GITHUB_TOKEN_GHE_EXAMPLE_COM`)
     /// 2. `gh auth token --hostname {host}` command
-    /// 3. `GITHUB_TOKEN` or `GH_TOKEN` (github.com only)
+    /// 3. `GITHUB_TOKEN` or `GH_TOKEN` (default host only)
     pub async fn get_token(&self, host: Option<&str>) -> Result<String> {
-        let host = host.unwrap_or(DEFAULT_HOST);
+        let host = host.unwrap_or(default_host());
+        let mut attempts = Vec::new();
 
         // Try host-specific env var
         let env_key = format!(
             "GITHUB_TOKEN_{}",
             host.replace(['.', '-'], "_").to_uppercase()
         );
-        if let Ok(token) = std::env::var(&env_key) {
-            debug!("Using token from env var {} for host {}", env_key, host);
-            return Ok(token);
+        match env_token(&env_key) {
+            Ok(token) => {
+                debug!("Using token from env var {} for host {}", env_key, host);
+                return Ok(token);
+            }
+            Err(reason) => attempts.push(reason),
         }
 
         // Try gh CLI with hostname
         debug!("Trying gh auth token for host {}", host);
-        let output = tokio::process::Command::new("gh")
-            .args(["auth", "token", "--hostname", host])
-            .output()
-            .await
-            .context("Failed to run 'gh auth token'")?;
-
-        if output.status.success() {
-            let token = String::from_utf8(output.stdout)
-                .context("Invalid UTF-8 in gh auth token output")?
-                .trim()
-                .to_string();
-            if !token.is_empty() {
+        match gh_token(host).await {
+            Ok(token) => {
                 debug!("Using token from gh CLI for host {}", host);
                 return Ok(token);
             }
+            Err(reason) => attempts.push(reason),
         }
 
-        // Fallback to default token (for github.com only)
-        if host == DEFAULT_HOST {
-            if let Some(ref token) = self.default_token {
-                debug!("Using default token (GITHUB_TOKEN/GH_TOKEN) for github.com");
-                return Ok(token.clone());
+        // Fallback to default token (for the default host only)
+        if host == default_host() {
+            match &self.default_token {
+                Ok(token) => {
+                    debug!("Using default token (GITHUB_TOKEN/GH_TOKEN) for {}", host);
+                    return Ok(token.clone());
+                }
+                Err(reason) => attempts.push(reason.clone()),
             }
         }
 
-        Err(anyhow::anyhow!(
-            "No token found for host '{}'. \
-             Set {} or run 'gh auth login --hostname {}'",
-            host,
+        debug!("No token for host {}: {}", host, attempts.join("; "));
+        Err(MissingTokenError {
+            host: host.to_string(),
             env_key,
-            host
-        ))
+            attempts,
+        }
+        .into())
+    }
+}
+
+/// Read a token from an env var (Err = why it can't be used)
+fn env_token(name: &str) -> std::result::Result<String, String> {
+    match std::env::var(name) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        Ok(_) => Err(format!("{} is set but empty", name)),
+        Err(VarError::NotPresent) => Err(format!("{} is not set", name)),
+        Err(VarError::NotUnicode(_)) => Err(format!("{} is not valid UTF-8", name)),
+    }
+}
+
+/// Ask the gh CLI for the token of `host` (Err = why it has none)
+///
+/// Unexpected failures (e.g. a locked keyring) are retried with backoff;
+/// a missing `gh` or a host that isn't logged in are reported right away.
+async fn gh_token(host: &str) -> std::result::Result<String, String> {
+    let mut delay = GH_TOKEN_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        let output = match tokio::process::Command::new("gh")
+            .args(["auth", "token", "--hostname", host])
+            .output()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err("gh CLI is not installed".to_string());
+            }
+            Err(e) => return Err(format!("failed to run 'gh auth token': {}", e)),
+        };
+
+        if output.status.success() {
+            let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            return if token.is_empty() {
+                Err("'gh auth token' printed no token".to_string())
+            } else {
+                Ok(token)
+            };
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_not_logged_in(&stderr) {
+            return Err(format!("gh CLI is not logged in to {}", host));
+        }
+
+        let reason = stderr
+            .lines()
+            .next()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| output.status.to_string());
+        if attempt >= GH_TOKEN_ATTEMPTS {
+            return Err(format!("'gh auth token' failed: {}", reason));
+        }
+
+        debug!(
+            "'gh auth token' failed (attempt {}/{}), retrying in {:?}: {}",
+            attempt, GH_TOKEN_ATTEMPTS, delay, reason
+        );
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+        attempt += 1;
     }
 }
 
+/// Whether `gh auth token` failed because the host isn't logged in
+fn is_not_logged_in(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    stderr.contains("no oauth token") || stderr.contains("not logged in")
+}
+
 /// Manages GitHub API clients for multiple hosts
 ///
 /// Lazily creates and caches clients per host. Each client is configured
@@ -128,6 +257,8 @@ pub struct ClientManager {
     tokens: TokenResolver,
     /// Default cache mode for new clients
     cache_mode: CacheMode,
+    /// GitHub App credentials for the default host (None = token auth)
+    app_credentials: Option<AppCredentials>,
 }
 
 impl ClientManager {
@@ -138,6 +269,7 @@ impl ClientManager {
             cache,
             tokens: TokenResolver::new(),
             cache_mode: CacheMode::ReadWrite,
+            app_credentials: None,
         }
     }
 
@@ -148,6 +280,18 @@ impl ClientManager {
             cache,
             tokens: TokenResolver::new(),
             cache_mode,
+            app_credentials: None,
+        }
+    }
+
+    /// Authenticate the default host as a GitHub App installation
+    ///
+    /// Passing `None` switches back to token auth. Existing clients are
+    /// dropped so the next request uses the new credentials.
+    pub fn set_app_credentials(&mut self, credentials: Option<AppCredentials>) {
+        if self.app_credentials != credentials {
+            self.app_credentials = credentials;
+            self.clients.clear();
         }
     }
 
@@ -155,7 +299,7 @@ impl ClientManager {
     ///
     /// # Arguments
     ///
-    /// * `host` - The GitHub host (None = github.com)
+    /// * `host` - The GitHub host (None = default host)
     ///
     /// # Returns
     ///
@@ -164,7 +308,7 @@ impl ClientManager {
         &mut self,
         host: Option<&str>,
     ) -> Result<&CachedGitHubClient<OctocrabClient>> {
-        let key = host.unwrap_or(DEFAULT_HOST).to_string();
+        let key = host.unwrap_or(default_host()).to_string();
 
         if !self.clients.contains_key(&key) {
             let client = self.create_client(host).await?;
@@ -179,7 +323,7 @@ impl ClientManager {
         &mut self,
         host: Option<&str>,
     ) -> Result<&mut CachedGitHubClient<OctocrabClient>> {
-        let key = host.unwrap_or(DEFAULT_HOST).to_string();
+        let key = host.unwrap_or(default_host()).to_string();
 
         if !self.clients.contains_key(&key) {
             let client = self.create_client(host).await?;
@@ -191,7 +335,7 @@ impl ClientManager {
 
     /// Check if a client exists for the given host (without creating one)
     pub fn has_client(&self, host: Option<&str>) -> bool {
-        let key = host.unwrap_or(DEFAULT_HOST);
+        let key = host.unwrap_or(default_host());
         self.clients.contains_key(key)
     }
 
@@ -199,7 +343,7 @@ impl ClientManager {
     ///
     /// This can be useful for forcing re-authentication after token changes.
     pub fn remove_client(&mut self, host: Option<&str>) {
-        let key = host.unwrap_or(DEFAULT_HOST);
+        let key = host.unwrap_or(default_host());
         self.clients.remove(key);
     }
 
@@ -213,13 +357,21 @@ impl ClientManager {
     ) -> Result<CachedGitHubClient<OctocrabClient>> {
         // Ensure client exists
         let _ = self.get_client(host).await?;
-        let key = host.unwrap_or(DEFAULT_HOST);
+        let key = host.unwrap_or(default_host());
         Ok(self.clients.get(key).unwrap().clone())
     }
 
+    /// Re-read the token env vars (e.g. after the user exported a token)
+    ///
+    /// Clients without a token were never created, so only hosts that failed
+    /// to authenticate pick up the new tokens.
+    pub fn reload_tokens(&mut self) {
+        self.tokens = TokenResolver::new();
+    }
+
     /// Get the default token (github.com) if available
     pub fn default_token(&self) -> Option<&str> {
-        self.tokens.default_token.as_deref()
+        self.tokens.default_token.as_deref().ok()
     }
 
     /// Create a new client for the given host
@@ -227,28 +379,30 @@ impl ClientManager {
         &self,
         host: Option<&str>,
     ) -> Result<CachedGitHubClient<OctocrabClient>> {
-        let effective_host = host.unwrap_or(DEFAULT_HOST);
+        let effective_host = host.unwrap_or(default_host());
         info!("Creating GitHub client for host: {}", effective_host);
 
-        // Get token for this host
-        let token = self.tokens.get_token(host).await?;
-
-        // Build octocrab with appropriate base URI
-        let mut builder = Octocrab::builder().personal_token(token);
+        let base_url = api_base_url(effective_host);
+        let base_uri = (effective_host != DEFAULT_HOST).then_some(base_url.as_str());
 
-        let base_url = if let Some(h) = host {
-            if h != DEFAULT_HOST {
-                let uri = format!("https://{}/api/v3", h);
-                builder = builder.base_uri(&uri).context("Failed to set base URI")?;
-                uri
-            } else {
-                "https://api.github.com".to_string()
+        let octocrab = match &self.app_credentials {
+            // GitHub App auth only applies to the default host
+            Some(credentials) if effective_host == default_host() => {
+                credentials.build_octocrab(base_uri).await?
+            }
+            _ => {
+                // Get token for this host
+                let token = self.tokens.get_token(host).await?;
+
+                // Build octocrab with appropriate base URI
+                let mut builder = Octocrab::builder().personal_token(token);
+                if let Some(uri) = base_uri {
+                    builder = builder.base_uri(uri).context("Failed to set base URI")?;
+                }
+                builder.build().context("Failed to build Octocrab client")?
             }
-        } else {
-            "https://api.github.com".to_string()
         };
 
-        let octocrab = builder.build().context("Failed to build Octocrab client")?;
         let octocrab_client = OctocrabClient::with_base_url(Arc::new(octocrab), base_url);
         let cached =
             CachedGitHubClient::new(octocrab_client, Arc::clone(&self.cache), self.cache_mode);
@@ -287,6 +441,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_missing_token_error_survives_anyhow() {
+        let error: anyhow::Error = MissingTokenError {
+            host: "ghe.example.com".to_string(),
+            env_key: "GITHUB_TOKEN_GHE_EXAMPLE_COM".to_string(),
+            attempts: vec![
+                "GITHUB_TOKEN_GHE_EXAMPLE_COM is not set".to_string(),
+                "gh CLI is not installed".to_string(),
+            ],
+        }
+        .into();
+
+        let missing = error.downcast_ref::<MissingTokenError>().unwrap();
+        assert_eq!(missing.host, "ghe.example.com");
+        assert_eq!(
+            error.to_string(),
+            "No token found for host 'ghe.example.com' \
+             (GITHUB_TOKEN_GHE_EXAMPLE_COM is not set; gh CLI is not installed). \
+             Set GITHUB_TOKEN_GHE_EXAMPLE_COM or run 'gh auth login --hostname ghe.example.com'"
+        );
+    }
+
+    #[test]
+    fn test_env_token_tells_empty_from_missing() {
+        std::env::set_var("GH_CLIENT_TEST_TOKEN_SET", " token \n");
+        std::env::set_var("GH_CLIENT_TEST_TOKEN_EMPTY", "  ");
+
+        assert_eq!(env_token("GH_CLIENT_TEST_TOKEN_SET").unwrap(), "token");
+        assert_eq!(
+            env_token("GH_CLIENT_TEST_TOKEN_EMPTY").unwrap_err(),
+            "GH_CLIENT_TEST_TOKEN_EMPTY is set but empty"
+        );
+        assert_eq!(
+            env_token("GH_CLIENT_TEST_TOKEN_MISSING").unwrap_err(),
+            "GH_CLIENT_TEST_TOKEN_MISSING is not set"
+        );
+    }
+
+    #[test]
+    fn test_is_not_logged_in() {
+        assert!(is_not_logged_in("no oauth token found for github.com\n"));
+        assert!(is_not_logged_in(
+            "You are not logged into any GitHub hosts. To log in, run: gh auth login"
+        ));
+        assert!(!is_not_logged_in("error connecting to keyring"));
+    }
+
     #[test]
     fn test_client_manager_new() {
         let cache = Arc::new(Mutex::new(ApiCache::default()));
@@ -0,0 +1,126 @@
+//! GitHub host resolution
+//!
+//! Repositories without an explicit host use the default host. It is
+//! github.com unless overridden via `GITHUB_HOST` or `GH_HOST`, which lets
+//! GitHub Enterprise users point the whole application at their instance.
+
+use crate::DEFAULT_HOST;
+use log::{info, warn};
+use std::sync::OnceLock;
+
+/// Environment variables consulted (in order) for the default host
+pub const HOST_ENV_VARS: [&str; 2] = ["GITHUB_HOST", "GH_HOST"];
+
+/// Get the default host for repositories without an explicit host
+///
+/// Resolved once from [`HOST_ENV_VARS`]. Invalid values are logged and
+/// ignored in favour of github.com.
+pub fn default_host() -> &'static str {
+    static HOST: OnceLock<String> = OnceLock::new();
+
+    HOST.get_or_init(|| {
+        let Some((var, value)) = HOST_ENV_VARS.iter().find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| (*var, value))
+        }) else {
+            return DEFAULT_HOST.to_string();
+        };
+
+        match normalize_host(&value) {
+            Some(host) => {
+                info!("Using default GitHub host '{}' from {}", host, var);
+                host
+            }
+            None => {
+                warn!(
+                    "Ignoring invalid host '{}' in {}, falling back to {}",
+                    value, var, DEFAULT_HOST
+                );
+                DEFAULT_HOST.to_string()
+            }
+        }
+    })
+}
+
+/// Normalize a user supplied host into a bare `hostname[:port]`
+///
+/// Accepts an optional `http(s)://` scheme and trailing slash. Returns `None`
+/// if the result is not a valid hostname.
+pub fn normalize_host(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    let host = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .unwrap_or(trimmed)
+        .trim_end_matches('/')
+        .to_ascii_lowercase();
+
+    let (name, port) = match host.rsplit_once(':') {
+        Some((name, port)) => (name, Some(port)),
+        None => (host.as_str(), None),
+    };
+
+    let valid_name = !name.is_empty()
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    let valid_port = match port {
+        Some(port) => port.parse::<u16>().is_ok(),
+        None => true,
+    };
+
+    (valid_name && valid_port).then_some(host)
+}
+
+/// Get the REST API base URL for a host
+pub fn api_base_url(host: &str) -> String {
+    if host == DEFAULT_HOST {
+        "https://api.github.com".to_string()
+    } else {
+        format!("https://{}/api/v3", host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(
+            normalize_host("ghe.example.com"),
+            Some("ghe.example.com".to_string())
+        );
+        assert_eq!(
+            normalize_host(" https://GHE.example.com/ "),
+            Some("ghe.example.com".to_string())
+        );
+        assert_eq!(
+            normalize_host("ghe.example.com:8443"),
+            Some("ghe.example.com:8443".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_host_rejects_invalid() {
+        assert_eq!(normalize_host(""), None);
+        assert_eq!(normalize_host("ghe example.com"), None);
+        assert_eq!(normalize_host("ghe.example.com/api/v3"), None);
+        assert_eq!(normalize_host("ghe..example.com"), None);
+        assert_eq!(normalize_host("ghe.example.com:port"), None);
+    }
+
+    #[test]
+    fn test_api_base_url() {
+        assert_eq!(api_base_url("github.com"), "https://api.github.com");
+        assert_eq!(
+            api_base_url("ghe.example.com"),
+            "https://ghe.example.com/api/v3"
+        );
+    }
+}
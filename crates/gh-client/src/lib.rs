@@ -52,22 +52,29 @@
 //! # }
 //! ```
 
+pub mod app_auth;
 pub mod cached_client;
 pub mod client;
 pub mod client_manager;
+pub mod host;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock_client;
 pub mod octocrab_client;
 pub mod types;
 
 /// Default GitHub host (public GitHub)
 pub const DEFAULT_HOST: &str = "github.com";
 
+pub use app_auth::AppCredentials;
 pub use cached_client::CachedGitHubClient;
 pub use client::{CacheMode, GitHubClient};
-pub use client_manager::{ClientManager, TokenResolver};
+pub use client_manager::{is_unauthorized, ClientManager, MissingTokenError, TokenResolver};
+pub use host::{api_base_url, default_host, normalize_host};
 pub use octocrab_client::OctocrabClient;
 pub use types::{
-    CheckRun, CheckStatus, CiState, CiStatus, MergeMethod, MergeResult, PullRequest, ReviewComment,
-    ReviewEvent, WorkflowRun, WorkflowRunConclusion, WorkflowRunStatus,
+    CheckRun, CheckStatus, CiState, CiStatus, DraftReviewComment, MergeMethod, MergeResult,
+    PrCommit, PrStateFilter, PullRequest, ReviewComment, ReviewEvent, WorkflowRun,
+    WorkflowRunConclusion, WorkflowRunStatus,
 };
 
 // Re-export cache types for convenience
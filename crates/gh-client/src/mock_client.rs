@@ -0,0 +1,372 @@
+//! Scripted GitHub client for tests
+//!
+//! Serves a fixed PR list and records every call, so tests can assert what
+//! would have been sent to GitHub. Available to other crates' tests with the
+//! `test-util` feature.
+
+use crate::client::GitHubClient;
+use crate::types::{
+    CheckRun, CheckState, CheckStatus, CiState, CiStatus, DraftReviewComment, MaturityState,
+    MergeMethod, MergeResult, MergeableState, PrCommit, PrStateFilter, PullRequest, ReviewComment,
+    ReviewDecision, ReviewEvent, WorkflowRun,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::{Arc, Mutex};
+
+/// Mock client for testing
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    prs: Vec<PullRequest>,
+    /// Calls in order, e.g. "fetch_pull_requests" or "merge #1"
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockClient {
+    pub fn new(prs: Vec<PullRequest>) -> Self {
+        Self {
+            prs,
+            calls: Arc::default(),
+        }
+    }
+
+    /// Number of calls made so far
+    pub fn call_count(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+
+    /// Calls made so far
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: impl Into<String>) {
+        self.calls.lock().unwrap().push(call.into());
+    }
+}
+
+#[async_trait]
+impl GitHubClient for MockClient {
+    async fn fetch_pull_requests(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _base_branch: Option<&str>,
+    ) -> anyhow::Result<Vec<PullRequest>> {
+        self.record("fetch_pull_requests");
+        Ok(self.prs.clone())
+    }
+
+    async fn fetch_pull_requests_page(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _base_branch: Option<&str>,
+        _state: PrStateFilter,
+        per_page: u8,
+        page: u32,
+    ) -> anyhow::Result<Vec<PullRequest>> {
+        self.record("fetch_pull_requests_page");
+        Ok(self
+            .prs
+            .chunks(per_page.max(1) as usize)
+            .nth(page.saturating_sub(1) as usize)
+            .map(|chunk| chunk.to_vec())
+            .unwrap_or_default())
+    }
+
+    async fn fetch_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<PullRequest> {
+        self.record("fetch_pull_request");
+        self.prs
+            .iter()
+            .find(|pr| pr.number == pr_number)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("PR not found"))
+    }
+
+    async fn fetch_check_runs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit_sha: &str,
+    ) -> anyhow::Result<Vec<CheckRun>> {
+        self.record("fetch_check_runs");
+        Ok(vec![])
+    }
+
+    async fn fetch_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit_sha: &str,
+    ) -> anyhow::Result<CheckStatus> {
+        self.record("fetch_commit_status");
+        Ok(CheckStatus {
+            state: CheckState::Success,
+            total_count: 0,
+            statuses: vec![],
+        })
+    }
+
+    async fn merge_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+        _merge_method: MergeMethod,
+        _commit_title: Option<&str>,
+        _commit_message: Option<&str>,
+    ) -> anyhow::Result<MergeResult> {
+        self.record(format!("merge #{}", pr_number));
+        Ok(MergeResult {
+            merged: true,
+            sha: Some("abc123".to_string()),
+            message: "Merged".to_string(),
+        })
+    }
+
+    async fn update_pull_request_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        self.record(format!("rebase #{}", pr_number));
+        Ok(())
+    }
+
+    async fn create_review(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> anyhow::Result<()> {
+        self.record(format!(
+            "review #{} {:?}: {}",
+            pr_number,
+            event,
+            body.unwrap_or_default()
+        ));
+        Ok(())
+    }
+
+    async fn create_review_with_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+        _commit_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+        comments: &[DraftReviewComment],
+    ) -> anyhow::Result<()> {
+        self.record(format!(
+            "review #{} {:?} with {} comment(s): {}",
+            pr_number,
+            event,
+            comments.len(),
+            body.unwrap_or_default()
+        ));
+        Ok(())
+    }
+
+    async fn close_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        self.record(format!("close #{}", pr_number));
+        Ok(())
+    }
+
+    async fn mark_ready_for_review(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        self.record(format!("ready #{}", pr_number));
+        Ok(())
+    }
+
+    async fn convert_to_draft(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        self.record(format!("draft #{}", pr_number));
+        Ok(())
+    }
+
+    async fn set_file_viewed(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _path: &str,
+        _viewed: bool,
+    ) -> anyhow::Result<()> {
+        self.record("set_file_viewed");
+        Ok(())
+    }
+
+    async fn rerun_failed_jobs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> anyhow::Result<()> {
+        self.record("rerun_failed_jobs");
+        Ok(())
+    }
+
+    async fn rerun_workflow(&self, _owner: &str, _repo: &str, _run_id: u64) -> anyhow::Result<()> {
+        self.record("rerun_workflow");
+        Ok(())
+    }
+
+    async fn cancel_workflow_run(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _run_id: u64,
+    ) -> anyhow::Result<()> {
+        self.record("cancel_workflow_run");
+        Ok(())
+    }
+
+    async fn download_job_logs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _job_id: u64,
+    ) -> anyhow::Result<String> {
+        self.record("download_job_logs");
+        Ok(String::new())
+    }
+
+    async fn fetch_workflow_runs(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head_sha: &str,
+    ) -> anyhow::Result<Vec<WorkflowRun>> {
+        self.record("fetch_workflow_runs");
+        Ok(vec![])
+    }
+
+    async fn fetch_ci_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head_sha: &str,
+    ) -> anyhow::Result<CiStatus> {
+        self.record("fetch_ci_status");
+        Ok(CiStatus {
+            state: CiState::Success,
+            total_checks: 0,
+            passed: 0,
+            failed: 0,
+            pending: 0,
+        })
+    }
+
+    async fn create_review_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _commit_id: &str,
+        _path: &str,
+        _line: u32,
+        _side: &str,
+        _body: &str,
+    ) -> anyhow::Result<u64> {
+        self.record("create_review_comment");
+        Ok(12345) // Mock comment ID
+    }
+
+    async fn delete_review_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _comment_id: u64,
+    ) -> anyhow::Result<()> {
+        self.record("delete_review_comment");
+        Ok(())
+    }
+
+    async fn fetch_review_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> anyhow::Result<Vec<ReviewComment>> {
+        self.record("fetch_review_comments");
+        Ok(vec![]) // Empty list by default
+    }
+
+    async fn fetch_pr_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> anyhow::Result<Vec<PrCommit>> {
+        self.record("fetch_pr_commits");
+        Ok(vec![])
+    }
+
+    async fn fetch_review_decision(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> anyhow::Result<ReviewDecision> {
+        self.record("fetch_review_decision");
+        Ok(ReviewDecision::Pending)
+    }
+
+    async fn repository_exists(&self, _owner: &str, _repo: &str) -> anyhow::Result<bool> {
+        self.record("repository_exists");
+        Ok(true)
+    }
+
+    async fn fetch_viewer_login(&self) -> anyhow::Result<String> {
+        self.record("fetch_viewer_login");
+        Ok("testuser".to_string())
+    }
+}
+
+/// A ready, mergeable PR
+pub fn test_pr(number: u64) -> PullRequest {
+    PullRequest {
+        number,
+        title: format!("Test PR {}", number),
+        body: None,
+        author: "testuser".to_string(),
+        comments: 0,
+        head_sha: "abc123".to_string(),
+        base_branch: "main".to_string(),
+        head_branch: "feature".to_string(),
+        head_owner: None,
+        mergeable: Some(true),
+        mergeable_state: Some(MergeableState::Clean),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        html_url: "https://github.com/test/repo/pull/1".to_string(),
+        additions: 100,
+        deletions: 50,
+        maturity: MaturityState::Ready,
+        review_decision: ReviewDecision::Pending,
+        requested_reviewers: Vec::new(),
+    }
+}
@@ -6,9 +6,9 @@
 use crate::client::GitHubClient;
 use crate::types::{
     CheckConclusion, CheckRun, CheckRunStatus, CheckState, CheckStatus, CiState, CiStatus,
-    CommitStatus, MaturityState, MergeMethod, MergeResult, MergeableState, PullRequest,
-    ReviewComment, ReviewDecision, ReviewEvent, SortPrsExt, WorkflowRun, WorkflowRunConclusion,
-    WorkflowRunStatus,
+    CommitStatus, DraftReviewComment, MaturityState, MergeMethod, MergeResult, MergeableState,
+    PrCommit, PrStateFilter, PullRequest, ReviewComment, ReviewDecision, ReviewEvent, SortPrsExt,
+    WorkflowRun, WorkflowRunConclusion, WorkflowRunStatus,
 };
 use async_trait::async_trait;
 use log::debug;
@@ -59,6 +59,62 @@ impl OctocrabClient {
     pub fn octocrab_arc(&self) -> Arc<Octocrab> {
         Arc::clone(&self.octocrab)
     }
+
+    /// Run a GraphQL mutation that takes the pull request's node ID as `$id`
+    async fn pull_request_mutation(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        mutation: &str,
+    ) -> anyhow::Result<()> {
+        self.pull_request_mutation_with(owner, repo, pr_number, mutation, serde_json::json!({}))
+            .await
+    }
+
+    /// Like `pull_request_mutation`, with additional mutation variables
+    async fn pull_request_mutation_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        mutation: &str,
+        mut variables: serde_json::Value,
+    ) -> anyhow::Result<()> {
+        let pr = self
+            .octocrab
+            .pulls(owner, repo)
+            .get(pr_number)
+            .await
+            .map_err(format_octocrab_error)?;
+        let node_id = pr
+            .node_id
+            .ok_or_else(|| anyhow::anyhow!("PR #{} has no node ID", pr_number))?;
+
+        variables["id"] = serde_json::Value::String(node_id);
+        let payload = serde_json::json!({
+            "query": mutation,
+            "variables": variables,
+        });
+        let response: serde_json::Value = self
+            .octocrab
+            .graphql(&payload)
+            .await
+            .map_err(format_octocrab_error)?;
+
+        // GraphQL reports failures in the body with a 200 status
+        if let Some(errors) = response.get("errors").and_then(|e| e.as_array()) {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter_map(|e| e.get("message")?.as_str())
+                .collect();
+            if !errors.is_empty() {
+                anyhow::bail!("{}", messages.join(", "));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -113,6 +169,41 @@ impl GitHubClient for OctocrabClient {
         Ok(prs)
     }
 
+    async fn fetch_pull_requests_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_branch: Option<&str>,
+        state: PrStateFilter,
+        per_page: u8,
+        page: u32,
+    ) -> anyhow::Result<Vec<PullRequest>> {
+        debug!(
+            "Fetching {} PRs page {} for {}/{}",
+            state.as_str(),
+            page,
+            owner,
+            repo
+        );
+
+        let state = match state {
+            PrStateFilter::Open => octocrab::params::State::Open,
+            PrStateFilter::Closed => octocrab::params::State::Closed,
+            PrStateFilter::All => octocrab::params::State::All,
+        };
+
+        let pulls = self.octocrab.pulls(owner, repo);
+        let mut request = pulls.list().state(state).per_page(per_page).page(page);
+
+        if let Some(branch) = base_branch {
+            // .base() filters by target branch (where PR merges INTO)
+            request = request.base(branch);
+        }
+
+        let page = request.send().await?;
+        Ok(page.items.iter().map(PullRequest::from).collect())
+    }
+
     async fn fetch_pull_request(
         &self,
         owner: &str,
@@ -316,6 +407,46 @@ impl GitHubClient for OctocrabClient {
         Ok(())
     }
 
+    async fn create_review_with_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        commit_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+        comments: &[DraftReviewComment],
+    ) -> anyhow::Result<()> {
+        debug!(
+            "Creating {:?} review with {} comment(s) for PR #{} in {}/{}",
+            event,
+            comments.len(),
+            pr_number,
+            owner,
+            repo
+        );
+
+        let route = format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pr_number);
+
+        let mut payload = serde_json::json!({
+            "commit_id": commit_id,
+            "event": event,
+            "comments": comments,
+        });
+
+        if let Some(b) = body {
+            payload["body"] = serde_json::Value::String(b.to_string());
+        }
+
+        let _response: serde_json::Value = self
+            .octocrab
+            .post(route, Some(&payload))
+            .await
+            .map_err(format_octocrab_error)?;
+
+        Ok(())
+    }
+
     async fn close_pull_request(
         &self,
         owner: &str,
@@ -339,6 +470,78 @@ impl GitHubClient for OctocrabClient {
         Ok(())
     }
 
+    async fn mark_ready_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        debug!(
+            "Marking PR #{} in {}/{} ready for review",
+            pr_number, owner, repo
+        );
+
+        self.pull_request_mutation(
+            owner,
+            repo,
+            pr_number,
+            "mutation($id: ID!) { markPullRequestReadyForReview(input: { pullRequestId: $id }) { clientMutationId } }",
+        )
+        .await
+    }
+
+    async fn convert_to_draft(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<()> {
+        debug!(
+            "Converting PR #{} in {}/{} to draft",
+            pr_number, owner, repo
+        );
+
+        self.pull_request_mutation(
+            owner,
+            repo,
+            pr_number,
+            "mutation($id: ID!) { convertPullRequestToDraft(input: { pullRequestId: $id }) { clientMutationId } }",
+        )
+        .await
+    }
+
+    async fn set_file_viewed(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        path: &str,
+        viewed: bool,
+    ) -> anyhow::Result<()> {
+        debug!(
+            "Marking {} of PR #{} in {}/{} as {}",
+            path,
+            pr_number,
+            owner,
+            repo,
+            if viewed { "viewed" } else { "not viewed" }
+        );
+
+        let mutation = if viewed {
+            "mutation($id: ID!, $path: String!) { markFileAsViewed(input: { pullRequestId: $id, path: $path }) { clientMutationId } }"
+        } else {
+            "mutation($id: ID!, $path: String!) { unmarkFileAsViewed(input: { pullRequestId: $id, path: $path }) { clientMutationId } }"
+        };
+        self.pull_request_mutation_with(
+            owner,
+            repo,
+            pr_number,
+            mutation,
+            serde_json::json!({ "path": path }),
+        )
+        .await
+    }
+
     async fn rerun_failed_jobs(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()> {
         debug!(
             "Rerunning failed jobs for workflow run {} in {}/{}",
@@ -358,6 +561,69 @@ impl GitHubClient for OctocrabClient {
         Ok(())
     }
 
+    async fn rerun_workflow(&self, owner: &str, repo: &str, run_id: u64) -> anyhow::Result<()> {
+        debug!(
+            "Rerunning all jobs for workflow run {} in {}/{}",
+            run_id, owner, repo
+        );
+
+        let route = format!("/repos/{}/{}/actions/runs/{}/rerun", owner, repo, run_id);
+        let _response: serde_json::Value = self
+            .octocrab
+            .post(route, None::<&()>)
+            .await
+            .map_err(format_octocrab_error)?;
+
+        Ok(())
+    }
+
+    async fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> anyhow::Result<()> {
+        debug!("Cancelling workflow run {} in {}/{}", run_id, owner, repo);
+
+        let route = format!("/repos/{}/{}/actions/runs/{}/cancel", owner, repo, run_id);
+        let _response: serde_json::Value = self
+            .octocrab
+            .post(route, None::<&()>)
+            .await
+            .map_err(format_octocrab_error)?;
+
+        Ok(())
+    }
+
+    async fn download_job_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> anyhow::Result<String> {
+        debug!("Downloading logs for job {} in {}/{}", job_id, owner, repo);
+
+        // The endpoint answers with a redirect to the log file
+        let route = format!("/repos/{}/{}/actions/jobs/{}/logs", owner, repo, job_id);
+        let response = self
+            .octocrab
+            ._get(route)
+            .await
+            .map_err(format_octocrab_error)?;
+        let response = self
+            .octocrab
+            .follow_location_to_data(response)
+            .await
+            .map_err(format_octocrab_error)?;
+        let log = self
+            .octocrab
+            .body_to_string(response)
+            .await
+            .map_err(format_octocrab_error)?;
+
+        Ok(log)
+    }
+
     async fn fetch_workflow_runs(
         &self,
         owner: &str,
@@ -613,6 +879,72 @@ impl GitHubClient for OctocrabClient {
         Ok(comments)
     }
 
+    async fn fetch_pr_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> anyhow::Result<Vec<PrCommit>> {
+        debug!(
+            "Fetching commits for PR #{} in {}/{}",
+            pr_number, owner, repo
+        );
+
+        // GitHub lists at most 250 commits for a PR, in pages of up to 100
+        const MAX_PAGES: u32 = 3;
+        const PER_PAGE: usize = 100;
+
+        let route = format!("/repos/{}/{}/pulls/{}/commits", owner, repo, pr_number);
+
+        let mut response: Vec<serde_json::Value> = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let page_str = page.to_string();
+            let page_commits: Vec<serde_json::Value> = self
+                .octocrab
+                .get(
+                    &route,
+                    Some(&[("per_page", "100"), ("page", page_str.as_str())]),
+                )
+                .await
+                .map_err(format_octocrab_error)?;
+
+            let is_last_page = page_commits.len() < PER_PAGE;
+            response.extend(page_commits);
+            if is_last_page {
+                break;
+            }
+        }
+
+        let commits = response
+            .into_iter()
+            .filter_map(|c| {
+                let sha = c["sha"].as_str()?.to_string();
+                let message = c["commit"]["message"].as_str()?.to_string();
+                // Prefer the GitHub login, commits by unlinked emails only have a name
+                let author = c["author"]["login"]
+                    .as_str()
+                    .or_else(|| c["commit"]["author"]["name"].as_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let date = c["commit"]["author"]["date"]
+                    .as_str()
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc))?;
+                let html_url = c["html_url"].as_str()?.to_string();
+
+                Some(PrCommit {
+                    sha,
+                    message,
+                    author,
+                    date,
+                    html_url,
+                })
+            })
+            .collect();
+
+        Ok(commits)
+    }
+
     async fn fetch_review_decision(
         &self,
         owner: &str,
@@ -669,6 +1001,35 @@ impl GitHubClient for OctocrabClient {
 
         Ok(decision)
     }
+
+    async fn repository_exists(&self, owner: &str, repo: &str) -> anyhow::Result<bool> {
+        debug!("Checking that repository {}/{} exists", owner, repo);
+
+        let route = format!("/repos/{}/{}", owner, repo);
+        match self
+            .octocrab
+            .get::<serde_json::Value, _, _>(route, None::<&()>)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+                Ok(false)
+            }
+            Err(err) => Err(format_octocrab_error(err)),
+        }
+    }
+
+    async fn fetch_viewer_login(&self) -> anyhow::Result<String> {
+        debug!("Fetching the authenticated user");
+
+        let user = self
+            .octocrab
+            .current()
+            .user()
+            .await
+            .map_err(format_octocrab_error)?;
+        Ok(user.login)
+    }
 }
 
 /// Convert workflow run status string to enum
@@ -713,6 +1074,7 @@ impl From<&octocrab::models::pulls::PullRequest> for PullRequest {
             head_sha: pr.head.sha.clone(),
             base_branch: pr.base.ref_field.clone(),
             head_branch: pr.head.ref_field.clone(),
+            head_owner: fork_owner(pr),
             mergeable: pr.mergeable,
             mergeable_state: pr.mergeable_state.as_ref().map(convert_mergeable_state),
             created_at: pr.created_at.unwrap_or_else(chrono::Utc::now),
@@ -730,10 +1092,29 @@ impl From<&octocrab::models::pulls::PullRequest> for PullRequest {
                 MaturityState::Ready
             },
             review_decision: ReviewDecision::Unknown, // Fetched separately
+            // GitHub drops a reviewer from the list once they submitted a review
+            requested_reviewers: pr
+                .requested_reviewers
+                .iter()
+                .flatten()
+                .map(|u| u.login.clone())
+                .collect(),
         }
     }
 }
 
+/// Owner of the PR's HEAD repository, if it differs from the base repository owner
+fn fork_owner(pr: &octocrab::models::pulls::PullRequest) -> Option<String> {
+    let head_owner = pr.head.repo.as_ref()?.owner.as_ref()?.login.clone();
+    let base_owner = pr
+        .base
+        .repo
+        .as_ref()
+        .and_then(|repo| repo.owner.as_ref())
+        .map(|owner| owner.login.as_str());
+    (base_owner != Some(head_owner.as_str())).then_some(head_owner)
+}
+
 /// Convert octocrab MergeableState enum to our enum
 fn convert_mergeable_state(state: &octocrab::models::pulls::MergeableState) -> MergeableState {
     use octocrab::models::pulls::MergeableState as OMS;
@@ -34,6 +34,11 @@ pub struct PullRequest {
     /// HEAD branch name (e.g., "feature/foo")
     pub head_branch: String,
 
+    /// Owner of the HEAD repository when the PR comes from a fork
+    /// (None for branches of the base repository)
+    #[serde(default)]
+    pub head_owner: Option<String>,
+
     /// Whether the PR is mergeable (null if not yet computed by GitHub)
     pub mergeable: Option<bool>,
 
@@ -60,19 +65,39 @@ pub struct PullRequest {
 
     /// Review decision state (summarized from all reviews)
     pub review_decision: ReviewDecision,
+
+    /// Logins of users whose review is requested (and still outstanding)
+    #[serde(default)]
+    pub requested_reviewers: Vec<String>,
+}
+
+/// PR fields the PR list order is based on
+pub trait PrSortKey {
+    fn maturity(&self) -> MaturityState;
+    fn number(&self) -> u64;
+}
+
+impl PrSortKey for PullRequest {
+    fn maturity(&self) -> MaturityState {
+        self.maturity
+    }
+
+    fn number(&self) -> u64 {
+        self.number
+    }
 }
 
 pub trait SortPrsExt {
     fn sort_prs(&mut self);
 }
 
-impl SortPrsExt for Vec<PullRequest> {
+impl<T: PrSortKey> SortPrsExt for Vec<T> {
     /// Always sort for stable, first by maturity (Draft last) then descending by PR number
     fn sort_prs(&mut self) {
         self.sort_by(|a, b| {
-            let mat = b.maturity.cmp(&a.maturity);
+            let mat = b.maturity().cmp(&a.maturity());
             if mat == std::cmp::Ordering::Equal {
-                return b.number.cmp(&a.number);
+                return b.number().cmp(&a.number());
             }
             mat
         });
@@ -226,6 +251,30 @@ pub struct CommitStatus {
     pub target_url: Option<String>,
 }
 
+/// State filter for listing pull requests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrStateFilter {
+    /// Only open pull requests
+    #[default]
+    Open,
+    /// Only closed (including merged) pull requests
+    Closed,
+    /// Open and closed pull requests
+    All,
+}
+
+impl PrStateFilter {
+    /// GitHub API query parameter value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+            Self::All => "all",
+        }
+    }
+}
+
 /// Merge method for pull requests
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -239,6 +288,20 @@ pub enum MergeMethod {
     Rebase,
 }
 
+impl MergeMethod {
+    /// All merge methods, in the order GitHub lists them
+    pub const ALL: [MergeMethod; 3] = [Self::Merge, Self::Squash, Self::Rebase];
+
+    /// Short lowercase name (`merge`, `squash`, `rebase`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+        }
+    }
+}
+
 /// Result of a merge operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MergeResult {
@@ -384,6 +447,35 @@ pub struct ReviewComment {
     pub updated_at: DateTime<Utc>,
 }
 
+/// A commit of a pull request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrCommit {
+    /// Commit SHA
+    pub sha: String,
+    /// Full commit message
+    pub message: String,
+    /// Author's GitHub username, or the git author name if not linked to an account
+    pub author: String,
+    /// When the commit was authored
+    pub date: DateTime<Utc>,
+    /// HTML URL for viewing the commit in browser
+    pub html_url: String,
+}
+
+/// A line comment to attach to a newly created review
+///
+/// Uses GitHub's diff `position` semantics: the number of lines below the
+/// first hunk header of the file's diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftReviewComment {
+    /// File path the comment is on
+    pub path: String,
+    /// Position in the file's diff (1 = first line after the first hunk header)
+    pub position: u32,
+    /// Comment body text
+    pub body: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +496,7 @@ mod tests {
             head_sha: "abc123".to_string(),
             base_branch: "main".to_string(),
             head_branch: "feature/test".to_string(),
+            head_owner: Some("contributor".to_string()),
             mergeable: Some(true),
             mergeable_state: Some(MergeableState::Clean),
             created_at: Utc::now(),
@@ -413,6 +506,7 @@ mod tests {
             deletions: 50,
             maturity: MaturityState::Ready,
             review_decision: ReviewDecision::Approved,
+            requested_reviewers: vec!["reviewer".to_string()],
         };
 
         let json = serde_json::to_string(&pr).unwrap();
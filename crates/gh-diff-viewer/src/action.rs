@@ -29,6 +29,14 @@ pub enum DiffAction {
     NextHunk,
     /// Jump to previous hunk header
     PrevHunk,
+    /// Jump to the first change of the next file with hunks
+    NextFileChange,
+    /// Jump to the first change of the previous file with hunks
+    PrevFileChange,
+    /// Jump to the start of the next change in the current file
+    NextChange,
+    /// Jump to the start of the previous change in the current file
+    PrevChange,
 
     // === Scrolling ===
     /// Scroll down half page
@@ -49,6 +57,16 @@ pub enum DiffAction {
     FocusDiffContent,
     /// Toggle file tree visibility
     ToggleFileTree,
+    /// Grow the file tree pane by one step
+    GrowFileTree,
+    /// Shrink the file tree pane by one step
+    ShrinkFileTree,
+    /// Cycle the file status filter of the file tree
+    CycleFileStatusFilter,
+    /// Mark the selected file as viewed (or unmark it)
+    ToggleFileViewed,
+    /// Toggle between absolute and cursor-relative line numbers
+    ToggleRelativeLineNumbers,
 
     // === File Tree Operations ===
     /// Toggle expand/collapse of current tree node
@@ -88,6 +106,18 @@ pub enum DiffAction {
     /// Cancel comment editing
     CancelComment,
 
+    // === Comment List ===
+    /// Show or hide the list of all pending comments
+    ToggleCommentList,
+    /// Select the next comment in the list
+    CommentListNext,
+    /// Select the previous comment in the list
+    CommentListPrev,
+    /// Jump to the file and line of the selected comment
+    CommentListJump,
+    /// Delete the selected comment
+    CommentListDelete,
+
     // === Review ===
     /// Show review submission popup
     ShowReviewPopup,
@@ -88,6 +88,14 @@ pub enum DiffEvent {
         /// Index of the file in the files list.
         file_index: usize,
     },
+
+    /// File was marked (or unmarked) as viewed.
+    FileViewedChanged {
+        /// Path of the file.
+        file_path: String,
+        /// Whether the file is now viewed.
+        viewed: bool,
+    },
 }
 
 /// Direction for context expansion.
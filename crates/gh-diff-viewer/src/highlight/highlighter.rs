@@ -17,8 +17,10 @@ pub struct DiffHighlighter {
     cache: HashMap<u64, Vec<HighlightedSpan>>,
     /// Maximum cache size.
     max_cache_size: usize,
-    /// Cache of syntax references by file extension (avoids repeated file lookups).
+    /// Cache of resolved syntax indices by file path (avoids repeated lookups).
     syntax_cache: HashMap<String, usize>,
+    /// User-forced syntax names by file path.
+    syntax_overrides: HashMap<String, String>,
 }
 
 impl std::fmt::Debug for DiffHighlighter {
@@ -49,6 +51,7 @@ impl DiffHighlighter {
             cache: HashMap::new(),
             max_cache_size: 5000, // Increased for better performance
             syntax_cache: HashMap::new(),
+            syntax_overrides: HashMap::new(),
         }
     }
 
@@ -68,6 +71,7 @@ impl DiffHighlighter {
             cache: HashMap::new(),
             max_cache_size: 5000,
             syntax_cache: HashMap::new(),
+            syntax_overrides: HashMap::new(),
         }
     }
 
@@ -79,6 +83,7 @@ impl DiffHighlighter {
             cache: HashMap::new(),
             max_cache_size: 5000,
             syntax_cache: HashMap::new(),
+            syntax_overrides: HashMap::new(),
         }
     }
 
@@ -111,8 +116,8 @@ impl DiffHighlighter {
             return spans.clone();
         }
 
-        // Get syntax from extension cache (avoid expensive find_syntax_for_file on every line)
-        let syntax_idx = self.get_syntax_index(path);
+        // Get syntax from path cache (avoid expensive detection on every line)
+        let syntax_idx = self.get_syntax_index(path, None);
         let syntax = self
             .syntax_set
             .syntaxes()
@@ -136,29 +141,90 @@ impl DiffHighlighter {
         spans
     }
 
-    /// Get syntax index for a file path (cached by extension).
-    fn get_syntax_index(&mut self, path: &str) -> usize {
-        // Extract extension
-        let ext = std::path::Path::new(path)
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("")
-            .to_lowercase();
+    /// Force a syntax for a file path, bypassing detection.
+    ///
+    /// `syntax_name` is matched against syntect's syntax names (e.g. "Rust")
+    /// and falls back to extension tokens (e.g. "rs"). Unknown names are ignored.
+    pub fn set_syntax_override(&mut self, path: impl Into<String>, syntax_name: impl Into<String>) {
+        let path = path.into();
+        self.syntax_overrides
+            .insert(path.clone(), syntax_name.into());
+        self.syntax_cache.remove(&path);
+        // Highlighted lines are keyed by content, so drop them to re-highlight
+        self.cache.clear();
+    }
+
+    /// Resolve and cache the syntax for a file, using its first line for
+    /// shebang detection when the path alone is inconclusive.
+    ///
+    /// Call this before highlighting lines of a file whose first line is known.
+    pub fn resolve_syntax(&mut self, path: &str, first_line: Option<&str>) {
+        let _ = self.get_syntax_index(path, first_line);
+    }
 
+    /// Get the name of the syntax resolved for a path.
+    pub fn syntax_name(&mut self, path: &str) -> &str {
+        let idx = self.get_syntax_index(path, None);
+        self.syntax_set
+            .syntaxes()
+            .get(idx)
+            .map(|s| s.name.as_str())
+            .unwrap_or("Plain Text")
+    }
+
+    /// Get syntax index for a file path (cached by path).
+    ///
+    /// Detection order: user override, extension, well-known filename,
+    /// shebang of `first_line`, then plain text.
+    fn get_syntax_index(&mut self, path: &str, first_line: Option<&str>) -> usize {
         // Check syntax cache
-        if let Some(&idx) = self.syntax_cache.get(&ext) {
+        if let Some(&idx) = self.syntax_cache.get(path) {
             return idx;
         }
 
-        // Look up syntax (expensive - only do once per extension)
         let syntax = self
-            .syntax_set
-            .find_syntax_by_extension(&ext)
+            .syntax_overrides
+            .get(path)
+            .and_then(|name| {
+                self.syntax_set
+                    .find_syntax_by_name(name)
+                    .or_else(|| self.syntax_set.find_syntax_by_token(name))
+            })
             .or_else(|| {
-                // Try finding by full path as fallback
-                self.syntax_set.find_syntax_for_file(path).ok().flatten()
+                std::path::Path::new(path)
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .and_then(|ext| {
+                        self.syntax_set
+                            .find_syntax_by_extension(&ext.to_lowercase())
+                    })
             })
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+            .or_else(|| {
+                filename_syntax_token(path)
+                    .and_then(|token| self.syntax_set.find_syntax_by_token(token))
+            })
+            .or_else(|| {
+                // Some syntaxes list full filenames (e.g. "Rakefile") as extensions
+                std::path::Path::new(path)
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .and_then(|name| self.syntax_set.find_syntax_by_extension(name))
+            })
+            .or_else(|| {
+                first_line
+                    .filter(|line| line.starts_with("#!"))
+                    .and_then(|line| self.syntax_set.find_syntax_by_first_line(line))
+            });
+
+        let Some(syntax) = syntax else {
+            // Don't cache a plain-text miss until we've seen the first line,
+            // so a later shebang can still be detected
+            let plain = self.plain_text_index();
+            if first_line.is_some() {
+                self.syntax_cache.insert(path.to_string(), plain);
+            }
+            return plain;
+        };
 
         // Find index in syntax set
         let idx = self
@@ -168,11 +234,20 @@ impl DiffHighlighter {
             .position(|s| s.name == syntax.name)
             .unwrap_or(0);
 
-        // Cache by extension
-        self.syntax_cache.insert(ext, idx);
+        self.syntax_cache.insert(path.to_string(), idx);
         idx
     }
 
+    /// Index of the plain text syntax.
+    fn plain_text_index(&self) -> usize {
+        let plain = self.syntax_set.find_syntax_plain_text();
+        self.syntax_set
+            .syntaxes()
+            .iter()
+            .position(|s| s.name == plain.name)
+            .unwrap_or(0)
+    }
+
     /// Highlight content with a specific syntax.
     fn highlight_with_syntax(
         &self,
@@ -221,6 +296,33 @@ impl DiffHighlighter {
     }
 }
 
+/// Well-known extensionless filenames and the syntax token they map to.
+///
+/// Only syntaxes shipped with syntect's default set are listed.
+const FILENAME_SYNTAX_TOKENS: &[(&str, &str)] = &[
+    ("Makefile", "make"),
+    ("GNUmakefile", "make"),
+    ("makefile", "make"),
+    ("Gemfile", "rb"),
+    ("Rakefile", "rb"),
+    ("Podfile", "rb"),
+    ("Vagrantfile", "rb"),
+    ("Jenkinsfile", "groovy"),
+    (".bashrc", "sh"),
+    (".bash_profile", "sh"),
+    (".zshrc", "sh"),
+    (".profile", "sh"),
+];
+
+/// Map well-known extensionless filenames to a syntax token.
+fn filename_syntax_token(path: &str) -> Option<&'static str> {
+    let name = std::path::Path::new(path).file_name()?.to_str()?;
+    FILENAME_SYNTAX_TOKENS
+        .iter()
+        .find(|(filename, _)| *filename == name)
+        .map(|(_, token)| *token)
+}
+
 /// Convert syntect Style to our HighlightedSpan.
 fn syntect_to_span(style: Style, text: &str) -> HighlightedSpan {
     HighlightedSpan {
@@ -300,6 +402,50 @@ mod tests {
         assert!(!spans.is_empty());
     }
 
+    #[test]
+    fn test_filename_detection() {
+        let mut highlighter = DiffHighlighter::new();
+        assert_eq!(highlighter.syntax_name("build/Makefile"), "Makefile");
+        assert_eq!(highlighter.syntax_name("GNUmakefile"), "Makefile");
+    }
+
+    #[test]
+    fn test_every_mapped_filename_has_a_syntax() {
+        let mut highlighter = DiffHighlighter::new();
+        for (filename, token) in FILENAME_SYNTAX_TOKENS {
+            assert!(
+                highlighter.syntax_set.find_syntax_by_token(token).is_some(),
+                "no syntax for token {:?} of {}",
+                token,
+                filename
+            );
+            let path = format!("some/dir/{}", filename);
+            assert_ne!(highlighter.syntax_name(&path), "Plain Text", "{}", filename);
+        }
+    }
+
+    #[test]
+    fn test_shebang_detection() {
+        let mut highlighter = DiffHighlighter::new();
+        assert_eq!(highlighter.syntax_name("scripts/run"), "Plain Text");
+
+        highlighter.resolve_syntax("scripts/run", Some("#!/usr/bin/env python3"));
+        assert_eq!(highlighter.syntax_name("scripts/run"), "Python");
+    }
+
+    #[test]
+    fn test_syntax_override() {
+        let mut highlighter = DiffHighlighter::new();
+        assert_eq!(highlighter.syntax_name("config.txt"), "Plain Text");
+
+        highlighter.set_syntax_override("config.txt", "Rust");
+        assert_eq!(highlighter.syntax_name("config.txt"), "Rust");
+
+        // Unknown names fall back to detection
+        highlighter.set_syntax_override("main.rs", "NoSuchLanguage");
+        assert_eq!(highlighter.syntax_name("main.rs"), "Rust");
+    }
+
     #[test]
     fn test_available_themes() {
         let themes = DiffHighlighter::available_themes();
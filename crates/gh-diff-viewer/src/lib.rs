@@ -56,8 +56,8 @@ pub use action::DiffAction;
 pub use event::DiffEvent;
 pub use highlight::DiffHighlighter;
 pub use model::{
-    CommentPosition, DiffLine, DiffSide, FileDiff, FileStatus, Hunk, LineKind, PendingComment,
-    PullRequestDiff, ReviewEvent,
+    CommentPosition, DiffLine, DiffSide, FileDiff, FileStatus, FileStatusFilter, Hunk, LineKind,
+    PendingComment, PullRequestDiff, ReviewEvent,
 };
 pub use parser::parse_unified_diff;
 pub use state::DiffViewerState;
@@ -1,5 +1,6 @@
 //! Diff data structures representing a pull request's changes.
 
+use super::DiffSide;
 use ratatui::style::Color;
 
 /// A complete diff for a pull request.
@@ -34,6 +35,19 @@ impl PullRequestDiff {
         self.total_additions = self.files.iter().map(|f| f.additions).sum();
         self.total_deletions = self.files.iter().map(|f| f.deletions).sum();
     }
+
+    /// Total changed lines (additions plus deletions).
+    pub fn total_changes(&self) -> usize {
+        self.total_additions + self.total_deletions
+    }
+
+    /// Whether the diff exceeds the given file count or changed-line thresholds.
+    ///
+    /// A threshold of 0 disables that check.
+    pub fn is_large(&self, max_files: usize, max_changes: usize) -> bool {
+        (max_files > 0 && self.files.len() > max_files)
+            || (max_changes > 0 && self.total_changes() > max_changes)
+    }
 }
 
 /// Display info for a line: (hunk_index, optional_line_index).
@@ -166,6 +180,35 @@ impl FileDiff {
     pub fn total_lines(&self) -> usize {
         self.hunks.iter().map(|h| h.lines.len() + 1).sum() // +1 for hunk header
     }
+
+    /// Map a file line number to GitHub's diff `position`.
+    ///
+    /// GitHub counts positions from the line below the first `@@` header
+    /// (position 1); subsequent hunk headers also occupy a position.
+    /// Lines expanded locally are not part of GitHub's diff and are skipped.
+    pub fn diff_position(&self, side: DiffSide, line: u32) -> Option<u32> {
+        let mut position = 0u32;
+        for (hunk_idx, hunk) in self.hunks.iter().enumerate() {
+            if hunk_idx > 0 {
+                position += 1; // Hunk header
+            }
+            for diff_line in hunk.lines.iter().filter(|l| !l.is_expanded) {
+                if diff_line.kind == LineKind::Expansion {
+                    continue;
+                }
+                position += 1;
+                let line_no = match side {
+                    DiffSide::Left if diff_line.kind != LineKind::Addition => diff_line.old_line,
+                    DiffSide::Right if diff_line.kind != LineKind::Deletion => diff_line.new_line,
+                    _ => None,
+                };
+                if line_no == Some(line) {
+                    return Some(position);
+                }
+            }
+        }
+        None
+    }
 }
 
 /// File status in the diff.
@@ -428,6 +471,25 @@ mod tests {
         assert_eq!(file.display_name(), "src/new.rs");
     }
 
+    #[test]
+    fn test_large_diff_thresholds() {
+        let mut diff = PullRequestDiff::new("base", "head");
+        for (path, additions, deletions) in [("a.rs", 30, 10), ("b.rs", 5, 5)] {
+            let mut file = FileDiff::new(path);
+            file.additions = additions;
+            file.deletions = deletions;
+            diff.files.push(file);
+        }
+        diff.recalculate_totals();
+
+        assert_eq!(diff.total_changes(), 50);
+        assert!(!diff.is_large(2, 50));
+        assert!(diff.is_large(1, 50));
+        assert!(diff.is_large(2, 49));
+        // Zero disables a threshold
+        assert!(!diff.is_large(0, 0));
+    }
+
     #[test]
     fn test_hunk_header_format() {
         let hunk = Hunk::new(10, 5, 10, 7);
@@ -454,4 +516,40 @@ mod tests {
         assert_eq!(del.old_line, Some(8));
         assert_eq!(del.new_line, None);
     }
+
+    #[test]
+    fn test_diff_position_spans_hunks() {
+        let mut file = FileDiff::new("src/lib.rs");
+        let mut first = Hunk::new(1, 2, 1, 2);
+        first.lines.push(DiffLine::context("a", 1, 1));
+        first.lines.push(DiffLine::deletion("b", 2));
+        first.lines.push(DiffLine::addition("c", 2));
+        let mut second = Hunk::new(20, 1, 20, 2);
+        second.lines.push(DiffLine::context("x", 20, 20));
+        second.lines.push(DiffLine::addition("y", 21));
+        file.hunks = vec![first, second];
+
+        assert_eq!(file.diff_position(DiffSide::Right, 1), Some(1));
+        assert_eq!(file.diff_position(DiffSide::Left, 2), Some(2));
+        assert_eq!(file.diff_position(DiffSide::Right, 2), Some(3));
+        // Second hunk header occupies position 4
+        assert_eq!(file.diff_position(DiffSide::Right, 20), Some(5));
+        assert_eq!(file.diff_position(DiffSide::Right, 21), Some(6));
+        assert_eq!(file.diff_position(DiffSide::Right, 99), None);
+    }
+
+    #[test]
+    fn test_diff_position_skips_expanded_lines() {
+        let mut file = FileDiff::new("src/lib.rs");
+        let mut hunk = Hunk::new(5, 1, 5, 2);
+        let mut expanded = DiffLine::context("above", 4, 4);
+        expanded.is_expanded = true;
+        hunk.lines.push(expanded);
+        hunk.lines.push(DiffLine::context("a", 5, 5));
+        hunk.lines.push(DiffLine::addition("b", 6));
+        file.hunks = vec![hunk];
+
+        assert_eq!(file.diff_position(DiffSide::Right, 4), None);
+        assert_eq!(file.diff_position(DiffSide::Right, 6), Some(2));
+    }
 }
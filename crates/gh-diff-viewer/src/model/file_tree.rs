@@ -121,16 +121,39 @@ impl FileTreeNode {
 
     /// Flatten the tree into a list for rendering (respecting expanded state).
     pub fn flatten(&self) -> Vec<FlatFileEntry> {
+        self.flatten_filtered(&|_| true)
+    }
+
+    /// Flatten the tree, keeping only files matching `include`.
+    ///
+    /// Directories without matching files are left out, and directory stats
+    /// are the sum of their matching files.
+    pub fn flatten_filtered(&self, include: &dyn Fn(&FileTreeNode) -> bool) -> Vec<FlatFileEntry> {
         let mut result = Vec::new();
-        self.flatten_recursive(0, &mut result, &[]);
+        if let Some(stats) = self.visible_stats(include) {
+            self.flatten_recursive(0, &mut result, &[], stats, include);
+        }
         result
     }
 
+    /// Aggregate (additions, deletions) of matching files, None if there are none.
+    fn visible_stats(&self, include: &dyn Fn(&FileTreeNode) -> bool) -> Option<(usize, usize)> {
+        if !self.is_directory() {
+            return include(self).then_some((self.additions, self.deletions));
+        }
+        self.children
+            .iter()
+            .filter_map(|child| child.visible_stats(include))
+            .reduce(|(add, del), (a, d)| (add + a, del + d))
+    }
+
     fn flatten_recursive(
         &self,
         depth: usize,
         result: &mut Vec<FlatFileEntry>,
         ancestor_has_next: &[bool],
+        (additions, deletions): (usize, usize),
+        include: &dyn Fn(&FileTreeNode) -> bool,
     ) {
         // Skip root node itself
         if !self.name.is_empty() {
@@ -141,8 +164,8 @@ impl FileTreeNode {
                 is_dir: self.is_directory(),
                 is_expanded: self.expanded,
                 status: self.status,
-                additions: self.additions,
-                deletions: self.deletions,
+                additions,
+                deletions,
                 is_last: false, // Will be set by parent
                 ancestor_has_next: ancestor_has_next.to_vec(),
             });
@@ -151,9 +174,14 @@ impl FileTreeNode {
         // Add children if directory is expanded (or if this is root)
         if self.expanded || self.name.is_empty() {
             let child_depth = if self.name.is_empty() { 0 } else { depth + 1 };
-            let child_count = self.children.len();
+            let children: Vec<_> = self
+                .children
+                .iter()
+                .filter_map(|child| child.visible_stats(include).map(|stats| (child, stats)))
+                .collect();
+            let child_count = children.len();
 
-            for (i, child) in self.children.iter().enumerate() {
+            for (i, (child, stats)) in children.into_iter().enumerate() {
                 let is_last = i == child_count - 1;
 
                 // Build ancestor_has_next for children
@@ -164,7 +192,13 @@ impl FileTreeNode {
                     child_ancestor_has_next.push(!is_last);
                 }
 
-                child.flatten_recursive(child_depth, result, &child_ancestor_has_next);
+                child.flatten_recursive(
+                    child_depth,
+                    result,
+                    &child_ancestor_has_next,
+                    stats,
+                    include,
+                );
 
                 // Mark the entry we just added as last if applicable
                 if is_last {
@@ -223,6 +257,57 @@ impl FileTreeNode {
     }
 }
 
+/// Which files the file tree shows, by file status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileStatusFilter {
+    /// Show all files.
+    #[default]
+    All,
+    /// Show added files only.
+    Added,
+    /// Show modified files only.
+    Modified,
+    /// Show deleted files only.
+    Deleted,
+    /// Show renamed files only.
+    Renamed,
+}
+
+impl FileStatusFilter {
+    /// Next filter in the cycle (wraps around to All).
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Added,
+            Self::Added => Self::Modified,
+            Self::Modified => Self::Deleted,
+            Self::Deleted => Self::Renamed,
+            Self::Renamed => Self::All,
+        }
+    }
+
+    /// Display label for the file tree title.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Added => "Added",
+            Self::Modified => "Modified",
+            Self::Deleted => "Deleted",
+            Self::Renamed => "Renamed",
+        }
+    }
+
+    /// Whether a file with the given status passes the filter.
+    pub fn matches(self, status: Option<FileStatus>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Added => status == Some(FileStatus::Added),
+            Self::Modified => status == Some(FileStatus::Modified),
+            Self::Deleted => status == Some(FileStatus::Deleted),
+            Self::Renamed => status == Some(FileStatus::Renamed),
+        }
+    }
+}
+
 /// A flattened file tree entry for rendering.
 #[derive(Debug, Clone)]
 pub struct FlatFileEntry {
@@ -447,4 +532,38 @@ mod tests {
         };
         assert_eq!(nested_no_sibling.tree_prefix(), "   └─ ");
     }
+
+    #[test]
+    fn test_filtered_flatten_drops_empty_directories() {
+        let mut added = make_file_diff("src/new.rs", 7, 0);
+        added.status = FileStatus::Added;
+        let mut added_test = make_file_diff("tests/new_test.rs", 3, 0);
+        added_test.status = FileStatus::Added;
+        let files = vec![
+            added,
+            make_file_diff("src/main.rs", 10, 5),
+            added_test,
+            make_file_diff("docs/README.md", 1, 1),
+        ];
+        let tree = FileTreeNode::from_files(&files);
+
+        let filter = FileStatusFilter::Added;
+        let flat = tree.flatten_filtered(&|node| filter.matches(node.status));
+        let names: Vec<&str> = flat.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["src", "new.rs", "tests", "new_test.rs"]);
+
+        // Directory stats only count matching files
+        assert_eq!((flat[0].additions, flat[0].deletions), (7, 0));
+        assert!(flat[1].is_last);
+        assert!(flat[3].is_last);
+    }
+
+    #[test]
+    fn test_file_status_filter_cycles_back_to_all() {
+        let mut filter = FileStatusFilter::All;
+        for _ in 0..5 {
+            filter = filter.next();
+        }
+        assert_eq!(filter, FileStatusFilter::All);
+    }
 }
@@ -9,4 +9,4 @@ pub use diff::{
     DiffLine, DisplayLineInfo, FileDiff, FileStatus, HighlightedSpan, Hunk, LineKind,
     PullRequestDiff,
 };
-pub use file_tree::{FileTreeNode, FlatFileEntry};
+pub use file_tree::{FileStatusFilter, FileTreeNode, FlatFileEntry};
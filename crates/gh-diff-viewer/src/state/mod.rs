@@ -6,4 +6,6 @@ mod viewer_state;
 
 pub use comment_editor::CommentEditor;
 pub use navigation::{NavigationState, SelectionMode};
-pub use viewer_state::DiffViewerState;
+pub use viewer_state::{
+    DiffViewerState, DEFAULT_FILE_TREE_WIDTH, MAX_FILE_TREE_WIDTH, MIN_FILE_TREE_WIDTH,
+};
@@ -4,11 +4,20 @@ use super::{CommentEditor, NavigationState};
 use crate::action::DiffAction;
 use crate::event::{DiffEvent, ExpandDirection};
 use crate::model::{
-    DiffLine, DiffSide, FileDiff, FileTreeNode, FlatFileEntry, LineKind, PendingComment,
-    PullRequestDiff, ReviewEvent,
+    DiffLine, DiffSide, FileDiff, FileStatusFilter, FileTreeNode, FlatFileEntry, LineKind,
+    PendingComment, PullRequestDiff, ReviewEvent,
 };
 use std::collections::HashSet;
 
+/// Default width of the file tree pane (percent of the viewer width).
+pub const DEFAULT_FILE_TREE_WIDTH: u16 = 22;
+/// Minimum width of the file tree pane (percent).
+pub const MIN_FILE_TREE_WIDTH: u16 = 10;
+/// Maximum width of the file tree pane (percent).
+pub const MAX_FILE_TREE_WIDTH: u16 = 50;
+/// Step used when resizing the file tree pane (percent).
+const FILE_TREE_WIDTH_STEP: u16 = 2;
+
 /// Main state for the diff viewer widget.
 #[derive(Debug, Clone)]
 pub struct DiffViewerState {
@@ -28,6 +37,18 @@ pub struct DiffViewerState {
     pub selected_review_event: ReviewEvent,
     /// Viewport height (for scroll calculations)
     pub viewport_height: usize,
+    /// Width of the file tree pane in percent (content takes the rest).
+    pub file_tree_width: u16,
+    /// Show line numbers relative to the cursor line (default: absolute).
+    pub relative_line_numbers: bool,
+    /// Which files the file tree shows.
+    pub file_status_filter: FileStatusFilter,
+    /// Paths of files marked as viewed.
+    pub viewed_files: HashSet<String>,
+    /// Whether the pending comment list is visible.
+    pub show_comment_list: bool,
+    /// Selected entry in the comment list (index into `sorted_comment_indices`).
+    pub comment_list_cursor: usize,
 
     // === Cached state for rendering performance ===
     /// Cached flattened file tree (invalidated on expand/collapse).
@@ -50,6 +71,12 @@ impl DiffViewerState {
             show_review_popup: false,
             selected_review_event: ReviewEvent::Comment,
             viewport_height: 20, // Default, will be updated by orchestrator
+            file_tree_width: DEFAULT_FILE_TREE_WIDTH,
+            relative_line_numbers: false,
+            file_status_filter: FileStatusFilter::All,
+            viewed_files: HashSet::new(),
+            show_comment_list: false,
+            comment_list_cursor: 0,
             cached_flat_tree: None,
             cached_comment_lines: None,
         };
@@ -88,7 +115,11 @@ impl DiffViewerState {
     /// Get flattened file tree (cached).
     pub fn flat_tree(&mut self) -> &[FlatFileEntry] {
         if self.cached_flat_tree.is_none() {
-            self.cached_flat_tree = Some(self.file_tree.flatten());
+            let filter = self.file_status_filter;
+            self.cached_flat_tree = Some(
+                self.file_tree
+                    .flatten_filtered(&|node| filter.matches(node.status)),
+            );
         }
         self.cached_flat_tree.as_ref().unwrap()
     }
@@ -230,6 +261,149 @@ impl DiffViewerState {
         }
     }
 
+    /// Get display line indices where each hunk's changes start.
+    ///
+    /// This is the first added or deleted line of a hunk, or its header if the
+    /// hunk only contains context.
+    fn change_start_lines(&mut self) -> Vec<usize> {
+        let Some(file) = self.current_file_mut() else {
+            return Vec::new();
+        };
+        let display_info = file.display_info().to_vec();
+
+        let mut starts: Vec<usize> = Vec::new();
+        let mut current_hunk = None;
+        for (display_idx, (hunk_idx, line_idx)) in display_info.into_iter().enumerate() {
+            match line_idx {
+                None => {
+                    // Hunk header: fallback start until a change is found
+                    starts.push(display_idx);
+                    current_hunk = Some(hunk_idx);
+                }
+                Some(line_idx) if current_hunk == Some(hunk_idx) => {
+                    let kind = file.hunks[hunk_idx].lines[line_idx].kind;
+                    if matches!(kind, LineKind::Addition | LineKind::Deletion) {
+                        if let Some(start) = starts.last_mut() {
+                            *start = display_idx;
+                        }
+                        current_hunk = None; // Only the first change counts
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        starts
+    }
+
+    /// Jump to the next (or previous) change start in the current file.
+    fn jump_to_change(&mut self, forward: bool) -> bool {
+        let starts = self.change_start_lines();
+        let cursor = self.nav.cursor_line;
+        let target = if forward {
+            starts.into_iter().find(|&idx| idx > cursor)
+        } else {
+            starts.into_iter().rev().find(|&idx| idx < cursor)
+        };
+        if let Some(target) = target {
+            self.nav.cursor_line = target;
+            self.nav.scroll_cursor_near_top(self.viewport_height);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Jump to the first change of the next (or previous) file that has hunks.
+    ///
+    /// Files without hunks (e.g. binary files) are skipped. Selects the file,
+    /// focuses the diff content and keeps the file tree cursor in sync.
+    fn jump_to_file_change(&mut self, forward: bool) -> bool {
+        let current = self.nav.selected_file;
+        let has_hunks = |idx: &usize| !self.diff.files[*idx].hunks.is_empty();
+        let target = if forward {
+            (current + 1..self.diff.files.len()).find(has_hunks)
+        } else {
+            (0..current).rev().find(has_hunks)
+        };
+        let Some(target) = target else {
+            return false;
+        };
+
+        self.nav.exit_visual_mode();
+        self.nav.select_file(target, self.diff.files.len());
+        self.nav.file_tree_focused = false;
+        self.sync_file_tree_cursor_to_selected_file();
+        if let Some(&first) = self.change_start_lines().first() {
+            self.nav.cursor_line = first;
+            self.nav.scroll_cursor_near_top(self.viewport_height);
+        }
+        true
+    }
+
+    /// Change the file status filter of the file tree.
+    ///
+    /// If the selected file is filtered out, the first still-visible file is
+    /// selected instead.
+    pub fn set_file_status_filter(&mut self, filter: FileStatusFilter) {
+        self.file_status_filter = filter;
+        self.invalidate_flat_tree_cache();
+
+        let selected_visible = self
+            .diff
+            .files
+            .get(self.nav.selected_file)
+            .is_some_and(|f| filter.matches(Some(f.status)));
+        if !selected_visible {
+            let first_visible = self.flat_tree().iter().find_map(|e| e.path.clone());
+            if let Some(path) = first_visible {
+                if let Some(idx) = self.diff.files.iter().position(|f| f.path == path) {
+                    self.nav.exit_visual_mode();
+                    self.nav.select_file(idx, self.diff.files.len());
+                }
+            }
+        }
+
+        // Keep the tree cursor on the selected file (or within bounds)
+        self.sync_file_tree_cursor_to_selected_file();
+        let len = self.flat_tree_len();
+        self.nav.file_tree_cursor = self.nav.file_tree_cursor.min(len.saturating_sub(1));
+    }
+
+    /// File that `ToggleFileViewed` applies to: the file under the tree
+    /// cursor while the tree is focused, otherwise the selected file.
+    pub fn viewed_toggle_target(&self) -> Option<String> {
+        if self.nav.file_tree_focused {
+            let cursor = self.nav.file_tree_cursor;
+            match self.cached_flat_tree {
+                Some(ref entries) => entries.get(cursor)?.path.clone(),
+                None => {
+                    let filter = self.file_status_filter;
+                    self.file_tree
+                        .flatten_filtered(&|node| filter.matches(node.status))
+                        .get(cursor)?
+                        .path
+                        .clone()
+                }
+            }
+        } else {
+            self.current_file().map(|f| f.path.clone())
+        }
+    }
+
+    /// Mark (or unmark) a file as viewed.
+    pub fn set_file_viewed(&mut self, path: &str, viewed: bool) {
+        if viewed {
+            self.viewed_files.insert(path.to_string());
+        } else {
+            self.viewed_files.remove(path);
+        }
+    }
+
+    /// Set the file tree width, clamped to the allowed range.
+    pub fn set_file_tree_width(&mut self, percent: u16) {
+        self.file_tree_width = percent.clamp(MIN_FILE_TREE_WIDTH, MAX_FILE_TREE_WIDTH);
+    }
+
     /// Check if the comment editor is currently active.
     pub fn is_editing_comment(&self) -> bool {
         self.comment_editor.is_some()
@@ -257,6 +431,13 @@ impl DiffViewerState {
             return events;
         }
 
+        if self.show_comment_list {
+            if let Some(event) = self.handle_comment_list_action(&action) {
+                events.push(event);
+            }
+            return events;
+        }
+
         // Handle the action
         if let Some(event) = self.handle_normal_action(&action) {
             events.push(event);
@@ -285,6 +466,28 @@ impl DiffViewerState {
                 self.nav.toggle_file_tree();
                 None
             }
+            DiffAction::GrowFileTree => {
+                self.set_file_tree_width(self.file_tree_width + FILE_TREE_WIDTH_STEP);
+                None
+            }
+            DiffAction::ShrinkFileTree => {
+                self.set_file_tree_width(self.file_tree_width.saturating_sub(FILE_TREE_WIDTH_STEP));
+                None
+            }
+            DiffAction::CycleFileStatusFilter => {
+                self.set_file_status_filter(self.file_status_filter.next());
+                self.emit_selection_changed()
+            }
+            DiffAction::ToggleFileViewed => {
+                let file_path = self.viewed_toggle_target()?;
+                let viewed = !self.viewed_files.contains(&file_path);
+                self.set_file_viewed(&file_path, viewed);
+                Some(DiffEvent::FileViewedChanged { file_path, viewed })
+            }
+            DiffAction::ToggleRelativeLineNumbers => {
+                self.relative_line_numbers = !self.relative_line_numbers;
+                None
+            }
 
             // === Cursor Navigation ===
             DiffAction::CursorDown => {
@@ -362,6 +565,26 @@ impl DiffViewerState {
                 }
                 self.emit_selection_changed()
             }
+            DiffAction::NextFileChange => {
+                self.jump_to_file_change(true);
+                self.emit_selection_changed()
+            }
+            DiffAction::PrevFileChange => {
+                self.jump_to_file_change(false);
+                self.emit_selection_changed()
+            }
+            DiffAction::NextChange => {
+                if !self.nav.file_tree_focused {
+                    self.jump_to_change(true);
+                }
+                self.emit_selection_changed()
+            }
+            DiffAction::PrevChange => {
+                if !self.nav.file_tree_focused {
+                    self.jump_to_change(false);
+                }
+                self.emit_selection_changed()
+            }
 
             // === Scrolling ===
             DiffAction::ScrollHalfDown => {
@@ -449,6 +672,20 @@ impl DiffViewerState {
                 None
             }
 
+            // === Comment List ===
+            DiffAction::ToggleCommentList => {
+                self.show_comment_list = true;
+                self.comment_list_cursor = 0;
+                None
+            }
+            DiffAction::CommentListNext
+            | DiffAction::CommentListPrev
+            | DiffAction::CommentListJump
+            | DiffAction::CommentListDelete => {
+                // These are only valid while the comment list is visible
+                None
+            }
+
             // === General ===
             DiffAction::Close => Some(DiffEvent::Close),
 
@@ -540,6 +777,95 @@ impl DiffViewerState {
         }
     }
 
+    /// Handle actions when the comment list is visible.
+    fn handle_comment_list_action(&mut self, action: &DiffAction) -> Option<DiffEvent> {
+        match action {
+            DiffAction::CommentListNext | DiffAction::CursorDown => {
+                if self.comment_list_cursor + 1 < self.pending_comments.len() {
+                    self.comment_list_cursor += 1;
+                }
+                None
+            }
+            DiffAction::CommentListPrev | DiffAction::CursorUp => {
+                self.comment_list_cursor = self.comment_list_cursor.saturating_sub(1);
+                None
+            }
+            DiffAction::CommentListJump => self.jump_to_listed_comment(),
+            DiffAction::CommentListDelete => {
+                let index = *self
+                    .sorted_comment_indices()
+                    .get(self.comment_list_cursor)?;
+                let event = self.delete_pending_comment(index);
+                self.comment_list_cursor = self
+                    .comment_list_cursor
+                    .min(self.pending_comments.len().saturating_sub(1));
+                event
+            }
+            DiffAction::ToggleCommentList | DiffAction::Close => {
+                self.show_comment_list = false;
+                None
+            }
+            _ => None, // Other actions not handled in the comment list
+        }
+    }
+
+    /// Indices into `pending_comments`, sorted by file path then line.
+    pub fn sorted_comment_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.pending_comments.len()).collect();
+        indices.sort_by(|&a, &b| {
+            let (a, b) = (&self.pending_comments[a], &self.pending_comments[b]);
+            (&a.path, a.position.line).cmp(&(&b.path, b.position.line))
+        });
+        indices
+    }
+
+    /// The comment selected in the comment list.
+    pub fn selected_listed_comment(&self) -> Option<&PendingComment> {
+        let index = *self
+            .sorted_comment_indices()
+            .get(self.comment_list_cursor)?;
+        self.pending_comments.get(index)
+    }
+
+    /// Close the comment list and move the cursor to the selected comment.
+    fn jump_to_listed_comment(&mut self) -> Option<DiffEvent> {
+        let comment = self.selected_listed_comment()?;
+        let (path, side, line) = (
+            comment.path.clone(),
+            comment.position.side,
+            comment.position.line,
+        );
+        let file_idx = self.diff.files.iter().position(|f| f.path == path)?;
+
+        self.show_comment_list = false;
+        self.nav.exit_visual_mode();
+        self.nav.select_file(file_idx, self.diff.files.len());
+        self.nav.file_tree_focused = false;
+        self.sync_file_tree_cursor_to_selected_file();
+        if let Some(display_idx) = self.display_index_of_line(side, line) {
+            self.nav.cursor_line = display_idx;
+            self.nav.scroll_cursor_near_top(self.viewport_height);
+        }
+        self.emit_selection_changed()
+    }
+
+    /// Find the display index of a source line in the current file.
+    fn display_index_of_line(&mut self, side: DiffSide, line: u32) -> Option<usize> {
+        let file = self.current_file_mut()?;
+        let display_info = file.display_info().to_vec();
+        display_info.into_iter().position(|(hunk_idx, line_idx)| {
+            let Some(line_idx) = line_idx else {
+                return false;
+            };
+            let diff_line = &file.hunks[hunk_idx].lines[line_idx];
+            let line_no = match side {
+                DiffSide::Left => diff_line.old_line,
+                DiffSide::Right => diff_line.new_line,
+            };
+            line_no == Some(line)
+        })
+    }
+
     /// Open the comment editor for the current line/selection.
     /// If a comment already exists at this position, edit it instead of creating a new one.
     fn open_comment_editor(&mut self) -> Option<DiffEvent> {
@@ -746,12 +1072,20 @@ impl DiffViewerState {
     pub fn delete_pending_comment(&mut self, index: usize) -> Option<DiffEvent> {
         if index < self.pending_comments.len() {
             self.pending_comments.remove(index);
+            self.invalidate_comment_cache();
             Some(DiffEvent::CommentDeleted(index))
         } else {
             None
         }
     }
 
+    /// Remove comments that were submitted as part of a review.
+    pub fn remove_submitted_comments(&mut self, submitted: &[PendingComment]) {
+        self.pending_comments
+            .retain(|c| !submitted.iter().any(|s| s.id == c.id));
+        self.invalidate_comment_cache();
+    }
+
     /// Insert expanded context lines into a file.
     pub fn insert_expanded_lines(
         &mut self,
@@ -807,7 +1141,7 @@ impl DiffViewerState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::Hunk;
+    use crate::model::{CommentPosition, FileStatus, Hunk};
 
     fn sample_diff() -> PullRequestDiff {
         let mut diff = PullRequestDiff::new("base", "head");
@@ -926,6 +1260,50 @@ mod tests {
         assert!(!state.is_editing_comment());
     }
 
+    #[test]
+    fn test_remove_submitted_comments() {
+        let diff = sample_diff();
+        let mut state = DiffViewerState::new(diff);
+
+        let submitted = PendingComment::new(
+            "src/main.rs",
+            crate::model::CommentPosition::single(DiffSide::Right, 1),
+            "first",
+        );
+        let kept = PendingComment::new(
+            "src/main.rs",
+            crate::model::CommentPosition::single(DiffSide::Right, 2),
+            "second",
+        );
+        state.pending_comments = vec![submitted.clone(), kept];
+        assert_eq!(state.comment_lines_for_file("src/main.rs").len(), 2);
+
+        state.remove_submitted_comments(&[submitted]);
+        assert_eq!(state.pending_comments.len(), 1);
+        assert_eq!(state.pending_comments[0].body, "second");
+        assert_eq!(state.comment_lines_for_file("src/main.rs").len(), 1);
+    }
+
+    #[test]
+    fn test_file_tree_resize_is_clamped() {
+        let diff = sample_diff();
+        let mut state = DiffViewerState::new(diff);
+        assert_eq!(state.file_tree_width, DEFAULT_FILE_TREE_WIDTH);
+
+        state.handle_action(DiffAction::GrowFileTree);
+        assert_eq!(state.file_tree_width, DEFAULT_FILE_TREE_WIDTH + 2);
+
+        for _ in 0..30 {
+            state.handle_action(DiffAction::GrowFileTree);
+        }
+        assert_eq!(state.file_tree_width, MAX_FILE_TREE_WIDTH);
+
+        for _ in 0..30 {
+            state.handle_action(DiffAction::ShrinkFileTree);
+        }
+        assert_eq!(state.file_tree_width, MIN_FILE_TREE_WIDTH);
+    }
+
     #[test]
     fn test_set_viewport() {
         let diff = sample_diff();
@@ -937,4 +1315,149 @@ mod tests {
         });
         assert_eq!(state.viewport_height, 50);
     }
+
+    #[test]
+    fn test_toggle_relative_line_numbers() {
+        let mut state = DiffViewerState::new(sample_diff());
+        assert!(!state.relative_line_numbers);
+
+        state.handle_action(DiffAction::ToggleRelativeLineNumbers);
+        assert!(state.relative_line_numbers);
+        state.handle_action(DiffAction::ToggleRelativeLineNumbers);
+        assert!(!state.relative_line_numbers);
+    }
+
+    fn multi_file_diff() -> PullRequestDiff {
+        let mut diff = PullRequestDiff::new("abc", "def");
+
+        let mut first = FileDiff::new("a.rs");
+        let mut hunk = Hunk::new(1, 3, 1, 3);
+        hunk.lines.push(DiffLine::context("a", 1, 1));
+        hunk.lines.push(DiffLine::addition("b", 2));
+        hunk.lines.push(DiffLine::context("c", 2, 3));
+        first.hunks.push(hunk);
+        let mut hunk = Hunk::new(10, 2, 10, 2);
+        hunk.lines.push(DiffLine::context("d", 10, 10));
+        hunk.lines.push(DiffLine::deletion("e", 11));
+        first.hunks.push(hunk);
+        diff.files.push(first);
+
+        // Binary file without hunks
+        diff.files.push(FileDiff::new("b.png"));
+
+        let mut third = FileDiff::new("c.rs");
+        let mut hunk = Hunk::new(5, 2, 5, 2);
+        hunk.lines.push(DiffLine::context("f", 5, 5));
+        hunk.lines.push(DiffLine::addition("g", 6));
+        third.hunks.push(hunk);
+        diff.files.push(third);
+
+        diff
+    }
+
+    #[test]
+    fn test_next_and_prev_change() {
+        let mut state = DiffViewerState::new(multi_file_diff());
+        state.nav.file_tree_focused = false;
+
+        // Display lines: 0 header, 1 a, 2 +b, 3 c, 4 header, 5 d, 6 -e
+        state.handle_action(DiffAction::NextChange);
+        assert_eq!(state.nav.cursor_line, 2);
+        state.handle_action(DiffAction::NextChange);
+        assert_eq!(state.nav.cursor_line, 6);
+        state.handle_action(DiffAction::NextChange);
+        assert_eq!(state.nav.cursor_line, 6); // no further change
+        state.handle_action(DiffAction::PrevChange);
+        assert_eq!(state.nav.cursor_line, 2);
+    }
+
+    #[test]
+    fn test_file_change_navigation_skips_files_without_hunks() {
+        let mut state = DiffViewerState::new(multi_file_diff());
+
+        state.handle_action(DiffAction::NextFileChange);
+        assert_eq!(state.nav.selected_file, 2);
+        assert_eq!(state.nav.cursor_line, 2); // first change of c.rs
+        assert!(!state.nav.file_tree_focused);
+        let tree_path = state.flat_tree()[state.nav.file_tree_cursor].path.clone();
+        assert_eq!(tree_path.as_deref(), Some("c.rs"));
+
+        state.handle_action(DiffAction::NextFileChange);
+        assert_eq!(state.nav.selected_file, 2); // already at the last file
+
+        state.handle_action(DiffAction::PrevFileChange);
+        assert_eq!(state.nav.selected_file, 0);
+        assert_eq!(state.nav.cursor_line, 2);
+        assert_eq!(state.nav.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_comment_list_sorted_jump_and_delete() {
+        let mut state = DiffViewerState::new(multi_file_diff());
+        state.pending_comments = vec![
+            PendingComment::new("c.rs", CommentPosition::single(DiffSide::Right, 6), "third"),
+            PendingComment::new(
+                "a.rs",
+                CommentPosition::single(DiffSide::Left, 11),
+                "second",
+            ),
+            PendingComment::new("a.rs", CommentPosition::single(DiffSide::Right, 2), "first"),
+        ];
+
+        state.handle_action(DiffAction::ToggleCommentList);
+        assert!(state.show_comment_list);
+        assert_eq!(state.sorted_comment_indices(), vec![2, 1, 0]);
+
+        // Jump to the deleted line of a.rs (display: 0 header .. 4 header, 5 d, 6 -e)
+        state.handle_action(DiffAction::CommentListNext);
+        state.handle_action(DiffAction::CommentListJump);
+        assert!(!state.show_comment_list);
+        assert_eq!(state.nav.selected_file, 0);
+        assert_eq!(state.nav.cursor_line, 6);
+        assert!(!state.nav.file_tree_focused);
+
+        // Delete the first listed comment
+        state.handle_action(DiffAction::ToggleCommentList);
+        state.handle_action(DiffAction::CommentListDelete);
+        assert_eq!(state.pending_comments.len(), 2);
+        assert!(state.pending_comments.iter().all(|c| c.body != "first"));
+        assert!(state.comment_lines_for_file("a.rs").contains(&11));
+        assert!(!state.comment_lines_for_file("a.rs").contains(&2));
+    }
+
+    #[test]
+    fn test_file_status_filter_snaps_selection() {
+        let mut diff = multi_file_diff();
+        diff.files[2].status = FileStatus::Added;
+        let mut state = DiffViewerState::new(diff);
+        assert_eq!(state.nav.selected_file, 0);
+
+        // a.rs is modified: selection snaps to the added c.rs
+        state.handle_action(DiffAction::CycleFileStatusFilter);
+        assert_eq!(state.file_status_filter, FileStatusFilter::Added);
+        assert_eq!(state.nav.selected_file, 2);
+        assert_eq!(state.flat_tree_len(), 1);
+        assert_eq!(state.nav.file_tree_cursor, 0);
+
+        // Back to all files: selection stays
+        state.set_file_status_filter(FileStatusFilter::All);
+        assert_eq!(state.nav.selected_file, 2);
+        assert_eq!(state.flat_tree_len(), 3);
+    }
+
+    #[test]
+    fn test_toggle_file_viewed() {
+        let mut state = DiffViewerState::new(multi_file_diff());
+        state.nav.file_tree_focused = false;
+
+        let events = state.handle_action(DiffAction::ToggleFileViewed);
+        assert!(matches!(
+            &events[..],
+            [DiffEvent::FileViewedChanged { file_path, viewed: true }] if file_path == "a.rs"
+        ));
+        assert!(state.viewed_files.contains("a.rs"));
+
+        state.handle_action(DiffAction::ToggleFileViewed);
+        assert!(state.viewed_files.is_empty());
+    }
 }
@@ -0,0 +1,127 @@
+//! Pending comment list popup widget.
+
+use crate::model::PendingComment;
+use crate::traits::ThemeProvider;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Widget};
+
+/// Widget listing all pending comments, sorted by file then line.
+pub struct CommentListWidget<'a, T: ThemeProvider> {
+    /// Comments in display order.
+    comments: Vec<&'a PendingComment>,
+    /// Selected entry.
+    cursor: usize,
+    /// Theme provider.
+    theme: &'a T,
+}
+
+impl<'a, T: ThemeProvider> CommentListWidget<'a, T> {
+    /// Create a new comment list widget.
+    pub fn new(comments: Vec<&'a PendingComment>, cursor: usize, theme: &'a T) -> Self {
+        Self {
+            comments,
+            cursor,
+            theme,
+        }
+    }
+}
+
+/// First line of a comment body, used as the list snippet.
+fn snippet(body: &str) -> &str {
+    body.lines().find(|l| !l.trim().is_empty()).unwrap_or("")
+}
+
+impl<T: ThemeProvider> Widget for CommentListWidget<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Calculate popup dimensions
+        let popup_width = 80.min(area.width.saturating_sub(4));
+        let popup_height =
+            (self.comments.len().max(1) as u16 + 3).min(area.height.saturating_sub(4));
+
+        let popup_x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let popup_y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+
+        let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+        // Clear the area behind the popup
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::White))
+            .title(format!(" Pending Comments ({}) ", self.comments.len()));
+
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if self.comments.is_empty() {
+            buf.set_string(
+                inner.x,
+                inner.y,
+                "No pending comments",
+                Style::default().fg(Color::DarkGray),
+            );
+        }
+
+        // Keep the cursor visible (last row is reserved for the hint)
+        let visible = inner.height.saturating_sub(1) as usize;
+        let offset = (self.cursor + 1).saturating_sub(visible);
+        let width = inner.width as usize;
+
+        for (row, (idx, comment)) in self
+            .comments
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .enumerate()
+        {
+            let location = format!("{}:{}", comment.path, comment.position.line);
+            let text = format!("{}  {}", location, snippet(&comment.body));
+            let text: String = text.chars().take(width).collect();
+
+            let style = if idx == self.cursor {
+                Style::default()
+                    .fg(self.theme.cursor_foreground())
+                    .bg(self.theme.cursor_background())
+            } else {
+                Style::default()
+            };
+            let y = inner.y + row as u16;
+            for x in 0..inner.width {
+                buf.set_string(inner.x + x, y, " ", style);
+            }
+            buf.set_string(inner.x, y, &text, style);
+            if idx != self.cursor {
+                // Highlight the location in the comment indicator color
+                let location: String = location.chars().take(width).collect();
+                buf.set_string(
+                    inner.x,
+                    y,
+                    &location,
+                    style.fg(self.theme.comment_indicator_foreground()),
+                );
+            }
+        }
+
+        // Render key hint
+        let hint = "Enter: Jump | d: Delete | Esc: Close";
+        buf.set_string(
+            inner.x,
+            inner.y + inner.height.saturating_sub(1),
+            hint,
+            Style::default().fg(Color::DarkGray),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet_skips_blank_lines() {
+        assert_eq!(snippet("\n  \nfirst line\nsecond"), "first line");
+        assert_eq!(snippet(""), "");
+    }
+}
@@ -59,6 +59,8 @@ pub struct DiffContentWidget<'a, T: ThemeProvider> {
     focused: bool,
     /// Footer hints to display at the bottom border.
     footer_hints: Vec<FooterHint>,
+    /// Show line numbers relative to the cursor line (like Vim's `relativenumber`).
+    relative_line_numbers: bool,
 }
 
 impl<'a, T: ThemeProvider> DiffContentWidget<'a, T> {
@@ -82,6 +84,7 @@ impl<'a, T: ThemeProvider> DiffContentWidget<'a, T> {
             theme,
             focused,
             footer_hints: Vec::new(),
+            relative_line_numbers: false,
         }
     }
 
@@ -96,6 +99,12 @@ impl<'a, T: ThemeProvider> DiffContentWidget<'a, T> {
         self.footer_hints = hints;
         self
     }
+
+    /// Show line numbers relative to the cursor line.
+    pub fn with_relative_line_numbers(mut self, relative: bool) -> Self {
+        self.relative_line_numbers = relative;
+        self
+    }
 }
 
 impl<T: ThemeProvider> Widget for DiffContentWidget<'_, T> {
@@ -162,11 +171,27 @@ impl<T: ThemeProvider> Widget for DiffContentWidget<'_, T> {
         };
 
         // Use pre-computed values from render_data
-        let line_no_width = render_data.line_no_width;
+        // Relative numbers can be as large as the display line count
+        let line_no_width = if self.relative_line_numbers {
+            render_data
+                .line_no_width
+                .max(render_data.total_lines.to_string().len())
+        } else {
+            render_data.line_no_width
+        };
         let comment_lines = render_data.comment_lines;
         let visible_height = inner.height as usize;
         let file_path = file.path.as_str();
 
+        // Let the highlighter sniff a shebang when the diff starts at line 1
+        let first_line = file
+            .hunks
+            .first()
+            .filter(|h| h.new_start <= 1)
+            .and_then(|h| h.lines.first())
+            .map(|l| l.content.as_str());
+        self.highlighter.resolve_syntax(file_path, first_line);
+
         // Render visible lines by iterating directly over hunks
         // This avoids copying the entire display_info vector
         let scroll_end = self.scroll_offset + visible_height;
@@ -202,6 +227,9 @@ impl<T: ThemeProvider> Widget for DiffContentWidget<'_, T> {
                         .visual_selection
                         .map(|(start, end)| current_idx >= start && current_idx <= end)
                         .unwrap_or(false);
+                    // The cursor line keeps its absolute numbers
+                    let relative_no = (self.relative_line_numbers && !is_cursor)
+                        .then(|| current_idx.abs_diff(self.cursor_line));
 
                     self.render_diff_line(
                         line,
@@ -209,6 +237,7 @@ impl<T: ThemeProvider> Widget for DiffContentWidget<'_, T> {
                         y,
                         inner.width,
                         line_no_width,
+                        relative_no,
                         is_cursor,
                         in_selection,
                         file_path,
@@ -269,6 +298,7 @@ impl<T: ThemeProvider> DiffContentWidget<'_, T> {
         y: u16,
         width: u16,
         line_no_width: usize,
+        relative_no: Option<usize>,
         is_cursor: bool,
         in_selection: bool,
         file_path: &str,
@@ -313,11 +343,20 @@ impl<T: ThemeProvider> DiffContentWidget<'_, T> {
             base_style.fg(self.theme.line_number_foreground())
         };
 
+        let format_no = |n: Option<u32>| {
+            n.map(|n| format!("{:>width$}", n, width = line_no_width))
+                .unwrap_or_else(|| " ".repeat(line_no_width))
+        };
+        // Relative mode shows a single distance in the new line column
+        let (old_no, new_no) = match relative_no {
+            Some(distance) => (
+                " ".repeat(line_no_width),
+                format!("{:>width$}", distance, width = line_no_width),
+            ),
+            None => (format_no(line.old_line), format_no(line.new_line)),
+        };
+
         // Render old line number
-        let old_no = line
-            .old_line
-            .map(|n| format!("{:>width$}", n, width = line_no_width))
-            .unwrap_or_else(|| " ".repeat(line_no_width));
         buf.set_string(current_x, y, &old_no, line_no_style);
         current_x += line_no_width as u16;
 
@@ -326,10 +365,6 @@ impl<T: ThemeProvider> DiffContentWidget<'_, T> {
         current_x += 1;
 
         // Render new line number
-        let new_no = line
-            .new_line
-            .map(|n| format!("{:>width$}", n, width = line_no_width))
-            .unwrap_or_else(|| " ".repeat(line_no_width));
         buf.set_string(current_x, y, &new_no, line_no_style);
         current_x += line_no_width as u16;
 
@@ -473,4 +508,62 @@ mod tests {
             true,
         );
     }
+
+    fn rendered_gutter(relative: bool, cursor_line: usize) -> Vec<String> {
+        let mut file = FileDiff::new("src/test.rs");
+        let mut hunk = Hunk::new(10, 2, 10, 3);
+        hunk.lines.push(DiffLine::context("a", 10, 10));
+        hunk.lines.push(DiffLine::addition("b", 11));
+        hunk.lines.push(DiffLine::context("c", 11, 12));
+        file.hunks.push(hunk);
+
+        let line_no_width = file.line_no_width();
+        let comment_lines = HashSet::new();
+        let render_data = DiffRenderData {
+            line_no_width,
+            comment_lines: &comment_lines,
+            display_name: "src/test.rs",
+            total_lines: file.total_lines(),
+        };
+        let mut highlighter = DiffHighlighter::new();
+        let theme = DefaultTheme;
+        let area = Rect::new(0, 0, 40, 6);
+        let mut buf = Buffer::empty(area);
+        DiffContentWidget::new(
+            Some(&file),
+            Some(render_data),
+            cursor_line,
+            0,
+            &mut highlighter,
+            &theme,
+            true,
+        )
+        .with_relative_line_numbers(relative)
+        .render(area, &mut buf);
+
+        // Gutter of the diff lines (skipping border and hunk header)
+        (2..5)
+            .map(|y| {
+                (1..10)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_absolute_line_numbers_by_default() {
+        assert_eq!(
+            rendered_gutter(false, 2),
+            vec!["  10   10", "       11", "  11   12"]
+        );
+    }
+
+    #[test]
+    fn test_relative_line_numbers_keep_cursor_line_absolute() {
+        assert_eq!(
+            rendered_gutter(true, 2),
+            vec!["        1", "       11", "        1"]
+        );
+    }
 }
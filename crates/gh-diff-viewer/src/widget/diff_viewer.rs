@@ -1,6 +1,9 @@
 //! Main diff viewer composite widget.
 
-use super::{DiffContentWidget, DiffRenderData, FileTreeWidget, FooterHint, ReviewPopupWidget};
+use super::{
+    CommentListWidget, DiffContentWidget, DiffRenderData, FileTreeWidget, FooterHint,
+    ReviewPopupWidget,
+};
 use crate::highlight::DiffHighlighter;
 use crate::state::DiffViewerState;
 use crate::traits::ThemeProvider;
@@ -76,7 +79,12 @@ impl<T: ThemeProvider> DiffViewer<'_, T> {
 
         // Calculate layout
         let chunks = if state.nav.show_file_tree {
-            Layout::horizontal([Constraint::Percentage(22), Constraint::Percentage(78)]).split(area)
+            let tree_width = state.file_tree_width;
+            Layout::horizontal([
+                Constraint::Percentage(tree_width),
+                Constraint::Percentage(100u16.saturating_sub(tree_width)),
+            ])
+            .split(area)
         } else {
             Layout::horizontal([Constraint::Length(0), Constraint::Percentage(100)]).split(area)
         };
@@ -97,7 +105,9 @@ impl<T: ThemeProvider> DiffViewer<'_, T> {
                 file_tree_cursor,
                 file_tree_focused,
                 self.theme,
-            );
+            )
+            .with_filter(state.file_status_filter)
+            .with_viewed(&state.viewed_files);
             file_tree.render(chunks[0], buf);
         }
 
@@ -141,7 +151,8 @@ impl<T: ThemeProvider> DiffViewer<'_, T> {
             !file_tree_focused,
         )
         .with_selection(visual_selection)
-        .with_footer_hints(self.footer_hints.clone());
+        .with_footer_hints(self.footer_hints.clone())
+        .with_relative_line_numbers(state.relative_line_numbers);
 
         diff_content.render(chunks[1], buf);
 
@@ -155,6 +166,17 @@ impl<T: ThemeProvider> DiffViewer<'_, T> {
             popup.render(area, buf);
         }
 
+        // Render pending comment list if visible
+        if state.show_comment_list {
+            let comments = state
+                .sorted_comment_indices()
+                .into_iter()
+                .map(|idx| &state.pending_comments[idx])
+                .collect();
+            CommentListWidget::new(comments, state.comment_list_cursor, self.theme)
+                .render(area, buf);
+        }
+
         // TODO: Render comment editor popup if active
         if state.comment_editor.is_some() {
             // Comment editor popup would go here
@@ -1,9 +1,10 @@
 //! File tree widget for navigation.
 
-use crate::model::{FileStatus, FlatFileEntry};
+use crate::model::{FileStatus, FileStatusFilter, FlatFileEntry};
 use crate::traits::ThemeProvider;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Widget};
+use std::collections::HashSet;
 
 /// Widget for rendering the file tree navigation pane.
 pub struct FileTreeWidget<'a, T: ThemeProvider> {
@@ -15,6 +16,10 @@ pub struct FileTreeWidget<'a, T: ThemeProvider> {
     focused: bool,
     /// Theme provider.
     theme: &'a T,
+    /// Active file status filter (shown in the title).
+    filter: FileStatusFilter,
+    /// Paths of files marked as viewed (rendered with a checkmark, grayed out).
+    viewed: Option<&'a HashSet<String>>,
 }
 
 impl<'a, T: ThemeProvider> FileTreeWidget<'a, T> {
@@ -25,8 +30,22 @@ impl<'a, T: ThemeProvider> FileTreeWidget<'a, T> {
             selected,
             focused,
             theme,
+            filter: FileStatusFilter::All,
+            viewed: None,
         }
     }
+
+    /// Set the paths of files marked as viewed.
+    pub fn with_viewed(mut self, viewed: &'a HashSet<String>) -> Self {
+        self.viewed = Some(viewed);
+        self
+    }
+
+    /// Set the active file status filter.
+    pub fn with_filter(mut self, filter: FileStatusFilter) -> Self {
+        self.filter = filter;
+        self
+    }
 }
 
 impl<T: ThemeProvider> Widget for FileTreeWidget<'_, T> {
@@ -41,7 +60,10 @@ impl<T: ThemeProvider> Widget for FileTreeWidget<'_, T> {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(" Files ");
+            .title(match self.filter {
+                FileStatusFilter::All => " Files ".to_string(),
+                filter => format!(" Files [{}] ", filter.label()),
+            });
 
         let inner = block.inner(area);
         block.render(area, buf);
@@ -99,6 +121,12 @@ impl<T: ThemeProvider> FileTreeWidget<'_, T> {
             None => "",
         };
 
+        let is_viewed = entry
+            .path
+            .as_ref()
+            .is_some_and(|p| self.viewed.is_some_and(|v| v.contains(p)));
+        let viewed_mark = if is_viewed { "✓ " } else { "" };
+
         // Stats
         let stats = if entry.additions > 0 || entry.deletions > 0 {
             format!(" +{}/-{}", entry.additions, entry.deletions)
@@ -108,7 +136,10 @@ impl<T: ThemeProvider> FileTreeWidget<'_, T> {
 
         // Calculate available width for name
         // tree_prefix uses 3 chars per level ("├─ ", "│  ", etc.)
-        let prefix_len = tree_prefix.chars().count() + icon.chars().count() + status_char.len();
+        let prefix_len = tree_prefix.chars().count()
+            + icon.chars().count()
+            + status_char.len()
+            + viewed_mark.chars().count();
         let stats_len = stats.len();
         let available = (width as usize).saturating_sub(prefix_len + stats_len + 1);
 
@@ -166,9 +197,22 @@ impl<T: ThemeProvider> FileTreeWidget<'_, T> {
             current_x += status_char.len() as u16;
         }
 
-        // Render name
+        // Render viewed checkmark
+        if is_viewed {
+            let mark_style = if selected {
+                base_style
+            } else {
+                base_style.fg(Color::Green)
+            };
+            buf.set_string(current_x, y, viewed_mark, mark_style);
+            current_x += viewed_mark.chars().count() as u16;
+        }
+
+        // Render name (viewed files are grayed out)
         let name_style = if entry.is_dir && !selected {
             base_style.fg(self.theme.file_tree_directory_foreground())
+        } else if is_viewed && !selected {
+            base_style.fg(Color::DarkGray)
         } else {
             base_style
         };
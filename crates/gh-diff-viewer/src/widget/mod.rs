@@ -1,10 +1,12 @@
 //! Ratatui widgets for the diff viewer.
 
+mod comment_list;
 mod diff_content;
 mod diff_viewer;
 mod file_tree;
 mod review_popup;
 
+pub use comment_list::CommentListWidget;
 pub use diff_content::{DiffContentWidget, DiffRenderData, FooterHint};
 pub use diff_viewer::DiffViewer;
 pub use file_tree::FileTreeWidget;
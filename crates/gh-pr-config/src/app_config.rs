@@ -3,8 +3,10 @@
 //! Configuration loaded from gh-pr-tui.toml file.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Configuration for an external issue tracker (Jira, Linear, etc.)
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -20,6 +22,258 @@ pub struct IssueTrackerConfig {
     pub repos: Vec<String>,
 }
 
+/// Which pull requests to list by state
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PrListState {
+    /// Only open pull requests
+    #[default]
+    Open,
+    /// Only closed (including merged) pull requests
+    Closed,
+    /// Open and closed pull requests
+    All,
+}
+
+/// Per-repository overrides for the PR list query (`[[pr_list.repo]]`)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PrListOverride {
+    /// Glob patterns of repos this override applies to (e.g., ["my-org/*"])
+    pub repos: Vec<String>,
+    /// PR state filter
+    #[serde(default)]
+    pub state: Option<PrListState>,
+    /// Page size for API requests
+    #[serde(default)]
+    pub per_page: Option<u8>,
+    /// Maximum number of PRs to load
+    #[serde(default)]
+    pub max_prs: Option<usize>,
+}
+
+/// PR list query configuration (`[pr_list]` section)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrListConfig {
+    /// PR state filter: "open" (default), "closed" or "all"
+    #[serde(default)]
+    pub state: PrListState,
+    /// Page size for API requests (max 100)
+    #[serde(default = "default_per_page")]
+    pub per_page: u8,
+    /// Maximum number of PRs to load per repository
+    #[serde(default = "default_max_prs")]
+    pub max_prs: usize,
+    /// Per-repository overrides (first matching entry wins)
+    #[serde(default)]
+    pub repo: Vec<PrListOverride>,
+}
+
+fn default_per_page() -> u8 {
+    30
+}
+
+fn default_max_prs() -> usize {
+    50
+}
+
+impl Default for PrListConfig {
+    fn default() -> Self {
+        Self {
+            state: PrListState::default(),
+            per_page: default_per_page(),
+            max_prs: default_max_prs(),
+            repo: Vec::new(),
+        }
+    }
+}
+
+/// PR behaviour configuration (`[pr]` section)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrConfig {
+    /// Refresh the PR list of the active repository every N seconds (0 = disabled)
+    #[serde(default)]
+    pub auto_refresh_secs: u64,
+    /// Auto-refresh every tracked repository (staggered) instead of only the active one
+    #[serde(default)]
+    pub auto_refresh_all: bool,
+    /// Let bulk operations include selected PRs hidden by the current filter
+    /// (they are skipped by default, so only visible PRs are touched)
+    #[serde(default)]
+    pub bulk_include_hidden: bool,
+    /// Columns of the PR table in display order (e.g. `["number", "title", "status"]`);
+    /// empty shows all columns
+    #[serde(default)]
+    pub columns: Vec<String>,
+    /// Maximum number of browser tabs opened at once when opening the CI logs
+    /// of several PRs
+    #[serde(default = "default_max_browser_tabs")]
+    pub max_browser_tabs: usize,
+}
+
+fn default_max_browser_tabs() -> usize {
+    5
+}
+
+impl Default for PrConfig {
+    fn default() -> Self {
+        Self {
+            auto_refresh_secs: 0,
+            auto_refresh_all: false,
+            bulk_include_hidden: false,
+            columns: Vec::new(),
+            max_browser_tabs: default_max_browser_tabs(),
+        }
+    }
+}
+
+/// Theme configuration (`[theme]` section)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ThemeConfig {
+    /// Built-in base theme: "dark", "light" or "high-contrast"
+    /// (None = detected from the terminal background)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Per-field color overrides (e.g. `accent_primary = "#22d3ee"`), hex or named colors
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>,
+}
+
+/// Build log viewer configuration (`[log]` section)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LogConfig {
+    /// Show timestamps in front of build log lines
+    /// (the last toggled value from the session takes precedence)
+    #[serde(default)]
+    pub show_timestamps: bool,
+}
+
+/// Diff viewer configuration (`[diff]` section)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiffConfig {
+    /// Sync files marked as viewed to GitHub (they are always remembered locally)
+    #[serde(default)]
+    pub sync_viewed: bool,
+    /// Warn about a large PR above this many changed files (0 = disabled)
+    #[serde(default = "default_large_pr_files")]
+    pub large_pr_files: usize,
+    /// Warn about a large PR above this many changed lines (0 = disabled)
+    #[serde(default = "default_large_pr_changes")]
+    pub large_pr_changes: usize,
+}
+
+fn default_large_pr_files() -> usize {
+    50
+}
+
+fn default_large_pr_changes() -> usize {
+    1000
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self {
+            sync_viewed: false,
+            large_pr_files: default_large_pr_files(),
+            large_pr_changes: default_large_pr_changes(),
+        }
+    }
+}
+
+/// How to notify about finished CI runs
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyMode {
+    /// No notification
+    Off,
+    /// Terminal bell
+    #[default]
+    Bell,
+    /// Desktop notification (`notify-send` on Linux, `osascript` on macOS)
+    Desktop,
+    /// Terminal bell and desktop notification
+    Both,
+}
+
+/// Notification configuration (`[notify]` section)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NotifyConfig {
+    /// Notification when CI of a watched PR finishes:
+    /// "off", "bell" (default), "desktop" or "both"
+    #[serde(default)]
+    pub ci: NotifyMode,
+}
+
+/// Main loop tuning (`[ui]` section)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UiConfig {
+    /// Time spent applying queued background results before the next frame
+    /// is drawn, in milliseconds (clamped to 1..=200)
+    #[serde(default = "default_frame_budget_ms")]
+    pub frame_budget_ms: u64,
+    /// Input poll timeout while idle, in milliseconds (clamped to 10..=2000).
+    /// Larger values save CPU and redraws on slow connections.
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+}
+
+fn default_frame_budget_ms() -> u64 {
+    16 // ~60fps
+}
+
+fn default_poll_interval_ms() -> u64 {
+    250
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            frame_budget_ms: default_frame_budget_ms(),
+            poll_interval_ms: default_poll_interval_ms(),
+        }
+    }
+}
+
+impl UiConfig {
+    /// Frame budget, clamped to a sane range
+    pub fn frame_budget(&self) -> Duration {
+        Duration::from_millis(self.frame_budget_ms.clamp(1, 200))
+    }
+
+    /// Idle poll interval, clamped to a sane range
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms.clamp(10, 2000))
+    }
+}
+
+/// How to authenticate against GitHub
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMode {
+    /// Personal access token (env var or `gh auth token`)
+    #[default]
+    Pat,
+    /// GitHub App installation token
+    App,
+}
+
+/// Authentication configuration (`[auth]` section)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AuthConfig {
+    /// Authentication mode: "pat" (default) or "app"
+    #[serde(default)]
+    pub mode: AuthMode,
+    /// GitHub App ID (`GITHUB_APP_ID` takes precedence)
+    #[serde(default)]
+    pub app_id: Option<u64>,
+    /// Path to the GitHub App private key PEM (`GITHUB_APP_PRIVATE_KEY_PATH` takes precedence)
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+    /// Installation to authenticate as (`GITHUB_APP_INSTALLATION_ID` takes
+    /// precedence; if neither is set, the App's first installation)
+    #[serde(default)]
+    pub installation_id: Option<u64>,
+}
+
 /// Application configuration loaded from gh-pr-tui.toml
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AppConfig {
@@ -31,7 +285,8 @@ pub struct AppConfig {
     #[serde(default = "default_temp_dir")]
     pub temp_dir: String,
 
-    /// Default message for PR approvals
+    /// Default message for PR approvals (`$NUMBER`, `$AUTHOR` and `$TITLE`
+    /// are substituted per PR)
     #[serde(default = "default_approval_message")]
     pub approval_message: String,
 
@@ -50,6 +305,47 @@ pub struct AppConfig {
     /// External issue tracker configurations
     #[serde(default)]
     pub issue_tracker: Vec<IssueTrackerConfig>,
+
+    /// GitHub authentication
+    #[serde(default)]
+    pub auth: AuthConfig,
+
+    /// Which PRs to load and how many
+    #[serde(default)]
+    pub pr_list: PrListConfig,
+
+    /// PR behaviour (auto-refresh)
+    #[serde(default)]
+    pub pr: PrConfig,
+
+    /// Custom keybindings: key pattern (e.g. "ctrl+r", "g g") -> command id
+    /// (e.g. "pr_refresh"), merged over the default keymap
+    #[serde(default)]
+    pub keybindings: BTreeMap<String, String>,
+
+    /// Color theme
+    #[serde(default)]
+    pub theme: ThemeConfig,
+
+    /// Build log viewer
+    #[serde(default)]
+    pub log: LogConfig,
+
+    /// Diff viewer
+    #[serde(default)]
+    pub diff: DiffConfig,
+
+    /// Main loop tuning
+    #[serde(default)]
+    pub ui: UiConfig,
+
+    /// Notifications (e.g. CI of watched PRs finished)
+    #[serde(default)]
+    pub notify: NotifyConfig,
+
+    /// Local working copies: "org/repo" -> checkout directory (`~/` is expanded)
+    #[serde(default)]
+    pub local_repos: BTreeMap<String, String>,
 }
 
 fn default_ide_command() -> String {
@@ -89,6 +385,16 @@ impl Default for AppConfig {
             request_changes_message: default_request_changes_message(),
             close_message: default_close_message(),
             issue_tracker: Vec::new(),
+            auth: AuthConfig::default(),
+            pr_list: PrListConfig::default(),
+            pr: PrConfig::default(),
+            keybindings: BTreeMap::new(),
+            theme: ThemeConfig::default(),
+            log: LogConfig::default(),
+            diff: DiffConfig::default(),
+            ui: UiConfig::default(),
+            notify: NotifyConfig::default(),
+            local_repos: BTreeMap::new(),
         }
     }
 }
@@ -113,6 +419,15 @@ impl AppConfig {
         log::debug!("Using default app config");
         Self::default()
     }
+
+    /// Local working copy configured for `org/repo`, with a leading `~/` expanded
+    pub fn local_repo_path(&self, org: &str, repo: &str) -> Option<PathBuf> {
+        let path = self.local_repos.get(&format!("{}/{}", org, repo))?;
+        match path.strip_prefix("~/") {
+            Some(rest) => std::env::home_dir().map(|home| home.join(rest)),
+            None => Some(PathBuf::from(path)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -196,6 +511,160 @@ repos = ["my-org/*", "other-org/repo"]
         assert_eq!(config.issue_tracker[0].repos[0], "my-org/*");
     }
 
+    #[test]
+    fn test_auth_config_parsing() {
+        let config = AppConfig::default();
+        assert_eq!(config.auth.mode, AuthMode::Pat);
+
+        let toml = r#"
+[auth]
+mode = "app"
+app_id = 12345
+private_key_path = "/etc/gh-pr-lander/app.pem"
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.auth.mode, AuthMode::App);
+        assert_eq!(config.auth.app_id, Some(12345));
+        assert_eq!(
+            config.auth.private_key_path.as_deref(),
+            Some("/etc/gh-pr-lander/app.pem")
+        );
+        assert_eq!(config.auth.installation_id, None);
+    }
+
+    #[test]
+    fn test_pr_list_config_parsing() {
+        let config = AppConfig::default();
+        assert_eq!(config.pr_list.state, PrListState::Open);
+        assert_eq!(config.pr_list.per_page, 30);
+        assert_eq!(config.pr_list.max_prs, 50);
+
+        let toml = r#"
+[pr_list]
+max_prs = 200
+
+[[pr_list.repo]]
+repos = ["my-org/*"]
+state = "all"
+per_page = 100
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.pr_list.state, PrListState::Open);
+        assert_eq!(config.pr_list.per_page, 30);
+        assert_eq!(config.pr_list.max_prs, 200);
+        assert_eq!(config.pr_list.repo.len(), 1);
+        assert_eq!(config.pr_list.repo[0].state, Some(PrListState::All));
+        assert_eq!(config.pr_list.repo[0].per_page, Some(100));
+        assert_eq!(config.pr_list.repo[0].max_prs, None);
+    }
+
+    #[test]
+    fn test_keybindings_parsing() {
+        let toml = r#"
+[keybindings]
+"ctrl+r" = "pr_refresh_all"
+"g g" = "navigate_to_top"
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.keybindings.len(), 2);
+        assert_eq!(config.keybindings["ctrl+r"], "pr_refresh_all");
+        assert_eq!(config.keybindings["g g"], "navigate_to_top");
+    }
+
+    #[test]
+    fn test_pr_config_parsing() {
+        assert_eq!(AppConfig::default().pr.auto_refresh_secs, 0);
+
+        let toml = r#"
+[pr]
+auto_refresh_secs = 120
+auto_refresh_all = true
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.pr.auto_refresh_secs, 120);
+        assert!(config.pr.auto_refresh_all);
+    }
+
+    #[test]
+    fn test_theme_config_parsing() {
+        assert_eq!(AppConfig::default().theme.name, None);
+
+        let toml = r##"
+[theme]
+name = "light"
+
+[theme.colors]
+accent_primary = "#ff8800"
+status_error = "red"
+        "##;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.theme.name.as_deref(), Some("light"));
+        assert_eq!(config.theme.colors["accent_primary"], "#ff8800");
+        assert_eq!(config.theme.colors["status_error"], "red");
+    }
+
+    #[test]
+    fn test_ui_config_parsing() {
+        let config = AppConfig::default();
+        assert_eq!(config.ui.frame_budget(), Duration::from_millis(16));
+        assert_eq!(config.ui.poll_interval(), Duration::from_millis(250));
+
+        let toml = r#"
+[ui]
+frame_budget_ms = 0
+poll_interval_ms = 60000
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        // Out-of-range values are clamped
+        assert_eq!(config.ui.frame_budget(), Duration::from_millis(1));
+        assert_eq!(config.ui.poll_interval(), Duration::from_millis(2000));
+    }
+
+    #[test]
+    fn test_notify_config_parsing() {
+        assert_eq!(AppConfig::default().notify.ci, NotifyMode::Bell);
+
+        let toml = r#"
+[notify]
+ci = "both"
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.notify.ci, NotifyMode::Both);
+    }
+
+    #[test]
+    fn test_log_config_parsing() {
+        assert!(!AppConfig::default().log.show_timestamps);
+
+        let toml = r#"
+[log]
+show_timestamps = true
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert!(config.log.show_timestamps);
+    }
+
+    #[test]
+    fn test_local_repos_parsing() {
+        let toml = r#"
+[local_repos]
+"my-org/api" = "/work/api"
+"my-org/web" = "~/code/web"
+        "#;
+        let config: AppConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.local_repo_path("my-org", "api"),
+            Some(PathBuf::from("/work/api"))
+        );
+        if let Some(home) = std::env::home_dir() {
+            assert_eq!(
+                config.local_repo_path("my-org", "web"),
+                Some(home.join("code/web"))
+            );
+        }
+        assert_eq!(config.local_repo_path("my-org", "other"), None);
+    }
+
     #[test]
     fn test_load_with_override_path_reads_that_file() {
         let path = env::temp_dir().join(format!(
@@ -0,0 +1,83 @@
+//! Atomic file writes
+//!
+//! Content is written to a temporary file next to the destination, which is
+//! then renamed over it. An interrupted write (crash, full disk) leaves the
+//! previous file intact instead of a truncated one.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Atomically replace the file at `path` with `contents`
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    write_atomic_with(path, |file| file.write_all(contents.as_ref()))
+}
+
+/// Atomically replace the file at `path` with whatever `write` writes
+///
+/// If `write` fails, the destination is left untouched and the temporary
+/// file is removed.
+pub fn write_atomic_with(
+    path: &Path,
+    write: impl FnOnce(&mut File) -> io::Result<()>,
+) -> io::Result<()> {
+    let temp = temp_path(path);
+    let result = File::create(&temp).and_then(|mut file| {
+        write(&mut file)?;
+        file.sync_all()
+    });
+    let result = result.and_then(|_| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Temporary file next to `path` (same directory, so the rename stays on one filesystem)
+fn temp_path(path: &Path) -> PathBuf {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    PathBuf::from(temp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "gh-pr-config-atomic-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_write_replaces_file() {
+        let path = test_path("replace");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!temp_path(&path).exists());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_failed_write_keeps_previous_file() {
+        let path = test_path("failure");
+        fs::write(&path, r#"["previous"]"#).unwrap();
+
+        // Simulate a full disk after part of the content was written
+        let result = write_atomic_with(&path, |file| {
+            file.write_all(b"[\"trunc")?;
+            Err(io::Error::other("no space left on device"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"["previous"]"#);
+        assert!(!temp_path(&path).exists());
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -6,27 +6,33 @@
 //! - Application configuration (AppConfig)
 //! - Session persistence (Session)
 //! - Recent repositories persistence
+//! - Atomic file writes for persisted state
 
 /// Default GitHub host (public GitHub)
 pub const DEFAULT_HOST: &str = "github.com";
 
 pub mod app_config;
+pub mod atomic_write;
 pub mod config_file;
 pub mod files; // Deprecated: use `paths` module instead
 pub mod paths;
 pub mod recent_repositories;
 pub mod session;
 
-pub use app_config::{AppConfig, IssueTrackerConfig};
+pub use app_config::{
+    AppConfig, AuthConfig, AuthMode, DiffConfig, IssueTrackerConfig, LogConfig, NotifyConfig,
+    NotifyMode, PrConfig, PrListConfig, PrListOverride, PrListState, ThemeConfig, UiConfig,
+};
 pub use config_file::load_config_file;
 pub use paths::{
     api_cache_path, app_config_path, cache_dir, config_dir, global_session_path, has_local_session,
-    local_session_path, recent_repositories_path,
+    local_session_path, pr_export_path, recent_repositories_path,
 };
 pub use recent_repositories::{
-    load_recent_repositories, save_recent_repositories, RecentRepository,
+    load_recent_repositories, load_recent_repositories_checked, save_recent_repositories,
+    RecentRepositoriesLoad, RecentRepository,
 };
-pub use session::Session;
+pub use session::{pr_key, repo_key, Session};
 
 // Re-export deprecated functions for backward compatibility
 #[allow(deprecated)]
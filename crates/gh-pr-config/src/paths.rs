@@ -48,6 +48,12 @@ pub fn app_config_path() -> Result<PathBuf> {
     Ok(config_dir()?.join("config.toml"))
 }
 
+/// JSON export of a repository's PR list in the home directory.
+pub fn pr_export_path(org: &str, repo: &str) -> Result<PathBuf> {
+    let home = std::env::home_dir().context("Could not determine home directory")?;
+    Ok(home.join(format!("{APP_NAME}-export-{org}-{repo}.json")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,11 +2,14 @@
 //!
 //! Handles loading and saving recently used repositories.
 
-#[allow(deprecated)] // Intentionally using legacy path until migration complete
-use crate::files::{create_recent_repositories_file, open_recent_repositories_file};
-use crate::DEFAULT_HOST;
+use crate::atomic_write::write_atomic;
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+/// Legacy location of the recent repositories file (relative to the working directory)
+const RECENT_REPOSITORIES_FILE: &str = ".gh-pr-lander.repos.json";
 
 /// A recently used repository entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,7 +21,10 @@ pub struct RecentRepository {
     /// Branch name (default: "main")
     #[serde(default = "default_branch")]
     pub branch: String,
-    /// GitHub host (None = github.com)
+    /// GitHub host, persisted so GHE repositories round-trip
+    ///
+    /// None for older files without a host: the default host (`GITHUB_HOST`/
+    /// `GH_HOST` or github.com) is resolved when the repository is used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub host: Option<String>,
 }
@@ -43,8 +49,8 @@ impl RecentRepository {
         branch: impl Into<String>,
         host: Option<String>,
     ) -> Self {
-        // Normalize github.com to None
-        let host = host.filter(|h| h != DEFAULT_HOST && !h.is_empty());
+        // An empty host means the default host
+        let host = host.filter(|h| !h.is_empty());
         Self {
             org: org.into(),
             repo: repo.into(),
@@ -52,54 +58,107 @@ impl RecentRepository {
             host,
         }
     }
+}
 
-    /// Get the effective host (defaults to github.com)
-    pub fn effective_host(&self) -> &str {
-        self.host.as_deref().unwrap_or(DEFAULT_HOST)
-    }
+/// Outcome of loading the recent repositories file
+#[derive(Debug)]
+pub enum RecentRepositoriesLoad {
+    /// The file was read (it may list no repositories)
+    Loaded(Vec<RecentRepository>),
+    /// There is no file yet
+    Missing,
+    /// The file could not be read or parsed. It was moved to `backup` (None if
+    /// that failed too), so the next save doesn't overwrite the user's data.
+    Corrupt {
+        error: String,
+        backup: Option<PathBuf>,
+    },
+}
 
-    /// Check if this is a github.com repository
-    pub fn is_github_com(&self) -> bool {
-        self.host.is_none()
+impl RecentRepositoriesLoad {
+    /// The loaded repositories (empty if the file is missing or corrupt)
+    pub fn into_repositories(self) -> Vec<RecentRepository> {
+        match self {
+            Self::Loaded(repos) => repos,
+            Self::Missing | Self::Corrupt { .. } => Vec::new(),
+        }
     }
 }
 
 /// Load recent repositories from the config file
 ///
 /// Returns an empty vector if the file doesn't exist or can't be parsed.
+/// Use [`load_recent_repositories_checked`] to tell these cases apart.
 pub fn load_recent_repositories() -> Vec<RecentRepository> {
-    #[allow(deprecated)] // Intentionally using legacy path until migration complete
-    match open_recent_repositories_file() {
-        Ok(file) => {
-            let reader = BufReader::new(file);
-            match serde_json::from_reader(reader) {
-                Ok(repos) => {
-                    log::info!("Loaded recent repositories from .gh-pr-lander.repos.json");
-                    repos
+    load_recent_repositories_checked().into_repositories()
+}
+
+/// Load recent repositories, backing up a corrupt file
+pub fn load_recent_repositories_checked() -> RecentRepositoriesLoad {
+    load_recent_repositories_from(Path::new(RECENT_REPOSITORIES_FILE))
+}
+
+fn load_recent_repositories_from(path: &Path) -> RecentRepositoriesLoad {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            log::debug!("No recent repositories file found, starting fresh");
+            return RecentRepositoriesLoad::Missing;
+        }
+        Err(e) => {
+            // Unreadable (e.g. permissions): leave the file alone
+            log::warn!("Failed to open {}: {}", path.display(), e);
+            return RecentRepositoriesLoad::Corrupt {
+                error: e.to_string(),
+                backup: None,
+            };
+        }
+    };
+
+    match serde_json::from_reader(BufReader::new(file)) {
+        Ok(repos) => {
+            log::info!("Loaded recent repositories from {}", path.display());
+            RecentRepositoriesLoad::Loaded(repos)
+        }
+        Err(e) => {
+            log::warn!("Failed to parse recent repositories file: {}", e);
+            let mut backup = path.as_os_str().to_owned();
+            backup.push(".bak");
+            let backup = PathBuf::from(backup);
+            let backup = match std::fs::rename(path, &backup) {
+                Ok(()) => {
+                    log::warn!("Moved corrupt {} to {}", path.display(), backup.display());
+                    Some(backup)
                 }
                 Err(e) => {
-                    log::warn!("Failed to parse recent repositories file: {}", e);
-                    Vec::new()
+                    log::error!("Failed to back up {}: {}", path.display(), e);
+                    None
                 }
+            };
+            RecentRepositoriesLoad::Corrupt {
+                error: e.to_string(),
+                backup,
             }
         }
-        Err(_) => {
-            log::debug!("No recent repositories file found, starting fresh");
-            Vec::new()
-        }
     }
 }
 
 /// Save recent repositories to the config file
 ///
-/// Returns an error if the file cannot be created or written.
+/// The file is replaced atomically, so an interrupted save keeps the previous
+/// list. Returns an error if the file cannot be written.
 pub fn save_recent_repositories(repos: &[RecentRepository]) -> anyhow::Result<()> {
-    #[allow(deprecated)] // Intentionally using legacy path until migration complete
-    let file = create_recent_repositories_file()?;
-    serde_json::to_writer_pretty(file, repos)?;
+    let content = serde_json::to_string_pretty(repos)?;
+    write_atomic(Path::new(RECENT_REPOSITORIES_FILE), content).with_context(|| {
+        format!(
+            "Failed to write recent repositories file ({})",
+            RECENT_REPOSITORIES_FILE
+        )
+    })?;
     log::info!(
-        "Saved {} recent repositories to .gh-pr-lander.repos.json",
-        repos.len()
+        "Saved {} recent repositories to {}",
+        repos.len(),
+        RECENT_REPOSITORIES_FILE
     );
     Ok(())
 }
@@ -141,30 +200,37 @@ mod tests {
             "main",
             Some("github.example.com".to_string()),
         );
-        assert_eq!(repo.host, Some("github.example.com".to_string()));
-        assert_eq!(repo.effective_host(), "github.example.com");
-        assert!(!repo.is_github_com());
+        assert_eq!(repo.host.as_deref(), Some("github.example.com"));
     }
 
     #[test]
     fn test_host_normalization() {
-        // github.com should be normalized to None
         let repo =
             RecentRepository::with_host("org", "repo", "main", Some("github.com".to_string()));
-        assert!(repo.host.is_none());
-        assert!(repo.is_github_com());
+        assert_eq!(repo.host.as_deref(), Some("github.com"));
 
-        // Empty string should be normalized to None
+        // No host or an empty string means the default host
+        let repo = RecentRepository::with_host("org", "repo", "main", None);
+        assert_eq!(repo.host, None);
         let repo = RecentRepository::with_host("org", "repo", "main", Some("".to_string()));
-        assert!(repo.host.is_none());
+        assert_eq!(repo.host, None);
     }
 
     #[test]
-    fn test_host_serde_skip_none() {
-        let repo = RecentRepository::new("org", "repo", "main");
+    fn test_host_serde_persists_github_com() {
+        let repo =
+            RecentRepository::with_host("org", "repo", "main", Some("github.com".to_string()));
         let json = serde_json::to_string(&repo).unwrap();
-        // host should not appear in JSON when None
-        assert!(!json.contains("host"));
+        assert!(json.contains(r#""host":"github.com""#));
+    }
+
+    #[test]
+    fn test_missing_host_uses_default_host() {
+        // Files written before the host was persisted have no host field,
+        // they resolve to the default host (which may be GITHUB_HOST) at use
+        let json = r#"[{"org": "test", "repo": "repo", "branch": "main"}]"#;
+        let parsed: Vec<RecentRepository> = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed[0].host, None);
     }
 
     #[test]
@@ -175,6 +241,56 @@ mod tests {
         assert!(json.contains("ghe.example.com"));
 
         let parsed: RecentRepository = serde_json::from_str(&json).unwrap();
-        assert_eq!(parsed.host, Some("ghe.example.com".to_string()));
+        assert_eq!(parsed.host.as_deref(), Some("ghe.example.com"));
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "gh-pr-config-{}-{}.repos.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let path = temp_path("missing");
+        assert!(matches!(
+            load_recent_repositories_from(&path),
+            RecentRepositoriesLoad::Missing
+        ));
+    }
+
+    #[test]
+    fn test_load_valid_file() {
+        let path = temp_path("valid");
+        std::fs::write(&path, r#"[{"org": "test", "repo": "repo"}]"#).unwrap();
+
+        let result = load_recent_repositories_from(&path);
+
+        let _ = std::fs::remove_file(&path);
+        let repos = result.into_repositories();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].repo, "repo");
+    }
+
+    #[test]
+    fn test_corrupt_file_is_backed_up() {
+        let path = temp_path("corrupt");
+        std::fs::write(&path, r#"[{"org": "test", "repo": "#).unwrap();
+
+        let result = load_recent_repositories_from(&path);
+
+        let RecentRepositoriesLoad::Corrupt { backup, .. } = result else {
+            panic!("expected a corrupt file, got {:?}", result);
+        };
+        let backup = backup.expect("corrupt file should be backed up");
+        assert!(!path.exists());
+        assert_eq!(
+            std::fs::read_to_string(&backup).unwrap(),
+            r#"[{"org": "test", "repo": "#
+        );
+        assert!(backup.to_string_lossy().ends_with(".repos.json.bak"));
+        let _ = std::fs::remove_file(&backup);
     }
 }
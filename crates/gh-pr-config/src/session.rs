@@ -12,9 +12,11 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
+use crate::atomic_write::write_atomic;
 use crate::paths;
 use crate::DEFAULT_HOST;
 
@@ -38,6 +40,25 @@ pub struct SessionData {
     pub selected_repo_host: Option<String>,
     /// Selected PR number (not index) - more stable across refreshes
     pub selected_pr_no: Option<usize>,
+    /// Last-used diff viewer file tree width (percent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_file_tree_width: Option<u16>,
+    /// Last toggled build log timestamp display
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_log_show_timestamps: Option<bool>,
+    /// Files marked as viewed in the diff viewer, per PR (see `pr_key`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub viewed_files: BTreeMap<String, BTreeSet<String>>,
+    /// Snoozed PRs with their `updated_at` when snoozed (see `pr_key`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub snoozed_prs: BTreeMap<String, DateTime<Utc>>,
+    /// Last-used PR filter per repository (see `repo_key`); repositories
+    /// without an entry show all PRs
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub repo_filters: BTreeMap<String, String>,
+    /// How often each command was run from the command palette, by command id
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub command_usage: BTreeMap<String, u32>,
 }
 
 /// Complete session with metadata
@@ -118,7 +139,8 @@ impl Session {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(path, content)
+        // Atomic, so an interrupted save never leaves a truncated session behind
+        write_atomic(path, content)
             .with_context(|| format!("Failed to write session file: {:?}", path))?;
 
         log::info!("Saved session to {:?}", path);
@@ -141,6 +163,95 @@ impl Session {
         self.session.selected_pr_no = Some(pr_no);
     }
 
+    /// Update the diff viewer file tree width (percent)
+    pub fn set_diff_file_tree_width(&mut self, width: u16) {
+        self.session.diff_file_tree_width = Some(width);
+    }
+
+    /// Update the build log timestamp display
+    pub fn set_build_log_show_timestamps(&mut self, show: bool) {
+        self.session.build_log_show_timestamps = Some(show);
+    }
+
+    /// Mark (or unmark) a file of a PR as viewed
+    pub fn set_file_viewed(&mut self, pr_key: &str, path: &str, viewed: bool) {
+        let files = self
+            .session
+            .viewed_files
+            .entry(pr_key.to_string())
+            .or_default();
+        if viewed {
+            files.insert(path.to_string());
+        } else {
+            files.remove(path);
+        }
+        if files.is_empty() {
+            self.session.viewed_files.remove(pr_key);
+        }
+    }
+
+    /// Get the files of a PR marked as viewed
+    pub fn viewed_files(&self, pr_key: &str) -> Vec<String> {
+        self.session
+            .viewed_files
+            .get(pr_key)
+            .map(|files| files.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Snooze a PR until it is updated after `updated_at` (None un-snoozes it)
+    pub fn set_pr_snoozed(&mut self, pr_key: &str, updated_at: Option<DateTime<Utc>>) {
+        match updated_at {
+            Some(updated_at) => {
+                self.session
+                    .snoozed_prs
+                    .insert(pr_key.to_string(), updated_at);
+            }
+            None => {
+                self.session.snoozed_prs.remove(pr_key);
+            }
+        }
+    }
+
+    /// Get the `updated_at` of a PR when it was snoozed, if it is snoozed
+    pub fn pr_snoozed_at(&self, pr_key: &str) -> Option<DateTime<Utc>> {
+        self.session.snoozed_prs.get(pr_key).copied()
+    }
+
+    /// Remember the PR filter of a repository (None forgets it)
+    pub fn set_repo_filter(&mut self, repo_key: &str, filter: Option<&str>) {
+        match filter {
+            Some(filter) => {
+                self.session
+                    .repo_filters
+                    .insert(repo_key.to_string(), filter.to_string());
+            }
+            None => {
+                self.session.repo_filters.remove(repo_key);
+            }
+        }
+    }
+
+    /// Get the last-used PR filter of a repository
+    pub fn repo_filter(&self, repo_key: &str) -> Option<&str> {
+        self.session.repo_filters.get(repo_key).map(String::as_str)
+    }
+
+    /// Count a use of a command from the command palette
+    pub fn record_command_use(&mut self, command: &str) {
+        let count = self
+            .session
+            .command_usage
+            .entry(command.to_string())
+            .or_default();
+        *count = count.saturating_add(1);
+    }
+
+    /// Get how often each command was run from the command palette
+    pub fn command_usage(&self) -> &BTreeMap<String, u32> {
+        &self.session.command_usage
+    }
+
     /// Get selected repository as tuple (org, name, branch, host)
     pub fn selected_repo(&self) -> Option<(&str, &str, &str, Option<&str>)> {
         match (
@@ -162,6 +273,26 @@ impl Session {
     pub fn selected_pr_no(&self) -> Option<usize> {
         self.session.selected_pr_no
     }
+
+    /// Get the last-used diff viewer file tree width (percent)
+    pub fn diff_file_tree_width(&self) -> Option<u16> {
+        self.session.diff_file_tree_width
+    }
+
+    /// Get the last toggled build log timestamp display
+    pub fn build_log_show_timestamps(&self) -> Option<bool> {
+        self.session.build_log_show_timestamps
+    }
+}
+
+/// Key identifying a PR in the session maps (`host/org/repo#number`)
+pub fn pr_key(host: &str, org: &str, repo: &str, pr_number: u64) -> String {
+    format!("{}#{}", repo_key(host, org, repo), pr_number)
+}
+
+/// Key identifying a repository in the session maps (`host/org/repo`)
+pub fn repo_key(host: &str, org: &str, repo: &str) -> String {
+    format!("{}/{}/{}", host, org, repo)
 }
 
 #[cfg(test)]
@@ -226,6 +357,30 @@ mod tests {
         assert_eq!(parsed.selected_pr_no(), Some(42));
     }
 
+    #[test]
+    fn test_session_serialization_with_file_tree_width() {
+        let mut session = Session::default();
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("diff_file_tree_width"));
+
+        session.set_diff_file_tree_width(30);
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.diff_file_tree_width(), Some(30));
+    }
+
+    #[test]
+    fn test_session_serialization_with_build_log_timestamps() {
+        let mut session = Session::default();
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("build_log_show_timestamps"));
+
+        session.set_build_log_show_timestamps(true);
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.build_log_show_timestamps(), Some(true));
+    }
+
     #[test]
     fn test_session_serialization_with_host() {
         let mut session = Session::default();
@@ -239,4 +394,89 @@ mod tests {
         let (_, _, _, host) = parsed.selected_repo().unwrap();
         assert_eq!(host, Some("ghe.example.com"));
     }
+
+    #[test]
+    fn test_session_serialization_with_viewed_files() {
+        let mut session = Session::default();
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("viewed_files"));
+
+        let key = pr_key("github.com", "org", "repo", 7);
+        session.set_file_viewed(&key, "src/main.rs", true);
+        session.set_file_viewed(&key, "src/lib.rs", true);
+        session.set_file_viewed(&key, "src/lib.rs", false);
+
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.viewed_files(&key), vec!["src/main.rs".to_string()]);
+
+        // Unmarking the last file drops the PR entry
+        session.set_file_viewed(&key, "src/main.rs", false);
+        assert!(session.session.viewed_files.is_empty());
+    }
+
+    #[test]
+    fn test_session_serialization_with_snoozed_prs() {
+        let mut session = Session::default();
+        let key = pr_key("github.com", "org", "repo", 7);
+        let updated_at = Utc::now();
+        session.set_pr_snoozed(&key, Some(updated_at));
+
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.pr_snoozed_at(&key), Some(updated_at));
+
+        session.set_pr_snoozed(&key, None);
+        assert_eq!(session.pr_snoozed_at(&key), None);
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("snoozed_prs"));
+    }
+
+    #[test]
+    fn test_session_serialization_with_repo_filters() {
+        let mut session = Session::default();
+        let key = repo_key("github.com", "org", "repo");
+        session.set_repo_filter(&key, Some("drafts"));
+
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.repo_filter(&key), Some("drafts"));
+
+        session.set_repo_filter(&key, None);
+        assert_eq!(session.repo_filter(&key), None);
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("repo_filters"));
+    }
+
+    #[test]
+    fn test_session_serialization_with_command_usage() {
+        let mut session = Session::default();
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        assert!(!toml_str.contains("command_usage"));
+
+        session.record_command_use("pr_merge");
+        session.record_command_use("pr_merge");
+        session.record_command_use("pr_refresh");
+
+        let toml_str = toml::to_string_pretty(&session).unwrap();
+        let parsed: Session = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.command_usage().get("pr_merge"), Some(&2));
+        assert_eq!(parsed.command_usage().get("pr_refresh"), Some(&1));
+    }
+
+    #[test]
+    fn test_session_without_repo_filters_loads() {
+        // Sessions written before per-repository filters existed
+        let toml_str = r#"
+            [meta]
+            last_modified = "2024-01-01T00:00:00Z"
+            version = 1
+
+            [session]
+            selected_pr_no = 3
+        "#;
+        let session: Session = toml::from_str(toml_str).unwrap();
+        assert_eq!(session.repo_filter("github.com/org/repo"), None);
+        assert_eq!(session.selected_pr_no(), Some(3));
+    }
 }
@@ -1,8 +1,16 @@
 use ratatui::{prelude::*, style::palette::tailwind};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Names of the built-in themes, in cycling order
+pub const BUILTIN_THEMES: [&str; 3] = ["dark", "light", "high-contrast"];
 
 /// Application theme - centralized color and style management
 #[derive(Debug, Clone)]
 pub struct Theme {
+    /// Name of the built-in theme this theme is based on
+    pub name: &'static str,
+
     // Background colors
     pub bg_primary: Color,
     pub bg_secondary: Color,
@@ -67,9 +75,106 @@ impl Default for Theme {
 }
 
 impl Theme {
+    /// Get a built-in theme by name (see [`BUILTIN_THEMES`])
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// The built-in theme following this one in [`BUILTIN_THEMES`] (wraps around)
+    pub fn next_builtin(&self) -> Self {
+        let current = BUILTIN_THEMES
+            .iter()
+            .position(|name| *name == self.name)
+            .unwrap_or(0);
+        let next = BUILTIN_THEMES[(current + 1) % BUILTIN_THEMES.len()];
+        Self::builtin(next).unwrap_or_default()
+    }
+
+    /// Build a theme from a built-in base and per-field color overrides
+    ///
+    /// Colors accept hex strings (`"#1e293b"`) and named colors (`"red"`,
+    /// `"lightblue"`). Unknown base names, unknown fields and invalid colors
+    /// are logged and fall back to the base theme value.
+    pub fn from_config(name: &str, colors: &BTreeMap<String, String>) -> Self {
+        let mut theme = Self::builtin(name).unwrap_or_else(|| {
+            log::warn!("Unknown theme '{}', using 'dark'", name);
+            Self::dark()
+        });
+
+        for (field, value) in colors {
+            let Some(slot) = theme.color_mut(field) else {
+                log::warn!("Unknown theme color '{}', ignoring", field);
+                continue;
+            };
+            match Color::from_str(value) {
+                Ok(color) => *slot = color,
+                Err(_) => log::warn!(
+                    "Invalid color '{}' for theme field '{}', keeping default",
+                    value,
+                    field
+                ),
+            }
+        }
+
+        theme
+    }
+
+    /// Mutable access to a color field by its name (e.g. `"accent_primary"`)
+    fn color_mut(&mut self, field: &str) -> Option<&mut Color> {
+        let color = match field {
+            "bg_primary" => &mut self.bg_primary,
+            "bg_secondary" => &mut self.bg_secondary,
+            "bg_tertiary" => &mut self.bg_tertiary,
+            "bg_panel" => &mut self.bg_panel,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "text_muted" => &mut self.text_muted,
+            "text_header" => &mut self.text_header,
+            "accent_primary" => &mut self.accent_primary,
+            "accent_secondary" => &mut self.accent_secondary,
+            "status_success" => &mut self.status_success,
+            "status_error" => &mut self.status_error,
+            "status_warning" => &mut self.status_warning,
+            "status_info" => &mut self.status_info,
+            "status_checking" => &mut self.status_checking,
+            "action_navigate" => &mut self.action_navigate,
+            "action_select" => &mut self.action_select,
+            "action_open" => &mut self.action_open,
+            "action_refresh" => &mut self.action_refresh,
+            "action_filter" => &mut self.action_filter,
+            "action_merge" => &mut self.action_merge,
+            "action_rebase" => &mut self.action_rebase,
+            "action_danger" => &mut self.action_danger,
+            "action_help" => &mut self.action_help,
+            "selected_bg" => &mut self.selected_bg,
+            "selected_fg" => &mut self.selected_fg,
+            "active_bg" => &mut self.active_bg,
+            "active_fg" => &mut self.active_fg,
+            "table_header_bg" => &mut self.table_header_bg,
+            "table_header_fg" => &mut self.table_header_fg,
+            "table_row_fg" => &mut self.table_row_fg,
+            "table_row_bg_normal" => &mut self.table_row_bg_normal,
+            "table_row_bg_alt" => &mut self.table_row_bg_alt,
+            "tab_line_bg" => &mut self.tab_line_bg,
+            "tab_active_bg" => &mut self.tab_active_bg,
+            "tab_active_fg" => &mut self.tab_active_fg,
+            "tab_inactive_fg" => &mut self.tab_inactive_fg,
+            "tab_hint_fg" => &mut self.tab_hint_fg,
+            _ => return None,
+        };
+        Some(color)
+    }
+
     /// Dark theme (default) - Cyan/Magenta color scheme
     pub fn dark() -> Self {
         Self {
+            name: "dark",
+
             // Backgrounds
             bg_primary: tailwind::SLATE.c950,
             bg_secondary: tailwind::SLATE.c900,
@@ -128,6 +233,132 @@ impl Theme {
         }
     }
 
+    /// Light theme - dark slate text on light backgrounds, teal/fuchsia accents
+    pub fn light() -> Self {
+        Self {
+            name: "light",
+
+            // Backgrounds
+            bg_primary: tailwind::SLATE.c50,
+            bg_secondary: tailwind::SLATE.c100,
+            bg_tertiary: tailwind::SLATE.c200,
+            bg_panel: tailwind::SLATE.c100,
+
+            // Text
+            text_primary: tailwind::SLATE.c900,
+            text_secondary: tailwind::SLATE.c700,
+            text_muted: tailwind::SLATE.c500,
+            text_header: tailwind::SLATE.c950,
+
+            // Accents
+            accent_primary: tailwind::CYAN.c700,
+            accent_secondary: tailwind::FUCHSIA.c700,
+
+            // Status
+            status_success: tailwind::GREEN.c700,
+            status_error: tailwind::RED.c700,
+            status_warning: tailwind::AMBER.c700,
+            status_info: tailwind::CYAN.c700,
+            status_checking: tailwind::PURPLE.c700,
+
+            // Actions
+            action_navigate: tailwind::CYAN.c700,
+            action_select: tailwind::CYAN.c800,
+            action_open: tailwind::FUCHSIA.c700,
+            action_refresh: tailwind::PURPLE.c700,
+            action_filter: tailwind::VIOLET.c700,
+            action_merge: tailwind::GREEN.c700,
+            action_rebase: tailwind::PURPLE.c800,
+            action_danger: tailwind::RED.c700,
+            action_help: tailwind::SLATE.c600,
+
+            // Selection (Space key)
+            selected_bg: tailwind::FUCHSIA.c200,
+            selected_fg: tailwind::SLATE.c950,
+
+            // Active/focused row
+            active_bg: tailwind::CYAN.c200,
+            active_fg: tailwind::SLATE.c950,
+
+            // Table
+            table_header_bg: tailwind::SLATE.c200,
+            table_header_fg: tailwind::SLATE.c950,
+            table_row_fg: tailwind::SLATE.c800,
+            table_row_bg_normal: tailwind::SLATE.c50,
+            table_row_bg_alt: tailwind::SLATE.c100,
+
+            // Tab bar
+            tab_line_bg: tailwind::SLATE.c200,
+            tab_active_bg: tailwind::CYAN.c700,
+            tab_active_fg: tailwind::SLATE.c50,
+            tab_inactive_fg: tailwind::SLATE.c600,
+            tab_hint_fg: tailwind::FUCHSIA.c700,
+        }
+    }
+
+    /// High-contrast theme - pure black background with saturated ANSI colors
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast",
+
+            // Backgrounds
+            bg_primary: Color::Black,
+            bg_secondary: Color::Black,
+            bg_tertiary: Color::DarkGray,
+            bg_panel: Color::Black,
+
+            // Text
+            text_primary: Color::White,
+            text_secondary: Color::White,
+            text_muted: Color::Gray,
+            text_header: Color::White,
+
+            // Accents
+            accent_primary: Color::LightCyan,
+            accent_secondary: Color::LightMagenta,
+
+            // Status
+            status_success: Color::LightGreen,
+            status_error: Color::LightRed,
+            status_warning: Color::LightYellow,
+            status_info: Color::LightCyan,
+            status_checking: Color::LightMagenta,
+
+            // Actions
+            action_navigate: Color::LightCyan,
+            action_select: Color::LightBlue,
+            action_open: Color::LightMagenta,
+            action_refresh: Color::LightBlue,
+            action_filter: Color::LightMagenta,
+            action_merge: Color::LightGreen,
+            action_rebase: Color::LightBlue,
+            action_danger: Color::LightRed,
+            action_help: Color::White,
+
+            // Selection (Space key)
+            selected_bg: Color::Yellow,
+            selected_fg: Color::Black,
+
+            // Active/focused row
+            active_bg: Color::White,
+            active_fg: Color::Black,
+
+            // Table
+            table_header_bg: Color::Black,
+            table_header_fg: Color::White,
+            table_row_fg: Color::White,
+            table_row_bg_normal: Color::Black,
+            table_row_bg_alt: Color::Black,
+
+            // Tab bar
+            tab_line_bg: Color::Black,
+            tab_active_bg: Color::White,
+            tab_active_fg: Color::Black,
+            tab_inactive_fg: Color::Gray,
+            tab_hint_fg: Color::LightYellow,
+        }
+    }
+
     // Prebuilt styles for common use cases
 
     /// Style for panel backgrounds (shortcuts, logs, etc.)
@@ -268,3 +499,44 @@ impl Theme {
         self.active_bg
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_themes_cycle() {
+        let mut theme = Theme::default();
+        for expected in BUILTIN_THEMES.iter().cycle().skip(1).take(3) {
+            theme = theme.next_builtin();
+            assert_eq!(theme.name, *expected);
+        }
+        assert!(Theme::builtin("solarized").is_none());
+    }
+
+    #[test]
+    fn test_from_config_applies_hex_and_named_colors() {
+        let colors = BTreeMap::from([
+            ("accent_primary".to_string(), "#ff8800".to_string()),
+            ("status_error".to_string(), "red".to_string()),
+        ]);
+        let theme = Theme::from_config("light", &colors);
+
+        assert_eq!(theme.name, "light");
+        assert_eq!(theme.accent_primary, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.status_error, Color::Red);
+        assert_eq!(theme.bg_primary, Theme::light().bg_primary);
+    }
+
+    #[test]
+    fn test_from_config_falls_back_on_invalid_entries() {
+        let colors = BTreeMap::from([
+            ("accent_primary".to_string(), "not-a-color".to_string()),
+            ("no_such_field".to_string(), "red".to_string()),
+        ]);
+        let theme = Theme::from_config("unknown", &colors);
+
+        assert_eq!(theme.name, "dark");
+        assert_eq!(theme.accent_primary, Theme::dark().accent_primary);
+    }
+}
@@ -0,0 +1,21 @@
+//! Action History screen actions
+//!
+//! Actions specific to the action history overlay.
+
+use strum::IntoStaticStr;
+
+/// Actions for the Action History screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
+pub enum ActionHistoryAction {
+    // Navigation (translated from NavigationAction)
+    /// Scroll to the next (newer) action
+    NavigateNext,
+    /// Scroll to the previous (older) action
+    NavigatePrevious,
+    /// Scroll to the top (oldest actions)
+    NavigateToTop,
+    /// Scroll to the bottom (newest actions)
+    NavigateToBottom,
+    /// Update visible height (for proper scroll bounds)
+    SetVisibleHeight(usize),
+}
@@ -2,6 +2,7 @@
 //!
 //! Used for rendering contextual help/suggestions in the UI footer.
 
+use crate::capabilities::PanelCapabilities;
 use crate::command_id::CommandId;
 
 /// Category for grouping available actions in the UI.
@@ -52,4 +53,61 @@ impl AvailableAction {
     pub fn selection(command: CommandId, label: &'static str) -> Self {
         Self::new(command, label, ActionCategory::Selection)
     }
+
+    /// Generic actions implied by a view's capabilities.
+    ///
+    /// Views only list their own commands; navigation, scrolling and closing
+    /// follow from what the keyboard layer routes for these capabilities.
+    pub fn for_capabilities(capabilities: PanelCapabilities) -> Vec<Self> {
+        let mut actions = Vec::new();
+        if capabilities.supports_item_navigation() || capabilities.supports_vim_navigation() {
+            actions.push(Self::navigation(CommandId::NavigateNext, "Down"));
+            actions.push(Self::navigation(CommandId::NavigatePrevious, "Up"));
+        }
+        if capabilities.supports_vim_vertical_scroll() {
+            actions.push(Self::navigation(CommandId::NavigateToTop, "Top"));
+            actions.push(Self::navigation(CommandId::NavigateToBottom, "Bottom"));
+        } else if capabilities.contains(PanelCapabilities::SCROLL_VERTICAL) {
+            actions.push(Self::navigation(CommandId::NavigatePageDown, "Page Down"));
+        }
+        if capabilities.is_dismissible() {
+            actions.push(Self::navigation(CommandId::GlobalClose, "Close"));
+        }
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(capabilities: PanelCapabilities) -> Vec<CommandId> {
+        AvailableAction::for_capabilities(capabilities)
+            .iter()
+            .map(|action| action.command)
+            .collect()
+    }
+
+    #[test]
+    fn test_capability_actions() {
+        assert!(commands(PanelCapabilities::empty()).is_empty());
+        assert!(commands(PanelCapabilities::TEXT_INPUT).is_empty());
+        assert_eq!(
+            commands(PanelCapabilities::SCROLL_VERTICAL | PanelCapabilities::DISMISSIBLE),
+            vec![CommandId::NavigatePageDown, CommandId::GlobalClose]
+        );
+        assert_eq!(
+            commands(
+                PanelCapabilities::VIM_NAVIGATION_BINDINGS
+                    | PanelCapabilities::SCROLL_VERTICAL
+                    | PanelCapabilities::VIM_SCROLL_BINDINGS
+            ),
+            vec![
+                CommandId::NavigateNext,
+                CommandId::NavigatePrevious,
+                CommandId::NavigateToTop,
+                CommandId::NavigateToBottom
+            ]
+        );
+    }
 }
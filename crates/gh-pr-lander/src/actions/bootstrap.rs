@@ -2,6 +2,8 @@
 //!
 //! Actions for application initialization and configuration loading.
 
+use strum::IntoStaticStr;
+
 /// Actions for application bootstrap/initialization
 ///
 /// Note: `ClientReady` has been moved to `Event` enum as it's a fact that middleware
@@ -9,7 +11,7 @@
 ///
 /// Session-related actions have been moved to `SessionAction` to maintain
 /// proper separation of concerns.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum BootstrapAction {
     /// Bootstrap process started
     Start,
@@ -17,6 +19,10 @@ pub enum BootstrapAction {
     End,
     /// Application configuration loaded
     ConfigLoaded(gh_pr_config::AppConfig),
+    /// Keymap built from the defaults and the user's `[keybindings]` overrides
+    KeymapLoaded(crate::keybindings::Keymap),
+    /// Theme built from the `[theme]` config section
+    ThemeLoaded(gh_pr_lander_theme::Theme),
     /// Request to load recent repositories from config
     LoadRecentRepositories,
     /// Recent repositories loaded
@@ -3,9 +3,10 @@
 //! Tagged actions for the build log panel.
 
 use crate::state::{BuildLogJobMetadata, BuildLogPrContext};
+use strum::IntoStaticStr;
 
 /// Tagged actions for the build log panel
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum BuildLogAction {
     // === Loading ===
     /// Open build logs for current PR (triggers async fetch)
@@ -36,13 +37,15 @@ pub enum BuildLogAction {
     Toggle,
     /// Expand all nodes
     ExpandAll,
-    /// Collapse all nodes
+    /// Collapse all nodes below the top-level workflows
     CollapseAll,
+    /// Collapse everything except the jobs and steps with errors
+    CollapseToErrors,
 
     // === Error Navigation ===
-    /// Jump to next error
+    /// Jump to next error (next search match while a search is active)
     NextError,
-    /// Jump to previous error
+    /// Jump to previous error (previous search match while a search is active)
     PrevError,
 
     // === Scrolling ===
@@ -55,11 +58,43 @@ pub enum BuildLogAction {
     /// Page up
     PageUp,
 
+    // === Workflow Runs ===
+    /// Rerun the failed jobs of the workflow at the cursor (handled by middleware)
+    RerunWorkflow,
+
+    // === Raw Logs ===
+    /// Download the complete raw log of the job at the cursor (handled by middleware)
+    DownloadJobLog,
+
+    // === Clipboard ===
+    /// Copy the log line, step or job under the cursor (handled by middleware)
+    CopySelection,
+
+    // === Search ===
+    /// Start editing the search query
+    StartSearch,
+    /// Character typed into the search query
+    SearchChar(char),
+    /// Remove the last character of the search query
+    SearchBackspace,
+    /// Clear the search query
+    SearchClear,
+    /// Stop editing, keeping the search active
+    SearchConfirm,
+    /// Stop editing and clear the search
+    SearchCancel,
+
     // === View Options ===
     /// Toggle timestamp display
     ToggleTimestamps,
+    /// Set timestamp display (restored from config/session)
+    SetShowTimestamps(bool),
+    /// Toggle wrapping of long log lines
+    ToggleWrap,
+    /// Toggle showing only workflows, jobs and steps with errors
+    ToggleFailedOnly,
 
     // === Viewport ===
-    /// Update viewport height (called during render)
-    SetViewportHeight(usize),
+    /// Update viewport size (synced with the terminal size)
+    SetViewport { width: usize, height: usize },
 }
@@ -2,8 +2,12 @@
 //!
 //! Actions specific to the command palette overlay.
 
+use crate::command_id::CommandId;
+use std::collections::HashMap;
+use strum::IntoStaticStr;
+
 /// Actions for the Command Palette screen
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, IntoStaticStr)]
 pub enum CommandPaletteAction {
     // Navigation (translated from NavigationAction)
     /// Navigate to next command in the list
@@ -20,8 +24,14 @@ pub enum CommandPaletteAction {
     Clear,
 
     // Specific actions
-    /// Close the command palette
-    Close,
     /// Execute selected command
     Execute,
+    /// Open the palette with a prefilled query (e.g. `repo ` to pick an argument)
+    OpenWithQuery(String),
+
+    // Usage tracking
+    /// A command was run from the palette (counted for the recent section)
+    CommandUsed(CommandId),
+    /// Usage counts restored from the session
+    UsageLoaded(HashMap<CommandId, u32>),
 }
@@ -0,0 +1,35 @@
+//! Commit List actions
+//!
+//! Actions for the read-only popup listing the commits of a PR.
+
+use crate::domain_models::PrCommit;
+use strum::IntoStaticStr;
+
+/// Actions for the Commit List popup
+#[derive(Debug, Clone, IntoStaticStr)]
+pub enum CommitListAction {
+    /// Open the commit list for the current PR (handled by middleware)
+    Open,
+    /// Start loading the commits of a PR
+    LoadStart { pr_number: usize },
+    /// Commits of a PR loaded
+    Loaded {
+        pr_number: usize,
+        commits: Vec<PrCommit>,
+    },
+    /// Failed to load the commits of a PR
+    LoadError { pr_number: usize, error: String },
+
+    // Navigation (translated from NavigationAction)
+    /// Move the cursor to the next commit
+    NavigateNext,
+    /// Move the cursor to the previous commit
+    NavigatePrevious,
+    /// Move the cursor to the first commit
+    NavigateToTop,
+    /// Move the cursor to the last commit
+    NavigateToBottom,
+
+    /// Open the commit under the cursor in the browser (handled by middleware)
+    OpenInBrowser,
+}
@@ -1,12 +1,15 @@
 //! Confirmation Popup actions
 //!
 //! Actions specific to the confirmation popup overlay.
-//! The popup is reusable for various PR operations (approve, comment, request changes, close).
+//! The popup is reusable for various PR operations (approve, comment, request changes, close,
+//! and merging PRs that are not ready), and for any command via [`ConfirmationPopupAction::request`].
 
+use crate::actions::Action;
 use crate::state::ConfirmationIntent;
+use strum::IntoStaticStr;
 
 /// Actions for the Confirmation Popup screen
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum ConfirmationPopupAction {
     /// Show the confirmation popup with the given configuration
     Show {
@@ -28,6 +31,23 @@ pub enum ConfirmationPopupAction {
     Confirm,
     /// Intent has been dispatched - close the popup (handled by reducer)
     Confirmed,
-    /// Cancel and close the popup (Esc, x, q)
-    Cancel,
+}
+
+impl ConfirmationPopupAction {
+    /// Ask for a yes/no confirmation and dispatch `on_confirm` when the user confirms
+    pub fn request(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        on_confirm: Action,
+    ) -> Self {
+        Self::Show {
+            intent: ConfirmationIntent::Request {
+                title: title.into(),
+                message: message.into(),
+                on_confirm: Box::new(on_confirm),
+            },
+            default_message: String::new(),
+            repo_context: String::new(),
+        }
+    }
 }
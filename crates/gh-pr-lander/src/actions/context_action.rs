@@ -7,11 +7,13 @@
 //! - Add repository: Submit form
 //! - Build log: Toggle section expansion
 
+use strum::IntoStaticStr;
+
 /// Semantic actions that views interpret differently.
 ///
 /// These represent user intent, not specific operations.
 /// Each view translates them to view-specific actions via `translate_context_action`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
 pub enum ContextAction {
     /// Primary action on focused item (Enter key)
     ///
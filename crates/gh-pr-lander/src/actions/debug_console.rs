@@ -2,10 +2,12 @@
 //!
 //! Actions specific to the debug console overlay.
 
+use crate::log_reader::LogLine;
 use std::fmt;
+use strum::IntoStaticStr;
 
 /// Actions for the Debug Console screen
-#[derive(Clone)]
+#[derive(Clone, IntoStaticStr)]
 pub enum DebugConsoleAction {
     // Navigation (translated from NavigationAction)
     /// Scroll to next log entry
@@ -23,7 +25,23 @@ pub enum DebugConsoleAction {
     /// Update visible height (for proper scroll bounds)
     SetVisibleHeight(usize),
     /// Batch update of lines from middleware
-    LinesUpdated(Vec<String>),
+    LinesUpdated(Vec<LogLine>),
+
+    // Filters
+    /// Cycle the minimum displayed level (trace → debug → info → warn → error)
+    CycleMinLevel,
+    /// Start editing the text filter
+    StartFilter,
+    /// Character typed into the text filter
+    FilterChar(char),
+    /// Remove the last character of the text filter
+    FilterBackspace,
+    /// Clear the text filter
+    FilterClear,
+    /// Stop editing, keeping the text filter
+    FilterConfirm,
+    /// Stop editing and clear the text filter
+    FilterCancel,
 }
 
 // Custom Debug to avoid logging full line contents (prevents feedback loop)
@@ -37,6 +55,13 @@ impl fmt::Debug for DebugConsoleAction {
             Self::Clear => write!(f, "Clear"),
             Self::SetVisibleHeight(h) => write!(f, "SetVisibleHeight({})", h),
             Self::LinesUpdated(lines) => write!(f, "LinesUpdated(<{} lines>)", lines.len()),
+            Self::CycleMinLevel => write!(f, "CycleMinLevel"),
+            Self::StartFilter => write!(f, "StartFilter"),
+            Self::FilterChar(c) => write!(f, "FilterChar({:?})", c),
+            Self::FilterBackspace => write!(f, "FilterBackspace"),
+            Self::FilterClear => write!(f, "FilterClear"),
+            Self::FilterConfirm => write!(f, "FilterConfirm"),
+            Self::FilterCancel => write!(f, "FilterCancel"),
         }
     }
 }
@@ -3,6 +3,7 @@
 //! Tagged actions for the diff viewer panel.
 
 use gh_diff_viewer::{DiffEvent, PullRequestDiff};
+use strum::IntoStaticStr;
 
 /// A review comment loaded from GitHub
 #[derive(Debug, Clone)]
@@ -20,7 +21,7 @@ pub struct LoadedComment {
 }
 
 /// Tagged actions for the diff viewer panel
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum DiffViewerAction {
     // === Loading ===
     /// Open diff viewer for current PR (triggers async fetch)
@@ -115,10 +116,16 @@ pub enum DiffViewerAction {
     /// Submit review with selected option (updates inner state, closes popup)
     SubmitReview,
     /// Request to submit review via API (handled by GitHub middleware)
+    ///
+    /// Pending comments not yet posted to GitHub are attached to the review.
     SubmitReviewRequest {
         pr_number: u64,
         event: gh_diff_viewer::ReviewEvent,
     },
+    /// Review was successfully submitted (clears the submitted comments, closes popup)
+    ReviewSubmitted {
+        comments: Vec<gh_diff_viewer::PendingComment>,
+    },
     /// Request to submit a single line comment via API (handled by GitHub middleware)
     SubmitCommentRequest {
         pr_number: u64,
@@ -157,4 +164,12 @@ pub enum DiffViewerAction {
     // === Viewport ===
     /// Update viewport dimensions
     SetViewport { width: u16, height: u16 },
+    /// Set the file tree width in percent (e.g. restored from the session)
+    SetFileTreeWidth(u16),
+
+    // === Viewed Files ===
+    /// Mark a file of the open PR as viewed (or unmark it)
+    SetFileViewed { path: String, viewed: bool },
+    /// Restore the viewed files of the open PR (from the session)
+    SetViewedFiles(Vec<String>),
 }
@@ -26,11 +26,13 @@
 //! - `ConfigLoaded` (not `LoadConfig`)
 //! - `BootstrapCompleted` (not `CompleteBootstrap`)
 
+use strum::IntoStaticStr;
+
 /// Events that re-enter the middleware chain
 ///
 /// These represent facts about what has happened in the system.
 /// Middleware can observe these and dispatch further actions in response.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum Event {
     // === Bootstrap Events ===
     /// GitHub client has been initialized and is ready for API calls
@@ -3,11 +3,13 @@
 //! These actions affect the application as a whole.
 
 use ratatui::crossterm::event::KeyEvent;
+use strum::IntoStaticStr;
 
+use crate::command_id::CommandId;
 use crate::views::View;
 
 /// Global actions that affect the entire application
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum GlobalAction {
     /// Raw key pressed (before translation)
     KeyPressed(KeyEvent),
@@ -19,6 +21,30 @@ pub enum GlobalAction {
     PushView(Box<dyn View>),
     /// Replace entire view stack with new view
     ReplaceView(Box<dyn View>),
+    /// Switch to the next built-in theme
+    CycleTheme,
+    /// Run the last repeatable command again (handled by keyboard middleware)
+    RepeatLast,
+    /// A repeatable command ran from a key binding or the command palette
+    RememberCommand(CommandId),
+    /// Switch the PR table between compact and detailed rows
+    CycleRowDensity,
+    /// Hide the error banner
+    DismissError,
+    /// Hide the visible toast and show the next queued one
+    DismissToast,
     /// Periodic tick for animations
     Tick,
+    /// Advance frame-based animations (e.g. loading spinner) to the elapsed time
+    AnimationFrame(std::time::Duration),
+    /// Terminal was resized (debounced)
+    TerminalResized { width: u16, height: u16 },
+    /// Left mouse button clicked at (column, row) - translated by the active view
+    MouseClicked(u16, u16),
+    /// First key of a two-key sequence pressed, waiting for the second key
+    KeySequenceStarted(char),
+    /// Pending two-key sequence timed out without a second key (handled by middleware)
+    KeySequenceTimeout,
+    /// Two-key sequence completed, cancelled or timed out
+    KeySequenceEnded,
 }
@@ -2,8 +2,10 @@
 //!
 //! Actions specific to the key bindings help overlay.
 
+use strum::IntoStaticStr;
+
 /// Actions for the Key Bindings screen
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
 pub enum KeyBindingsAction {
     // Navigation (translated from NavigationAction)
     /// Scroll to next item
@@ -14,8 +16,4 @@ pub enum KeyBindingsAction {
     NavigateToTop,
     /// Scroll to bottom
     NavigateToBottom,
-
-    // Specific actions
-    /// Close the key bindings panel
-    Close,
 }
@@ -2,11 +2,16 @@
 //!
 //! Actions for the automated merge bot that manages PR merge queues.
 
+use crate::state::MergeBotPlan;
+use strum::IntoStaticStr;
+
 /// Actions for the merge bot subsystem
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum MergeBotAction {
-    /// Start the merge bot
+    /// Start the merge bot (shows the planned queue for confirmation first)
     Start,
+    /// Plan confirmed - carry it out for the repository's PRs
+    Begin { repo_idx: usize, plan: MergeBotPlan },
     /// Stop the merge bot
     Stop,
     /// Add selected PRs to the merge queue
@@ -19,4 +24,6 @@ pub enum MergeBotAction {
     CheckComplete(usize, usize, bool),
     /// A PR merge has completed (repo_idx, pr_number, success, message)
     MergeComplete(usize, usize, bool, String),
+    /// The bot worked through the whole queue
+    Finished,
 }
@@ -15,9 +15,11 @@ pub mod navigation;
 pub mod text_input;
 
 // Screen-specific action types
+pub mod action_history;
 pub mod bootstrap;
 pub mod build_log;
 pub mod command_palette;
+pub mod commit_list;
 pub mod confirmation_popup;
 pub mod debug_console;
 pub mod diff_viewer;
@@ -26,14 +28,17 @@ pub mod merge_bot;
 pub mod pull_request;
 pub mod repository;
 pub mod session;
+pub mod setup_wizard;
 pub mod splash;
 pub mod status_bar;
 
 // Re-export all action types for convenience
-pub use available_action::AvailableAction;
+pub use action_history::ActionHistoryAction;
+pub use available_action::{ActionCategory, AvailableAction};
 pub use bootstrap::BootstrapAction;
 pub use build_log::BuildLogAction;
 pub use command_palette::CommandPaletteAction;
+pub use commit_list::CommitListAction;
 pub use confirmation_popup::ConfirmationPopupAction;
 pub use context_action::ContextAction;
 pub use debug_console::DebugConsoleAction;
@@ -46,17 +51,20 @@ pub use navigation::NavigationAction;
 pub use pull_request::PullRequestAction;
 pub use repository::RepositoryAction;
 pub use session::SessionAction;
+pub use setup_wizard::SetupWizardAction;
 pub use splash::SplashAction;
 pub use status_bar::StatusBarAction;
 pub use text_input::TextInputAction;
 
+use strum::IntoStaticStr;
+
 /// Root action enum - tagged by screen/domain
 ///
 /// Actions are categorized as:
 /// - `Navigate` / `TextInput` / `ViewContext`: Generic actions that need translation by the active view
 /// - `Global`: Application-wide actions (quit, view management, tick)
 /// - Screen-specific variants: Already targeted to a specific screen's reducer
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum Action {
     // Events (re-enter middleware chain)
     /// Events are facts/observations that re-enter the middleware chain.
@@ -77,6 +85,10 @@ pub enum Action {
     /// Global application actions
     Global(GlobalAction),
 
+    /// An operation failed: shows the error banner and records it in the status bar.
+    /// Use `Action::error(message, context)` to create.
+    ErrorOccurred { context: String, message: String },
+
     // Screen-specific actions (already targeted)
     /// Pull Request screen actions
     PullRequest(PullRequestAction),
@@ -86,6 +98,8 @@ pub enum Action {
     KeyBindings(KeyBindingsAction),
     /// Debug Console actions
     DebugConsole(DebugConsoleAction),
+    /// Action History actions
+    ActionHistory(ActionHistoryAction),
     /// Splash screen actions
     Splash(SplashAction),
     /// Bootstrap/initialization actions
@@ -96,6 +110,8 @@ pub enum Action {
     StatusBar(StatusBarAction),
     /// Build Log panel actions
     BuildLog(BuildLogAction),
+    /// Commit List popup actions
+    CommitList(CommitListAction),
     /// Confirmation Popup actions (approve, comment, request changes, close)
     ConfirmationPopup(ConfirmationPopupAction),
     /// Diff Viewer panel actions
@@ -104,6 +120,8 @@ pub enum Action {
     Repository(RepositoryAction),
     /// Session management actions
     Session(SessionAction),
+    /// First-run Setup Wizard actions
+    SetupWizard(SetupWizardAction),
 
     /// No-op action
     None,
@@ -123,4 +141,56 @@ impl Action {
     pub fn event(event: Event) -> Action {
         Action::Event(event)
     }
+
+    /// Factory method for reporting a failure to the user.
+    ///
+    /// Takes the same arguments as `StatusBarAction::error`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// dispatcher.dispatch(Action::error(format!("Merge failed: {}", e), "Merge"));
+    /// ```
+    pub fn error(message: impl Into<String>, context: impl Into<String>) -> Action {
+        Action::ErrorOccurred {
+            context: context.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Variant path of the action, e.g. "Global::Quit"
+    ///
+    /// Built from the variant names alone, so payloads (typed text, tokens,
+    /// API responses) are neither formatted nor exposed.
+    pub fn name(&self) -> String {
+        let inner: Option<&'static str> = match self {
+            Action::Event(a) => Some(a.into()),
+            Action::Navigate(a) => Some(a.into()),
+            Action::TextInput(a) => Some(a.into()),
+            Action::ViewContext(a) => Some(a.into()),
+            Action::Global(a) => Some(a.into()),
+            Action::PullRequest(a) => Some(a.into()),
+            Action::CommandPalette(a) => Some(a.into()),
+            Action::KeyBindings(a) => Some(a.into()),
+            Action::DebugConsole(a) => Some(a.into()),
+            Action::ActionHistory(a) => Some(a.into()),
+            Action::Splash(a) => Some(a.into()),
+            Action::Bootstrap(a) => Some(a.into()),
+            Action::MergeBot(a) => Some(a.into()),
+            Action::StatusBar(a) => Some(a.into()),
+            Action::BuildLog(a) => Some(a.into()),
+            Action::CommitList(a) => Some(a.into()),
+            Action::ConfirmationPopup(a) => Some(a.into()),
+            Action::DiffViewer(a) => Some(a.into()),
+            Action::Repository(a) => Some(a.into()),
+            Action::Session(a) => Some(a.into()),
+            Action::SetupWizard(a) => Some(a.into()),
+            Action::ErrorOccurred { .. } | Action::None => None,
+        };
+        let outer: &'static str = self.into();
+        match inner {
+            Some(inner) => format!("{}::{}", outer, inner),
+            None => outer.to_string(),
+        }
+    }
 }
@@ -3,8 +3,10 @@
 //! These are generic navigation actions that views can translate
 //! into their screen-specific actions.
 
+use strum::IntoStaticStr;
+
 /// Generic navigation actions (vim-style)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
 pub enum NavigationAction {
     /// Navigate to next item (j, down arrow)
     Next,
@@ -18,4 +20,12 @@ pub enum NavigationAction {
     ToTop,
     /// Navigate to bottom (G)
     ToBottom,
+    /// Navigate one page down (PageDown)
+    PageDown,
+    /// Navigate one page up (PageUp)
+    PageUp,
+    /// Navigate half a page down (Ctrl+D)
+    HalfPageDown,
+    /// Navigate half a page up (Ctrl+U)
+    HalfPageUp,
 }
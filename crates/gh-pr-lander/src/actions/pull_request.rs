@@ -2,11 +2,16 @@
 //!
 //! Actions specific to the main PR view screen.
 
-use crate::domain_models::{MergeableStatus, Pr, Repository, ReviewDecision};
+use crate::domain_models::{
+    MaturityState, MergeMethod, MergeableStatus, Pr, Repository, ReviewDecision,
+};
 use crate::state::PrFilter;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use strum::IntoStaticStr;
 
 /// Actions for the Pull Request screen
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum PullRequestAction {
     // Navigation (translated from NavigationAction)
     /// Navigate to next PR in the table
@@ -17,45 +22,133 @@ pub enum PullRequestAction {
     NavigateToTop,
     /// Navigate to bottom of PR list
     NavigateToBottom,
+    /// Move the cursor one page (viewport height) down
+    NavigatePageDown,
+    /// Move the cursor one page (viewport height) up
+    NavigatePageUp,
+    /// Move the cursor half a page down
+    NavigateHalfPageDown,
+    /// Move the cursor half a page up
+    NavigateHalfPageUp,
+    /// Number of PR rows visible in the table (dispatched on resize)
+    SetPrViewportHeight(usize),
+
+    // Go to PR number (quick-jump input)
+    /// Open the "go to PR number" input
+    GotoPrStart,
+    /// Digit typed into the input
+    GotoPrChar(char),
+    /// Delete the last digit
+    GotoPrBackspace,
+    /// Clear the input
+    GotoPrClear,
+    /// Move the cursor to the entered PR and close the input
+    GotoPrConfirm,
+    /// Close the input without moving the cursor
+    GotoPrCancel,
+    /// Navigate to the given row of the (filtered) PR table (mouse click)
+    NavigateToRow(usize),
+    /// Scroll the focused row's title left
+    ScrollTitleLeft,
+    /// Scroll the focused row's title right
+    ScrollTitleRight,
+    /// Show or hide the description panel of the focused PR
+    ToggleDescription,
+    /// Scroll the description panel down (ignored while it is hidden)
+    ScrollDescriptionDown,
+    /// Scroll the description panel up (ignored while it is hidden)
+    ScrollDescriptionUp,
+    /// Inner width of the description panel (dispatched on resize)
+    SetDescriptionWidth(u16),
 
     // Repository switching
     /// Switch to next repository tab
     RepositoryNext,
     /// Switch to previous repository tab
     RepositoryPrevious,
+    /// Switch to the repository tab at the given index (clamped to the last tab)
+    RepositorySelect(usize),
 
     // PR Loading
     /// Start loading PRs for a repository
     LoadStart { repo: Repository },
+    /// Start reloading PRs for a repository, bypassing the cache
+    RefreshStart { repo: Repository },
     /// PRs loaded successfully for a repository
     Loaded { repo: Repository, prs: Vec<Pr> },
+    /// Further page of PRs loaded in the background (appended, keeps cursor and selection)
+    MorePrsLoaded { repo: Repository, prs: Vec<Pr> },
+    /// All pages of the PR list arrived; whether the list was cut off with
+    /// more PRs left on GitHub
+    HasMorePrs { repo: Repository, has_more: bool },
+    /// Load the next page of PRs beyond the PR limit (handled by middleware)
+    LoadMore,
+    /// Login of the authenticated user on the repository's host (for the
+    /// "My PRs" and "Attention" filters)
+    ViewerLoginLoaded { repo: Repository, login: String },
     /// Failed to load PRs for a repository
     LoadError { repo: Repository, error: String },
+    /// Snoozed PRs restored from the session (PR number -> `updated_at` when snoozed)
+    RestoreSnoozed {
+        repo: Repository,
+        snoozed: HashMap<usize, DateTime<Utc>>,
+    },
 
     // Selection
     /// Toggle selection of the current PR (at cursor)
     ToggleSelection,
-    /// Select all PRs in the current repository
+    /// Select all PRs passing the active filter in the current repository
     SelectAll,
+    /// Select all PRs in the current repository, ignoring the active filter
+    SelectAllUnfiltered,
     /// Deselect all PRs in the current repository
     DeselectAll,
+    /// Snooze the current PR until it is updated (or wake it up again)
+    ToggleSnooze,
+    /// Watch the current PR for CI completion notifications (or stop watching)
+    ToggleWatch,
+    /// Toggle the selection of every PR passing the active filter
+    InvertSelection,
 
     // Operations
     /// Open current PR in browser
     OpenInBrowser,
+    /// Open the cursor PR author's profile in browser
+    OpenAuthorProfile,
+    /// Copy the cursor PR's head branch (`owner:branch` for forks) to the clipboard
+    CopyBranch,
+    /// Copy the selected PRs (or cursor PR) to the clipboard as a markdown list
+    CopyMarkdown,
     /// Open current PR diff in configured IDE
     OpenInIDE,
+    /// Checkout the current PR branch in the configured local working copy
+    CheckoutLocal,
     /// Open CI build logs in browser
     OpenBuildLogs,
+    /// Open the CI build logs of the selected PRs, or else of every PR with a
+    /// failed build (capped at `[pr] max_browser_tabs`)
+    OpenFailingBuildLogs,
     /// Open a related issue in external tracker (Jira, Linear, etc.)
     OpenRelatedIssue { url: String },
     /// Refresh PRs for the current repository
     Refresh,
+    /// Auto-refresh timer fired (handled by middleware)
+    AutoRefreshTick,
+    /// Refresh PRs for all repositories, staggered to avoid rate limits
+    RefreshAll,
+    /// Export the PR list of the current repository as JSON
+    ExportJson,
     // Merge operations
     /// Request to merge selected PRs (or cursor PR if none selected)
+    ///
+    /// Asks for confirmation first if any target PR is not ready to merge.
     MergeRequest,
+    /// Merge the given PRs of the current repository (confirmed)
+    MergePrs { pr_numbers: Vec<u64> },
     /// Merge started for a PR
     MergeStart { repo: Repository, pr_number: usize },
+    /// Set how PRs are merged from now on (merge commit, squash or rebase)
+    SetMergeMethod(MergeMethod),
 
     // Rebase operations
     /// Request to rebase/update selected PRs
@@ -107,6 +200,16 @@ pub enum PullRequestAction {
     /// Close started for a PR
     CloseStart { repo: Repository, pr_number: usize },
 
+    // Draft state operations
+    /// Request to mark selected draft PRs as ready for review
+    MarkReadyRequest,
+    /// Mark ready for review started for a PR
+    MarkReadyStart { repo: Repository, pr_number: usize },
+    /// Request to convert selected ready PRs back to drafts
+    ConvertToDraftRequest,
+    /// Convert to draft started for a PR
+    ConvertToDraftStart { repo: Repository, pr_number: usize },
+
     // CI/Build Status actions
     /// Trigger a CI status check for a specific PR
     CheckBuildStatus {
@@ -126,6 +229,13 @@ pub enum PullRequestAction {
         pr_number: u64,
         decision: ReviewDecision,
     },
+    /// A PR was marked ready for review or converted to draft (the repository
+    /// it belongs to gets refreshed)
+    MaturityUpdated {
+        repo: Repository,
+        pr_number: usize,
+        maturity: MaturityState,
+    },
     /// Update PR stats (additions/deletions) after fetching individual PR details
     StatsUpdated {
         repo: Repository,
@@ -135,18 +245,30 @@ pub enum PullRequestAction {
     },
     /// Request to rerun failed jobs for the current PR
     RerunFailedJobs,
+    /// Request to rerun all jobs (not only failed ones) for the current PR
+    RerunAllJobs,
     /// Rerun started for a workflow run
     RerunStart {
         repo: Repository,
         pr_number: u64,
         run_id: u64,
     },
+    /// Request to cancel the running CI workflows of the current PR
+    CancelRuns,
+    /// Cancellation started for a workflow run
+    CancelRunStart {
+        repo: Repository,
+        pr_number: u64,
+        run_id: u64,
+    },
 
     // Filters
     /// Cycle through filter presets
     CycleFilter,
     /// Set a specific filter
     SetFilter(PrFilter),
+    /// Last-used filter of a repository restored from the session
+    RestoreFilter { repo: Repository, filter: PrFilter },
     /// Clear the current filter (show all PRs)
     ClearFilter,
 }
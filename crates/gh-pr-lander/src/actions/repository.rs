@@ -3,8 +3,9 @@
 //! Includes both repository operations and the add repository form.
 
 use crate::domain_models::Repository;
+use strum::IntoStaticStr;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, IntoStaticStr)]
 pub enum RepositoryAction {
     // === Repository operations ===
     /// Open the current repository in the browser
@@ -13,12 +14,35 @@ pub enum RepositoryAction {
     /// Adds a new repository to the list
     AddRepository(Repository),
 
-    /// Remove the currently selected repository from the list
+    /// Adds several repositories at once (startup), skipping duplicates
+    ///
+    /// Added in a single reducer step, before any of their PRs start loading,
+    /// so load results never arrive for a repository that is not in the list.
+    AddRepositoriesBulk(Vec<Repository>),
+
+    /// Ask for confirmation before removing the currently selected repository
+    RemoveCurrentRepositoryRequest,
+
+    /// Remove the currently selected repository from the list (confirmed)
     RemoveCurrentRepository,
 
+    /// Swap the selected repository with its left neighbor
+    MoveLeft,
+    /// Swap the selected repository with its right neighbor
+    MoveRight,
+
+    /// Save the repository list (order included) to disk
+    PersistRepositories,
+
     /// Load all repository related data (e.g., pull requests etc.)
     LoadRepositoryData(Repository),
 
+    /// Look up the default branch of the `--repo` repository given without
+    /// `@branch` (handled by GitHubMiddleware)
+    ResolveStartupRepository { org: String, repo: String },
+    /// The `--repo` repository on its default branch - add and focus it
+    StartupRepositoryResolved(Repository),
+
     // === Add Repository Form actions ===
     /// Move to next field (Tab)
     FormNextField,
@@ -32,8 +56,13 @@ pub enum RepositoryAction {
     /// Clear entire current field
     FormClearField,
 
-    /// Confirm and add the repository (Enter)
+    /// Confirm and add the repository (Enter) once it is verified to exist
     FormConfirm,
-    /// Close the form without adding (Esc)
-    FormClose,
+
+    /// Check that the repository exists before adding it (handled by GitHubMiddleware)
+    VerifyRepository(Repository),
+    /// The repository exists - add it and close the form
+    RepositoryVerified(Repository),
+    /// The repository was not found, is not accessible or could not be checked
+    RepositoryVerifyFailed { repo: Repository, message: String },
 }
@@ -2,8 +2,10 @@
 //!
 //! Actions for session state management (load/save/restore).
 
+use strum::IntoStaticStr;
+
 /// Actions for session lifecycle management
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum SessionAction {
     /// Session state loaded from disk
     /// Contains the previously selected repository and PR number
@@ -0,0 +1,24 @@
+//! Setup Wizard actions
+//!
+//! Actions for the first-run wizard shown when no GitHub token is found.
+
+use strum::IntoStaticStr;
+
+/// Actions for the Setup Wizard
+#[derive(Debug, Clone, IntoStaticStr)]
+pub enum SetupWizardAction {
+    /// No token was found for the host (opens the wizard)
+    TokenMissing {
+        host: String,
+        env_key: String,
+        /// Why each token source failed
+        attempts: Vec<String>,
+    },
+    /// Run `gh auth login` in the terminal (handled by the main loop, which
+    /// hands the terminal over to `gh` while it runs)
+    RunGhAuthLogin,
+    /// `gh auth login` exited (Err carries what went wrong)
+    GhAuthLoginFinished(Result<(), String>),
+    /// Look for a token again and re-initialize the client (handled by middleware)
+    Retry,
+}
@@ -2,8 +2,10 @@
 //!
 //! Actions specific to the splash/loading screen.
 
+use strum::IntoStaticStr;
+
 /// Actions for the Splash screen
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr)]
 pub enum SplashAction {
     /// Animation frame advance
     Tick,
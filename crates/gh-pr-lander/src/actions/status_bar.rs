@@ -3,9 +3,10 @@
 //! Actions for the status bar - user feedback for operations.
 
 use crate::state::StatusKind;
+use strum::IntoStaticStr;
 
 /// Actions for the status bar
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, IntoStaticStr)]
 pub enum StatusBarAction {
     /// Push a new status message
     Push {
@@ -3,8 +3,10 @@
 //! These are generic text input actions that views can translate
 //! into their screen-specific actions.
 
+use strum::IntoStaticStr;
+
 /// Generic text input actions
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, IntoStaticStr)]
 pub enum TextInputAction {
     /// Character typed into input field
     Char(char),
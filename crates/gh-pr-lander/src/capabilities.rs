@@ -41,6 +41,9 @@ bitflags! {
 
         /// Panel has multiple panes that Tab can switch between
         const PANE_SWITCHING = 1 << 7;
+
+        /// Panel is a popup that Esc dismisses (via `GlobalAction::Close`)
+        const DISMISSIBLE = 1 << 8;
     }
 }
 
@@ -83,6 +86,14 @@ impl PanelCapabilities {
     pub fn supports_pane_switching(self) -> bool {
         self.contains(Self::PANE_SWITCHING)
     }
+
+    /// Check if panel is dismissible
+    ///
+    /// When true, Esc closes the panel once there is no text input
+    /// left to cancel
+    pub fn is_dismissible(self) -> bool {
+        self.contains(Self::DISMISSIBLE)
+    }
 }
 
 impl Default for PanelCapabilities {
@@ -131,4 +142,14 @@ mod tests {
         // Vim navigation doesn't require scroll capabilities
         assert!(!vim_nav.supports_vim_vertical_scroll());
     }
+
+    #[test]
+    fn test_dismissible_independent_of_text_input() {
+        let popup = PanelCapabilities::SCROLL_VERTICAL | PanelCapabilities::DISMISSIBLE;
+        assert!(popup.is_dismissible());
+        assert!(!popup.accepts_text_input());
+
+        let input = PanelCapabilities::TEXT_INPUT;
+        assert!(!input.is_dismissible());
+    }
 }
@@ -1,5 +1,9 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use simplelog::LevelFilter;
 use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::domain_models::Repository;
 
 #[derive(Parser, Debug)]
 #[command(name = "gh-pr-lander", version, about)]
@@ -7,4 +11,169 @@ pub struct Cli {
     /// Use a specific config file instead of the global one.
     #[arg(short, long, value_name = "PATH")]
     pub config: Option<PathBuf>,
+
+    /// Focus this repository on startup, adding it (on its default branch unless
+    /// `@BRANCH` is given) if it's not in the recent list.
+    #[arg(short, long, value_name = "OWNER/NAME[@BRANCH]")]
+    pub repo: Option<RepoArg>,
+
+    /// Log level: off, error, warn, info, debug or trace (overrides RUST_LOG).
+    #[arg(long, value_name = "LEVEL", value_parser = parse_log_level)]
+    pub log_level: Option<LevelFilter>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Non-interactive subcommands (run without starting the TUI)
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Print the pull requests of a repository and exit.
+    List {
+        /// Repository to list PRs for (the default branch unless `@BRANCH` is given).
+        #[arg(value_name = "OWNER/NAME[@BRANCH]")]
+        repo: RepoArg,
+
+        /// Print JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+fn parse_log_level(s: &str) -> Result<LevelFilter, String> {
+    LevelFilter::from_str(s).map_err(|_| {
+        format!(
+            "invalid log level '{}' (expected off, error, warn, info, debug or trace)",
+            s
+        )
+    })
+}
+
+/// A repository given as `owner/name[@branch]` on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoArg {
+    pub owner: String,
+    pub name: String,
+    /// Base branch, None to use the repository's default branch
+    pub branch: Option<String>,
+}
+
+impl RepoArg {
+    /// The repository on the default host, tracking the given branch
+    ///
+    /// None without `@branch`: the default branch has to be looked up first.
+    pub fn to_repository(&self) -> Option<Repository> {
+        self.branch
+            .as_deref()
+            .map(|branch| Repository::new(&self.owner, &self.name, branch))
+    }
+
+    /// Whether `repo` is this repository (any of its branches without `@branch`)
+    pub fn matches(&self, repo: &Repository) -> bool {
+        let branch = self.branch.as_deref().unwrap_or(&repo.branch);
+        repo.is_duplicate_of(&Repository::new(&self.owner, &self.name, branch))
+    }
+}
+
+impl FromStr for RepoArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (repo, branch) = match s.trim().split_once('@') {
+            Some((repo, branch)) if !branch.is_empty() => (repo, Some(branch.to_string())),
+            Some(_) => return Err(format!("expected OWNER/NAME@BRANCH, got '{}'", s)),
+            None => (s.trim(), None),
+        };
+        match repo.split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+                Ok(Self {
+                    owner: owner.to_string(),
+                    name: name.to_string(),
+                    branch,
+                })
+            }
+            _ => Err(format!("expected OWNER/NAME[@BRANCH], got '{}'", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_and_log_level() {
+        let cli = Cli::try_parse_from([
+            "gh-pr-lander",
+            "--repo",
+            "sassman/gh-pr-lander-rs",
+            "--log-level",
+            "warn",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.repo,
+            Some(RepoArg {
+                owner: "sassman".to_string(),
+                name: "gh-pr-lander-rs".to_string(),
+                branch: None,
+            })
+        );
+        assert_eq!(cli.log_level, Some(LevelFilter::Warn));
+    }
+
+    #[test]
+    fn test_parse_list_subcommand() {
+        let cli = Cli::try_parse_from(["gh-pr-lander", "list", "org/repo", "--json"]).unwrap();
+        match cli.command {
+            Some(Command::List { repo, json }) => {
+                assert_eq!(repo.owner, "org");
+                assert_eq!(repo.name, "repo");
+                assert!(json);
+            }
+            None => panic!("expected list subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_parse_repo_with_branch() {
+        let repo: RepoArg = "org/repo@develop".parse().unwrap();
+        assert_eq!(repo.branch.as_deref(), Some("develop"));
+        assert_eq!(repo.to_repository().unwrap().branch, "develop");
+
+        let repo: RepoArg = "org/repo@release/1.x".parse().unwrap();
+        assert_eq!(repo.name, "repo");
+        assert_eq!(repo.branch.as_deref(), Some("release/1.x"));
+
+        let repo: RepoArg = "org/repo".parse().unwrap();
+        assert_eq!(repo.branch, None);
+        assert_eq!(repo.to_repository(), None);
+    }
+
+    #[test]
+    fn test_repo_arg_matches_tracked_repository() {
+        let tracked = Repository::new("Org", "Repo", "develop");
+
+        let repo: RepoArg = "org/repo".parse().unwrap();
+        assert!(repo.matches(&tracked));
+        let repo: RepoArg = "org/repo@DEVELOP".parse().unwrap();
+        assert!(repo.matches(&tracked));
+        let repo: RepoArg = "org/repo@main".parse().unwrap();
+        assert!(!repo.matches(&tracked));
+
+        // Only repositories on the default host match
+        let enterprise = Repository::with_host("org", "repo", "develop", Some("ghe.corp".into()));
+        let repo: RepoArg = "org/repo".parse().unwrap();
+        assert!(!repo.matches(&enterprise));
+    }
+
+    #[test]
+    fn test_invalid_repo_is_rejected() {
+        for invalid in ["sassman", "/repo", "owner/", "a/b/c", "owner/repo@"] {
+            assert!(
+                Cli::try_parse_from(["gh-pr-lander", "--repo", invalid]).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
 }
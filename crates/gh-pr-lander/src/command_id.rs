@@ -3,10 +3,15 @@
 //! This module defines all command IDs as an enum for type-safe,
 //! memory-efficient command references that can be serialized/deserialized.
 
+use serde::de::IntoDeserializer;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-use crate::{actions::RepositoryAction, views::KeyBindingsView};
+use crate::{
+    actions::RepositoryAction,
+    state::{AppState, PrFilter},
+    views::KeyBindingsView,
+};
 
 /// Unique identifier for each command in the application.
 ///
@@ -33,12 +38,38 @@ pub enum CommandId {
     RepositoryAdd,
     /// Remove the current repository from the list
     RepositoryRemove,
+    /// Move current repository tab one position to the left
+    RepositoryMoveLeft,
+    /// Move current repository tab one position to the right
+    RepositoryMoveRight,
     /// Open the current repository in the browser
     RepositoryOpenInBrowser,
     /// Switch to the next repository
     RepositoryNext,
     /// Switch to the previous repository
     RepositoryPrevious,
+    /// Switch to a repository by name (palette argument: `repo <name>`)
+    RepositorySwitch,
+    /// Switch to repository tab 1
+    RepositorySelect1,
+    /// Switch to repository tab 2
+    RepositorySelect2,
+    /// Switch to repository tab 3
+    RepositorySelect3,
+    /// Switch to repository tab 4
+    RepositorySelect4,
+    /// Switch to repository tab 5
+    RepositorySelect5,
+    /// Switch to repository tab 6
+    RepositorySelect6,
+    /// Switch to repository tab 7
+    RepositorySelect7,
+    /// Switch to repository tab 8
+    RepositorySelect8,
+    /// Switch to repository tab 9
+    RepositorySelect9,
+    /// Switch to repository tab 10
+    RepositorySelect10,
 
     // === Navigation ===
     /// Navigate to the next item (down)
@@ -53,12 +84,26 @@ pub enum CommandId {
     NavigateToTop,
     /// Navigate to the bottom (G in vim)
     NavigateToBottom,
+    /// Navigate one page down
+    NavigatePageDown,
+    /// Navigate one page up
+    NavigatePageUp,
+    /// Navigate half a page down (Ctrl+D in vim)
+    NavigateHalfPageDown,
+    /// Navigate half a page up (Ctrl+U in vim)
+    NavigateHalfPageUp,
 
     // === Debug ===
     /// Toggle the debug console visibility
     DebugToggleConsoleView,
     /// Clear the debug console logs
     DebugClearLogs,
+    /// Cycle the minimum log level shown in the debug console
+    DebugCycleLogLevel,
+    /// Filter debug console lines by text
+    DebugFilterLogs,
+    /// Toggle the action history (recorded with `GH_PR_LANDER_DEBUG`)
+    DebugToggleActionHistory,
 
     // === Command palette ===
     /// Open the command palette
@@ -67,18 +112,36 @@ pub enum CommandId {
     // === PR Selection ===
     /// Toggle selection of current PR
     PrToggleSelection,
-    /// Select all PRs in current repository
+    /// Select all PRs matching the active filter
     PrSelectAll,
+    /// Select all PRs, ignoring the active filter
+    PrSelectAllUnfiltered,
     /// Deselect all PRs
     PrDeselectAll,
+    /// Invert selection of visible PRs
+    PrInvertSelection,
     /// Refresh PRs for current repository
     PrRefresh,
+    /// Refresh PRs for all repositories (staggered)
+    PrRefreshAll,
+    /// Load the next page of PRs beyond the PR limit
+    PrLoadMore,
+    /// Export PR list of current repository as JSON
+    PrExportJson,
 
     // === PR Operations ===
     /// Open current PR in browser
     PrOpenInBrowser,
+    /// Open the PR author's profile in browser
+    PrOpenAuthor,
+    /// Copy the PR's head branch name to the clipboard
+    PrCopyBranch,
+    /// Copy the selected PRs as a markdown list to the clipboard
+    PrCopyMarkdown,
     /// Merge selected PRs
     PrMerge,
+    /// Choose how PRs are merged (palette argument: `merge-method <method>`)
+    PrSetMergeMethod,
     /// Rebase/update selected PRs
     PrRebase,
     /// Approve selected PRs (shows confirmation popup)
@@ -89,22 +152,50 @@ pub enum CommandId {
     PrRequestChanges,
     /// Close selected PRs without merging (shows confirmation popup)
     PrClose,
+    /// Mark draft PRs as ready for review
+    PrMarkReady,
+    /// Convert ready PRs back to drafts
+    PrConvertToDraft,
 
     // === CI/Build Status ===
     /// Rerun failed CI jobs for current PR
     PrRerunFailedJobs,
+    /// Rerun all CI jobs of the current PR
+    PrRerunAllJobs,
+    /// Cancel running CI workflows of the current PR
+    PrCancelRuns,
     /// Open CI build logs in browser
     PrOpenBuildLogs,
+    /// Open CI build logs of all failing (or selected) PRs in browser
+    PrOpenFailingBuildLogs,
 
     // === IDE Integration ===
     /// Open current PR in configured IDE
     PrOpenInIDE,
+    /// Snooze the current PR until it is updated
+    PrToggleSnooze,
+    /// Watch the current PR for CI completion notifications
+    PrToggleWatch,
+    /// Checkout current PR branch in the configured local working copy
+    PrCheckoutLocal,
+    /// Show the commits of the current PR
+    PrShowCommits,
+    /// Show or hide the description panel of the current PR
+    PrToggleDescription,
+    /// Scroll the PR description panel down
+    PrDescriptionScrollDown,
+    /// Scroll the PR description panel up
+    PrDescriptionScrollUp,
+    /// Switch between compact and detailed PR rows
+    PrCycleRowDensity,
 
     // === Filter & Search ===
     /// Cycle through filter presets
     PrCycleFilter,
     /// Clear the current filter
     PrClearFilter,
+    /// Jump to a PR by its number
+    PrGotoNumber,
 
     // === Merge Bot ===
     /// Start merge bot for selected PRs
@@ -133,6 +224,20 @@ pub enum CommandId {
     BuildLogExpandAll,
     /// Collapse all nodes in build logs
     BuildLogCollapseAll,
+    /// Toggle wrapping of long build log lines
+    BuildLogToggleWrap,
+    /// Toggle showing only failed build log nodes
+    BuildLogToggleFailedOnly,
+    /// Copy the selected build log line, step or job
+    BuildLogCopy,
+    /// Download the full raw log of the selected job
+    BuildLogDownloadJobLog,
+    /// Rerun the failed jobs of the selected workflow
+    BuildLogRerunWorkflow,
+    /// Collapse the build log to the nodes with errors
+    BuildLogCollapseToErrors,
+    /// Search log lines in the build log
+    BuildLogSearch,
 
     // === Diff Viewer ===
     /// Open diff viewer for current PR
@@ -155,6 +260,12 @@ pub enum CommandId {
     DiffViewerPageUp,
 
     // === General ===
+    /// Switch to the next built-in theme
+    ThemeCycle,
+    /// Run the last repeatable command again (`.` in vim)
+    RepeatLast,
+    /// Dismiss the error banner
+    ErrorDismiss,
     /// Close the current view/panel
     GlobalClose,
     /// Quit the application
@@ -168,10 +279,12 @@ impl CommandId {
     /// those are handled separately in the reducer.
     pub fn to_action(self) -> crate::actions::Action {
         use crate::actions::{
-            Action, ContextAction, DebugConsoleAction, GlobalAction, MergeBotAction,
-            NavigationAction, PullRequestAction,
+            Action, CommandPaletteAction, CommitListAction, ContextAction, DebugConsoleAction,
+            GlobalAction, MergeBotAction, NavigationAction, PullRequestAction,
+        };
+        use crate::views::{
+            ActionHistoryView, AddRepositoryView, CommandPaletteView, DebugConsoleView,
         };
-        use crate::views::{AddRepositoryView, CommandPaletteView, DebugConsoleView};
 
         match self {
             // Semantic/Context actions (translated by views)
@@ -184,12 +297,31 @@ impl CommandId {
             Self::RepositoryAdd => {
                 Action::Global(GlobalAction::PushView(Box::new(AddRepositoryView::new())))
             }
-            Self::RepositoryRemove => Action::Repository(RepositoryAction::RemoveCurrentRepository),
+            Self::RepositoryRemove => {
+                Action::Repository(RepositoryAction::RemoveCurrentRepositoryRequest)
+            }
+            Self::RepositoryMoveLeft => Action::Repository(RepositoryAction::MoveLeft),
+            Self::RepositoryMoveRight => Action::Repository(RepositoryAction::MoveRight),
             Self::RepositoryOpenInBrowser => {
                 Action::Repository(RepositoryAction::OpenRepositoryInBrowser)
             }
             Self::RepositoryNext => Action::PullRequest(PullRequestAction::RepositoryNext),
             Self::RepositoryPrevious => Action::PullRequest(PullRequestAction::RepositoryPrevious),
+            Self::RepositorySwitch | Self::PrSetMergeMethod => {
+                // The palette lists the argument candidates once the keyword is typed
+                let keyword = self.argument_keyword().unwrap_or_default();
+                Action::CommandPalette(CommandPaletteAction::OpenWithQuery(format!("{keyword} ")))
+            }
+            Self::RepositorySelect1 => Action::PullRequest(PullRequestAction::RepositorySelect(0)),
+            Self::RepositorySelect2 => Action::PullRequest(PullRequestAction::RepositorySelect(1)),
+            Self::RepositorySelect3 => Action::PullRequest(PullRequestAction::RepositorySelect(2)),
+            Self::RepositorySelect4 => Action::PullRequest(PullRequestAction::RepositorySelect(3)),
+            Self::RepositorySelect5 => Action::PullRequest(PullRequestAction::RepositorySelect(4)),
+            Self::RepositorySelect6 => Action::PullRequest(PullRequestAction::RepositorySelect(5)),
+            Self::RepositorySelect7 => Action::PullRequest(PullRequestAction::RepositorySelect(6)),
+            Self::RepositorySelect8 => Action::PullRequest(PullRequestAction::RepositorySelect(7)),
+            Self::RepositorySelect9 => Action::PullRequest(PullRequestAction::RepositorySelect(8)),
+            Self::RepositorySelect10 => Action::PullRequest(PullRequestAction::RepositorySelect(9)),
 
             // Navigation
             Self::NavigateNext => Action::Navigate(NavigationAction::Next),
@@ -198,12 +330,21 @@ impl CommandId {
             Self::NavigateRight => Action::Navigate(NavigationAction::Right),
             Self::NavigateToTop => Action::Navigate(NavigationAction::ToTop),
             Self::NavigateToBottom => Action::Navigate(NavigationAction::ToBottom),
+            Self::NavigatePageDown => Action::Navigate(NavigationAction::PageDown),
+            Self::NavigatePageUp => Action::Navigate(NavigationAction::PageUp),
+            Self::NavigateHalfPageDown => Action::Navigate(NavigationAction::HalfPageDown),
+            Self::NavigateHalfPageUp => Action::Navigate(NavigationAction::HalfPageUp),
 
             // Debug
             Self::DebugToggleConsoleView => {
                 Action::Global(GlobalAction::PushView(Box::new(DebugConsoleView::new())))
             }
             Self::DebugClearLogs => Action::DebugConsole(DebugConsoleAction::Clear),
+            Self::DebugCycleLogLevel => Action::DebugConsole(DebugConsoleAction::CycleMinLevel),
+            Self::DebugFilterLogs => Action::DebugConsole(DebugConsoleAction::StartFilter),
+            Self::DebugToggleActionHistory => {
+                Action::Global(GlobalAction::PushView(Box::new(ActionHistoryView::new())))
+            }
 
             // Command palette
             Self::CommandPaletteOpen => {
@@ -213,28 +354,58 @@ impl CommandId {
             // PR Selection
             Self::PrToggleSelection => Action::PullRequest(PullRequestAction::ToggleSelection),
             Self::PrSelectAll => Action::PullRequest(PullRequestAction::SelectAll),
+            Self::PrSelectAllUnfiltered => {
+                Action::PullRequest(PullRequestAction::SelectAllUnfiltered)
+            }
             Self::PrDeselectAll => Action::PullRequest(PullRequestAction::DeselectAll),
+            Self::PrInvertSelection => Action::PullRequest(PullRequestAction::InvertSelection),
             Self::PrRefresh => Action::PullRequest(PullRequestAction::Refresh),
+            Self::PrRefreshAll => Action::PullRequest(PullRequestAction::RefreshAll),
+            Self::PrLoadMore => Action::PullRequest(PullRequestAction::LoadMore),
+            Self::PrExportJson => Action::PullRequest(PullRequestAction::ExportJson),
 
             // PR Operations
             Self::PrOpenInBrowser => Action::PullRequest(PullRequestAction::OpenInBrowser),
+            Self::PrOpenAuthor => Action::PullRequest(PullRequestAction::OpenAuthorProfile),
+            Self::PrCopyBranch => Action::PullRequest(PullRequestAction::CopyBranch),
+            Self::PrCopyMarkdown => Action::PullRequest(PullRequestAction::CopyMarkdown),
             Self::PrMerge => Action::PullRequest(PullRequestAction::MergeRequest),
             Self::PrRebase => Action::PullRequest(PullRequestAction::RebaseRequest),
             Self::PrApprove => Action::PullRequest(PullRequestAction::ApproveRequest),
             Self::PrComment => Action::PullRequest(PullRequestAction::CommentRequest),
             Self::PrRequestChanges => Action::PullRequest(PullRequestAction::RequestChangesRequest),
             Self::PrClose => Action::PullRequest(PullRequestAction::CloseRequest),
+            Self::PrMarkReady => Action::PullRequest(PullRequestAction::MarkReadyRequest),
+            Self::PrConvertToDraft => Action::PullRequest(PullRequestAction::ConvertToDraftRequest),
 
             // CI/Build Status
             Self::PrRerunFailedJobs => Action::PullRequest(PullRequestAction::RerunFailedJobs),
+            Self::PrRerunAllJobs => Action::PullRequest(PullRequestAction::RerunAllJobs),
+            Self::PrCancelRuns => Action::PullRequest(PullRequestAction::CancelRuns),
             Self::PrOpenBuildLogs => Action::PullRequest(PullRequestAction::OpenBuildLogs),
+            Self::PrOpenFailingBuildLogs => {
+                Action::PullRequest(PullRequestAction::OpenFailingBuildLogs)
+            }
 
             // IDE Integration
             Self::PrOpenInIDE => Action::PullRequest(PullRequestAction::OpenInIDE),
+            Self::PrToggleSnooze => Action::PullRequest(PullRequestAction::ToggleSnooze),
+            Self::PrToggleWatch => Action::PullRequest(PullRequestAction::ToggleWatch),
+            Self::PrCheckoutLocal => Action::PullRequest(PullRequestAction::CheckoutLocal),
+            Self::PrShowCommits => Action::CommitList(CommitListAction::Open),
+            Self::PrToggleDescription => Action::PullRequest(PullRequestAction::ToggleDescription),
+            Self::PrDescriptionScrollDown => {
+                Action::PullRequest(PullRequestAction::ScrollDescriptionDown)
+            }
+            Self::PrDescriptionScrollUp => {
+                Action::PullRequest(PullRequestAction::ScrollDescriptionUp)
+            }
+            Self::PrCycleRowDensity => Action::Global(GlobalAction::CycleRowDensity),
 
             // Filter & Search
             Self::PrCycleFilter => Action::PullRequest(PullRequestAction::CycleFilter),
             Self::PrClearFilter => Action::PullRequest(PullRequestAction::ClearFilter),
+            Self::PrGotoNumber => Action::PullRequest(PullRequestAction::GotoPrStart),
 
             // Merge Bot
             Self::MergeBotStart => Action::MergeBot(MergeBotAction::Start),
@@ -258,6 +429,23 @@ impl CommandId {
             Self::BuildLogCollapseAll => {
                 Action::BuildLog(crate::actions::BuildLogAction::CollapseAll)
             }
+            Self::BuildLogCollapseToErrors => {
+                Action::BuildLog(crate::actions::BuildLogAction::CollapseToErrors)
+            }
+            Self::BuildLogToggleWrap => {
+                Action::BuildLog(crate::actions::BuildLogAction::ToggleWrap)
+            }
+            Self::BuildLogToggleFailedOnly => {
+                Action::BuildLog(crate::actions::BuildLogAction::ToggleFailedOnly)
+            }
+            Self::BuildLogCopy => Action::BuildLog(crate::actions::BuildLogAction::CopySelection),
+            Self::BuildLogDownloadJobLog => {
+                Action::BuildLog(crate::actions::BuildLogAction::DownloadJobLog)
+            }
+            Self::BuildLogRerunWorkflow => {
+                Action::BuildLog(crate::actions::BuildLogAction::RerunWorkflow)
+            }
+            Self::BuildLogSearch => Action::BuildLog(crate::actions::BuildLogAction::StartSearch),
 
             // Diff Viewer
             Self::DiffViewerOpen => Action::DiffViewer(crate::actions::DiffViewerAction::Open),
@@ -285,6 +473,9 @@ impl CommandId {
             Self::DiffViewerPageUp => Action::DiffViewer(crate::actions::DiffViewerAction::PageUp),
 
             // General
+            Self::ThemeCycle => Action::Global(GlobalAction::CycleTheme),
+            Self::RepeatLast => Action::Global(GlobalAction::RepeatLast),
+            Self::ErrorDismiss => Action::Global(GlobalAction::DismissError),
             Self::GlobalClose => Action::Global(GlobalAction::Close),
             Self::GlobalQuit => Action::Global(GlobalAction::Quit),
         }
@@ -302,9 +493,22 @@ impl CommandId {
             // Repository
             Self::RepositoryAdd => "Add repository",
             Self::RepositoryRemove => "Remove repository",
+            Self::RepositoryMoveLeft => "Move repository left",
+            Self::RepositoryMoveRight => "Move repository right",
             Self::RepositoryOpenInBrowser => "Open repository in browser",
             Self::RepositoryNext => "Next repository",
             Self::RepositoryPrevious => "Previous repository",
+            Self::RepositorySwitch => "Switch repository",
+            Self::RepositorySelect1 => "Go to repository 1",
+            Self::RepositorySelect2 => "Go to repository 2",
+            Self::RepositorySelect3 => "Go to repository 3",
+            Self::RepositorySelect4 => "Go to repository 4",
+            Self::RepositorySelect5 => "Go to repository 5",
+            Self::RepositorySelect6 => "Go to repository 6",
+            Self::RepositorySelect7 => "Go to repository 7",
+            Self::RepositorySelect8 => "Go to repository 8",
+            Self::RepositorySelect9 => "Go to repository 9",
+            Self::RepositorySelect10 => "Go to repository 10",
 
             // Navigation
             Self::NavigateNext => "Navigate down",
@@ -313,10 +517,17 @@ impl CommandId {
             Self::NavigateRight => "Navigate right",
             Self::NavigateToTop => "Navigate to top",
             Self::NavigateToBottom => "Navigate to bottom",
+            Self::NavigatePageDown => "Page down",
+            Self::NavigatePageUp => "Page up",
+            Self::NavigateHalfPageDown => "Half page down",
+            Self::NavigateHalfPageUp => "Half page up",
 
             // Debug
             Self::DebugToggleConsoleView => "Toggle debug console",
             Self::DebugClearLogs => "Clear debug logs",
+            Self::DebugCycleLogLevel => "Cycle log level",
+            Self::DebugFilterLogs => "Filter logs",
+            Self::DebugToggleActionHistory => "Toggle action history",
 
             // Command palette
             Self::CommandPaletteOpen => "Open command palette",
@@ -324,28 +535,51 @@ impl CommandId {
             // PR Selection
             Self::PrToggleSelection => "Toggle PR selection",
             Self::PrSelectAll => "Select all PRs",
+            Self::PrSelectAllUnfiltered => "Select all PRs (ignore filter)",
             Self::PrDeselectAll => "Deselect all PRs",
+            Self::PrInvertSelection => "Invert PR selection",
             Self::PrRefresh => "Refresh PRs",
+            Self::PrRefreshAll => "Refresh PRs of all repositories",
+            Self::PrLoadMore => "Load more PRs",
+            Self::PrExportJson => "Export PRs as JSON",
 
             // PR Operations
             Self::PrOpenInBrowser => "Open PR in browser",
+            Self::PrOpenAuthor => "Open PR author profile",
+            Self::PrCopyBranch => "Copy PR branch name",
+            Self::PrCopyMarkdown => "Copy PRs as markdown",
             Self::PrMerge => "Merge PRs",
+            Self::PrSetMergeMethod => "Set merge method",
             Self::PrRebase => "Rebase PRs",
             Self::PrApprove => "Approve PRs",
             Self::PrComment => "Comment on PRs",
             Self::PrRequestChanges => "Request changes on PRs",
             Self::PrClose => "Close PRs",
+            Self::PrMarkReady => "Mark PR ready for review",
+            Self::PrConvertToDraft => "Convert PR to draft",
 
             // CI/Build Status
             Self::PrRerunFailedJobs => "Rerun failed CI jobs",
+            Self::PrRerunAllJobs => "Rerun all CI jobs",
+            Self::PrCancelRuns => "Cancel running CI",
             Self::PrOpenBuildLogs => "Open CI build logs",
+            Self::PrOpenFailingBuildLogs => "Open CI logs of failing PRs",
 
             // IDE Integration
             Self::PrOpenInIDE => "Open PR diff in IDE",
+            Self::PrToggleSnooze => "Snooze PR",
+            Self::PrToggleWatch => "Watch PR CI",
+            Self::PrCheckoutLocal => "Checkout PR locally",
+            Self::PrShowCommits => "Show PR commits",
+            Self::PrToggleDescription => "Toggle PR description",
+            Self::PrDescriptionScrollDown => "Scroll PR description down",
+            Self::PrDescriptionScrollUp => "Scroll PR description up",
+            Self::PrCycleRowDensity => "Toggle compact/detailed PR rows",
 
             // Filter & Search
             Self::PrCycleFilter => "Cycle PR filter",
             Self::PrClearFilter => "Clear PR filter",
+            Self::PrGotoNumber => "Go to PR number",
 
             // Merge Bot
             Self::MergeBotStart => "Start merge bot",
@@ -363,6 +597,13 @@ impl CommandId {
             Self::BuildLogToggleTimestamps => "Toggle timestamps",
             Self::BuildLogExpandAll => "Expand all",
             Self::BuildLogCollapseAll => "Collapse all",
+            Self::BuildLogCollapseToErrors => "Collapse to errors",
+            Self::BuildLogToggleWrap => "Toggle line wrap",
+            Self::BuildLogToggleFailedOnly => "Toggle failed only",
+            Self::BuildLogCopy => "Copy to clipboard",
+            Self::BuildLogDownloadJobLog => "Download full job log",
+            Self::BuildLogRerunWorkflow => "Rerun workflow",
+            Self::BuildLogSearch => "Search logs",
 
             // Diff Viewer
             Self::DiffViewerOpen => "Open diff viewer",
@@ -376,6 +617,9 @@ impl CommandId {
             Self::DiffViewerPageUp => "Page up",
 
             // General
+            Self::ThemeCycle => "Cycle theme",
+            Self::RepeatLast => "Repeat last command",
+            Self::ErrorDismiss => "Dismiss error",
             Self::GlobalClose => "Close",
             Self::GlobalQuit => "Quit",
         }
@@ -393,9 +637,24 @@ impl CommandId {
             // Repository
             Self::RepositoryAdd => "Add a new repository to track",
             Self::RepositoryRemove => "Remove the current repository from the list",
+            Self::RepositoryMoveLeft => "Move the current repository tab one position to the left",
+            Self::RepositoryMoveRight => {
+                "Move the current repository tab one position to the right"
+            }
             Self::RepositoryOpenInBrowser => "Open the current repository in your browser",
             Self::RepositoryNext => "Switch to the next repository",
             Self::RepositoryPrevious => "Switch to the previous repository",
+            Self::RepositorySwitch => "Switch to a repository by name (type: repo <name>)",
+            Self::RepositorySelect1 => "Switch to the 1st repository tab",
+            Self::RepositorySelect2 => "Switch to the 2nd repository tab",
+            Self::RepositorySelect3 => "Switch to the 3rd repository tab",
+            Self::RepositorySelect4 => "Switch to the 4th repository tab",
+            Self::RepositorySelect5 => "Switch to the 5th repository tab",
+            Self::RepositorySelect6 => "Switch to the 6th repository tab",
+            Self::RepositorySelect7 => "Switch to the 7th repository tab",
+            Self::RepositorySelect8 => "Switch to the 8th repository tab",
+            Self::RepositorySelect9 => "Switch to the 9th repository tab",
+            Self::RepositorySelect10 => "Switch to the 10th repository tab",
 
             // Navigation
             Self::NavigateNext => "Move selection or navigate down",
@@ -404,39 +663,107 @@ impl CommandId {
             Self::NavigateRight => "Move selection or navigate right",
             Self::NavigateToTop => "Jump to the first item",
             Self::NavigateToBottom => "Jump to the last item",
+            Self::NavigatePageDown => "Move the selection down by one page",
+            Self::NavigatePageUp => "Move the selection up by one page",
+            Self::NavigateHalfPageDown => "Move the selection down by half a page",
+            Self::NavigateHalfPageUp => "Move the selection up by half a page",
 
             // Debug
             Self::DebugToggleConsoleView => "Show or hide the debug console",
             Self::DebugClearLogs => "Clear all debug console logs",
+            Self::DebugCycleLogLevel => {
+                "Cycle the minimum level of debug console lines (trace to error)"
+            }
+            Self::DebugFilterLogs => "Show only debug console lines containing a text",
+            Self::DebugToggleActionHistory => {
+                "Show or hide the recently dispatched actions with timestamps"
+            }
 
             // Command palette
             Self::CommandPaletteOpen => "Open the command palette to search and execute commands",
 
             // PR Selection
             Self::PrToggleSelection => "Toggle selection of the current PR for bulk operations",
-            Self::PrSelectAll => "Select all PRs in the current repository",
+            Self::PrSelectAll => "Select all PRs matching the active filter",
+            Self::PrSelectAllUnfiltered => {
+                "Select all PRs in the current repository, including filtered-out ones"
+            }
             Self::PrDeselectAll => "Clear all PR selections",
+            Self::PrInvertSelection => {
+                "Toggle the selection of every PR matching the active filter"
+            }
             Self::PrRefresh => "Refresh PRs for the current repository",
+            Self::PrRefreshAll => "Refresh PRs for every repository, one after another",
+            Self::PrLoadMore => {
+                "Load the next page of PRs when the list stopped at the PR limit (max_prs)"
+            }
+            Self::PrExportJson => "Write the PR list of the current repository to a JSON file",
 
             // PR Operations
             Self::PrOpenInBrowser => "Open the current PR in your default web browser",
+            Self::PrOpenAuthor => "Open the GitHub profile of the current PR's author",
+            Self::PrCopyBranch => {
+                "Copy the current PR's head branch to the clipboard (owner:branch for forks)"
+            }
+            Self::PrCopyMarkdown => {
+                "Copy the selected PRs (or the current PR) as a markdown list, e.g. for standup notes"
+            }
             Self::PrMerge => "Merge selected PRs (or current PR if none selected)",
+            Self::PrSetMergeMethod => {
+                "Merge with a merge commit, squash or rebase (type: merge-method <method>)"
+            }
             Self::PrRebase => "Update selected PRs with latest from base branch",
             Self::PrApprove => "Approve selected PRs with a review",
             Self::PrComment => "Post a comment on selected PRs",
             Self::PrRequestChanges => "Request changes on selected PRs with a review",
             Self::PrClose => "Close selected PRs without merging",
+            Self::PrMarkReady => "Mark selected draft PRs as ready for review",
+            Self::PrConvertToDraft => "Convert selected ready PRs back to drafts",
 
             // CI/Build Status
-            Self::PrRerunFailedJobs => "Rerun failed CI workflow jobs for the current PR",
+            Self::PrRerunFailedJobs => {
+                "Rerun only the failed jobs of failed CI workflow runs for the current PR"
+            }
+            Self::PrRerunAllJobs => {
+                "Rerun every job of all CI workflow runs for the current PR, including passed ones"
+            }
+            Self::PrCancelRuns => {
+                "Cancel queued and in-progress CI workflow runs for the current PR"
+            }
             Self::PrOpenBuildLogs => "Open CI build logs in your default web browser",
+            Self::PrOpenFailingBuildLogs => {
+                "Open the CI build logs of the selected PRs, or of every PR with a failed build"
+            }
 
             // IDE Integration
             Self::PrOpenInIDE => "Open the PR diff in your configured IDE (uses gh pr view)",
+            Self::PrToggleSnooze => {
+                "Dim the current PR and move it to the bottom until it is updated (toggle)"
+            }
+            Self::PrToggleWatch => {
+                "Notify when CI of the current PR finishes, configured under [notify] (toggle)"
+            }
+            Self::PrCheckoutLocal => {
+                "Checkout the PR branch in the local working copy configured under [local_repos] (uses gh pr checkout)"
+            }
+            Self::PrShowCommits => {
+                "List the commits of the current PR; Enter opens the selected commit in the browser"
+            }
+            Self::PrToggleDescription => {
+                "Show or hide a panel with the description of the PR under the cursor"
+            }
+            Self::PrDescriptionScrollDown => "Scroll the PR description panel down one line",
+            Self::PrDescriptionScrollUp => "Scroll the PR description panel up one line",
+            Self::PrCycleRowDensity => {
+                "Switch the PR table between single-line rows and two-line rows with author, age, status and branch"
+            }
 
             // Filter & Search
-            Self::PrCycleFilter => "Cycle through filter presets (All, Ready, Needs Rebase, etc.)",
+            Self::PrCycleFilter => {
+                "Cycle through filter presets (All, Ready, Needs Rebase, Drafts, Attention, etc.)"
+            }
             Self::PrClearFilter => "Clear the current filter and show all PRs",
+            Self::PrGotoNumber => "Move the cursor to the PR with the entered number",
 
             // Merge Bot
             Self::MergeBotStart => "Start automated merge bot for selected PRs",
@@ -454,6 +781,23 @@ impl CommandId {
             Self::BuildLogToggleTimestamps => "Toggle timestamp display in log lines",
             Self::BuildLogExpandAll => "Expand all nodes in the build log tree",
             Self::BuildLogCollapseAll => "Collapse all nodes in the build log tree",
+            Self::BuildLogCollapseToErrors => {
+                "Collapse the build log tree, keeping only jobs and steps with errors expanded"
+            }
+            Self::BuildLogToggleWrap => {
+                "Wrap long log lines across multiple rows instead of scrolling horizontally"
+            }
+            Self::BuildLogToggleFailedOnly => {
+                "Only show workflows, jobs and steps with errors in the build log tree"
+            }
+            Self::BuildLogCopy => "Copy the selected log line, or all lines of a step or job",
+            Self::BuildLogDownloadJobLog => {
+                "Download the complete raw log of the selected job to a temp file and open it"
+            }
+            Self::BuildLogRerunWorkflow => {
+                "Rerun the failed jobs of the workflow run at the cursor only"
+            }
+            Self::BuildLogSearch => "Search build log lines for a text (n/N jump between matches)",
 
             // Diff Viewer
             Self::DiffViewerOpen => {
@@ -471,6 +815,9 @@ impl CommandId {
             Self::DiffViewerPageUp => "Scroll up one page in the diff viewer",
 
             // General
+            Self::ThemeCycle => "Switch to the next built-in theme (dark, light, high-contrast)",
+            Self::RepeatLast => "Run the last command again on the current selection or cursor",
+            Self::ErrorDismiss => "Hide the error banner at the top of the screen",
             Self::GlobalClose => "Close the current view or panel",
             Self::GlobalQuit => "Exit the application",
         }
@@ -483,37 +830,81 @@ impl CommandId {
 
             Self::RepositoryAdd
             | Self::RepositoryRemove
+            | Self::RepositoryMoveLeft
+            | Self::RepositoryMoveRight
             | Self::RepositoryOpenInBrowser
             | Self::RepositoryNext
-            | Self::RepositoryPrevious => "Repository",
+            | Self::RepositoryPrevious
+            | Self::RepositorySwitch
+            | Self::RepositorySelect1
+            | Self::RepositorySelect2
+            | Self::RepositorySelect3
+            | Self::RepositorySelect4
+            | Self::RepositorySelect5
+            | Self::RepositorySelect6
+            | Self::RepositorySelect7
+            | Self::RepositorySelect8
+            | Self::RepositorySelect9
+            | Self::RepositorySelect10 => "Repository",
 
             Self::NavigateNext
             | Self::NavigatePrevious
             | Self::NavigateLeft
             | Self::NavigateRight
             | Self::NavigateToTop
-            | Self::NavigateToBottom => "Navigation",
+            | Self::NavigateToBottom
+            | Self::NavigatePageDown
+            | Self::NavigatePageUp
+            | Self::NavigateHalfPageDown
+            | Self::NavigateHalfPageUp => "Navigation",
 
-            Self::DebugToggleConsoleView | Self::DebugClearLogs => "Debug",
+            Self::DebugToggleConsoleView
+            | Self::DebugClearLogs
+            | Self::DebugCycleLogLevel
+            | Self::DebugFilterLogs
+            | Self::DebugToggleActionHistory => "Debug",
 
             Self::CommandPaletteOpen => "Command Palette",
 
             Self::PrToggleSelection
             | Self::PrSelectAll
+            | Self::PrSelectAllUnfiltered
             | Self::PrDeselectAll
+            | Self::PrInvertSelection
             | Self::PrRefresh
+            | Self::PrRefreshAll
+            | Self::PrLoadMore
+            | Self::PrExportJson
             | Self::PrOpenInBrowser
+            | Self::PrOpenAuthor
+            | Self::PrCopyBranch
+            | Self::PrCopyMarkdown
             | Self::PrMerge
+            | Self::PrSetMergeMethod
             | Self::PrRebase
             | Self::PrApprove
             | Self::PrComment
             | Self::PrRequestChanges
             | Self::PrClose
+            | Self::PrMarkReady
+            | Self::PrConvertToDraft
             | Self::PrRerunFailedJobs
+            | Self::PrRerunAllJobs
+            | Self::PrCancelRuns
             | Self::PrOpenBuildLogs
+            | Self::PrOpenFailingBuildLogs
             | Self::PrOpenInIDE
+            | Self::PrToggleSnooze
+            | Self::PrToggleWatch
+            | Self::PrCheckoutLocal
+            | Self::PrShowCommits
+            | Self::PrToggleDescription
+            | Self::PrDescriptionScrollDown
+            | Self::PrDescriptionScrollUp
+            | Self::PrCycleRowDensity
             | Self::PrCycleFilter
-            | Self::PrClearFilter => "Pull Request",
+            | Self::PrClearFilter
+            | Self::PrGotoNumber => "Pull Request",
 
             Self::MergeBotStart | Self::MergeBotStop | Self::MergeBotAddToQueue => "Merge Bot",
 
@@ -525,7 +916,14 @@ impl CommandId {
             | Self::BuildLogToggle
             | Self::BuildLogToggleTimestamps
             | Self::BuildLogExpandAll
-            | Self::BuildLogCollapseAll => "Build Log",
+            | Self::BuildLogCollapseAll
+            | Self::BuildLogCollapseToErrors
+            | Self::BuildLogToggleWrap
+            | Self::BuildLogToggleFailedOnly
+            | Self::BuildLogCopy
+            | Self::BuildLogDownloadJobLog
+            | Self::BuildLogRerunWorkflow
+            | Self::BuildLogSearch => "Build Log",
 
             Self::DiffViewerOpen
             | Self::DiffViewerSwitchPane
@@ -537,7 +935,11 @@ impl CommandId {
             | Self::DiffViewerPageDown
             | Self::DiffViewerPageUp => "Diff Viewer",
 
-            Self::GlobalClose | Self::GlobalQuit => "General",
+            Self::ThemeCycle
+            | Self::RepeatLast
+            | Self::ErrorDismiss
+            | Self::GlobalClose
+            | Self::GlobalQuit => "General",
         }
     }
 
@@ -575,6 +977,10 @@ impl CommandId {
             | Self::NavigateRight
             | Self::NavigateToTop
             | Self::NavigateToBottom
+            | Self::NavigatePageDown
+            | Self::NavigatePageUp
+            | Self::NavigateHalfPageDown
+            | Self::NavigateHalfPageUp
             | Self::CommandPaletteOpen => false,
 
             // Build log navigation commands are keyboard-driven within the view
@@ -583,7 +989,14 @@ impl CommandId {
             | Self::BuildLogToggle
             | Self::BuildLogToggleTimestamps
             | Self::BuildLogExpandAll
-            | Self::BuildLogCollapseAll => false,
+            | Self::BuildLogCollapseAll
+            | Self::BuildLogCollapseToErrors
+            | Self::BuildLogToggleWrap
+            | Self::BuildLogToggleFailedOnly
+            | Self::BuildLogCopy
+            | Self::BuildLogDownloadJobLog
+            | Self::BuildLogRerunWorkflow
+            | Self::BuildLogSearch => false,
 
             // Diff viewer view-specific commands are keyboard-driven
             Self::DiffViewerSwitchPane
@@ -595,18 +1008,298 @@ impl CommandId {
             | Self::DiffViewerPageDown
             | Self::DiffViewerPageUp => false,
 
-            // MergeBot is not yet tested nor stable
-            Self::MergeBotAddToQueue | Self::MergeBotStart | Self::MergeBotStop => false,
+            // Repository tab shortcuts are keyboard-driven
+            Self::RepositorySelect1
+            | Self::RepositorySelect2
+            | Self::RepositorySelect3
+            | Self::RepositorySelect4
+            | Self::RepositorySelect5
+            | Self::RepositorySelect6
+            | Self::RepositorySelect7
+            | Self::RepositorySelect8
+            | Self::RepositorySelect9
+            | Self::RepositorySelect10 => false,
+
+            // Queueing single PRs is not implemented yet
+            Self::MergeBotAddToQueue => false,
 
             // All others are shown (including DiffViewerOpen)
             _ => true,
         }
     }
 
+    /// Check if this command should be listed in the key bindings help
+    ///
+    /// Everything in the palette, plus the keyboard-only commands worth
+    /// knowing about: basic navigation and the repository tab shortcuts.
+    pub fn show_in_help(&self) -> bool {
+        self.show_in_palette()
+            || matches!(
+                self,
+                Self::NavigateNext
+                    | Self::NavigatePrevious
+                    | Self::NavigateToTop
+                    | Self::NavigateToBottom
+                    | Self::RepositorySelect1
+                    | Self::RepositorySelect2
+                    | Self::RepositorySelect3
+                    | Self::RepositorySelect4
+                    | Self::RepositorySelect5
+                    | Self::RepositorySelect6
+                    | Self::RepositorySelect7
+                    | Self::RepositorySelect8
+                    | Self::RepositorySelect9
+                    | Self::RepositorySelect10
+            )
+    }
+
+    /// Check if [`Self::RepeatLast`] may run this command again
+    ///
+    /// Only operations on the focused or selected PRs are repeatable, so
+    /// repeating runs the last operation rather than the last key press
+    /// (navigation, opening views, ...).
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            Self::PrMerge
+                | Self::PrRebase
+                | Self::PrApprove
+                | Self::PrComment
+                | Self::PrRequestChanges
+                | Self::PrClose
+                | Self::PrMarkReady
+                | Self::PrConvertToDraft
+                | Self::PrRerunFailedJobs
+                | Self::PrRerunAllJobs
+                | Self::PrCancelRuns
+                | Self::PrToggleSnooze
+                | Self::PrToggleWatch
+                | Self::PrOpenInBrowser
+                | Self::PrCopyBranch
+                | Self::PrCopyMarkdown
+                | Self::PrOpenInIDE
+                | Self::BuildLogRerunWorkflow
+        )
+    }
+
+    /// Palette keyword for commands that take an argument
+    ///
+    /// Typing `<keyword> <argument>` in the command palette lists the
+    /// argument's candidates (e.g. `repo gh-pr` or `merge-method squash`).
+    pub fn argument_keyword(&self) -> Option<&'static str> {
+        match self {
+            Self::RepositorySwitch => Some("repo"),
+            Self::PrSetMergeMethod => Some("merge-method"),
+            _ => None,
+        }
+    }
+
+    /// Placeholder shown in the palette input until the argument is typed
+    pub fn argument_placeholder(&self) -> Option<&'static str> {
+        match self {
+            Self::RepositorySwitch => Some("<name>"),
+            Self::PrSetMergeMethod => Some("<merge|squash|rebase>"),
+            _ => None,
+        }
+    }
+
+    /// Parse a snake_case command id (e.g. "pr_refresh") as used in config and session files
+    pub fn from_name(name: &str) -> Option<Self> {
+        let deserializer: serde::de::value::StrDeserializer<serde::de::value::Error> =
+            name.trim().into_deserializer();
+        Self::deserialize(deserializer).ok()
+    }
+
+    /// The snake_case command id (e.g. "pr_refresh"), the inverse of [`Self::from_name`]
+    pub fn name(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
     /// Get all command IDs that should appear in the command palette
     pub fn palette_command_ids() -> Vec<CommandId> {
         use strum::IntoEnumIterator;
 
         Self::iter().filter(|id| id.show_in_palette()).collect()
     }
+
+    /// Why this command can't run in the current state, if it can't
+    ///
+    /// Unlike [`Self::show_in_palette`] this depends on the state: the command
+    /// palette grays out unavailable commands and reports the reason when one
+    /// is executed anyway.
+    pub fn unavailable_reason(&self, state: &AppState) -> Option<&'static str> {
+        let main_view = &state.main_view;
+        let repo_data = main_view.repo_data.get(&main_view.selected_repository);
+        match self {
+            Self::RepositoryRemove
+            | Self::RepositoryOpenInBrowser
+            | Self::PrRefresh
+            | Self::PrRefreshAll
+            | Self::PrExportJson
+            | Self::PrCycleFilter
+            | Self::PrGotoNumber
+            | Self::RepositorySwitch
+                if main_view.repositories.is_empty() =>
+            {
+                Some("No repository added")
+            }
+
+            Self::RepositoryMoveLeft | Self::RepositoryMoveRight
+                if main_view.repositories.len() < 2 =>
+            {
+                Some("Only one repository added")
+            }
+
+            Self::PrLoadMore if !repo_data.is_some_and(|data| data.has_more_prs) => {
+                Some("All PRs are loaded")
+            }
+
+            Self::PrClearFilter
+                if !repo_data.is_some_and(|data| data.current_filter != PrFilter::All) =>
+            {
+                Some("No filter is active")
+            }
+
+            Self::PrDeselectAll
+                if !repo_data.is_some_and(|data| !data.selected_pr_numbers.is_empty()) =>
+            {
+                Some("No PRs are selected")
+            }
+
+            // Candidates hidden by the filter are reported when it runs
+            Self::PrOpenFailingBuildLogs
+                if !repo_data.is_some_and(|data| {
+                    !data
+                        .failing_build_targets(state.app_config.pr.bulk_include_hidden)
+                        .is_empty()
+                        || data.hidden_selection_count() > 0
+                        || data.hidden_failing_build_count() > 0
+                }) =>
+            {
+                Some("No PR with a failed build")
+            }
+
+            Self::DebugToggleActionHistory if !state.action_history.enabled => {
+                Some("Actions are only recorded with GH_PR_LANDER_DEBUG set")
+            }
+
+            Self::RepeatLast => match state.last_command {
+                Some(id) => id.unavailable_reason(state),
+                None => Some("No command to repeat yet"),
+            },
+
+            Self::PrSelectAll
+            | Self::PrSelectAllUnfiltered
+            | Self::PrInvertSelection
+            | Self::PrOpenInBrowser
+            | Self::PrOpenAuthor
+            | Self::PrCopyBranch
+            | Self::PrCopyMarkdown
+            | Self::PrMerge
+            | Self::PrRebase
+            | Self::PrApprove
+            | Self::PrComment
+            | Self::PrRequestChanges
+            | Self::PrClose
+            | Self::PrMarkReady
+            | Self::PrConvertToDraft
+            | Self::PrRerunFailedJobs
+            | Self::PrRerunAllJobs
+            | Self::PrCancelRuns
+            | Self::PrOpenBuildLogs
+            | Self::PrOpenInIDE
+            | Self::PrToggleSnooze
+            | Self::PrToggleWatch
+            | Self::PrCheckoutLocal
+            | Self::PrShowCommits
+            | Self::BuildLogOpen
+            | Self::DiffViewerOpen
+                if main_view.focused_pr().is_none() =>
+            {
+                Some("No pull request to act on")
+            }
+
+            _ => None,
+        }
+    }
+
+    /// Whether this command can run in the current state
+    pub fn is_enabled(&self, state: &AppState) -> bool {
+        self.unavailable_reason(state).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::{Pr, Repository};
+    use crate::state::RepositoryData;
+
+    #[test]
+    fn test_name_round_trip() {
+        assert_eq!(CommandId::PrRefresh.name(), "pr_refresh");
+        assert_eq!(
+            CommandId::from_name("pr_refresh"),
+            Some(CommandId::PrRefresh)
+        );
+        assert_eq!(CommandId::from_name("no_such_command"), None);
+    }
+
+    #[test]
+    fn test_pr_commands_need_a_pr() {
+        let mut state = AppState::default();
+        assert_eq!(
+            CommandId::PrRefresh.unavailable_reason(&state),
+            Some("No repository added")
+        );
+        assert!(!CommandId::PrMerge.is_enabled(&state));
+        assert!(CommandId::RepositoryAdd.is_enabled(&state));
+
+        state.main_view.repositories = vec![Repository::new("org", "repo", "main")];
+        state.main_view.repo_data.insert(
+            0,
+            RepositoryData {
+                prs: vec![Pr::new(1, "Fix bug", "octocat", "abc")],
+                ..Default::default()
+            },
+        );
+        assert!(CommandId::PrRefresh.is_enabled(&state));
+        assert!(CommandId::PrMerge.is_enabled(&state));
+        assert!(!CommandId::PrDeselectAll.is_enabled(&state));
+        assert!(!CommandId::PrLoadMore.is_enabled(&state));
+    }
+
+    #[test]
+    fn test_repository_shortcuts_are_listed_in_help_only() {
+        assert!(!CommandId::RepositorySelect1.show_in_palette());
+        assert!(CommandId::RepositorySelect1.show_in_help());
+        assert!(CommandId::RepositorySelect10.show_in_help());
+        assert!(CommandId::PrMerge.show_in_help());
+        assert!(!CommandId::Confirm.show_in_help());
+    }
+
+    #[test]
+    fn test_repeat_last_follows_last_command() {
+        assert!(CommandId::PrMerge.is_repeatable());
+        assert!(!CommandId::NavigateNext.is_repeatable());
+        assert!(!CommandId::BuildLogOpen.is_repeatable());
+        assert!(!CommandId::RepeatLast.is_repeatable());
+
+        let mut state = AppState::default();
+        assert_eq!(
+            CommandId::RepeatLast.unavailable_reason(&state),
+            Some("No command to repeat yet")
+        );
+
+        state.last_command = Some(CommandId::PrMerge);
+        assert_eq!(
+            CommandId::RepeatLast.unavailable_reason(&state),
+            Some("No repository added")
+        );
+        state.last_command = Some(CommandId::ThemeCycle);
+        assert!(CommandId::RepeatLast.is_enabled(&state));
+    }
 }
@@ -4,13 +4,25 @@
 //! or keyboard shortcuts. Commands wrap CommandIds with display metadata.
 //!
 //! Supports both static commands (from CommandId) and dynamic commands
-//! (generated at runtime, e.g., for issue tracker links).
+//! (generated at runtime, e.g., for issue tracker links). The most used
+//! commands are repeated in a "Recent" section on top of the list.
+//!
+//! Commands with an argument keyword (e.g. `repo <name>`) list their argument
+//! candidates as dynamic commands once the keyword is typed.
 
-use crate::actions::Action;
+use crate::actions::{Action, PullRequestAction};
 use crate::command_id::CommandId;
+use crate::domain_models::MergeMethod;
 use crate::keybindings::Keymap;
 use crate::state::AppState;
 use crate::utils::issue_extractor::RepoContext;
+use std::collections::HashMap;
+
+/// Number of most used commands listed in the "Recent" section
+const RECENT_COMMANDS: usize = 5;
+
+/// Category of the commands in the "Recent" section
+const RECENT_CATEGORY: &str = "Recent";
 
 /// Source of a command - either a static CommandId or a dynamic action
 #[derive(Debug, Clone)]
@@ -33,6 +45,19 @@ pub struct Command {
     pub source: CommandSource,
     /// The keyboard shortcut hint (populated from keybindings)
     pub shortcut_hint: Option<String>,
+    /// Whether this is a copy listed in the "Recent" section
+    pub recent: bool,
+    /// The argument this command stands for, if it is an argument candidate
+    pub argument: Option<CommandArgument>,
+}
+
+/// An argument candidate of a command that takes an argument
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandArgument {
+    /// The command the argument belongs to
+    pub command: CommandId,
+    /// The argument value matched against the typed argument
+    pub value: String,
 }
 
 impl Command {
@@ -41,6 +66,8 @@ impl Command {
         Self {
             source: CommandSource::Static(id),
             shortcut_hint: None,
+            recent: false,
+            argument: None,
         }
     }
 
@@ -49,6 +76,8 @@ impl Command {
         Self {
             source: CommandSource::Static(id),
             shortcut_hint: Some(hint.into()),
+            recent: false,
+            argument: None,
         }
     }
 
@@ -67,6 +96,35 @@ impl Command {
                 category: category.into(),
             },
             shortcut_hint: None,
+            recent: false,
+            argument: None,
+        }
+    }
+
+    /// Create an argument candidate for a command that takes an argument
+    ///
+    /// Candidates are only listed once the command's keyword is typed.
+    pub fn argument(
+        command: CommandId,
+        value: impl Into<String>,
+        action: Action,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            argument: Some(CommandArgument {
+                command,
+                value: value.into(),
+            }),
+            ..Self::dynamic(action, title, description, command.category())
+        }
+    }
+
+    /// The command id of a static command
+    pub fn id(&self) -> Option<CommandId> {
+        match &self.source {
+            CommandSource::Static(id) => Some(*id),
+            CommandSource::Dynamic { .. } => None,
         }
     }
 
@@ -88,12 +146,25 @@ impl Command {
 
     /// Get the category for grouping
     pub fn category(&self) -> &str {
+        if self.recent {
+            return RECENT_CATEGORY;
+        }
         match &self.source {
             CommandSource::Static(id) => id.category(),
             CommandSource::Dynamic { category, .. } => category,
         }
     }
 
+    /// Why this command can't run in the current state, if it can't
+    ///
+    /// Dynamic commands are generated for the current state and always available.
+    pub fn unavailable_reason(&self, state: &AppState) -> Option<&'static str> {
+        match &self.source {
+            CommandSource::Static(id) => id.unavailable_reason(state),
+            CommandSource::Dynamic { .. } => None,
+        }
+    }
+
     /// Get the action to dispatch
     pub fn to_action(&self) -> Action {
         match &self.source {
@@ -105,13 +176,13 @@ impl Command {
 
 /// Get all commands with shortcut hints populated from the keymap
 ///
-/// Uses `compact_hint_for_command` to show all keybindings for a command
-/// (e.g., "q/Esc" for GlobalClose instead of just "q")
+/// Uses `all_hints_for_command` to show all keybindings for a command
+/// (e.g., "q / Esc" for GlobalClose instead of just "q")
 pub fn get_palette_commands_with_hints(keymap: &Keymap) -> Vec<Command> {
     CommandId::palette_command_ids()
         .into_iter()
         .map(|id| {
-            if let Some(hint) = keymap.compact_hint_for_command(id) {
+            if let Some(hint) = keymap.all_hints_for_command(id) {
                 Command::with_shortcut(id, hint)
             } else {
                 Command::new(id)
@@ -120,17 +191,44 @@ pub fn get_palette_commands_with_hints(keymap: &Keymap) -> Vec<Command> {
         .collect()
 }
 
+/// Split a query like `repo gh-pr` into the command taking the argument and
+/// the (possibly empty) argument
+pub fn parse_argument_query(query: &str) -> Option<(CommandId, &str)> {
+    let (keyword, argument) = query.trim_start().split_once(' ')?;
+    let id = CommandId::palette_command_ids()
+        .into_iter()
+        .find(|id| id.argument_keyword() == Some(keyword))?;
+    Some((id, argument.trim()))
+}
+
 /// Filter commands based on a search query
 ///
 /// Performs case-insensitive fuzzy matching on title, description, and category.
+/// The "Recent" section is only listed for an empty query, so search results
+/// don't show a command twice. An argument query (e.g. `repo gh-pr`) lists
+/// only the matching argument candidates of that command.
 pub fn filter_commands(commands: &[Command], query: &str) -> Vec<Command> {
+    if let Some((id, argument)) = parse_argument_query(query) {
+        let argument_lower = argument.to_lowercase();
+        return commands
+            .iter()
+            .filter(|cmd| {
+                cmd.argument.as_ref().is_some_and(|arg| {
+                    arg.command == id && arg.value.to_lowercase().contains(&argument_lower)
+                })
+            })
+            .cloned()
+            .collect();
+    }
+
+    let commands = commands.iter().filter(|cmd| cmd.argument.is_none());
     if query.is_empty() {
-        return commands.to_vec();
+        return commands.cloned().collect();
     }
 
     let query_lower = query.to_lowercase();
     commands
-        .iter()
+        .filter(|cmd| !cmd.recent)
         .filter(|cmd| {
             cmd.title().to_lowercase().contains(&query_lower)
                 || cmd.description().to_lowercase().contains(&query_lower)
@@ -195,7 +293,63 @@ pub fn build_palette_commands(state: &AppState) -> Vec<Command> {
         &pr_texts,
         &repo_ctx,
     ));
-    all
+    let mut commands = recent_commands(&all, &state.command_palette.usage);
+    commands.extend(all);
+    commands.extend(argument_commands(state));
+    commands
+}
+
+/// Argument candidates of the commands that take an argument
+fn argument_commands(state: &AppState) -> Vec<Command> {
+    let main_view = &state.main_view;
+    let repositories = main_view
+        .repositories
+        .iter()
+        .enumerate()
+        .map(|(idx, repo)| {
+            Command::argument(
+                CommandId::RepositorySwitch,
+                repo.display_name(),
+                Action::PullRequest(PullRequestAction::RepositorySelect(idx)),
+                format!("Switch to {}", repo.display_name()),
+                format!("Switch to {}", repo.full_display_name()),
+            )
+        });
+    let merge_methods = MergeMethod::ALL.into_iter().map(|method| {
+        let current = if method == main_view.merge_method {
+            " (current)"
+        } else {
+            ""
+        };
+        Command::argument(
+            CommandId::PrSetMergeMethod,
+            method.as_str(),
+            Action::PullRequest(PullRequestAction::SetMergeMethod(method)),
+            format!("Merge method: {}", method.as_str()),
+            format!("Merge PRs with the {} method{}", method.as_str(), current),
+        )
+    });
+    repositories.chain(merge_methods).collect()
+}
+
+/// Copies of the most used commands, most used first
+fn recent_commands(commands: &[Command], usage: &HashMap<CommandId, u32>) -> Vec<Command> {
+    let mut used: Vec<(&Command, u32)> = commands
+        .iter()
+        .filter_map(|cmd| {
+            let count = *usage.get(&cmd.id()?)?;
+            (count > 0).then_some((cmd, count))
+        })
+        .collect();
+    // Stable sort keeps the category order for commands used equally often
+    used.sort_by(|(_, a), (_, b)| b.cmp(a));
+    used.into_iter()
+        .take(RECENT_COMMANDS)
+        .map(|(cmd, _)| Command {
+            recent: true,
+            ..cmd.clone()
+        })
+        .collect()
 }
 
 fn selected_pr_texts(state: &AppState) -> Vec<String> {
@@ -203,18 +357,9 @@ fn selected_pr_texts(state: &AppState) -> Vec<String> {
     let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) else {
         return vec![];
     };
-    let pr_numbers: Vec<usize> = if repo_data.selected_pr_numbers.is_empty() {
-        repo_data
-            .prs
-            .get(repo_data.selected_pr)
-            .map(|pr| vec![pr.number])
-            .unwrap_or_default()
-    } else {
-        repo_data.selected_pr_numbers.iter().copied().collect()
-    };
-    pr_numbers
+    repo_data
+        .target_prs(state.app_config.pr.bulk_include_hidden)
         .iter()
-        .filter_map(|&num| repo_data.prs.iter().find(|pr| pr.number == num))
         .map(|pr| format!("{} {}", pr.title, pr.body))
         .collect()
 }
@@ -225,12 +370,67 @@ fn repo_context(state: &AppState) -> RepoContext {
         .main_view
         .repositories
         .get(repo_idx)
-        .map(|repo| {
-            RepoContext::new(
-                &repo.org,
-                &repo.repo,
-                repo.host.as_deref().unwrap_or(gh_client::DEFAULT_HOST),
-            )
-        })
+        .map(|repo| RepoContext::new(&repo.org, &repo.repo, repo.effective_host()))
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_commands_listed_by_usage() {
+        let commands = vec![
+            Command::new(CommandId::PrRefresh),
+            Command::new(CommandId::PrMerge),
+            Command::new(CommandId::PrApprove),
+        ];
+        let usage = HashMap::from([(CommandId::PrMerge, 1), (CommandId::PrApprove, 3)]);
+
+        let recent = recent_commands(&commands, &usage);
+        let ids: Vec<_> = recent.iter().filter_map(Command::id).collect();
+        assert_eq!(ids, vec![CommandId::PrApprove, CommandId::PrMerge]);
+        assert!(recent.iter().all(|cmd| cmd.category() == RECENT_CATEGORY));
+
+        // Searching lists every command once
+        let mut all = recent;
+        all.extend(commands);
+        assert_eq!(filter_commands(&all, "").len(), 5);
+        assert_eq!(filter_commands(&all, "merge").len(), 1);
+    }
+
+    #[test]
+    fn test_argument_query_lists_candidates() {
+        let mut state = AppState::default();
+        state.main_view.repositories = vec![
+            crate::domain_models::Repository::new("sassman", "gh-pr-lander-rs", "main"),
+            crate::domain_models::Repository::new("rust-lang", "rust", "master"),
+        ];
+        let commands = build_palette_commands(&state);
+
+        assert_eq!(
+            parse_argument_query("repo gh-pr"),
+            Some((CommandId::RepositorySwitch, "gh-pr"))
+        );
+        assert_eq!(parse_argument_query("repository"), None);
+
+        let repos = filter_commands(&commands, "repo ");
+        assert_eq!(repos.len(), 2);
+        let matched = filter_commands(&commands, "repo GH-PR");
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].title(), "Switch to sassman/gh-pr-lander-rs");
+
+        let methods = filter_commands(&commands, "merge-method squash");
+        assert_eq!(methods.len(), 1);
+        assert!(matches!(
+            methods[0].to_action(),
+            Action::PullRequest(PullRequestAction::SetMergeMethod(MergeMethod::Squash))
+        ));
+
+        // Candidates stay hidden from regular searches
+        assert!(filter_commands(&commands, "squash").is_empty());
+        assert!(filter_commands(&commands, "")
+            .iter()
+            .all(|cmd| cmd.argument.is_none()));
+    }
+}
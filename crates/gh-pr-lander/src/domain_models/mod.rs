@@ -5,6 +5,7 @@
 
 pub mod auto_merge;
 pub mod operation_monitor;
+pub mod pr_commit;
 pub mod pr_filter;
 pub mod pr_number;
 pub mod pull_request;
@@ -16,11 +17,14 @@ pub mod task_status;
 pub use auto_merge::AutoMergePr;
 #[allow(unused_imports)]
 pub use operation_monitor::{OperationMonitor, OperationType};
+pub use pr_commit::PrCommit;
 #[allow(unused_imports)]
 pub use pr_filter::PrFilter;
 #[allow(unused_imports)]
 pub use pr_number::PrNumber;
-pub use pull_request::{LoadingState, MaturityState, MergeableStatus, Pr, ReviewDecision};
+pub use pull_request::{
+    LoadingState, MaturityState, MergeMethod, MergeableStatus, MergeableSummary, Pr, ReviewDecision,
+};
 pub use repository::Repository;
 #[allow(unused_imports)]
 pub use task_status::{TaskStatus, TaskStatusType};
@@ -0,0 +1,60 @@
+//! PR commit domain model
+
+use chrono::{DateTime, Utc};
+
+/// A commit of a pull request
+#[derive(Debug, Clone)]
+pub struct PrCommit {
+    /// Commit SHA
+    pub sha: String,
+    /// Full commit message
+    pub message: String,
+    /// Author's GitHub username (or git author name)
+    pub author: String,
+    /// When the commit was authored
+    pub date: DateTime<Utc>,
+    /// HTML URL for viewing the commit in browser
+    pub html_url: String,
+}
+
+impl PrCommit {
+    /// First line of the commit message
+    pub fn summary(&self) -> &str {
+        self.message.lines().next().unwrap_or("")
+    }
+
+    /// Abbreviated SHA (first 7 characters)
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+}
+
+impl From<gh_client::PrCommit> for PrCommit {
+    fn from(commit: gh_client::PrCommit) -> Self {
+        Self {
+            sha: commit.sha,
+            message: commit.message,
+            author: commit.author,
+            date: commit.date,
+            html_url: commit.html_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_and_short_sha() {
+        let commit = PrCommit {
+            sha: "0123456789abcdef".to_string(),
+            message: "Fix the thing\n\nLonger explanation\nover several lines".to_string(),
+            author: "octocat".to_string(),
+            date: Utc::now(),
+            html_url: String::new(),
+        };
+        assert_eq!(commit.summary(), "Fix the thing");
+        assert_eq!(commit.short_sha(), "0123456");
+    }
+}
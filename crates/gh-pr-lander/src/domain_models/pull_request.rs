@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 // Re-export from gh_client for convenience
-pub use gh_client::types::{MaturityState, ReviewDecision};
+pub use gh_client::types::{MaturityState, MergeMethod, ReviewDecision};
 
 /// A GitHub Pull Request
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +29,9 @@ pub struct Pr {
     pub head_sha: String,
     /// HEAD branch name (e.g., "feature/my-branch")
     pub head_branch: String,
+    /// Owner of the fork the PR comes from (None for same-repository branches)
+    #[serde(default)]
+    pub head_owner: Option<String>,
     /// When the PR was created
     pub created_at: DateTime<Utc>,
     /// When the PR was last updated
@@ -43,6 +46,9 @@ pub struct Pr {
     pub maturity: MaturityState,
     /// Review decision state (approved, changes requested, etc.)
     pub review_decision: ReviewDecision,
+    /// Logins of users whose review is requested and still outstanding
+    #[serde(default)]
+    pub requested_reviewers: Vec<String>,
 }
 
 impl Pr {
@@ -63,6 +69,7 @@ impl Pr {
             needs_rebase: false,
             head_sha: head_sha.into(),
             head_branch: String::new(),
+            head_owner: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             html_url: String::new(),
@@ -70,6 +77,7 @@ impl Pr {
             deletions: 0,
             maturity: MaturityState::Ready,
             review_decision: ReviewDecision::Unknown,
+            requested_reviewers: Vec::new(),
         }
     }
 
@@ -78,6 +86,15 @@ impl Pr {
         self.html_url = url.into();
         self
     }
+
+    /// HEAD branch as understood by `gh pr checkout` / `git fetch`:
+    /// `owner:branch` for forks, just the branch name otherwise
+    pub fn branch_ref(&self) -> String {
+        match &self.head_owner {
+            Some(owner) => format!("{}:{}", owner, self.head_branch),
+            None => self.head_branch.clone(),
+        }
+    }
 }
 
 /// Mergeable status of a Pull Request
@@ -104,6 +121,17 @@ pub enum MergeableStatus {
     Merging,
 }
 
+/// Lets PR lists be sorted like the client sorts them (`SortPrsExt`)
+impl gh_client::types::PrSortKey for Pr {
+    fn maturity(&self) -> MaturityState {
+        self.maturity
+    }
+
+    fn number(&self) -> u64 {
+        self.number as u64
+    }
+}
+
 impl From<gh_client::CiState> for MergeableStatus {
     fn from(ci: gh_client::CiState) -> Self {
         match ci {
@@ -147,6 +175,83 @@ impl MergeableStatus {
             Self::Merging => "Merging...",
         }
     }
+
+    /// Whether merging in this state is likely to fail or merge broken code
+    pub fn is_merge_risk(&self) -> bool {
+        matches!(self, Self::BuildFailed | Self::Checking | Self::Conflicted)
+    }
+}
+
+/// Aggregate mergeable states of a set of PRs (e.g. the multi-selection)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeableSummary {
+    /// Number of PRs summarized
+    pub total: usize,
+    /// Non-zero counts per status, in display order
+    pub counts: Vec<(MergeableStatus, usize)>,
+}
+
+impl MergeableSummary {
+    /// Display order of the statuses, most actionable first
+    const ORDER: [MergeableStatus; 9] = [
+        MergeableStatus::Ready,
+        MergeableStatus::NeedsRebase,
+        MergeableStatus::BuildFailed,
+        MergeableStatus::Conflicted,
+        MergeableStatus::Blocked,
+        MergeableStatus::Checking,
+        MergeableStatus::Rebasing,
+        MergeableStatus::Merging,
+        MergeableStatus::Unknown,
+    ];
+
+    /// Summarize the given PRs
+    pub fn from_prs<'a>(prs: impl IntoIterator<Item = &'a Pr>) -> Self {
+        let statuses: Vec<MergeableStatus> = prs.into_iter().map(|pr| pr.mergeable).collect();
+        let counts = Self::ORDER
+            .iter()
+            .map(|status| (*status, statuses.iter().filter(|s| *s == status).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        Self {
+            total: statuses.len(),
+            counts,
+        }
+    }
+
+    /// Counts as display text: "3 ready · 1 needs rebase · 1 conflicts"
+    pub fn label(&self) -> String {
+        self.counts
+            .iter()
+            .map(|(status, count)| {
+                format!(
+                    "{} {}",
+                    count,
+                    status.label().trim_end_matches("...").to_lowercase()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+
+    /// Whether every summarized PR is ready to merge
+    pub fn all_ready(&self) -> bool {
+        self.counts
+            .iter()
+            .all(|(status, _)| *status == MergeableStatus::Ready)
+    }
+
+    /// Whether any summarized PR cannot be merged as-is
+    pub fn has_blockers(&self) -> bool {
+        self.counts.iter().any(|(status, _)| {
+            matches!(
+                status,
+                MergeableStatus::BuildFailed
+                    | MergeableStatus::Conflicted
+                    | MergeableStatus::Blocked
+            )
+        })
+    }
 }
 
 /// Loading state for PR data
@@ -162,3 +267,39 @@ pub enum LoadingState {
     /// Failed to load
     Error(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: usize, mergeable: MergeableStatus) -> Pr {
+        let mut pr = Pr::new(number, format!("PR {}", number), "author", "sha");
+        pr.mergeable = mergeable;
+        pr
+    }
+
+    #[test]
+    fn test_mergeable_summary_label() {
+        let prs = [
+            pr(1, MergeableStatus::Conflicted),
+            pr(2, MergeableStatus::Ready),
+            pr(3, MergeableStatus::NeedsRebase),
+            pr(4, MergeableStatus::Ready),
+        ];
+        let summary = MergeableSummary::from_prs(&prs);
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.label(), "2 ready · 1 needs rebase · 1 conflicts");
+        assert!(!summary.all_ready());
+        assert!(summary.has_blockers());
+    }
+
+    #[test]
+    fn test_branch_ref_includes_fork_owner() {
+        let mut pr = pr(1, MergeableStatus::Ready);
+        pr.head_branch = "fix/typo".to_string();
+        assert_eq!(pr.branch_ref(), "fix/typo");
+
+        pr.head_owner = Some("contributor".to_string());
+        assert_eq!(pr.branch_ref(), "contributor:fix/typo");
+    }
+}
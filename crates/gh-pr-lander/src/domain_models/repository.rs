@@ -2,7 +2,6 @@
 //!
 //! Domain model for GitHub repositories.
 
-use gh_pr_config::DEFAULT_HOST;
 use serde::{Deserialize, Serialize};
 
 /// A tracked GitHub repository
@@ -14,7 +13,7 @@ pub struct Repository {
     pub repo: String,
     /// Branch name (default: "main")
     pub branch: String,
-    /// GitHub host (None = github.com)
+    /// GitHub host (None = the default host, see `gh_client::default_host`)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub host: Option<String>,
 }
@@ -31,14 +30,18 @@ impl Repository {
     }
 
     /// Create a repository with a custom host
+    ///
+    /// A host equal to the default host is normalized to None, so explicitly
+    /// chosen github.com repositories keep their host when `GITHUB_HOST`
+    /// points elsewhere.
     pub fn with_host(
         org: impl Into<String>,
         repo: impl Into<String>,
         branch: impl Into<String>,
         host: Option<String>,
     ) -> Self {
-        // Normalize github.com to None
-        let host = host.filter(|h| h != DEFAULT_HOST && !h.is_empty());
+        // Normalize the default host to None
+        let host = host.filter(|h| h != gh_client::default_host() && !h.is_empty());
         Self {
             org: org.into(),
             repo: repo.into(),
@@ -47,12 +50,12 @@ impl Repository {
         }
     }
 
-    /// Get the effective host (defaults to github.com)
+    /// Get the effective host (defaults to `GITHUB_HOST`/`GH_HOST` or github.com)
     pub fn effective_host(&self) -> &str {
-        self.host.as_deref().unwrap_or(DEFAULT_HOST)
+        self.host.as_deref().unwrap_or(gh_client::default_host())
     }
 
-    /// Check if this is a github.com repository
+    /// Check if this repository uses the default host
     pub fn is_github_com(&self) -> bool {
         self.host.is_none()
     }
@@ -67,6 +70,17 @@ impl Repository {
         )
     }
 
+    /// Get the web URL for a user's profile on this host
+    ///
+    /// GitHub App bot accounts (`name[bot]`) have no user page and link to
+    /// their app page instead.
+    pub fn user_profile_url(&self, login: &str) -> String {
+        match login.strip_suffix("[bot]") {
+            Some(app) => format!("https://{}/apps/{}", self.effective_host(), app),
+            None => format!("https://{}/{}", self.effective_host(), login),
+        }
+    }
+
     /// Get the SSH clone URL
     pub fn ssh_url(&self) -> String {
         format!(
@@ -79,11 +93,24 @@ impl Repository {
 
     /// Get the API base URL for this host
     pub fn api_base_url(&self) -> String {
-        if self.is_github_com() {
-            "https://api.github.com".to_string()
-        } else {
-            format!("https://{}/api/v3", self.effective_host())
+        gh_client::api_base_url(self.effective_host())
+    }
+
+    /// Whether `other` refers to the same repository and branch
+    ///
+    /// Org, repo and branch compare case-insensitively and a trailing `.git`
+    /// on the repo name is ignored, so `Org/Repo.git` duplicates `org/repo`.
+    pub fn is_duplicate_of(&self, other: &Repository) -> bool {
+        fn repo_name(repo: &str) -> &str {
+            repo.strip_suffix(".git").unwrap_or(repo)
         }
+
+        self.org.eq_ignore_ascii_case(&other.org)
+            && repo_name(&self.repo).eq_ignore_ascii_case(repo_name(&other.repo))
+            && self.branch.eq_ignore_ascii_case(&other.branch)
+            && self
+                .effective_host()
+                .eq_ignore_ascii_case(other.effective_host())
     }
 
     /// Display name for the repository (org/repo)
@@ -136,6 +163,26 @@ mod tests {
         assert_eq!(repo.web_url(), "https://ghe.example.com/org/repo");
     }
 
+    #[test]
+    fn test_user_profile_url() {
+        let repo = Repository::new("org", "repo", "main");
+        assert_eq!(
+            repo.user_profile_url("octocat"),
+            "https://github.com/octocat"
+        );
+        assert_eq!(
+            repo.user_profile_url("dependabot[bot]"),
+            "https://github.com/apps/dependabot"
+        );
+
+        let repo =
+            Repository::with_host("org", "repo", "main", Some("ghe.example.com".to_string()));
+        assert_eq!(
+            repo.user_profile_url("octocat"),
+            "https://ghe.example.com/octocat"
+        );
+    }
+
     #[test]
     fn test_ssh_url() {
         let repo = Repository::new("rust-lang", "rust", "main");
@@ -173,4 +220,23 @@ mod tests {
         assert!(repo.is_github_com());
         assert!(repo.host.is_none());
     }
+
+    #[test]
+    fn test_duplicate_ignores_casing() {
+        let repo = Repository::new("sassman", "gh-pr-lander-rs", "main");
+        assert!(repo.is_duplicate_of(&Repository::new("Sassman", "GH-PR-Lander-rs", "Main")));
+        assert!(!repo.is_duplicate_of(&Repository::new("sassman", "gh-pr-lander-rs", "dev")));
+        assert!(!repo.is_duplicate_of(&Repository::new("other", "gh-pr-lander-rs", "main")));
+    }
+
+    #[test]
+    fn test_duplicate_ignores_trailing_git() {
+        let repo = Repository::new("org", "repo", "main");
+        assert!(repo.is_duplicate_of(&Repository::new("org", "repo.git", "main")));
+        assert!(Repository::new("org", "Repo.git", "main").is_duplicate_of(&repo));
+
+        // A different host is a different repository
+        let ghe = Repository::with_host("org", "repo", "main", Some("ghe.example.com".to_string()));
+        assert!(!repo.is_duplicate_of(&ghe));
+    }
 }
@@ -9,11 +9,13 @@
 //! - `KeyPattern`: Textual representation of keys (e.g., "ctrl+p", "p a")
 //! - `Keymap`: Collection of bindings with matching logic
 //!
-//! Key patterns are textual and serializable, allowing future configuration via files.
+//! Key patterns are textual and serializable, so users can override bindings via the
+//! `[keybindings]` table of the config file (see [`Keymap::apply_overrides`]).
 
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
 use crate::command_id::CommandId;
 
@@ -40,7 +42,7 @@ impl KeyBinding {
 }
 
 /// Parsed key pattern for matching
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParsedKeyPattern {
     /// Single key with optional modifiers
     Single {
@@ -114,11 +116,40 @@ pub fn parse_key_pattern(pattern: &str) -> Option<ParsedKeyPattern> {
     }
 
     // Parse the key code
-    let code = parse_key_code(key_part)?;
+    let code = match parse_key_code(key_part)? {
+        // Terminals report shifted letters as uppercase chars (e.g. "shift+m" -> 'M')
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        code => code,
+    };
 
     Some(ParsedKeyPattern::Single { code, modifiers })
 }
 
+/// Build a display hint from a textual key pattern
+///
+/// E.g. "ctrl+r" -> "Ctrl+R", "g g" -> "g → g", "shift+tab" -> "Shift+Tab"
+pub fn hint_for_keys(keys: &str) -> String {
+    let keys = keys.trim();
+    if keys.contains(' ') {
+        return keys.split_whitespace().collect::<Vec<_>>().join(" → ");
+    }
+    if keys.chars().count() == 1 {
+        return keys.to_string();
+    }
+    keys.split('+')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
 /// Parse a key code string into a KeyCode
 fn parse_key_code(s: &str) -> Option<KeyCode> {
     match s {
@@ -156,6 +187,9 @@ fn parse_key_code(s: &str) -> Option<KeyCode> {
     }
 }
 
+/// How long to wait for the second key of a two-key sequence
+pub const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// State for tracking pending keys in two-key sequences
 #[derive(Debug, Clone)]
 pub struct PendingKey {
@@ -183,6 +217,34 @@ impl Keymap {
         Self { bindings: parsed }
     }
 
+    /// Merge user-defined bindings (key pattern -> command id) over this keymap
+    ///
+    /// A user binding replaces every existing binding with the same key pattern.
+    /// Invalid key patterns and unknown command ids are logged and skipped.
+    pub fn apply_overrides(&mut self, overrides: &BTreeMap<String, String>) {
+        for (keys, command) in overrides {
+            let Some(pattern) = parse_key_pattern(keys) else {
+                log::warn!("Ignoring keybinding with invalid key pattern: {:?}", keys);
+                continue;
+            };
+            let Some(command) = CommandId::from_name(command) else {
+                log::warn!(
+                    "Ignoring keybinding {:?}: unknown command {:?}",
+                    keys,
+                    command
+                );
+                continue;
+            };
+
+            log::info!("Binding {:?} to {:?}", keys, command);
+            self.bindings.retain(|(_, existing)| *existing != pattern);
+            self.bindings.push((
+                KeyBinding::new(keys.trim(), hint_for_keys(keys), command),
+                pattern,
+            ));
+        }
+    }
+
     /// Try to match a key event against the keymap
     ///
     /// Returns (matched_commands, should_clear_pending, new_pending_key)
@@ -192,8 +254,6 @@ impl Keymap {
         key: &KeyEvent,
         pending: Option<&PendingKey>,
     ) -> (Vec<CommandId>, bool, Option<char>) {
-        const SEQUENCE_TIMEOUT_SECS: u64 = 2;
-
         // Get current char if it's a simple char press (no ctrl/alt)
         let current_char = if let KeyCode::Char(c) = key.code {
             if !key.modifiers.contains(KeyModifiers::CONTROL)
@@ -208,8 +268,7 @@ impl Keymap {
         };
 
         // Check for valid pending key (not timed out)
-        let valid_pending =
-            pending.filter(|p| p.timestamp.elapsed().as_secs() < SEQUENCE_TIMEOUT_SECS);
+        let valid_pending = pending.filter(|p| p.timestamp.elapsed() < SEQUENCE_TIMEOUT);
 
         // If we have a pending key, try to complete a sequence
         if let (Some(pending), Some(current)) = (valid_pending, current_char) {
@@ -242,7 +301,7 @@ impl Keymap {
                     }
                 }
                 ParsedKeyPattern::Sequence { first, .. } => {
-                    // Check if this key starts a sequence (only if no single-key matches yet)
+                    // Check if this key starts a sequence
                     if new_pending.is_none() {
                         if let Some(c) = current_char {
                             if c == *first {
@@ -254,20 +313,54 @@ impl Keymap {
             }
         }
 
+        // Starting a sequence wins over single-key matches; a standalone binding
+        // of the same key is run once the sequence times out (see `standalone_commands`)
+        if let Some(pending) = new_pending {
+            return (vec![], false, Some(pending));
+        }
+
         // If we have single-key matches, return them
         if !matches.is_empty() {
             return (matches, true, None);
         }
 
-        // If we're starting a sequence, return that
-        if let Some(pending) = new_pending {
-            return (vec![], false, Some(pending));
-        }
-
         // No match
         (vec![], true, None)
     }
 
+    /// Get the bindings that complete a two-key sequence starting with `prefix`
+    ///
+    /// Returns (second key, binding) pairs in keymap order (for which-key hints).
+    pub fn continuations(&self, prefix: char) -> Vec<(char, &KeyBinding)> {
+        self.bindings
+            .iter()
+            .filter_map(|(binding, pattern)| match pattern {
+                ParsedKeyPattern::Sequence { first, second } if *first == prefix => {
+                    Some((*second, binding))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the single-key commands bound to `key` (without Ctrl/Alt)
+    ///
+    /// Used to re-interpret the first key of a timed-out sequence.
+    pub fn standalone_commands(&self, key: char) -> Vec<CommandId> {
+        self.bindings
+            .iter()
+            .filter_map(|(binding, pattern)| match pattern {
+                ParsedKeyPattern::Single { code, modifiers }
+                    if *code == KeyCode::Char(key)
+                        && !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    Some(binding.command)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Get all bindings (for displaying in help/command palette)
     pub fn bindings(&self) -> impl Iterator<Item = &KeyBinding> {
         self.bindings.iter().map(|(b, _)| b)
@@ -306,6 +399,22 @@ impl Keymap {
         command: CommandId,
         keep: F,
     ) -> Option<String>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.joined_hints(command, keep, "/")
+    }
+
+    /// Get all hints for a command, spaced out for listings (e.g., "Ctrl+R / F5")
+    ///
+    /// Used where there is room to show every binding, like the command palette
+    /// and the key bindings panel. Returns `None` if the command is unbound.
+    pub fn all_hints_for_command(&self, command: CommandId) -> Option<String> {
+        self.joined_hints(command, |_| true, " / ")
+    }
+
+    /// Deduplicated hints of a command that pass `keep`, joined by `separator`
+    fn joined_hints<F>(&self, command: CommandId, keep: F, separator: &str) -> Option<String>
     where
         F: Fn(&str) -> bool,
     {
@@ -325,7 +434,7 @@ impl Keymap {
         if unique_hints.is_empty() {
             None
         } else {
-            Some(unique_hints.join("/"))
+            Some(unique_hints.join(separator))
         }
     }
 }
@@ -462,6 +571,137 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_hints_joins_every_binding() {
+        let keymap = test_keymap();
+
+        assert_eq!(
+            keymap.all_hints_for_command(CommandId::GlobalClose),
+            Some("q / Esc".to_string())
+        );
+        // Deduplicated like the compact hint
+        assert_eq!(
+            keymap.all_hints_for_command(CommandId::RepositoryPrevious),
+            Some("Shift+Tab".to_string())
+        );
+        assert_eq!(
+            keymap.all_hints_for_command(CommandId::CommandPaletteOpen),
+            None
+        );
+    }
+
+    #[test]
+    fn test_any_binding_of_a_command_matches() {
+        let keymap = Keymap::new(vec![
+            KeyBinding::new("ctrl+r", "Ctrl+R", CommandId::PrRefresh),
+            KeyBinding::new("f5", "F5", CommandId::PrRefresh),
+        ]);
+
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(
+            keymap.match_key(&ctrl_r, None).0,
+            vec![CommandId::PrRefresh]
+        );
+        let f5 = KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE);
+        assert_eq!(keymap.match_key(&f5, None).0, vec![CommandId::PrRefresh]);
+    }
+
+    #[test]
+    fn test_continuations_for_prefix() {
+        use CommandId::*;
+        let keymap = Keymap::new(vec![
+            KeyBinding::new("p m", "p → m", PrMerge),
+            KeyBinding::new("g g", "gg", NavigateToTop),
+            KeyBinding::new("p a", "p → a", PrApprove),
+            KeyBinding::new("p", "p", GlobalClose),
+        ]);
+
+        let continuations: Vec<_> = keymap
+            .continuations('p')
+            .into_iter()
+            .map(|(key, binding)| (key, binding.command))
+            .collect();
+        assert_eq!(continuations, vec![('m', PrMerge), ('a', PrApprove)]);
+        assert!(keymap.continuations('x').is_empty());
+    }
+
+    #[test]
+    fn test_sequence_prefix_wins_over_standalone_key() {
+        use CommandId::*;
+        let keymap = Keymap::new(vec![
+            KeyBinding::new("g", "g", GlobalClose),
+            KeyBinding::new("g g", "gg", NavigateToTop),
+            KeyBinding::new("ctrl+g", "Ctrl+G", PrMerge),
+        ]);
+
+        let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        let (commands, clear, pending) = keymap.match_key(&g, None);
+        assert!(commands.is_empty());
+        assert!(!clear);
+        assert_eq!(pending, Some('g'));
+
+        assert_eq!(keymap.standalone_commands('g'), vec![GlobalClose]);
+        assert!(keymap.standalone_commands('x').is_empty());
+    }
+
+    #[test]
+    fn test_override_with_two_key_sequence() {
+        let mut keymap = test_keymap();
+        let overrides = BTreeMap::from([("g g".to_string(), "navigate_to_top".to_string())]);
+        keymap.apply_overrides(&overrides);
+
+        assert_eq!(
+            keymap.hint_for_command(CommandId::NavigateToTop),
+            Some("g → g")
+        );
+
+        let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        let (_, _, pending) = keymap.match_key(&g, None);
+        assert_eq!(pending, Some('g'));
+        let pending = PendingKey {
+            key: 'g',
+            timestamp: Instant::now(),
+        };
+        let (cmds, _, _) = keymap.match_key(&g, Some(&pending));
+        assert_eq!(cmds, vec![CommandId::NavigateToTop]);
+    }
+
+    #[test]
+    fn test_override_with_modified_key_replaces_default() {
+        let mut keymap = test_keymap();
+        let overrides = BTreeMap::from([
+            ("shift+m".to_string(), "pr_merge".to_string()),
+            ("ctrl+j".to_string(), "navigate_previous".to_string()),
+            // Replaces the default "q" binding
+            ("q".to_string(), "global_quit".to_string()),
+            // Invalid entries are skipped
+            ("ctrl+".to_string(), "pr_merge".to_string()),
+            ("x".to_string(), "no_such_command".to_string()),
+        ]);
+        keymap.apply_overrides(&overrides);
+
+        // Terminals report Shift+m as 'M' with SHIFT
+        let key = KeyEvent::new(KeyCode::Char('M'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.match_key(&key, None).0, vec![CommandId::PrMerge]);
+        assert_eq!(keymap.hint_for_command(CommandId::PrMerge), Some("Shift+M"));
+
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL);
+        assert_eq!(
+            keymap.match_key(&key, None).0,
+            vec![CommandId::NavigatePrevious]
+        );
+
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(keymap.match_key(&key, None).0, vec![CommandId::GlobalQuit]);
+        assert_eq!(
+            keymap.hints_for_command(CommandId::GlobalClose),
+            vec!["Esc"]
+        );
+
+        let key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert!(keymap.match_key(&key, None).0.is_empty());
+    }
+
     #[test]
     fn test_uppercase_key_matching() {
         use CommandId::*;
@@ -21,22 +21,46 @@ pub fn default_keymap() -> Keymap {
         KeyBinding::new("tab", "Tab", RepositoryNext),
         KeyBinding::new("shift+tab", "Shift+Tab", RepositoryPrevious),
         KeyBinding::new("backtab", "Shift+Tab", RepositoryPrevious),
+        KeyBinding::new("1", "1", RepositorySelect1),
+        KeyBinding::new("2", "2", RepositorySelect2),
+        KeyBinding::new("3", "3", RepositorySelect3),
+        KeyBinding::new("4", "4", RepositorySelect4),
+        KeyBinding::new("5", "5", RepositorySelect5),
+        KeyBinding::new("6", "6", RepositorySelect6),
+        KeyBinding::new("7", "7", RepositorySelect7),
+        KeyBinding::new("8", "8", RepositorySelect8),
+        KeyBinding::new("9", "9", RepositorySelect9),
+        KeyBinding::new("0", "0", RepositorySelect10),
         KeyBinding::new("r a", "r → a", RepositoryAdd),
         KeyBinding::new("r o", "r → o", RepositoryOpenInBrowser),
+        KeyBinding::new("r d", "r → d", RepositoryRemove),
+        KeyBinding::new("r h", "r → h", RepositoryMoveLeft),
+        KeyBinding::new("r l", "r → l", RepositoryMoveRight),
+        KeyBinding::new("r r", "r → r", PrRefreshAll),
         // Scrolling
         // Note: "gg" and "G" are handled specially in keyboard middleware
         KeyBinding::new("g g", "gg", NavigateToTop),
         KeyBinding::new("G", "G", NavigateToBottom),
+        KeyBinding::new("pagedown", "PgDn", NavigatePageDown),
+        KeyBinding::new("pageup", "PgUp", NavigatePageUp),
+        KeyBinding::new("ctrl+d", "Ctrl+D", NavigateHalfPageDown),
+        KeyBinding::new("ctrl+u", "Ctrl+U", NavigateHalfPageUp),
         // Debug
         KeyBinding::new("`", "`", DebugToggleConsoleView),
         KeyBinding::new("c", "c", DebugClearLogs),
+        KeyBinding::new("L", "L", DebugCycleLogLevel),
+        KeyBinding::new("/", "/", DebugFilterLogs),
+        KeyBinding::new("~", "~", DebugToggleActionHistory),
         // Command palette
         KeyBinding::new("ctrl+p", "Ctrl+P", CommandPaletteOpen),
         // PR Selection
         KeyBinding::new("space", "Space", PrToggleSelection),
         KeyBinding::new("ctrl+a", "Ctrl+A", PrSelectAll),
         KeyBinding::new("u", "u", PrDeselectAll),
+        KeyBinding::new("i", "i", PrInvertSelection),
         KeyBinding::new("ctrl+r", "Ctrl+R", PrRefresh),
+        KeyBinding::new("f5", "F5", PrRefresh),
+        KeyBinding::new("p n", "p -> n", PrLoadMore),
         // PR Operations
         KeyBinding::new("enter", "Enter", PrOpenInBrowser),
         KeyBinding::new("p m", "p -> m", PrMerge),
@@ -44,12 +68,27 @@ pub fn default_keymap() -> Keymap {
         KeyBinding::new("p c", "p -> c", PrComment),
         KeyBinding::new("p d", "p -> d", PrRequestChanges),
         KeyBinding::new("p x", "p -> x", PrClose),
+        KeyBinding::new("p R", "p -> R", PrMarkReady),
+        KeyBinding::new("p D", "p -> D", PrConvertToDraft),
         KeyBinding::new("p i", "p -> i", PrOpenInIDE),
+        KeyBinding::new("p o", "p -> o", PrCheckoutLocal),
+        KeyBinding::new("p z", "p -> z", PrToggleSnooze),
+        KeyBinding::new("p w", "p -> w", PrToggleWatch),
+        KeyBinding::new("p C", "p -> C", PrShowCommits),
+        KeyBinding::new("p b", "p -> b", PrToggleDescription),
+        KeyBinding::new("J", "J", PrDescriptionScrollDown),
+        KeyBinding::new("K", "K", PrDescriptionScrollUp),
+        KeyBinding::new("v", "v", PrCycleRowDensity),
+        KeyBinding::new("p u", "p -> u", PrOpenAuthor),
+        KeyBinding::new("p y", "p -> y", PrCopyBranch),
+        KeyBinding::new("p Y", "p -> Y", PrCopyMarkdown),
         KeyBinding::new("p l", "p -> l", PrOpenBuildLogs),
+        KeyBinding::new("p L", "p -> L", PrOpenFailingBuildLogs),
         KeyBinding::new("p r", "p -> r", PrRebase),
         // Filter & Search
         KeyBinding::new("f", "f", PrCycleFilter),
         KeyBinding::new("F", "F", PrClearFilter),
+        KeyBinding::new("#", "#", PrGotoNumber),
         // Build Log Operations
         KeyBinding::new("b l", "b -> l", BuildLogOpen),
         // Diff Viewer
@@ -61,7 +100,7 @@ pub fn default_keymap() -> Keymap {
         KeyBinding::new("ctrl+f", "Ctrl+F", DiffViewerPageDown),
         KeyBinding::new("ctrl+b", "Ctrl+B", DiffViewerPageUp),
         // Merge Bot
-        // KeyBinding::new("M", "M", MergeBotStart),
+        KeyBinding::new("M", "M", MergeBotStart),
         // KeyBinding::new("Q", "Q", MergeBotAddToQueue),
         // Help
         KeyBinding::new("?", "?", KeyBindingsToggleView),
@@ -73,7 +112,16 @@ pub fn default_keymap() -> Keymap {
         KeyBinding::new("t", "t", BuildLogToggleTimestamps),
         KeyBinding::new("e", "e", BuildLogExpandAll),
         KeyBinding::new("E", "E", BuildLogCollapseAll),
+        KeyBinding::new("z", "z", BuildLogCollapseToErrors),
+        KeyBinding::new("w", "w", BuildLogToggleWrap),
+        KeyBinding::new("f", "f", BuildLogToggleFailedOnly),
+        KeyBinding::new("y", "y", BuildLogCopy),
+        KeyBinding::new("D", "D", BuildLogDownloadJobLog),
+        KeyBinding::new("R", "R", BuildLogRerunWorkflow),
+        KeyBinding::new("/", "/", BuildLogSearch),
         // General
+        KeyBinding::new(".", ".", RepeatLast),
+        KeyBinding::new("x", "x", ErrorDismiss),
         KeyBinding::new("q", "q", GlobalClose),
         KeyBinding::new("esc", "Esc", GlobalClose),
         KeyBinding::new("ctrl+c", "Ctrl+C", GlobalQuit),
@@ -0,0 +1,90 @@
+//! `list` subcommand
+//!
+//! Prints the PR list of a repository to stdout and exits, without starting
+//! the TUI. Uses the same config, authentication and loading code as the app.
+
+use crate::cli::RepoArg;
+use crate::domain_models::{Pr, Repository};
+use crate::middleware::github_middleware::{app_credentials, default_branch, load_prs};
+use crate::utils::pr_export;
+use crate::utils::pr_list_query::PrListQuery;
+use gh_client::{ApiCache, ClientManager};
+use gh_pr_config::AppConfig;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::runtime::Runtime;
+
+/// Maximum width of the title column in the table output
+const TITLE_WIDTH: usize = 60;
+
+/// Load the PRs of `repo_arg` and print them as a table (or JSON with `json`)
+///
+/// Without an explicit `@branch`, the PRs against the repository's default
+/// branch are listed.
+pub fn run(repo_arg: &RepoArg, config_path: Option<&Path>, json: bool) -> anyhow::Result<()> {
+    let config = AppConfig::load(config_path);
+
+    let cache_file = gh_pr_config::api_cache_path()?;
+    let cache = Arc::new(Mutex::new(ApiCache::new(cache_file).unwrap_or_default()));
+    let mut client_manager = ClientManager::new(cache);
+    client_manager.set_app_credentials(app_credentials(&config.auth));
+
+    let runtime = Runtime::new()?;
+    let (repo, prs) = runtime.block_on(async {
+        let client = client_manager.clone_client(None).await?;
+        let repo = match repo_arg.to_repository() {
+            Some(repo) => repo,
+            None => Repository::new(
+                &repo_arg.owner,
+                &repo_arg.name,
+                default_branch(&client, &repo_arg.owner, &repo_arg.name).await?,
+            ),
+        };
+        let query = PrListQuery::for_repo(&config.pr_list, &repo);
+        let prs = load_prs(&client, &repo, &query).await?;
+        anyhow::Ok((repo, prs))
+    })?;
+    log::info!(
+        "list: Loaded {} PRs for {}",
+        prs.len(),
+        repo.full_display_name()
+    );
+
+    if json {
+        println!("{}", pr_export::to_json(&prs)?);
+    } else {
+        print!("{}", format_table(&prs));
+    }
+    Ok(())
+}
+
+/// Plain-text table with number, title, author and status columns
+fn format_table(prs: &[Pr]) -> String {
+    let author_width = prs
+        .iter()
+        .map(|pr| pr.author.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("AUTHOR".len());
+
+    let mut out = format!(
+        "{:>6}  {:<TITLE_WIDTH$}  {:<author_width$}  {}\n",
+        "#", "TITLE", "AUTHOR", "STATUS"
+    );
+    for pr in prs {
+        let title = if pr.title.chars().count() > TITLE_WIDTH {
+            let truncated: String = pr.title.chars().take(TITLE_WIDTH - 1).collect();
+            format!("{}…", truncated)
+        } else {
+            pr.title.clone()
+        };
+        out.push_str(&format!(
+            "{:>6}  {:<TITLE_WIDTH$}  {:<author_width$}  {}\n",
+            pr.number,
+            title,
+            pr.author,
+            pr.mergeable.label()
+        ));
+    }
+    out
+}
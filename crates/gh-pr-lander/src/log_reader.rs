@@ -1,11 +1,47 @@
 //! File Log Reader
 //!
 //! Reads log files with tailing support for the debug console.
-//! Returns only new lines (delta) for efficient updates.
+//! Returns only new lines (delta) for efficient updates, each tagged with
+//! its parsed log level.
 
+use log::Level;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A log line with its parsed level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Level of the record this line belongs to (None if unknown)
+    pub level: Option<Level>,
+    /// Raw line text
+    pub text: String,
+}
+
+/// Parse the level of a simplelog line (`<timestamp> [LEVEL] <message>`)
+pub fn parse_level(line: &str) -> Option<Level> {
+    let start = line.find('[')?;
+    let end = start + line[start..].find(']')?;
+    Level::from_str(line[start + 1..end].trim()).ok()
+}
+
+/// Tag lines with their level; lines without one (e.g. continuation lines
+/// of multi-line messages) inherit the level of the previous line
+fn tag_lines(lines: Vec<String>, mut last_level: Option<Level>) -> Vec<LogLine> {
+    lines
+        .into_iter()
+        .map(|text| {
+            if let Some(level) = parse_level(&text) {
+                last_level = Some(level);
+            }
+            LogLine {
+                level: last_level,
+                text,
+            }
+        })
+        .collect()
+}
 
 /// Reads log file with tailing support
 ///
@@ -15,6 +51,8 @@ pub struct FileLogReader {
     path: PathBuf,
     last_position: u64,
     max_initial_lines: usize,
+    /// Level of the last line read, inherited by continuation lines
+    last_level: Option<Level>,
 }
 
 impl FileLogReader {
@@ -28,20 +66,21 @@ impl FileLogReader {
             path,
             last_position: 0,
             max_initial_lines,
+            last_level: None,
         }
     }
 
     /// Read initial content (last N lines)
     ///
     /// Returns the initial lines to populate the view
-    pub fn read_initial(&mut self) -> std::io::Result<Vec<String>> {
+    pub fn read_initial(&mut self) -> std::io::Result<Vec<LogLine>> {
         let file = File::open(&self.path)?;
         let reader = BufReader::new(&file);
 
         // Read all lines, keep last max_initial_lines
         let all_lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
         let start = all_lines.len().saturating_sub(self.max_initial_lines);
-        let initial_lines = all_lines[start..].to_vec();
+        let initial_lines = self.tag(all_lines[start..].to_vec());
 
         // Remember position for tailing
         self.last_position = file.metadata()?.len();
@@ -52,7 +91,7 @@ impl FileLogReader {
     /// Poll for new lines (non-blocking)
     ///
     /// Returns only the NEW lines since last poll (delta)
-    pub fn poll_new_lines(&mut self) -> std::io::Result<Vec<String>> {
+    pub fn poll_new_lines(&mut self) -> std::io::Result<Vec<LogLine>> {
         let mut file = File::open(&self.path)?;
         let current_size = file.metadata()?.len();
 
@@ -66,7 +105,16 @@ impl FileLogReader {
         let new_lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
 
         self.last_position = current_size;
-        Ok(new_lines)
+        Ok(self.tag(new_lines))
+    }
+
+    /// Tag lines with their level, remembering the last one for the next read
+    fn tag(&mut self, lines: Vec<String>) -> Vec<LogLine> {
+        let lines = tag_lines(lines, self.last_level);
+        if let Some(last) = lines.last() {
+            self.last_level = last.level;
+        }
+        lines
     }
 
     /// Reset position to re-read from start
@@ -74,3 +122,36 @@ impl FileLogReader {
         self.last_position = 0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(
+            parse_level("2025-01-01T12:00:00+01:00 [WARN] gh_pr_lander: slow"),
+            Some(Level::Warn)
+        );
+        assert_eq!(parse_level("12:00:00 [ INFO] padded"), Some(Level::Info));
+        assert_eq!(parse_level("no level here"), None);
+        assert_eq!(parse_level("[not-a-level] text"), None);
+    }
+
+    #[test]
+    fn test_continuation_lines_inherit_level() {
+        let lines = tag_lines(
+            vec![
+                "t [ERROR] failed:".to_string(),
+                "  caused by: timeout".to_string(),
+                "t [DEBUG] next".to_string(),
+            ],
+            None,
+        );
+        let levels: Vec<_> = lines.iter().map(|line| line.level).collect();
+        assert_eq!(
+            levels,
+            vec![Some(Level::Error), Some(Level::Error), Some(Level::Debug)]
+        );
+    }
+}
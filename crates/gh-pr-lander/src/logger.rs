@@ -26,14 +26,9 @@ fn log_file_path() -> PathBuf {
     }
 }
 
-/// Initialize file-based logging
-///
-/// Creates a log file with timestamp.
-/// Returns the path to the log file for use by the debug console.
-pub fn init() -> PathBuf {
-    let log_file = log_file_path();
-
-    let level = std::env::var("RUST_LOG")
+/// Log level from `RUST_LOG` (Debug if unset)
+fn env_log_level() -> LevelFilter {
+    std::env::var("RUST_LOG")
         .map(|v| match v.to_lowercase().as_str() {
             "error" => LevelFilter::Error,
             "warn" => LevelFilter::Warn,
@@ -42,7 +37,18 @@ pub fn init() -> PathBuf {
             "trace" => LevelFilter::Trace,
             _ => LevelFilter::Info,
         })
-        .unwrap_or(LevelFilter::Debug);
+        .unwrap_or(LevelFilter::Debug)
+}
+
+/// Initialize file-based logging
+///
+/// Creates a log file with timestamp. An explicit `level` (from `--log-level`)
+/// takes precedence over `RUST_LOG`.
+/// Returns the path to the log file for use by the debug console.
+pub fn init(level: Option<LevelFilter>) -> PathBuf {
+    let log_file = log_file_path();
+
+    let level = level.unwrap_or_else(env_log_level);
 
     // Configure simplelog with timestamps
     let config = ConfigBuilder::new()
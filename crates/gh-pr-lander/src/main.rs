@@ -1,9 +1,9 @@
 use ratatui::{
     backend::CrosstermBackend,
     crossterm::{
-        event::{self, Event, KeyEventKind},
+        event::{self, EnableMouseCapture, Event, KeyEventKind, MouseButton, MouseEventKind},
         execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+        terminal::{enable_raw_mode, EnterAlternateScreen},
     },
     Terminal,
 };
@@ -22,32 +22,46 @@ mod dispatcher;
 mod domain_models;
 mod keybindings;
 mod keymap;
+mod list_command;
 mod log_reader;
 mod logger;
 mod middleware;
 mod reducers;
 mod state;
 mod store;
+mod terminal_background;
+mod terminal_restore;
 mod utils;
 mod view_models;
 mod views;
 
-use actions::{Action, BootstrapAction, GlobalAction};
+use actions::{Action, BootstrapAction, GlobalAction, NavigationAction, SetupWizardAction};
 use background::{spawn_background_worker, SharedState};
 use clap::Parser;
-use cli::Cli;
+use cli::{Cli, Command};
 use middleware::{
-    app_config_middleware::AppConfigMiddleware, bootstrap_middleware::BootstrapMiddleware,
+    app_config_middleware::AppConfigMiddleware,
+    auto_refresh_middleware::AutoRefreshMiddleware,
+    bootstrap_middleware::BootstrapMiddleware,
+    build_log_middleware::BuildLogMiddleware,
     command_palette_middleware::CommandPaletteMiddleware,
     confirmation_popup_middleware::ConfirmationPopupMiddleware,
-    debug_console_middleware::DebugConsoleMiddleware, diff_viewer_middleware::DiffViewerMiddleware,
-    github_middleware::GitHubMiddleware, keyboard_middleware::KeyboardMiddleware,
-    navigation_middleware::NavigationMiddleware, pull_request_middleware::PullRequestMiddleware,
-    repository_middleware::RepositoryMiddleware, session_middleware::SessionMiddleware,
-    text_input_middleware::TextInputMiddleware, Middleware,
+    debug_console_middleware::DebugConsoleMiddleware,
+    diff_viewer_middleware::DiffViewerMiddleware,
+    github_middleware::GitHubMiddleware,
+    invariant_middleware::{InvariantMiddleware, DEBUG_ENV},
+    keyboard_middleware::KeyboardMiddleware,
+    navigation_middleware::NavigationMiddleware,
+    notification_middleware::NotificationMiddleware,
+    pull_request_middleware::PullRequestMiddleware,
+    repository_middleware::RepositoryMiddleware,
+    session_middleware::SessionMiddleware,
+    text_input_middleware::TextInputMiddleware,
+    Middleware,
 };
 use state::AppState;
 use store::Store;
+use utils::viewport_sync::ViewportSync;
 
 fn main() -> io::Result<()> {
     // Parse CLI args first — `--help`/`--version`/parse errors must print
@@ -55,14 +69,29 @@ fn main() -> io::Result<()> {
     let cli = Cli::parse();
 
     // Initialize file-based logger (returns log file path for debug console)
-    let log_file = logger::init();
+    let log_file = logger::init(cli.log_level);
+
+    // Non-interactive subcommands never touch the terminal
+    if let Some(Command::List { repo, json }) = &cli.command {
+        if let Err(e) = list_command::run(repo, cli.config.as_deref(), *json) {
+            log::error!("list failed: {:#}", e);
+            eprintln!("Error: {:#}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     log::info!("Starting GitHub PR Lander");
 
-    // Setup terminal
+    // Query the terminal background while it still answers on the main screen
+    let detected_theme = terminal_background::detect_theme_name();
+
+    // Setup terminal (restored on panic and termination signals as well)
+    terminal_restore::install_panic_hook();
+    terminal_restore::install_signal_handler()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -71,27 +100,36 @@ fn main() -> io::Result<()> {
     let (result_tx, result_rx) = mpsc::channel::<Action>();
 
     // Create shared state for background thread to read
-    let initial_state = AppState::default();
+    let mut initial_state = AppState::default();
+    initial_state.action_history.enabled = std::env::var_os(DEBUG_ENV).is_some();
     let shared_state: SharedState = Arc::new(RwLock::new(initial_state.clone()));
 
     // Create store (main thread only, no middleware)
     let mut store = Store::new(initial_state);
 
+    // Persist the session on every way out, including Ctrl+C, panics and signals
+    let session_middleware = SessionMiddleware::new();
+    terminal_restore::set_exit_hook(session_middleware.exit_hook(shared_state.clone()));
+
     // Build middleware list (will run on background thread)
     let middleware: Vec<Box<dyn Middleware + Send>> = vec![
+        Box::new(InvariantMiddleware::new()), // Debug-only action sanity checks (GH_PR_LANDER_DEBUG)
         Box::new(BootstrapMiddleware::new()),
-        Box::new(SessionMiddleware::new()), // Session load/save - early in chain
-        Box::new(AppConfigMiddleware::new(cli.config.clone())), // Load app config early
-        Box::new(GitHubMiddleware::new()),  // GitHub client & API operations
+        Box::new(session_middleware), // Session load/save - early in chain
+        Box::new(AppConfigMiddleware::new(cli.config.clone(), detected_theme)), // Load app config early
+        Box::new(GitHubMiddleware::new()), // GitHub client & API operations
+        Box::new(AutoRefreshMiddleware::new()), // Periodic PR refresh
+        Box::new(NotificationMiddleware::new()), // CI completion notifications for watched PRs
         Box::new(KeyboardMiddleware::new()),
         // Translation middlewares - convert generic actions to view-specific actions
         Box::new(NavigationMiddleware::new()),
         Box::new(TextInputMiddleware::new()),
         // View-specific middlewares
         Box::new(DiffViewerMiddleware::new()),
+        Box::new(BuildLogMiddleware::new()),
         Box::new(CommandPaletteMiddleware::new()),
         Box::new(ConfirmationPopupMiddleware::new()),
-        Box::new(RepositoryMiddleware::new()),
+        Box::new(RepositoryMiddleware::new(cli.repo.clone())),
         Box::new(PullRequestMiddleware::new()), // Bulk loading coordination
         Box::new(DebugConsoleMiddleware::new(log_file)), // Debug console log reader
     ];
@@ -124,11 +162,10 @@ fn main() -> io::Result<()> {
     if let Err(e) = bg_handle.join() {
         log::error!("Background thread panicked: {:?}", e);
     }
+    terminal_restore::run_exit_hook();
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+    terminal_restore::restore()?;
 
     if let Err(err) = result {
         eprintln!("Error: {}", err);
@@ -138,9 +175,17 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-/// Maximum time budget for processing actions before rendering
-/// This ensures smooth animations even when many actions are queued
-const RENDER_BUDGET: Duration = Duration::from_millis(16); // ~60fps frame budget
+// The time budget for processing actions before rendering (`[ui] frame_budget_ms`)
+// and the idle input poll timeout (`[ui] poll_interval_ms`) come from the config
+
+/// Input poll timeout while busy (loading, animating, or recent activity)
+const ACTIVE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long after the last input or background result the loop stays active
+const ACTIVE_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Redraw at least this often while idle
+const IDLE_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
 
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
@@ -149,8 +194,21 @@ fn run_app(
     result_rx: &mpsc::Receiver<Action>,
     shared_state: &SharedState,
 ) -> io::Result<()> {
+    let app_start = Instant::now();
+    let mut viewport_sync = ViewportSync::new();
+    let mut last_activity = Instant::now();
+    let mut last_draw = Instant::now();
+    let mut force_redraw = true;
+    // Start time of the key sequence whose timeout was already reported
+    let mut timed_out_sequence: Option<Instant> = None;
+
     loop {
+        // Read every iteration, the config is loaded after the loop started
+        let render_budget = store.state().app_config.ui.frame_budget();
+        let idle_poll_interval = store.state().app_config.ui.poll_interval();
+
         // === PHASE 1: Process results from background (time-budgeted) ===
+        // The budget keeps animations smooth even when many actions are queued
         let start = Instant::now();
         let mut processed = 0;
 
@@ -165,6 +223,19 @@ fn run_app(
                     log::trace!("Main loop: re-routing event to middleware: {:?}", event);
                     action_tx.send(Action::Event(event)).ok();
                 }
+                // Interactive login needs the terminal, which only this thread owns
+                Action::SetupWizard(SetupWizardAction::RunGhAuthLogin) => {
+                    let host = store.state().setup_wizard.host.clone();
+                    log::info!("Main loop: running gh auth login for {}", host);
+                    let result =
+                        terminal_restore::suspend(terminal, || utils::gh_auth::login(&host))?;
+                    action_tx
+                        .send(Action::SetupWizard(SetupWizardAction::GhAuthLoginFinished(
+                            result,
+                        )))
+                        .ok();
+                    force_redraw = true;
+                }
                 action => {
                     // Apply to reducer
                     store.dispatch(action);
@@ -178,7 +249,7 @@ fn run_app(
             processed += 1;
 
             // Check time budget
-            if start.elapsed() >= RENDER_BUDGET {
+            if start.elapsed() >= render_budget {
                 break;
             }
         }
@@ -191,38 +262,64 @@ fn run_app(
             );
         }
 
-        // === PHASE 2: Render ===
-        let mut terminal_height = 0u16;
-        terminal.draw(|frame| {
-            let area = frame.area();
-            terminal_height = area.height;
-            views::render(store.state(), area, frame);
-        })?;
-
-        // Update debug console visible height based on terminal size
-        // (70% of screen height minus 2 for borders)
-        let debug_console_height = ((terminal_height as usize) * 70 / 100).saturating_sub(2);
-        if store.state().debug_console.visible_height != debug_console_height {
-            store.dispatch(Action::DebugConsole(
-                crate::actions::DebugConsoleAction::SetVisibleHeight(debug_console_height),
-            ));
+        // Keep viewport-dependent state in sync with the (debounced) terminal size
+        let size = terminal.size()?;
+        for action in
+            viewport_sync.actions((size.width, size.height), store.state(), Instant::now())
+        {
+            store.dispatch(action);
         }
 
-        // Update diff viewer viewport height based on terminal size
-        // (full height minus 3 for status bar and borders)
-        let terminal_width = terminal.size()?.width;
-        let diff_viewport_height = terminal_height.saturating_sub(3) as usize;
-        if let Some(ref inner) = store.state().diff_viewer.inner {
-            if inner.viewport_height != diff_viewport_height {
-                store.dispatch(Action::DiffViewer(
-                    crate::actions::DiffViewerAction::SetViewport {
-                        width: terminal_width,
-                        height: diff_viewport_height as u16,
-                    },
-                ));
+        if processed > 0 {
+            last_activity = Instant::now();
+        }
+        // Report an expired key sequence once, so the middleware can resolve it
+        if let Some(pending) = &store.state().pending_sequence {
+            if pending.is_expired() && timed_out_sequence != Some(pending.started_at) {
+                timed_out_sequence = Some(pending.started_at);
+                action_tx
+                    .send(Action::Global(GlobalAction::KeySequenceTimeout))
+                    .ok();
             }
         }
 
+        // Hide the error banner once it has been shown long enough
+        if store
+            .state()
+            .error_banner
+            .as_ref()
+            .is_some_and(|banner| banner.is_expired())
+        {
+            store.dispatch(Action::Global(GlobalAction::DismissError));
+        }
+
+        // Advance to the next queued toast once the visible one has expired
+        if store.state().toasts.is_expired() {
+            store.dispatch(Action::Global(GlobalAction::DismissToast));
+        }
+
+        let is_animating = store.state().is_animating();
+        if is_animating && store.state().spinner.needs_update(app_start.elapsed()) {
+            store.dispatch(Action::Global(GlobalAction::AnimationFrame(
+                app_start.elapsed(),
+            )));
+        }
+        let is_active = is_animating || last_activity.elapsed() < ACTIVE_GRACE_PERIOD;
+
+        // === PHASE 2: Render (skipped when state is unchanged) ===
+        // Periodic redraws keep relative times ("updated 2m ago") current
+        if store.take_dirty()
+            || force_redraw
+            || is_animating
+            || last_draw.elapsed() >= IDLE_REDRAW_INTERVAL
+        {
+            terminal.draw(|frame| {
+                views::render(store.state(), frame.area(), frame);
+            })?;
+            last_draw = Instant::now();
+            force_redraw = false;
+        }
+
         // === PHASE 3: Check quit condition ===
         if !store.state().running {
             // Signal background to shutdown
@@ -231,15 +328,41 @@ fn run_app(
         }
 
         // === PHASE 4: Handle user input ===
-        if event::poll(Duration::from_millis(10))? {
-            if let Event::Key(key) = event::read()? {
+        let poll_timeout = if is_active {
+            ACTIVE_POLL_INTERVAL
+        } else {
+            idle_poll_interval
+        };
+        if event::poll(poll_timeout)? {
+            last_activity = Instant::now();
+            match event::read()? {
                 // Only process key press events (ignore key release)
-                if key.kind == KeyEventKind::Press {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     // Send to background for middleware processing
                     action_tx
                         .send(Action::Global(GlobalAction::KeyPressed(key)))
                         .ok();
                 }
+                Event::Mouse(mouse) => {
+                    let action = match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => Some(Action::Global(
+                            GlobalAction::MouseClicked(mouse.column, mouse.row),
+                        )),
+                        // Scroll wheel moves the cursor like j/k
+                        MouseEventKind::ScrollDown => {
+                            Some(Action::Navigate(NavigationAction::Next))
+                        }
+                        MouseEventKind::ScrollUp => {
+                            Some(Action::Navigate(NavigationAction::Previous))
+                        }
+                        _ => None,
+                    };
+                    if let Some(action) = action {
+                        action_tx.send(action).ok();
+                    }
+                }
+                Event::Resize(..) => force_redraw = true,
+                _ => {}
             }
         }
     }
@@ -1,32 +1,39 @@
 //! App Config Middleware
 //!
-//! Handles loading application configuration on bootstrap.
+//! Handles loading application configuration on bootstrap, including the
+//! user's keybinding overrides and color theme. Without an explicitly
+//! configured theme, the theme detected from the terminal background is used.
 
 use crate::actions::{Action, BootstrapAction};
 use crate::dispatcher::Dispatcher;
+use crate::keymap::default_keymap;
 use crate::middleware::Middleware;
 use crate::state::AppState;
 use gh_pr_config::AppConfig;
+use gh_pr_lander_theme::Theme;
 use std::path::PathBuf;
 
 /// Middleware for loading application configuration
 pub struct AppConfigMiddleware {
     config_loaded: bool,
     config_override: Option<PathBuf>,
+    /// Built-in theme matching the terminal background (detected at startup)
+    detected_theme: &'static str,
 }
 
 impl AppConfigMiddleware {
-    pub fn new(config_override: Option<PathBuf>) -> Self {
+    pub fn new(config_override: Option<PathBuf>, detected_theme: &'static str) -> Self {
         Self {
             config_loaded: false,
             config_override,
+            detected_theme,
         }
     }
 }
 
 impl Default for AppConfigMiddleware {
     fn default() -> Self {
-        Self::new(None)
+        Self::new(None, "dark")
     }
 }
 
@@ -42,6 +49,15 @@ impl Middleware for AppConfigMiddleware {
                         "AppConfigMiddleware: Loaded config (ide_command: {})",
                         config.ide_command
                     );
+                    if !config.keybindings.is_empty() {
+                        let mut keymap = default_keymap();
+                        keymap.apply_overrides(&config.keybindings);
+                        dispatcher
+                            .dispatch(Action::Bootstrap(BootstrapAction::KeymapLoaded(keymap)));
+                    }
+                    let theme_name = config.theme.name.as_deref().unwrap_or(self.detected_theme);
+                    let theme = Theme::from_config(theme_name, &config.theme.colors);
+                    dispatcher.dispatch(Action::Bootstrap(BootstrapAction::ThemeLoaded(theme)));
                     dispatcher.dispatch(Action::Bootstrap(BootstrapAction::ConfigLoaded(config)));
                     self.config_loaded = true;
                 }
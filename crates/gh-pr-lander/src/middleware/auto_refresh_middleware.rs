@@ -0,0 +1,194 @@
+//! Auto-Refresh Middleware
+//!
+//! Periodically refreshes the PR list of the active repository:
+//! - Starts a single timer thread once the config is loaded (`pr.auto_refresh_secs > 0`)
+//! - The timer thread dispatches `AutoRefreshTick` at a fraction of the interval
+//! - On each tick, dispatches `Refresh` if the active repository is due and the
+//!   user is not in the middle of something (loading, popup, text input)
+//! - With `pr.auto_refresh_all`, dispatches a staggered `RefreshAll` instead
+//!
+//! The timer always targets whatever repository is active at tick time, so adding,
+//! removing or switching repositories never spawns additional timers.
+
+use crate::actions::{Action, BootstrapAction, PullRequestAction};
+use crate::capabilities::PanelCapabilities;
+use crate::dispatcher::Dispatcher;
+use crate::domain_models::LoadingState;
+use crate::middleware::Middleware;
+use crate::state::AppState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Lower bound for the tick period, so short intervals don't flood the action queue
+const MIN_TICK_PERIOD: Duration = Duration::from_secs(1);
+
+/// Granularity at which the timer thread checks for shutdown
+const SLEEP_STEP: Duration = Duration::from_millis(250);
+
+/// Middleware for periodic PR list refreshes
+pub struct AutoRefreshMiddleware {
+    /// Refresh interval (None = disabled)
+    interval: Option<Duration>,
+    /// Refresh all repositories instead of only the active one
+    refresh_all: bool,
+    /// Flag to signal the timer thread to stop
+    timer_active: Arc<AtomicBool>,
+    /// Handle to the timer thread
+    timer_thread: Option<JoinHandle<()>>,
+}
+
+impl AutoRefreshMiddleware {
+    pub fn new() -> Self {
+        Self {
+            interval: None,
+            refresh_all: false,
+            timer_active: Arc::new(AtomicBool::new(false)),
+            timer_thread: None,
+        }
+    }
+
+    /// Start the timer thread (no-op if already running)
+    fn start_timer(&mut self, interval: Duration, dispatcher: &Dispatcher) {
+        if self.timer_active.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // Tick several times per interval so a manual refresh (which resets
+        // `last_attempt`) only delays the next auto-refresh by a fraction of it
+        let tick_period = (interval / 4).max(MIN_TICK_PERIOD);
+        let timer_active = Arc::clone(&self.timer_active);
+        let dispatcher = dispatcher.clone();
+
+        self.timer_thread = Some(thread::spawn(move || {
+            let mut elapsed = Duration::ZERO;
+            while timer_active.load(Ordering::SeqCst) {
+                thread::sleep(SLEEP_STEP);
+                elapsed += SLEEP_STEP;
+                if elapsed >= tick_period {
+                    elapsed = Duration::ZERO;
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::AutoRefreshTick));
+                }
+            }
+        }));
+    }
+
+    /// Stop the timer thread
+    fn stop_timer(&mut self) {
+        self.timer_active.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.timer_thread.take() {
+            let _ = handle.join();
+        }
+    }
+
+    /// Whether the active repository should be refreshed now
+    fn should_refresh(state: &AppState, interval: Duration) -> bool {
+        // Don't disrupt the user while they are typing or confirming something
+        if state.confirmation_popup.is_some()
+            || state
+                .active_view()
+                .capabilities(state)
+                .contains(PanelCapabilities::TEXT_INPUT)
+        {
+            return false;
+        }
+
+        let repo_idx = state.main_view.selected_repository;
+        let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) else {
+            return false;
+        };
+
+        if repo_data.loading_state == LoadingState::Loading {
+            return false;
+        }
+
+        // Due once the last (manual or automatic) load attempt is older than the
+        // interval, so a repository failing to load isn't retried on every tick
+        match repo_data.last_attempt.or(repo_data.last_updated) {
+            Some(last_attempt) => {
+                let age = chrono::Local::now() - last_attempt;
+                age.to_std().map(|age| age >= interval).unwrap_or(false)
+            }
+            None => true,
+        }
+    }
+}
+
+impl Default for AutoRefreshMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for AutoRefreshMiddleware {
+    fn drop(&mut self) {
+        self.stop_timer();
+    }
+}
+
+impl Middleware for AutoRefreshMiddleware {
+    fn handle(&mut self, action: &Action, state: &AppState, dispatcher: &Dispatcher) -> bool {
+        match action {
+            Action::Bootstrap(BootstrapAction::ConfigLoaded(config)) => {
+                let secs = config.pr.auto_refresh_secs;
+                if secs > 0 {
+                    log::info!("AutoRefreshMiddleware: Refreshing PRs every {}s", secs);
+                    let interval = Duration::from_secs(secs);
+                    self.interval = Some(interval);
+                    self.refresh_all = config.pr.auto_refresh_all;
+                    self.start_timer(interval, dispatcher);
+                }
+                true // Pass through
+            }
+
+            Action::PullRequest(PullRequestAction::AutoRefreshTick) => {
+                if let Some(interval) = self.interval {
+                    if Self::should_refresh(state, interval) {
+                        let refresh = if self.refresh_all {
+                            PullRequestAction::RefreshAll
+                        } else {
+                            PullRequestAction::Refresh
+                        };
+                        log::debug!("AutoRefreshMiddleware: Dispatching {:?}", refresh);
+                        dispatcher.dispatch(Action::PullRequest(refresh));
+                    }
+                }
+                false // Consume - the tick carries no state change
+            }
+
+            _ => true, // All other actions pass through
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RepositoryData;
+
+    const INTERVAL: Duration = Duration::from_secs(300);
+
+    fn state_with(repo_data: RepositoryData) -> AppState {
+        let mut state = AppState::default();
+        state.main_view.repo_data.insert(0, repo_data);
+        state
+    }
+
+    #[test]
+    fn test_failed_load_waits_for_the_interval() {
+        let state = state_with(RepositoryData {
+            loading_state: LoadingState::Error("rate limited".to_string()),
+            last_attempt: Some(chrono::Local::now()),
+            ..Default::default()
+        });
+        assert!(!AutoRefreshMiddleware::should_refresh(&state, INTERVAL));
+
+        let state = state_with(RepositoryData {
+            loading_state: LoadingState::Error("rate limited".to_string()),
+            last_attempt: Some(chrono::Local::now() - chrono::Duration::minutes(10)),
+            ..Default::default()
+        });
+        assert!(AutoRefreshMiddleware::should_refresh(&state, INTERVAL));
+    }
+}
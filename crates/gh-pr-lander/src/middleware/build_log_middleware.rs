@@ -0,0 +1,63 @@
+//! Build Log Middleware
+//!
+//! Handles side effects for the build log panel that don't involve GitHub:
+//! - Copying the selected log line, step or job to the clipboard
+
+use crate::actions::{Action, BuildLogAction, StatusBarAction};
+use crate::dispatcher::Dispatcher;
+use crate::middleware::Middleware;
+use crate::state::AppState;
+use crate::utils::clipboard;
+
+/// Middleware for build log side effects
+pub struct BuildLogMiddleware;
+
+impl BuildLogMiddleware {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BuildLogMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for BuildLogMiddleware {
+    fn handle(&mut self, action: &Action, state: &AppState, dispatcher: &Dispatcher) -> bool {
+        if let Action::BuildLog(BuildLogAction::CopySelection) = action {
+            let lines = state.build_log.selected_lines();
+            if lines.is_empty() {
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                    "Nothing to copy",
+                    "Build Log",
+                )));
+                return false;
+            }
+
+            let text = lines
+                .iter()
+                .map(|line| clipboard::strip_ansi(line))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            match clipboard::copy_text(&text) {
+                Ok(()) => {
+                    let noun = if lines.len() == 1 { "line" } else { "lines" };
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                        format!("Copied {} {} to clipboard", lines.len(), noun),
+                        "Build Log",
+                    )));
+                }
+                Err(e) => {
+                    log::warn!("BuildLogMiddleware: {}", e);
+                    dispatcher.dispatch(Action::error(e, "Build Log"));
+                }
+            }
+            return false;
+        }
+
+        true
+    }
+}
@@ -1,9 +1,11 @@
 //! Command Palette Middleware
 //!
 //! Executes the selected command when CommandPalette::Execute is dispatched.
+//! Commands that are unavailable in the current state report why instead.
+//! Commands taking an argument keep the palette open and prefill their keyword.
 //! Text input and navigation are handled via view translation (translate_text_input/translate_navigation).
 
-use crate::actions::{Action, CommandPaletteAction};
+use crate::actions::{Action, CommandPaletteAction, GlobalAction, StatusBarAction};
 use crate::commands::{build_palette_commands, filter_commands};
 use crate::dispatcher::Dispatcher;
 use crate::middleware::Middleware;
@@ -32,8 +34,32 @@ impl Middleware for CommandPaletteMiddleware {
             let filtered = filter_commands(&all_commands, &state.command_palette.query);
 
             if let Some(cmd) = filtered.get(state.command_palette.selected_index) {
-                log::debug!("Command palette executing: {}", cmd.title());
-                dispatcher.dispatch(cmd.to_action());
+                if let Some(reason) = cmd.unavailable_reason(state) {
+                    log::debug!(
+                        "Command palette: {} is unavailable: {}",
+                        cmd.title(),
+                        reason
+                    );
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!("{} is unavailable: {}", cmd.title(), reason),
+                        "Command Palette",
+                    )));
+                } else {
+                    log::debug!("Command palette executing: {}", cmd.title());
+                    dispatcher.dispatch(cmd.to_action());
+                    if let Some(id) = cmd.id() {
+                        dispatcher.dispatch(Action::CommandPalette(
+                            CommandPaletteAction::CommandUsed(id),
+                        ));
+                        if id.is_repeatable() {
+                            dispatcher.dispatch(Action::Global(GlobalAction::RememberCommand(id)));
+                        }
+                        if id.argument_keyword().is_some() {
+                            // The palette stays open to pick the argument
+                            return false;
+                        }
+                    }
+                }
             }
             // Let the action continue to the reducer to close the palette
             return true;
@@ -3,10 +3,12 @@
 //! Handles the Confirm action by extracting the intent and message from state,
 //! then dispatching the appropriate PR action.
 
-use crate::actions::{Action, ConfirmationPopupAction, PullRequestAction};
+use crate::actions::{
+    Action, ConfirmationPopupAction, MergeBotAction, PullRequestAction, StatusBarAction,
+};
 use crate::dispatcher::Dispatcher;
 use crate::middleware::Middleware;
-use crate::state::{AppState, ConfirmationIntent};
+use crate::state::{AppState, ConfirmationIntent, MERGE_READY_ONLY};
 
 /// Middleware that handles confirmation popup action dispatching
 pub struct ConfirmationPopupMiddleware;
@@ -28,6 +30,10 @@ impl Middleware for ConfirmationPopupMiddleware {
         // Handle Confirm action - dispatch the appropriate PR action based on intent
         if let Action::ConfirmationPopup(ConfirmationPopupAction::Confirm) = action {
             if let Some(popup) = &state.confirmation_popup {
+                if !popup.is_valid() {
+                    log::debug!("Confirmation popup input invalid, not confirming");
+                    return false;
+                }
                 let message = popup.input_value.clone();
                 let pr_action = match &popup.intent {
                     ConfirmationIntent::Approve { pr_numbers } => {
@@ -54,6 +60,41 @@ impl Middleware for ConfirmationPopupMiddleware {
                             message,
                         })
                     }
+                    ConfirmationIntent::Merge {
+                        pr_numbers,
+                        unready,
+                        ..
+                    } => {
+                        let pr_numbers = if message.trim() == MERGE_READY_ONLY {
+                            pr_numbers
+                                .iter()
+                                .copied()
+                                .filter(|pr| !unready.contains(pr))
+                                .collect()
+                        } else {
+                            pr_numbers.clone()
+                        };
+                        if pr_numbers.is_empty() {
+                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                                "No ready PRs to merge",
+                                "Merge",
+                            )));
+                            dispatcher.dispatch(Action::ConfirmationPopup(
+                                ConfirmationPopupAction::Confirmed,
+                            ));
+                            return false;
+                        }
+                        Action::PullRequest(PullRequestAction::MergePrs { pr_numbers })
+                    }
+                    // Blocked PRs are skipped, everything else is queued
+                    ConfirmationIntent::StartMergeBot { plan, .. } => {
+                        Action::MergeBot(MergeBotAction::Begin {
+                            repo_idx: state.main_view.selected_repository,
+                            plan: plan.clone(),
+                        })
+                    }
+                    // Generic requests carry the action to dispatch
+                    ConfirmationIntent::Request { on_confirm, .. } => (**on_confirm).clone(),
                 };
 
                 log::debug!(
@@ -75,3 +116,103 @@ impl Middleware for ConfirmationPopupMiddleware {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{GlobalAction, RepositoryAction};
+    use crate::state::ConfirmationPopupState;
+    use std::sync::mpsc;
+
+    fn state_with_request(on_confirm: Action) -> AppState {
+        let mut state = AppState::default();
+        state.confirmation_popup = Some(ConfirmationPopupState::new(
+            ConfirmationIntent::Request {
+                title: "Remove Repository".to_string(),
+                message: "Really?".to_string(),
+                on_confirm: Box::new(on_confirm),
+            },
+            String::new(),
+            String::new(),
+        ));
+        state
+    }
+
+    #[test]
+    fn test_confirm_dispatches_carried_action() {
+        let (tx, rx) = mpsc::channel();
+        let state = state_with_request(Action::Repository(
+            RepositoryAction::RemoveCurrentRepository,
+        ));
+
+        let pass_through = ConfirmationPopupMiddleware::new().handle(
+            &Action::ConfirmationPopup(ConfirmationPopupAction::Confirm),
+            &state,
+            &Dispatcher::new(tx),
+        );
+
+        assert!(!pass_through);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(Action::Repository(
+                RepositoryAction::RemoveCurrentRepository
+            ))
+        ));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(Action::ConfirmationPopup(
+                ConfirmationPopupAction::Confirmed
+            ))
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_merge_without_ready_prs_dispatches_no_merge() {
+        let (tx, rx) = mpsc::channel();
+        let mut state = AppState::default();
+        state.confirmation_popup = Some(ConfirmationPopupState::new(
+            ConfirmationIntent::Merge {
+                pr_numbers: vec![1, 2],
+                unready: vec![1, 2],
+                warning: String::new(),
+                hidden_note: None,
+            },
+            MERGE_READY_ONLY.to_string(),
+            String::new(),
+        ));
+
+        ConfirmationPopupMiddleware::new().handle(
+            &Action::ConfirmationPopup(ConfirmationPopupAction::Confirm),
+            &state,
+            &Dispatcher::new(tx),
+        );
+
+        assert!(matches!(rx.try_recv(), Ok(Action::StatusBar(_))));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(Action::ConfirmationPopup(
+                ConfirmationPopupAction::Confirmed
+            ))
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_close_dispatches_nothing() {
+        let (tx, rx) = mpsc::channel();
+        let state = state_with_request(Action::Repository(
+            RepositoryAction::RemoveCurrentRepository,
+        ));
+
+        let pass_through = ConfirmationPopupMiddleware::new().handle(
+            &Action::Global(GlobalAction::Close),
+            &state,
+            &Dispatcher::new(tx),
+        );
+
+        // Close reaches the reducer, which pops the popup and clears its state
+        assert!(pass_through);
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Handles side effects for the diff viewer, including:
 //! - Close logic when Escape is pressed and there's nothing to cancel/escape from
-//! - Comment submission when Confirm is pressed while editing a comment
+//! - Comment updates when Confirm is pressed while editing a posted comment
+//!   (new comments stay pending until the review is submitted)
 //! - Review submission when Confirm is pressed in the review popup
+//! - Deleting posted comments from the pending comment list
+//! - Marking the selected file as viewed (`m`), so the session and GitHub
+//!   middlewares can persist and sync it
 
 use crate::actions::{Action, DiffViewerAction, GlobalAction};
 use crate::dispatcher::Dispatcher;
@@ -37,8 +41,8 @@ impl Middleware for DiffViewerMiddleware {
                         // Let reducer handle: cancel comment
                         return true;
                     }
-                    if inner.show_review_popup {
-                        // Let reducer handle: hide review popup
+                    if inner.show_review_popup || inner.show_comment_list {
+                        // Let reducer handle: hide review popup / comment list
                         return true;
                     }
                     if !inner.nav.file_tree_focused {
@@ -83,8 +87,13 @@ impl Middleware for DiffViewerMiddleware {
                                     ));
                                 }
                                 // Empty body without github_id - just close the editor (no API call)
+                            } else if editor.github_id.is_none() {
+                                // New comment - stays pending and is attached to the review
+                                log::debug!(
+                                    "DiffViewerMiddleware: Confirm with new comment - queued for review"
+                                );
                             } else if let Some(ref head_sha) = state.diff_viewer.head_sha {
-                                // Non-empty comment - submit to GitHub
+                                // Non-empty edit of a posted comment - submit to GitHub
                                 log::debug!(
                                     "DiffViewerMiddleware: Confirm with comment - dispatching SubmitCommentRequest"
                                 );
@@ -118,14 +127,67 @@ impl Middleware for DiffViewerMiddleware {
                                 },
                             ));
                         }
-                        // Let the Confirm action pass through to close the popup
-                        return true;
+                        // Consume Confirm: the popup stays open until the review is
+                        // submitted (closed by ReviewSubmitted), so errors keep it visible
+                        return false;
                     }
                 }
                 // Not in comment editor or review popup - let action pass through
                 true
             }
 
+            // Delete from the comment list: posted comments are deleted on GitHub
+            // first and removed locally once CommentDeleted arrives
+            Action::DiffViewer(DiffViewerAction::KeyPress('d' | 'x')) => {
+                let Some(ref inner) = state.diff_viewer.inner else {
+                    return true;
+                };
+                if !inner.show_comment_list || inner.is_editing_comment() {
+                    return true;
+                }
+                let posted = inner
+                    .selected_listed_comment()
+                    .and_then(|c| c.github_id.map(|id| (c, id)));
+                match (posted, state.diff_viewer.pr_number) {
+                    (Some((comment, github_id)), Some(pr_number)) => {
+                        dispatcher.dispatch(Action::DiffViewer(
+                            DiffViewerAction::DeleteCommentRequest {
+                                pr_number,
+                                github_id,
+                                path: comment.path.clone(),
+                                line: comment.position.line,
+                                side: comment.position.side.as_github_str().to_string(),
+                            },
+                        ));
+                        false // Consume: removed locally after the API call
+                    }
+                    // Local-only comment - reducer removes it
+                    _ => true,
+                }
+            }
+
+            // Toggle the viewed flag of the selected file (normal mode only)
+            Action::DiffViewer(DiffViewerAction::KeyPress('m')) => {
+                let Some(ref inner) = state.diff_viewer.inner else {
+                    return true;
+                };
+                if inner.is_editing_comment()
+                    || inner.show_review_popup
+                    || inner.show_comment_list
+                    || state.diff_viewer.pending_bracket.is_some()
+                {
+                    return true;
+                }
+                if let Some(path) = inner.viewed_toggle_target() {
+                    let viewed = !inner.viewed_files.contains(&path);
+                    dispatcher.dispatch(Action::DiffViewer(DiffViewerAction::SetFileViewed {
+                        path,
+                        viewed,
+                    }));
+                }
+                false // Consume: handled via SetFileViewed
+            }
+
             // All other actions pass through
             _ => true,
         }
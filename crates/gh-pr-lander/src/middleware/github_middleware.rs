@@ -1,27 +1,37 @@
 //! GitHub Operations Middleware
 //!
 //! Central middleware for all GitHub API interactions:
-//! - Client initialization (on BootstrapStart)
+//! - Client initialization (on ConfigLoaded, and on retry from the setup wizard)
 //! - PR loading (fetch_pull_requests)
 //! - PR operations (merge, rebase, approve, close)
 //! - CI operations (rerun failed jobs)
 //! - Browser/IDE integration
 
 use crate::actions::{
-    Action, BootstrapAction, BuildLogAction, DiffViewerAction, Event, GlobalAction, LoadedComment,
-    PullRequestAction, RepositoryAction, StatusBarAction,
+    Action, BootstrapAction, BuildLogAction, CommitListAction, DiffViewerAction, Event,
+    GlobalAction, LoadedComment, MergeBotAction, PullRequestAction, RepositoryAction,
+    SetupWizardAction, StatusBarAction,
 };
 use crate::dispatcher::Dispatcher;
-use crate::domain_models::{MergeableStatus, Pr, Repository};
+use crate::domain_models::{MaturityState, MergeableStatus, MergeableSummary, Pr, Repository};
 use crate::middleware::Middleware;
 use crate::state::AppState;
-use crate::state::{BuildLogJobMetadata, BuildLogJobStatus, BuildLogPrContext};
+use crate::state::{
+    BuildLogJobMetadata, BuildLogJobStatus, BuildLogPrContext, MergeBotDecision, MergeBotPlan,
+};
 use crate::utils::browser::open_url;
-use crate::views::BuildLogView;
+use crate::utils::message_template;
+use crate::utils::pr_list_query::PrListQuery;
+use crate::views::{BuildLogView, CommitListView};
+use gh_client::types::SortPrsExt;
 use gh_client::{
-    octocrab::Octocrab, ApiCache, CacheMode, CachedGitHubClient, ClientManager, GitHubClient,
-    MergeMethod, OctocrabClient, PullRequest, ReviewEvent,
+    octocrab::Octocrab, ApiCache, AppCredentials, CacheMode, CachedGitHubClient, ClientManager,
+    DraftReviewComment, GitHubClient, MergeMethod, MissingTokenError, OctocrabClient, PullRequest,
+    ReviewEvent,
 };
+use gh_pr_config::{AuthConfig, AuthMode};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::runtime::Runtime;
@@ -33,8 +43,15 @@ pub struct GitHubMiddleware {
     runtime: Runtime,
     /// Client manager for multi-host support
     client_manager: Arc<TokioMutex<ClientManager>>,
+    /// Login of the authenticated user per host, fetched once
+    viewer_logins: ViewerLogins,
+    /// Stop flag of the running merge bot (replaced on every start)
+    merge_bot_stop: Arc<AtomicBool>,
 }
 
+/// Authenticated user's login per host
+type ViewerLogins = Arc<Mutex<HashMap<String, String>>>;
+
 impl GitHubMiddleware {
     /// Create a new GitHub middleware
     pub fn new() -> Self {
@@ -51,6 +68,8 @@ impl GitHubMiddleware {
         Self {
             runtime,
             client_manager: Arc::new(TokioMutex::new(client_manager)),
+            viewer_logins: Arc::new(Mutex::new(HashMap::new())),
+            merge_bot_stop: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -71,169 +90,170 @@ impl GitHubMiddleware {
     }
 
     /// Initialize the GitHub client for the default host (async, non-blocking)
-    fn initialize_client(&self, dispatcher: &Dispatcher) {
+    ///
+    /// Tokens are looked up afresh (incl. a `.env` file in the current
+    /// directory), so this also serves retries after the user provided one.
+    fn initialize_client(&self, credentials: Option<AppCredentials>, dispatcher: &Dispatcher) {
         let client_manager = self.client_manager_arc();
         let dispatcher = dispatcher.clone();
 
+        // Variables already set in the environment take precedence
+        match dotenvy::dotenv() {
+            Ok(path) => log::debug!("GitHubMiddleware: loaded .env file from {:?}", path),
+            Err(_) => log::debug!("GitHubMiddleware: no .env file found"),
+        }
+
         self.runtime.spawn(async move {
             let mut manager = client_manager.lock().await;
+            manager.set_app_credentials(credentials);
+            manager.reload_tokens();
             match manager.get_client(None).await {
                 Ok(_) => {
-                    log::info!("GitHubMiddleware: GitHub client initialized for github.com");
+                    log::info!(
+                        "GitHubMiddleware: GitHub client initialized for {}",
+                        gh_client::default_host()
+                    );
                     // Signal that client is ready - trigger any pending operations
                     dispatcher.dispatch(Action::event(Event::ClientReady));
                 }
                 Err(e) => {
                     log::warn!("GitHubMiddleware: GitHub client not initialized: {}", e);
+                    // Not authenticated yet: guide the user through the setup
+                    if let Some(missing) = e.downcast_ref::<MissingTokenError>() {
+                        dispatcher.dispatch(Action::SetupWizard(SetupWizardAction::TokenMissing {
+                            host: missing.host.clone(),
+                            env_key: missing.env_key.clone(),
+                            attempts: missing.attempts.clone(),
+                        }));
+                    } else {
+                        dispatcher.dispatch(Action::error(
+                            format!("GitHub client not available: {}", e),
+                            "GitHub",
+                        ));
+                    }
                 }
             }
         });
     }
 
+    /// Repository and PRs targeted by an operation (selected PRs or cursor PR)
+    ///
+    /// Selected PRs hidden by the current filter are only included when
+    /// `[pr] bulk_include_hidden` is set.
+    fn target_prs<'a>(&self, state: &'a AppState) -> Option<(&'a Repository, Vec<&'a Pr>)> {
+        let repo_idx = state.main_view.selected_repository;
+        let repo = state.main_view.repositories.get(repo_idx)?;
+        let repo_data = state.main_view.repo_data.get(&repo_idx)?;
+        Some((
+            repo,
+            repo_data.target_prs(state.app_config.pr.bulk_include_hidden),
+        ))
+    }
+
+    /// Number of selected PRs hidden by the current filter
+    fn hidden_selection_count(&self, state: &AppState) -> usize {
+        state
+            .main_view
+            .repo_data
+            .get(&state.main_view.selected_repository)
+            .map_or(0, |repo_data| repo_data.hidden_selection_count())
+    }
+
+    /// Warn that a bulk operation has nothing to do because every selected PR
+    /// is hidden by the current filter (and `[pr] bulk_include_hidden` is off)
+    fn warn_if_all_hidden(
+        &self,
+        state: &AppState,
+        dispatcher: &Dispatcher,
+        operation: &str,
+        source: &str,
+    ) {
+        if self.hidden_selection_count(state) > 0 {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                format!(
+                    "All selected PRs are hidden by the current filter, nothing to {}",
+                    operation
+                ),
+                source,
+            )));
+        }
+    }
+
     /// Get target PRs for an operation (selected PRs or cursor PR)
     /// Returns: Vec<(Repository, pr_number)>
     fn get_target_prs(&self, state: &AppState) -> Vec<(Repository, usize)> {
-        let repo_idx = state.main_view.selected_repository;
-
-        let Some(repo) = state.main_view.repositories.get(repo_idx) else {
+        let Some((repo, prs)) = self.target_prs(state) else {
             return vec![];
         };
+        prs.iter().map(|pr| (repo.clone(), pr.number)).collect()
+    }
 
-        if let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) {
-            // If there are selected PRs, use those
-            if !repo_data.selected_pr_numbers.is_empty() {
-                return repo_data
-                    .selected_pr_numbers
-                    .iter()
-                    .map(|&pr_num| (repo.clone(), pr_num))
-                    .collect();
-            }
-
-            // Otherwise use the cursor PR
-            if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
-                return vec![(repo.clone(), pr.number)];
-            }
-        }
-
-        vec![]
+    /// Get target PRs with their full details (selected PRs or cursor PR)
+    fn get_target_pr_details(&self, state: &AppState) -> Vec<Pr> {
+        let Some((_, prs)) = self.target_prs(state) else {
+            return vec![];
+        };
+        prs.into_iter().cloned().collect()
     }
 
     /// Get target PRs with author info for rebase operation
     /// Returns: Vec<(Repository, pr_number, author)>
     fn get_target_prs_with_author(&self, state: &AppState) -> Vec<(Repository, usize, String)> {
-        let repo_idx = state.main_view.selected_repository;
-
-        let Some(repo) = state.main_view.repositories.get(repo_idx) else {
+        let Some((repo, prs)) = self.target_prs(state) else {
             return vec![];
         };
+        prs.iter()
+            .map(|pr| (repo.clone(), pr.number, pr.author.clone()))
+            .collect()
+    }
 
-        if let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) {
-            // If there are selected PRs, use those
-            if !repo_data.selected_pr_numbers.is_empty() {
-                return repo_data
-                    .prs
-                    .iter()
-                    .filter(|pr| repo_data.selected_pr_numbers.contains(&pr.number))
-                    .map(|pr| (repo.clone(), pr.number, pr.author.clone()))
-                    .collect();
-            }
-
-            // Otherwise use the cursor PR
-            if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
-                return vec![(repo.clone(), pr.number, pr.author.clone())];
-            }
-        }
-
-        vec![]
+    /// Get target PRs with their draft state for draft/ready operations
+    /// Returns: Vec<(Repository, pr_number, maturity)>
+    fn get_target_prs_with_maturity(
+        &self,
+        state: &AppState,
+    ) -> Vec<(Repository, usize, MaturityState)> {
+        let Some((repo, prs)) = self.target_prs(state) else {
+            return vec![];
+        };
+        prs.iter()
+            .map(|pr| (repo.clone(), pr.number, pr.maturity))
+            .collect()
     }
 
     /// Get target PR URLs for opening in browser (respects multi-selection)
     fn get_target_pr_urls(&self, state: &AppState) -> Vec<String> {
-        let repo_idx = state.main_view.selected_repository;
-
-        if let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) {
-            // If there are selected PRs, use those
-            if !repo_data.selected_pr_numbers.is_empty() {
-                return repo_data
-                    .prs
-                    .iter()
-                    .filter(|pr| repo_data.selected_pr_numbers.contains(&pr.number))
-                    .map(|pr| pr.html_url.clone())
-                    .collect();
-            }
-
-            // Otherwise use the cursor PR
-            if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
-                return vec![pr.html_url.clone()];
-            }
-        }
-
-        vec![]
+        let Some((_, prs)) = self.target_prs(state) else {
+            return vec![];
+        };
+        prs.iter().map(|pr| pr.html_url.clone()).collect()
     }
 
     /// Get target PR info for IDE opening (respects multi-selection)
     /// Returns: Vec<(pr_number, Repository)>
     fn get_target_pr_info_for_ide(&self, state: &AppState) -> Vec<(usize, Repository)> {
-        let repo_idx = state.main_view.selected_repository;
-
-        if let Some(repo) = state.main_view.repositories.get(repo_idx) {
-            if let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) {
-                // If there are selected PRs, use those
-                if !repo_data.selected_pr_numbers.is_empty() {
-                    return repo_data
-                        .selected_pr_numbers
-                        .iter()
-                        .map(|&pr_num| (pr_num, repo.clone()))
-                        .collect();
-                }
-
-                // Otherwise use the cursor PR
-                if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
-                    return vec![(pr.number, repo.clone())];
-                }
-            }
-        }
-
-        vec![]
+        let Some((repo, prs)) = self.target_prs(state) else {
+            return vec![];
+        };
+        prs.iter().map(|pr| (pr.number, repo.clone())).collect()
     }
 
     /// Get target PR CI info for build operations (respects multi-selection)
     /// Returns: Vec<(Repository, pr_number, head_sha, head_branch)>
     fn get_target_pr_ci_info(&self, state: &AppState) -> Vec<(Repository, u64, String, String)> {
-        let repo_idx = state.main_view.selected_repository;
-
-        if let Some(repo) = state.main_view.repositories.get(repo_idx) {
-            if let Some(repo_data) = state.main_view.repo_data.get(&repo_idx) {
-                // If there are selected PRs, use those
-                if !repo_data.selected_pr_numbers.is_empty() {
-                    return repo_data
-                        .prs
-                        .iter()
-                        .filter(|pr| repo_data.selected_pr_numbers.contains(&pr.number))
-                        .map(|pr| {
-                            (
-                                repo.clone(),
-                                pr.number as u64,
-                                pr.head_sha.clone(),
-                                pr.head_branch.clone(),
-                            )
-                        })
-                        .collect();
-                }
-
-                // Otherwise use the cursor PR
-                if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
-                    return vec![(
-                        repo.clone(),
-                        pr.number as u64,
-                        pr.head_sha.clone(),
-                        pr.head_branch.clone(),
-                    )];
-                }
-            }
-        }
-
-        vec![]
+        let Some((repo, prs)) = self.target_prs(state) else {
+            return vec![];
+        };
+        prs.iter()
+            .map(|pr| {
+                (
+                    repo.clone(),
+                    pr.number as u64,
+                    pr.head_sha.clone(),
+                    pr.head_branch.clone(),
+                )
+            })
+            .collect()
     }
 
     /// Get repository context string for confirmation popup
@@ -281,18 +301,30 @@ impl GitHubMiddleware {
         }
     }
 
-    fn handle_pr_load_2(
+    /// Load the PRs of a repository (given by the action, never by index)
+    fn handle_pr_load(
         &self,
         repo: &Repository,
-        _state: &AppState,
+        state: &AppState,
         dispatcher: &Dispatcher,
         force_refresh: bool,
     ) -> bool {
         log::info!("PrLoad: Loading PRs for {}/{}", repo.org, repo.repo);
 
+        let query = PrListQuery::for_repo(&state.app_config.pr_list, repo);
+        // A reload fetches as many PRs as are shown, including those loaded with LoadMore
+        let loaded_before = state
+            .main_view
+            .repositories
+            .iter()
+            .position(|r| r.org == repo.org && r.repo == repo.repo)
+            .and_then(|idx| state.main_view.repo_data.get(&idx))
+            .map_or(0, |data| data.prs.len());
+        let max_prs = query.max_prs.max(loaded_before);
         let repo = repo.clone();
         let dispatcher = dispatcher.clone();
         let client_manager = self.client_manager_arc();
+        let viewer_logins = Arc::clone(&self.viewer_logins);
 
         // Spawn async task to load PRs
         let mode = if force_refresh {
@@ -327,10 +359,10 @@ impl GitHubMiddleware {
                     }
                     Err(e) => {
                         log::error!("Failed to get client for host {:?}: {}", repo.host, e);
-                        dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                        dispatcher.dispatch(Action::error(
                             format!("Failed to connect to GitHub: {}", e),
                             "Load",
-                        )));
+                        ));
                         dispatcher.dispatch(Action::PullRequest(PullRequestAction::LoadError {
                             repo,
                             error: e.to_string(),
@@ -340,95 +372,218 @@ impl GitHubMiddleware {
                 }
             };
 
-            match client
-                .fetch_pull_requests(&repo.org, &repo.repo, Some(&repo.branch))
-                .await
-            {
-                Ok(prs) => {
-                    let domain_prs: Vec<Pr> = prs.into_iter().map(convert_to_domain_pr).collect();
+            // The user's login feeds the "My PRs" and "Attention" filters
+            if let Some(login) = viewer_login(&client, &repo, &viewer_logins).await {
+                dispatcher.dispatch(Action::PullRequest(PullRequestAction::ViewerLoginLoaded {
+                    repo: repo.clone(),
+                    login,
+                }));
+            }
+
+            // Page through the PR list: on the first load, the first page replaces
+            // the list (Loaded) and later pages are appended in the background
+            // (MorePrsLoaded). A reload replaces the shown list once all pages
+            // arrived, so it neither shrinks meanwhile nor loses the cursor.
+            let reload = loaded_before > 0;
+            let mut reloaded = Vec::new();
+            let mut loaded = 0usize;
+            let mut page = 1u32;
+            // Stays true when loading stops at `max_prs` after a full page
+            let mut has_more = false;
+            while loaded < max_prs {
+                let result = load_pr_page(&client, &repo, &query, page, max_prs - loaded).await;
+
+                let (domain_prs, is_last_page) = match result {
+                    Ok(page_result) => page_result,
+                    Err(e) if page == 1 => {
+                        log::error!("Failed to load PRs for {}/{}: {}", repo.org, repo.repo, e);
+                        dispatcher.dispatch(Action::error(
+                            describe_load_error(&e, repo.host.as_deref()),
+                            "Load",
+                        ));
+                        dispatcher.dispatch(Action::PullRequest(PullRequestAction::LoadError {
+                            repo,
+                            error: e.to_string(),
+                        }));
+                        return;
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            "Failed to load PR page {} for {}/{}: {}",
+                            page,
+                            repo.org,
+                            repo.repo,
+                            e
+                        );
+                        dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                            format!("Loaded only {} PRs: {}", loaded, e),
+                            "Load",
+                        )));
+                        break;
+                    }
+                };
+
+                loaded += domain_prs.len();
+                log::info!(
+                    "Loaded page {} ({} PRs, {} total) for {}/{}",
+                    page,
+                    domain_prs.len(),
+                    loaded,
+                    repo.org,
+                    repo.repo
+                );
+
+                if reload {
+                    reloaded.extend(domain_prs);
+                } else {
+                    dispatch_loaded_prs(
+                        &repo,
+                        domain_prs,
+                        page == 1,
+                        &dispatcher,
+                        &client,
+                        &client_manager,
+                    );
+                }
+
+                has_more = !is_last_page;
+                if is_last_page {
+                    break;
+                }
+                page += 1;
+            }
+
+            if reload {
+                // Pages are sorted one by one, the reloaded list as a whole
+                reloaded.sort_prs();
+                dispatch_loaded_prs(&repo, reloaded, true, &dispatcher, &client, &client_manager);
+            }
+
+            if has_more {
+                log::info!(
+                    "Stopped at {} PRs for {}/{}, more are available",
+                    loaded,
+                    repo.org,
+                    repo.repo
+                );
+            }
+            dispatcher.dispatch(Action::PullRequest(PullRequestAction::HasMorePrs {
+                repo: repo.clone(),
+                has_more,
+            }));
+
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                format!("Loaded {} PRs from {}/{}", loaded, repo.org, repo.repo),
+                "Load",
+            )));
+        });
+
+        true // Let action pass through to reducer (to set loading state)
+    }
+
+    /// Fetch the page of PRs following the loaded ones and append it
+    fn handle_load_more(&self, state: &AppState, dispatcher: &Dispatcher) {
+        let repo_idx = state.main_view.selected_repository;
+        let (Some(repo), Some(repo_data)) = (
+            state.main_view.repositories.get(repo_idx),
+            state.main_view.repo_data.get(&repo_idx),
+        ) else {
+            return;
+        };
+
+        if !repo_data.has_more_prs {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                "All PRs are loaded",
+                "Load",
+            )));
+            return;
+        }
+
+        let query = PrListQuery::for_repo(&state.app_config.pr_list, repo);
+        // A page that was only partially kept is fetched again, its known PRs are skipped
+        let page = (repo_data.prs.len() / query.per_page as usize) as u32 + 1;
+        let known: std::collections::HashSet<usize> =
+            repo_data.prs.iter().map(|pr| pr.number).collect();
+        let repo = repo.clone();
+        let dispatcher = dispatcher.clone();
+        let client_manager = self.client_manager_arc();
+
+        dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+            format!("Loading more PRs from {}/{}...", repo.org, repo.repo),
+            "Load",
+        )));
+
+        self.runtime.spawn(async move {
+            let client = {
+                let mut manager = client_manager.lock().await;
+                match manager.clone_client(repo.host.as_deref()).await {
+                    Ok(client) => client,
+                    Err(e) => {
+                        dispatcher.dispatch(Action::error(
+                            format!("Failed to connect to GitHub: {}", e),
+                            "Load",
+                        ));
+                        return;
+                    }
+                }
+            };
+
+            match load_pr_page(&client, &repo, &query, page, usize::MAX).await {
+                Ok((mut prs, is_last_page)) => {
+                    prs.retain(|pr| !known.contains(&pr.number));
                     log::info!(
-                        "Loaded {} PRs for {}/{}",
-                        domain_prs.len(),
+                        "Loaded {} more PRs (page {}) for {}/{}",
+                        prs.len(),
+                        page,
                         repo.org,
                         repo.repo
                     );
-                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
-                        format!(
-                            "Loaded {} PRs from {}/{}",
-                            domain_prs.len(),
-                            repo.org,
-                            repo.repo
-                        ),
-                        "Load",
-                    )));
 
-                    // First dispatch Loaded to populate repo_data.prs
-                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::Loaded {
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::MorePrsLoaded {
                         repo: repo.clone(),
-                        prs: domain_prs.clone(),
+                        prs: prs.clone(),
                     }));
-
-                    // Then trigger CI status checks for each PR (background fetch)
-                    // This must come AFTER Loaded so the PRs exist when BuildStatusUpdated arrives
                     dispatch_ci_status_checks(
                         &repo,
-                        &domain_prs,
+                        &prs,
                         &dispatcher,
                         Arc::clone(&client_manager),
                     );
-
-                    // Also trigger background fetch for PR stats (additions/deletions)
                     dispatch_pr_stats_fetch(
                         &repo,
-                        &domain_prs,
+                        &prs,
                         &dispatcher,
-                        client,
+                        client.clone(),
                         Arc::clone(&client_manager),
                     );
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::HasMorePrs {
+                        repo: repo.clone(),
+                        has_more: !is_last_page,
+                    }));
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                        format!(
+                            "Loaded {} more PRs from {}/{}",
+                            prs.len(),
+                            repo.org,
+                            repo.repo
+                        ),
+                        "Load",
+                    )));
                 }
                 Err(e) => {
-                    log::error!("Failed to load PRs for {}/{}: {}", repo.org, repo.repo, e);
-                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                        format!("Failed to load PRs: {}", e),
+                    log::error!(
+                        "Failed to load more PRs for {}/{}: {}",
+                        repo.org,
+                        repo.repo,
+                        e
+                    );
+                    dispatcher.dispatch(Action::error(
+                        describe_load_error(&e, repo.host.as_deref()),
                         "Load",
-                    )));
-                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::LoadError {
-                        repo,
-                        error: e.to_string(),
-                    }));
+                    ));
                 }
             }
         });
-
-        true // Let action pass through to reducer (to set loading state)
-    }
-
-    /// Handle loading PRs for a repository
-    fn handle_pr_load(
-        &self,
-        repo_idx: usize,
-        state: &AppState,
-        dispatcher: &Dispatcher,
-        force_refresh: bool,
-    ) -> bool {
-        log::info!(
-            "PrLoad({}) received, repos in state: {}, force_refresh: {}",
-            repo_idx,
-            state.main_view.repositories.len(),
-            force_refresh
-        );
-
-        // Get the repository at this index
-        let Some(repo) = state.main_view.repositories.get(repo_idx) else {
-            log::warn!(
-                "PrLoad: Repository at index {} not found (state has {} repos), will retry",
-                repo_idx,
-                state.main_view.repositories.len()
-            );
-            return true;
-        };
-
-        self.handle_pr_load_2(repo, state, dispatcher, force_refresh)
     }
 }
 
@@ -441,12 +596,24 @@ impl Default for GitHubMiddleware {
 impl Middleware for GitHubMiddleware {
     fn handle(&mut self, action: &Action, state: &AppState, dispatcher: &Dispatcher) -> bool {
         match action {
-            // Initialize client on bootstrap (async, non-blocking)
-            Action::Bootstrap(BootstrapAction::Start) => {
-                self.initialize_client(dispatcher);
+            // Initialize client once the config (incl. auth mode) is known (async, non-blocking)
+            Action::Bootstrap(BootstrapAction::ConfigLoaded(config)) => {
+                self.initialize_client(app_credentials(&config.auth), dispatcher);
                 true // Let action pass through
             }
 
+            // Setup wizard: look for a token again
+            Action::SetupWizard(SetupWizardAction::Retry) => {
+                self.initialize_client(app_credentials(&state.app_config.auth), dispatcher);
+                true // Let reducer show the progress
+            }
+
+            // Setup wizard: retry right away once `gh auth login` succeeded
+            Action::SetupWizard(SetupWizardAction::GhAuthLoginFinished(Ok(()))) => {
+                dispatcher.dispatch(Action::SetupWizard(SetupWizardAction::Retry));
+                true
+            }
+
             // Client ready event - trigger repository loading
             Action::Event(Event::ClientReady) => {
                 log::info!("GitHub client ready, triggering repository loading");
@@ -455,18 +622,99 @@ impl Middleware for GitHubMiddleware {
             }
 
             Action::Repository(RepositoryAction::LoadRepositoryData(repo)) => {
-                self.handle_pr_load_2(repo, state, dispatcher, false)
+                self.handle_pr_load(repo, state, dispatcher, false)
+            }
+
+            Action::Repository(RepositoryAction::VerifyRepository(repo)) => {
+                let repo = repo.clone();
+                let dispatcher = dispatcher.clone();
+                let client_manager = self.client_manager_arc();
+
+                // Check asynchronously - the form shows "Verifying..." meanwhile
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        manager.clone_client(repo.host.as_deref()).await
+                    };
+                    let result = match client {
+                        Ok(client) => client.repository_exists(&repo.org, &repo.repo).await,
+                        Err(e) => Err(e),
+                    };
+
+                    let action = match result {
+                        Ok(true) => RepositoryAction::RepositoryVerified(repo),
+                        Ok(false) => {
+                            log::warn!("Repository {} not found", repo.display_name());
+                            RepositoryAction::RepositoryVerifyFailed {
+                                repo,
+                                message: "Repository not found or no access".to_string(),
+                            }
+                        }
+                        Err(e) => {
+                            log::error!(
+                                "Failed to verify repository {}: {}",
+                                repo.display_name(),
+                                e
+                            );
+                            RepositoryAction::RepositoryVerifyFailed {
+                                repo,
+                                message: format!("Could not verify repository: {}", e),
+                            }
+                        }
+                    };
+                    dispatcher.dispatch(Action::Repository(action));
+                });
+                false // Consume action
+            }
+
+            // `--repo` without a branch: look up the default branch before adding it
+            Action::Repository(RepositoryAction::ResolveStartupRepository { org, repo }) => {
+                let (org, repo) = (org.clone(), repo.clone());
+                let dispatcher = dispatcher.clone();
+                let client_manager = self.client_manager_arc();
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        manager.clone_client(None).await
+                    };
+                    let result = match client {
+                        Ok(client) => default_branch(&client, &org, &repo).await,
+                        Err(e) => Err(e),
+                    };
+
+                    match result {
+                        Ok(branch) => dispatcher.dispatch(Action::Repository(
+                            RepositoryAction::StartupRepositoryResolved(Repository::new(
+                                org, repo, branch,
+                            )),
+                        )),
+                        Err(e) => {
+                            log::error!("Failed to resolve {}/{}: {}", org, repo, e);
+                            dispatcher.dispatch(Action::error(
+                                format!("Could not open {}/{}: {}", org, repo, e),
+                                "Repositories",
+                            ));
+                        }
+                    }
+                });
+                false // Consume action
             }
 
             // Handle PR load start - actually fetch the PRs
             Action::PullRequest(PullRequestAction::LoadStart { repo }) => {
-                self.handle_pr_load_2(repo, state, dispatcher, false)
+                self.handle_pr_load(repo, state, dispatcher, false)
             }
 
-            // Handle PR refresh request (force refresh - bypass cache)
-            Action::PullRequest(PullRequestAction::Refresh) => {
-                let repo_idx = state.main_view.selected_repository;
-                self.handle_pr_load(repo_idx, state, dispatcher, true)
+            // Handle per-repository refresh (force refresh - bypass cache)
+            Action::PullRequest(PullRequestAction::RefreshStart { repo }) => {
+                self.handle_pr_load(repo, state, dispatcher, true)
+            }
+
+            // Load the next page of PRs beyond the PR limit
+            Action::PullRequest(PullRequestAction::LoadMore) => {
+                self.handle_load_more(state, dispatcher);
+                false // Consume action
             }
 
             // Handle repository switching - trigger CI status checks if needed
@@ -492,35 +740,233 @@ impl Middleware for GitHubMiddleware {
                 true // Let action pass through to reducer
             }
 
-            Action::PullRequest(PullRequestAction::OpenInBrowser) => {
-                let urls = self.get_target_pr_urls(state);
-                if urls.is_empty() {
-                    log::warn!("No PRs selected for opening in browser");
+            Action::PullRequest(PullRequestAction::RepositorySelect(idx)) => {
+                let num_repos = state.main_view.repositories.len();
+                if num_repos > 0 {
+                    self.trigger_ci_status_if_needed((*idx).min(num_repos - 1), state, dispatcher);
+                }
+                true // Let action pass through to reducer
+            }
+
+            Action::PullRequest(PullRequestAction::OpenInBrowser) => {
+                let urls = self.get_target_pr_urls(state);
+                if urls.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "open", "Browser");
+                    log::warn!("No PRs selected for opening in browser");
+                    return false;
+                }
+
+                log::info!("Opening {} PR(s) in browser", urls.len());
+
+                for url in urls {
+                    self.runtime.spawn(open_url(url));
+                }
+                false // Consume action
+            }
+
+            Action::PullRequest(PullRequestAction::OpenAuthorProfile) => {
+                let repo_idx = state.main_view.selected_repository;
+                let repo = state.main_view.repositories.get(repo_idx);
+                let pr = state
+                    .main_view
+                    .repo_data
+                    .get(&repo_idx)
+                    .and_then(|data| data.prs.get(data.selected_pr));
+
+                let (Some(repo), Some(pr)) = (repo, pr) else {
+                    log::warn!("No PR under cursor for opening author profile");
+                    return false;
+                };
+
+                let url = repo.user_profile_url(&pr.author);
+                log::info!("Opening author profile: {}", url);
+                self.runtime.spawn(open_url(url));
+                false // Consume action
+            }
+
+            Action::PullRequest(PullRequestAction::OpenRelatedIssue { url }) => {
+                log::info!("Opening related issue: {}", url);
+                self.runtime.spawn(open_url(url.clone()));
+                false // Consume action
+            }
+
+            Action::PullRequest(PullRequestAction::MergeRequest) => {
+                let targets = self.get_target_pr_details(state);
+                let hidden = self.hidden_selection_count(state);
+                if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "merge", "Merge");
+                    log::warn!("No PRs selected for merge");
+                    return false;
+                }
+
+                let pr_numbers: Vec<u64> = targets.iter().map(|pr| pr.number as u64).collect();
+                let unready: Vec<&Pr> = targets
+                    .iter()
+                    .filter(|pr| pr.mergeable.is_merge_risk())
+                    .collect();
+
+                if unready.is_empty() && hidden == 0 {
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::MergePrs {
+                        pr_numbers,
+                    }));
+                    return false;
+                }
+
+                // Some PRs have failing/pending CI or conflicts, or are hidden
+                // by the filter (selected before it was applied): confirm first
+                let warning = if unready.is_empty() {
+                    String::new()
+                } else {
+                    let numbers: Vec<String> =
+                        unready.iter().map(|pr| format!("#{}", pr.number)).collect();
+                    format!(
+                        "{}: {}",
+                        MergeableSummary::from_prs(unready.iter().copied()).label(),
+                        numbers.join(", ")
+                    )
+                };
+                let hidden_note = (hidden > 0).then(|| {
+                    let outcome = if state.app_config.pr.bulk_include_hidden {
+                        "will also be merged"
+                    } else {
+                        "will be skipped"
+                    };
+                    format!(
+                        "{} of your selected PRs {} hidden by the filter and {}",
+                        hidden,
+                        if hidden == 1 { "is" } else { "are" },
+                        outcome
+                    )
+                });
+                dispatcher.dispatch(Action::ConfirmationPopup(
+                    crate::actions::ConfirmationPopupAction::Show {
+                        intent: crate::state::ConfirmationIntent::Merge {
+                            pr_numbers,
+                            unready: unready.iter().map(|pr| pr.number as u64).collect(),
+                            warning,
+                            hidden_note,
+                        },
+                        default_message: crate::state::MERGE_READY_ONLY.to_string(),
+                        repo_context: self.get_repo_context(state),
+                    },
+                ));
+                false // Consume action
+            }
+
+            Action::MergeBot(MergeBotAction::Start) => {
+                let targets = self.get_target_pr_details(state);
+                if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "queue", "Merge Bot");
+                    log::warn!("No PRs selected for the merge bot");
                     return false;
                 }
 
-                log::info!("Opening {} PR(s) in browser", urls.len());
+                let plan = MergeBotPlan::from_prs(&targets);
+                for (pr_number, status, decision) in &plan.entries {
+                    log::info!(
+                        "Merge bot: PR #{} is {} -> {}",
+                        pr_number,
+                        status.label().trim_end_matches("..."),
+                        decision.label()
+                    );
+                }
 
-                for url in urls {
-                    self.runtime.spawn(open_url(url));
+                if plan.runnable().is_empty() {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "All queued PRs are blocked, merge bot not started",
+                        "Merge Bot",
+                    )));
+                    return false;
                 }
+
+                // Confirm the dry run before the bot touches anything
+                let skipped: Vec<&Pr> = targets
+                    .iter()
+                    .filter(|pr| {
+                        MergeBotDecision::for_status(pr.mergeable) == MergeBotDecision::Skip
+                    })
+                    .collect();
+                let warning = if skipped.is_empty() {
+                    String::new()
+                } else {
+                    let numbers: Vec<String> =
+                        skipped.iter().map(|pr| format!("#{}", pr.number)).collect();
+                    format!(
+                        "Skipping {}: {}",
+                        MergeableSummary::from_prs(skipped.iter().copied()).label(),
+                        numbers.join(", ")
+                    )
+                };
+                dispatcher.dispatch(Action::ConfirmationPopup(
+                    crate::actions::ConfirmationPopupAction::Show {
+                        intent: crate::state::ConfirmationIntent::StartMergeBot { plan, warning },
+                        default_message: String::new(),
+                        repo_context: self.get_repo_context(state),
+                    },
+                ));
                 false // Consume action
             }
 
-            Action::PullRequest(PullRequestAction::OpenRelatedIssue { url }) => {
-                log::info!("Opening related issue: {}", url);
-                self.runtime.spawn(open_url(url.clone()));
-                false // Consume action
+            // Plan confirmed - carry it out in the background
+            Action::MergeBot(MergeBotAction::Begin { repo_idx, plan }) => {
+                let Some(repo) = state.main_view.repositories.get(*repo_idx).cloned() else {
+                    log::error!("Merge bot: repository #{} not found", repo_idx);
+                    return false;
+                };
+
+                // A previous run must not keep merging alongside this one
+                self.merge_bot_stop.store(true, Ordering::SeqCst);
+                self.merge_bot_stop = Arc::new(AtomicBool::new(false));
+
+                let bot = MergeBotRun {
+                    repo,
+                    repo_idx: *repo_idx,
+                    merge_method: state.main_view.merge_method,
+                    stop: Arc::clone(&self.merge_bot_stop),
+                };
+                let plan = plan.clone();
+                let client_manager = self.client_manager_arc();
+                let dispatcher = dispatcher.clone();
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        match manager.clone_client(bot.repo.host.as_deref()).await {
+                            Ok(c) => c,
+                            Err(e) => {
+                                log::error!("Failed to get client: {}", e);
+                                dispatcher.dispatch(Action::error(
+                                    format!("Merge bot error: {}", e),
+                                    "Merge Bot",
+                                ));
+                                dispatcher.dispatch(Action::MergeBot(MergeBotAction::Finished));
+                                return;
+                            }
+                        }
+                    };
+                    bot.run(&client, &plan, &dispatcher).await;
+                });
+                true // Let the reducer fill the queue
             }
 
-            Action::PullRequest(PullRequestAction::MergeRequest) => {
-                let targets = self.get_target_prs(state);
-                if targets.is_empty() {
-                    log::warn!("No PRs selected for merge");
+            Action::MergeBot(MergeBotAction::Stop) => {
+                self.merge_bot_stop.store(true, Ordering::SeqCst);
+                true // Let the reducer clear the queue
+            }
+
+            Action::PullRequest(PullRequestAction::MergePrs { pr_numbers }) => {
+                let repo_idx = state.main_view.selected_repository;
+                let Some(repo) = state.main_view.repositories.get(repo_idx).cloned() else {
+                    log::error!("No repository selected");
                     return false;
-                }
+                };
+                let targets: Vec<(Repository, usize)> = pr_numbers
+                    .iter()
+                    .map(|&pr_number| (repo.clone(), pr_number as usize))
+                    .collect();
 
                 let client_manager = self.client_manager_arc();
+                let merge_method = state.main_view.merge_method;
 
                 for (repo, pr_number) in targets {
                     let dispatcher = dispatcher.clone();
@@ -543,10 +989,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Merge error: {}", e),
                                         "Merge",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -557,7 +1003,7 @@ impl Middleware for GitHubMiddleware {
                                 &repo.org,
                                 &repo.repo,
                                 pr_number as u64,
-                                MergeMethod::default(),
+                                merge_method,
                                 None,
                                 None,
                             )
@@ -575,17 +1021,17 @@ impl Middleware for GitHubMiddleware {
                             }
                             Ok(result) => {
                                 log::error!("Merge failed: {}", result.message);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Merge failed: {}", result.message),
                                     "Merge",
-                                )));
+                                ));
                             }
                             Err(e) => {
                                 log::error!("Merge error: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Merge error: {}", e),
                                     "Merge",
-                                )));
+                                ));
                             }
                         }
                     });
@@ -596,6 +1042,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::RebaseRequest) => {
                 let targets = self.get_target_prs_with_author(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "rebase", "Rebase");
                     log::warn!("No PRs selected for rebase");
                     return false;
                 }
@@ -624,10 +1071,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Rebase failed: {}", e),
                                         "Rebase",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -655,10 +1102,10 @@ impl Middleware for GitHubMiddleware {
                                 }
                                 Err(e) => {
                                     log::error!("Dependabot rebase request error: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Rebase request failed: {}", e),
                                         "Rebase",
-                                    )));
+                                    ));
                                 }
                             }
                         } else {
@@ -681,10 +1128,10 @@ impl Middleware for GitHubMiddleware {
                                 }
                                 Err(e) => {
                                     log::error!("Rebase error: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Rebase failed: {}", e),
                                         "Rebase",
-                                    )));
+                                    ));
                                 }
                             }
                         }
@@ -697,6 +1144,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::ApproveRequest) => {
                 let targets = self.get_target_prs(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "approve", "Approve");
                     log::warn!("No PRs selected for approval");
                     return false;
                 }
@@ -718,6 +1166,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::CommentRequest) => {
                 let targets = self.get_target_prs(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "comment on", "Comment");
                     log::warn!("No PRs selected for comment");
                     return false;
                 }
@@ -739,6 +1188,12 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::RequestChangesRequest) => {
                 let targets = self.get_target_prs(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(
+                        state,
+                        dispatcher,
+                        "request changes on",
+                        "Request Changes",
+                    );
                     log::warn!("No PRs selected for request changes");
                     return false;
                 }
@@ -768,20 +1223,37 @@ impl Middleware for GitHubMiddleware {
                     return false;
                 };
 
-                let message = if message.is_empty() {
-                    None
-                } else {
-                    Some(message.clone())
-                };
+                // Render `$NUMBER`/`$AUTHOR`/`$TITLE` per PR
+                let prs = state
+                    .main_view
+                    .repo_data
+                    .get(&repo_idx)
+                    .map(|data| data.prs.as_slice())
+                    .unwrap_or_default();
+                let (bodies, unrendered) =
+                    message_template::review_bodies(message, pr_numbers, prs);
+                if !unrendered.is_empty() {
+                    let numbers: Vec<String> =
+                        unrendered.iter().map(|pr| format!("#{}", pr)).collect();
+                    log::warn!(
+                        "Approval message can't be rendered for {}",
+                        numbers.join(", ")
+                    );
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!(
+                            "Skipped {}: PR no longer loaded, approval message can't be filled in",
+                            numbers.join(", ")
+                        ),
+                        "Approve",
+                    )));
+                }
 
                 let client_manager = self.client_manager_arc();
 
-                for pr_number in pr_numbers {
+                for (pr_number_owned, message) in bodies {
                     let dispatcher = dispatcher.clone();
                     let client_manager = Arc::clone(&client_manager);
-                    let message = message.clone();
-                    let pr_num = *pr_number as usize;
-                    let pr_number_owned = *pr_number;
+                    let pr_num = pr_number_owned as usize;
                     let repo = repo.clone();
 
                     dispatcher.dispatch(Action::PullRequest(PullRequestAction::ApproveStart {
@@ -800,48 +1272,23 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Approve failed: {}", e),
                                         "Approve",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
                         };
 
-                        match client
-                            .create_review(
-                                &repo.org,
-                                &repo.repo,
-                                pr_number_owned,
-                                ReviewEvent::Approve,
-                                message.as_deref(),
-                            )
-                            .await
-                        {
-                            Ok(()) => {
-                                log::info!("Successfully approved PR #{}", pr_number_owned);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
-                                    format!("PR #{} approved", pr_number_owned),
-                                    "Approve",
-                                )));
-                                // Update review decision locally
-                                dispatcher.dispatch(Action::PullRequest(
-                                    PullRequestAction::ReviewDecisionUpdated {
-                                        repo: repo.clone(),
-                                        pr_number: pr_number_owned,
-                                        decision: crate::domain_models::ReviewDecision::Approved,
-                                    },
-                                ));
-                            }
-                            Err(e) => {
-                                log::error!("Approve error: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                                    format!("Approve failed: {}", e),
-                                    "Approve",
-                                )));
-                            }
-                        }
+                        approve_pr(
+                            &client,
+                            &repo,
+                            pr_number_owned,
+                            message.as_deref(),
+                            &dispatcher,
+                        )
+                        .await;
                     });
                 }
                 false // Consume action
@@ -883,10 +1330,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Comment failed: {}", e),
                                         "Comment",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -907,10 +1354,10 @@ impl Middleware for GitHubMiddleware {
                             }
                             Err(e) => {
                                 log::error!("Comment error: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Comment failed: {}", e),
                                     "Comment",
-                                )));
+                                ));
                             }
                         }
                     });
@@ -956,10 +1403,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Request changes failed: {}", e),
                                         "Request Changes",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -996,10 +1443,10 @@ impl Middleware for GitHubMiddleware {
                             }
                             Err(e) => {
                                 log::error!("Request changes error: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Request changes failed: {}", e),
                                     "Request Changes",
-                                )));
+                                ));
                             }
                         }
                     });
@@ -1010,6 +1457,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::CloseRequest) => {
                 let targets = self.get_target_prs(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "close", "Close");
                     log::warn!("No PRs selected for closing");
                     return false;
                 }
@@ -1065,10 +1513,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Close failed: {}", e),
                                         "Close",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -1108,10 +1556,189 @@ impl Middleware for GitHubMiddleware {
                             }
                             Err(e) => {
                                 log::error!("Close error: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Close failed: {}", e),
                                     "Close",
+                                ));
+                            }
+                        }
+                    });
+                }
+                false // Consume action
+            }
+
+            Action::PullRequest(PullRequestAction::MarkReadyRequest) => {
+                let targets = self.get_target_prs_with_maturity(state);
+                if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "mark ready", "Ready");
+                    log::warn!("No PRs selected for marking ready");
+                    return false;
+                }
+
+                let (drafts, ready): (Vec<_>, Vec<_>) = targets
+                    .into_iter()
+                    .partition(|(_, _, maturity)| *maturity == MaturityState::Draft);
+
+                if !ready.is_empty() {
+                    let numbers: Vec<String> =
+                        ready.iter().map(|(_, pr, _)| format!("#{}", pr)).collect();
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!("Already ready for review: {}", numbers.join(", ")),
+                        "Ready",
+                    )));
+                }
+
+                let client_manager = Arc::clone(&self.client_manager);
+
+                for (repo, pr_number, _) in drafts {
+                    let dispatcher = dispatcher.clone();
+                    let client_manager = Arc::clone(&client_manager);
+
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::MarkReadyStart {
+                        repo: repo.clone(),
+                        pr_number,
+                    }));
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+                        format!("Marking PR #{} ready for review...", pr_number),
+                        "Ready",
+                    )));
+
+                    self.runtime.spawn(async move {
+                        // Get client inside async task
+                        let client = {
+                            let mut manager = client_manager.lock().await;
+                            match manager.clone_client(repo.host.as_deref()).await {
+                                Ok(c) => c,
+                                Err(e) => {
+                                    log::error!("Failed to get client: {}", e);
+                                    dispatcher.dispatch(Action::error(
+                                        format!("Mark ready failed: {}", e),
+                                        "Ready",
+                                    ));
+                                    return;
+                                }
+                            }
+                        };
+
+                        match client
+                            .mark_ready_for_review(&repo.org, &repo.repo, pr_number as u64)
+                            .await
+                        {
+                            Ok(()) => {
+                                log::info!("Marked PR #{} ready for review", pr_number);
+                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                                    format!("PR #{} ready for review", pr_number),
+                                    "Ready",
+                                )));
+                                dispatcher.dispatch(Action::PullRequest(
+                                    PullRequestAction::MaturityUpdated {
+                                        repo: repo.clone(),
+                                        pr_number,
+                                        maturity: MaturityState::Ready,
+                                    },
+                                ));
+                            }
+                            Err(e) => {
+                                log::error!("Mark ready error: {}", e);
+                                dispatcher.dispatch(Action::error(
+                                    format!("Mark ready failed: {}", e),
+                                    "Ready",
+                                ));
+                            }
+                        }
+                    });
+                }
+                false // Consume action
+            }
+
+            // Refresh the repository the PR belongs to, which need not be
+            // the selected one by the time the operation completes
+            Action::PullRequest(PullRequestAction::MaturityUpdated { repo, .. }) => {
+                dispatcher.dispatch(Action::PullRequest(PullRequestAction::RefreshStart {
+                    repo: repo.clone(),
+                }));
+                true // Let the reducer update the PR right away
+            }
+
+            Action::PullRequest(PullRequestAction::ConvertToDraftRequest) => {
+                let targets = self.get_target_prs_with_maturity(state);
+                if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "convert to draft", "Draft");
+                    log::warn!("No PRs selected for converting to draft");
+                    return false;
+                }
+
+                let (ready, drafts): (Vec<_>, Vec<_>) = targets
+                    .into_iter()
+                    .partition(|(_, _, maturity)| *maturity == MaturityState::Ready);
+
+                if !drafts.is_empty() {
+                    let numbers: Vec<String> =
+                        drafts.iter().map(|(_, pr, _)| format!("#{}", pr)).collect();
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!("Already drafts: {}", numbers.join(", ")),
+                        "Draft",
+                    )));
+                }
+
+                let client_manager = Arc::clone(&self.client_manager);
+
+                for (repo, pr_number, _) in ready {
+                    let dispatcher = dispatcher.clone();
+                    let client_manager = Arc::clone(&client_manager);
+
+                    dispatcher.dispatch(Action::PullRequest(
+                        PullRequestAction::ConvertToDraftStart {
+                            repo: repo.clone(),
+                            pr_number,
+                        },
+                    ));
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+                        format!("Converting PR #{} to draft...", pr_number),
+                        "Draft",
+                    )));
+
+                    self.runtime.spawn(async move {
+                        // Get client inside async task
+                        let client = {
+                            let mut manager = client_manager.lock().await;
+                            match manager.clone_client(repo.host.as_deref()).await {
+                                Ok(c) => c,
+                                Err(e) => {
+                                    log::error!("Failed to get client: {}", e);
+                                    dispatcher.dispatch(Action::error(
+                                        format!("Convert to draft failed: {}", e),
+                                        "Draft",
+                                    ));
+                                    return;
+                                }
+                            }
+                        };
+
+                        match client
+                            .convert_to_draft(&repo.org, &repo.repo, pr_number as u64)
+                            .await
+                        {
+                            Ok(()) => {
+                                log::info!("Converted PR #{} to draft", pr_number);
+                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                                    format!("PR #{} converted to draft", pr_number),
+                                    "Draft",
                                 )));
+                                dispatcher.dispatch(Action::PullRequest(
+                                    PullRequestAction::MaturityUpdated {
+                                        repo: repo.clone(),
+                                        pr_number,
+                                        maturity: MaturityState::Draft,
+                                    },
+                                ));
+                            }
+                            Err(e) => {
+                                log::error!("Convert to draft error: {}", e);
+                                dispatcher.dispatch(Action::error(
+                                    format!("Convert to draft failed: {}", e),
+                                    "Draft",
+                                ));
                             }
                         }
                     });
@@ -1122,6 +1749,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::OpenBuildLogs) => {
                 let targets = self.get_target_pr_ci_info(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "open", "Build Logs");
                     log::warn!("No PRs selected for opening build logs");
                     return false;
                 }
@@ -1129,8 +1757,65 @@ impl Middleware for GitHubMiddleware {
                 log::info!("Opening build logs for {} PR(s)", targets.len());
 
                 for (repo, _pr_number, _head_sha, head_branch) in targets {
-                    let url = format!("{}/actions?query=branch%3A{}", repo.web_url(), head_branch);
-                    self.runtime.spawn(open_url(url));
+                    self.runtime
+                        .spawn(open_url(build_ci_logs_url(&repo, &head_branch)));
+                }
+                false // Consume action
+            }
+
+            Action::PullRequest(PullRequestAction::OpenFailingBuildLogs) => {
+                let repo_idx = state.main_view.selected_repository;
+                let (Some(repo), Some(repo_data)) = (
+                    state.main_view.repositories.get(repo_idx),
+                    state.main_view.repo_data.get(&repo_idx),
+                ) else {
+                    return false;
+                };
+
+                let targets =
+                    repo_data.failing_build_targets(state.app_config.pr.bulk_include_hidden);
+                if targets.is_empty() {
+                    let hidden_failing = repo_data.hidden_failing_build_count();
+                    if !repo_data.selected_pr_numbers.is_empty() {
+                        self.warn_if_all_hidden(state, dispatcher, "open", "Build Logs");
+                    } else if hidden_failing > 0 {
+                        dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                            format!(
+                                "All {} PR(s) with failed builds are hidden by the current filter",
+                                hidden_failing
+                            ),
+                            "Build Logs",
+                        )));
+                    } else {
+                        dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                            "No PRs with failed builds",
+                            "Build Logs",
+                        )));
+                    }
+                    return false;
+                }
+
+                let max_tabs = state.app_config.pr.max_browser_tabs.max(1);
+                log::info!(
+                    "Opening build logs for {} of {} PR(s)",
+                    targets.len().min(max_tabs),
+                    targets.len()
+                );
+                for pr in targets.iter().take(max_tabs) {
+                    self.runtime
+                        .spawn(open_url(build_ci_logs_url(repo, &pr.head_branch)));
+                }
+
+                if targets.len() > max_tabs {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!(
+                            "Opened build logs for {} of {} PRs (max_browser_tabs = {})",
+                            max_tabs,
+                            targets.len(),
+                            max_tabs
+                        ),
+                        "Build Logs",
+                    )));
                 }
                 false // Consume action
             }
@@ -1138,6 +1823,7 @@ impl Middleware for GitHubMiddleware {
             Action::PullRequest(PullRequestAction::OpenInIDE) => {
                 let targets = self.get_target_pr_info_for_ide(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "open", "IDE");
                     log::warn!("No PRs selected for opening in IDE");
                     return false;
                 }
@@ -1154,7 +1840,7 @@ impl Middleware for GitHubMiddleware {
                     let temp_dir_base = temp_dir_base.clone();
                     let org = repo.org.clone();
                     let repo_name = repo.repo.clone();
-                    let repo_host = repo.host.clone();
+                    let repo_host = Some(repo.effective_host().to_string());
                     let ssh_url = repo.ssh_url();
 
                     self.runtime.spawn_blocking(move || {
@@ -1270,23 +1956,106 @@ impl Middleware for GitHubMiddleware {
                 false // Consume action
             }
 
-            Action::PullRequest(PullRequestAction::RerunFailedJobs) => {
+            Action::PullRequest(PullRequestAction::CheckoutLocal) => {
+                let repo_idx = state.main_view.selected_repository;
+                let Some(repo) = state.main_view.repositories.get(repo_idx) else {
+                    return false;
+                };
+                let Some(pr) = state
+                    .main_view
+                    .repo_data
+                    .get(&repo_idx)
+                    .and_then(|repo_data| repo_data.prs.get(repo_data.selected_pr))
+                else {
+                    log::warn!("No PR selected for local checkout");
+                    return false;
+                };
+
+                // Never fall back to a temp clone - that is what OpenInIDE is for
+                let Some(local_dir) = state.app_config.local_repo_path(&repo.org, &repo.repo)
+                else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!(
+                            "No local path configured for {}/{} - add it under [local_repos] in config.toml",
+                            repo.org, repo.repo
+                        ),
+                        "Checkout",
+                    )));
+                    return false;
+                };
+
+                let pr_number = pr.number;
+                let dispatcher = dispatcher.clone();
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+                    format!(
+                        "Checking out PR #{} in {}...",
+                        pr_number,
+                        local_dir.display()
+                    ),
+                    "Checkout",
+                )));
+
+                self.runtime.spawn_blocking(move || {
+                    let output = std::process::Command::new("gh")
+                        .args(["pr", "checkout", &pr_number.to_string()])
+                        .current_dir(&local_dir)
+                        .output();
+
+                    match output {
+                        Ok(output) if output.status.success() => {
+                            log::info!("Checked out PR #{} in {:?}", pr_number, local_dir);
+                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                                format!("Checked out PR #{} in {}", pr_number, local_dir.display()),
+                                "Checkout",
+                            )));
+                        }
+                        Ok(output) => {
+                            let stderr = String::from_utf8_lossy(&output.stderr);
+                            log::error!("gh pr checkout failed: {}", stderr);
+                            dispatcher.dispatch(Action::error(
+                                format!("gh pr checkout failed: {}", stderr.trim()),
+                                "Checkout",
+                            ));
+                        }
+                        Err(err) => {
+                            log::error!("Failed to run gh pr checkout: {}", err);
+                            dispatcher.dispatch(Action::error(
+                                format!("Failed to run gh pr checkout: {}", err),
+                                "Checkout",
+                            ));
+                        }
+                    }
+                });
+                false // Consume action
+            }
+
+            Action::PullRequest(
+                PullRequestAction::RerunFailedJobs | PullRequestAction::RerunAllJobs,
+            ) => {
+                // Rerun all jobs of every run, or only the failed jobs of failed runs
+                let rerun_all =
+                    matches!(action, Action::PullRequest(PullRequestAction::RerunAllJobs));
                 let targets = self.get_target_pr_ci_info(state);
                 if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "rerun", "Rerun");
                     log::warn!("No PRs selected for rerunning jobs");
                     return false;
                 }
 
-                log::info!("Rerunning failed jobs for {} PR(s)", targets.len());
+                log::info!(
+                    "Rerunning {} jobs for {} PR(s)",
+                    if rerun_all { "all" } else { "failed" },
+                    targets.len()
+                );
 
                 let client_manager = Arc::clone(&self.client_manager);
 
-                // Rerun failed jobs for each target PR
+                // Rerun jobs for each target PR
                 for (repo, pr_number, head_sha, _head_branch) in targets {
                     let dispatcher = dispatcher.clone();
                     let client_manager = Arc::clone(&client_manager);
 
-                    // Fetch workflow runs, then rerun failed ones
+                    // Fetch workflow runs, then rerun them (or only the failed ones)
                     self.runtime.spawn(async move {
                         // Get client inside async task
                         let client = {
@@ -1295,10 +2064,10 @@ impl Middleware for GitHubMiddleware {
                                 Ok(c) => c,
                                 Err(e) => {
                                     log::error!("Failed to get client for rerun: {}", e);
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Rerun failed: {}", e),
                                         "Rerun",
-                                    )));
+                                    ));
                                     return;
                                 }
                             }
@@ -1307,36 +2076,46 @@ impl Middleware for GitHubMiddleware {
                         // Fetch workflow runs for this commit
                         match client.fetch_workflow_runs(&repo.org, &repo.repo, &head_sha).await {
                             Ok(runs) => {
-                                // Filter to failed runs and rerun each
-                                let failed_runs: Vec<_> = runs
+                                // Only completed runs can be rerun: all of them, or
+                                // just the failed ones
+                                let target_runs: Vec<_> = runs
                                     .into_iter()
                                     .filter(|r| {
-                                        r.conclusion.as_ref().is_some_and(|c| {
-                                            matches!(
-                                                c,
-                                                gh_client::WorkflowRunConclusion::Failure
-                                                    | gh_client::WorkflowRunConclusion::TimedOut
-                                            )
-                                        })
+                                        matches!(r.status, gh_client::WorkflowRunStatus::Completed)
+                                            && (rerun_all
+                                                || r.conclusion.as_ref().is_some_and(|c| {
+                                                    matches!(
+                                                        c,
+                                                        gh_client::WorkflowRunConclusion::Failure
+                                                            | gh_client::WorkflowRunConclusion::TimedOut
+                                                    )
+                                                }))
                                     })
                                     .collect();
 
-                                if failed_runs.is_empty() {
+                                if target_runs.is_empty() {
                                     log::info!(
-                                        "No failed workflow runs to rerun for PR #{}",
+                                        "No completed {}workflow runs to rerun for PR #{}",
+                                        if rerun_all { "" } else { "failed " },
                                         pr_number
                                     );
                                     return;
                                 }
 
-                                for run in failed_runs {
+                                for run in target_runs {
                                     dispatcher.dispatch(Action::PullRequest(PullRequestAction::RerunStart {
                                         repo: repo.clone(),
                                         pr_number,
                                         run_id: run.id,
                                     }));
 
-                                    match client.rerun_failed_jobs(&repo.org, &repo.repo, run.id).await {
+                                    let result = if rerun_all {
+                                        client.rerun_workflow(&repo.org, &repo.repo, run.id).await
+                                    } else {
+                                        client.rerun_failed_jobs(&repo.org, &repo.repo, run.id).await
+                                    };
+
+                                    match result {
                                         Ok(()) => {
                                             log::info!(
                                                 "Successfully triggered rerun for workflow {} (PR #{})",
@@ -1357,11 +2136,9 @@ impl Middleware for GitHubMiddleware {
                                                 pr_number,
                                                 e
                                             );
-                                            dispatcher.dispatch(Action::StatusBar(
-                                                StatusBarAction::error(
-                                                    format!("Rerun failed: {}", e),
-                                                    "Rerun",
-                                                ),
+                                            dispatcher.dispatch(Action::error(
+                                                format!("Rerun failed: {}", e),
+                                                "Rerun",
                                             ));
                                         }
                                     }
@@ -1376,7 +2153,207 @@ impl Middleware for GitHubMiddleware {
                 false // Consume action
             }
 
+            Action::PullRequest(PullRequestAction::CancelRuns) => {
+                let targets = self.get_target_pr_ci_info(state);
+                if targets.is_empty() {
+                    self.warn_if_all_hidden(state, dispatcher, "cancel", "Cancel");
+                    log::warn!("No PRs selected for cancelling workflow runs");
+                    return false;
+                }
+
+                log::info!("Cancelling workflow runs for {} PR(s)", targets.len());
+
+                let client_manager = Arc::clone(&self.client_manager);
+
+                // Cancel running workflows for each target PR
+                for (repo, pr_number, head_sha, _head_branch) in targets {
+                    let dispatcher = dispatcher.clone();
+                    let client_manager = Arc::clone(&client_manager);
+
+                    // Fetch workflow runs, then cancel the ones still running
+                    self.runtime.spawn(async move {
+                        // Get client inside async task
+                        let client = {
+                            let mut manager = client_manager.lock().await;
+                            match manager.clone_client(repo.host.as_deref()).await {
+                                Ok(c) => c,
+                                Err(e) => {
+                                    log::error!("Failed to get client for cancel: {}", e);
+                                    dispatcher.dispatch(Action::error(
+                                        format!("Cancel failed: {}", e),
+                                        "Cancel",
+                                    ));
+                                    return;
+                                }
+                            }
+                        };
+
+                        let runs = match client
+                            .fetch_workflow_runs(&repo.org, &repo.repo, &head_sha)
+                            .await
+                        {
+                            Ok(runs) => runs,
+                            Err(e) => {
+                                log::error!(
+                                    "Failed to fetch workflow runs for PR #{}: {}",
+                                    pr_number,
+                                    e
+                                );
+                                dispatcher.dispatch(Action::error(
+                                    format!("Cancel failed: {}", e),
+                                    "Cancel",
+                                ));
+                                return;
+                            }
+                        };
+
+                        // Queued, waiting and in-progress runs can be cancelled
+                        let running_runs: Vec<_> = runs
+                            .into_iter()
+                            .filter(|r| r.status != gh_client::WorkflowRunStatus::Completed)
+                            .collect();
+
+                        if running_runs.is_empty() {
+                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                                format!("No running workflows to cancel for PR #{}", pr_number),
+                                "Cancel",
+                            )));
+                            return;
+                        }
+
+                        for run in running_runs {
+                            dispatcher.dispatch(Action::PullRequest(
+                                PullRequestAction::CancelRunStart {
+                                    repo: repo.clone(),
+                                    pr_number,
+                                    run_id: run.id,
+                                },
+                            ));
+
+                            match client
+                                .cancel_workflow_run(&repo.org, &repo.repo, run.id)
+                                .await
+                            {
+                                Ok(()) => {
+                                    log::info!(
+                                        "Cancelled workflow {} (PR #{})",
+                                        run.name,
+                                        pr_number
+                                    );
+                                    dispatcher.dispatch(Action::StatusBar(
+                                        StatusBarAction::success(
+                                            format!("Cancelled {} (PR #{})", run.name, pr_number),
+                                            "Cancel",
+                                        ),
+                                    ));
+                                }
+                                Err(e) => {
+                                    log::error!(
+                                        "Failed to cancel workflow {} (PR #{}): {}",
+                                        run.name,
+                                        pr_number,
+                                        e
+                                    );
+                                    dispatcher.dispatch(Action::error(
+                                        format!("Cancel failed: {}", e),
+                                        "Cancel",
+                                    ));
+                                }
+                            }
+                        }
+                    });
+                }
+                false // Consume action
+            }
+
             // === Build Log Operations ===
+            Action::CommitList(CommitListAction::Open) => {
+                let repo_idx = state.main_view.selected_repository;
+
+                let Some(repo) = state.main_view.repositories.get(repo_idx).cloned() else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "No repository selected",
+                        "Commits",
+                    )));
+                    return false;
+                };
+
+                let Some(pr_number) = state
+                    .main_view
+                    .repo_data
+                    .get(&repo_idx)
+                    .and_then(|data| data.prs.get(data.selected_pr))
+                    .map(|pr| pr.number)
+                else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "No PR selected",
+                        "Commits",
+                    )));
+                    return false;
+                };
+
+                dispatcher.dispatch(Action::CommitList(CommitListAction::LoadStart {
+                    pr_number,
+                }));
+                dispatcher.dispatch(Action::Global(GlobalAction::PushView(Box::new(
+                    CommitListView::new(),
+                ))));
+
+                let dispatcher = dispatcher.clone();
+                let client_manager = self.client_manager_arc();
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        match manager.clone_client(repo.host.as_deref()).await {
+                            Ok(c) => c,
+                            Err(e) => {
+                                log::error!("Failed to get client for commits: {}", e);
+                                dispatcher.dispatch(Action::CommitList(
+                                    CommitListAction::LoadError {
+                                        pr_number,
+                                        error: e.to_string(),
+                                    },
+                                ));
+                                return;
+                            }
+                        }
+                    };
+
+                    match client
+                        .fetch_pr_commits(&repo.org, &repo.repo, pr_number as u64)
+                        .await
+                    {
+                        Ok(commits) => {
+                            dispatcher.dispatch(Action::CommitList(CommitListAction::Loaded {
+                                pr_number,
+                                commits: commits.into_iter().map(Into::into).collect(),
+                            }));
+                        }
+                        Err(e) => {
+                            log::error!("Failed to load commits for PR #{}: {}", pr_number, e);
+                            dispatcher.dispatch(Action::CommitList(CommitListAction::LoadError {
+                                pr_number,
+                                error: e.to_string(),
+                            }));
+                            dispatcher.dispatch(Action::error(
+                                format!("Failed to load commits: {}", e),
+                                "Commits",
+                            ));
+                        }
+                    }
+                });
+
+                false // Consume action
+            }
+
+            Action::CommitList(CommitListAction::OpenInBrowser) => {
+                if let Some(commit) = state.commit_list.selected_commit() {
+                    self.runtime.spawn(open_url(commit.html_url.clone()));
+                }
+                false // Consume action
+            }
+
             Action::BuildLog(BuildLogAction::Open) => {
                 let repo_idx = state.main_view.selected_repository;
 
@@ -1442,10 +2419,10 @@ impl Middleware for GitHubMiddleware {
                                 dispatcher.dispatch(Action::BuildLog(BuildLogAction::LoadError(
                                     e.to_string(),
                                 )));
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Failed to load build logs: {}", e),
                                     "Build Logs",
-                                )));
+                                ));
                                 return;
                             }
                         }
@@ -1472,15 +2449,182 @@ impl Middleware for GitHubMiddleware {
                             )));
                         }
                         Err(e) => {
-                            log::error!("Failed to load build logs: {}", e);
-                            dispatcher
-                                .dispatch(Action::BuildLog(BuildLogAction::LoadError(e.clone())));
-                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                                format!("Failed to load build logs: {}", e),
+                            log::error!("Failed to load build logs: {}", e);
+                            dispatcher
+                                .dispatch(Action::BuildLog(BuildLogAction::LoadError(e.clone())));
+                            dispatcher.dispatch(Action::error(
+                                format!("Failed to load build logs: {}", e),
+                                "Build Logs",
+                            ));
+                        }
+                    }
+                });
+
+                false // Consume action
+            }
+
+            Action::BuildLog(BuildLogAction::RerunWorkflow) => {
+                let Some((workflow_name, run_id)) = state.build_log.cursor_workflow_run() else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "No workflow run known for the selection",
+                        "Rerun",
+                    )));
+                    return false;
+                };
+                let Some(repo) = state
+                    .main_view
+                    .repositories
+                    .get(state.main_view.selected_repository)
+                    .cloned()
+                else {
+                    return false;
+                };
+
+                let workflow_name = workflow_name.to_string();
+                let pr_number = state.build_log.pr_context.number as u64;
+                let dispatcher = dispatcher.clone();
+                let client_manager = Arc::clone(&self.client_manager);
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        match manager.clone_client(repo.host.as_deref()).await {
+                            Ok(c) => c,
+                            Err(e) => {
+                                log::error!("Failed to get client for rerun: {}", e);
+                                dispatcher.dispatch(Action::error(
+                                    format!("Rerun failed: {}", e),
+                                    "Rerun",
+                                ));
+                                return;
+                            }
+                        }
+                    };
+
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::RerunStart {
+                        repo: repo.clone(),
+                        pr_number,
+                        run_id,
+                    }));
+
+                    match client
+                        .rerun_failed_jobs(&repo.org, &repo.repo, run_id)
+                        .await
+                    {
+                        Ok(()) => {
+                            log::info!(
+                                "Successfully triggered rerun for workflow {} (PR #{})",
+                                workflow_name,
+                                pr_number
+                            );
+                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                                format!(
+                                    "Rerun triggered for {} (PR #{})",
+                                    workflow_name, pr_number
+                                ),
+                                "Rerun",
+                            )));
+                        }
+                        Err(e) => {
+                            log::error!(
+                                "Failed to rerun workflow {} (PR #{}): {}",
+                                workflow_name,
+                                pr_number,
+                                e
+                            );
+                            dispatcher
+                                .dispatch(Action::error(format!("Rerun failed: {}", e), "Rerun"));
+                        }
+                    }
+                });
+
+                false // Consume action
+            }
+
+            Action::BuildLog(BuildLogAction::DownloadJobLog) => {
+                let Some(job) = state.build_log.cursor_job_metadata() else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "Select a job to download its log",
+                        "Build Logs",
+                    )));
+                    return false;
+                };
+                let Some(job_id) = job.job_id else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        format!("No job ID known for '{}'", job.name),
+                        "Build Logs",
+                    )));
+                    return false;
+                };
+                let Some(repo) = state
+                    .main_view
+                    .repositories
+                    .get(state.main_view.selected_repository)
+                    .cloned()
+                else {
+                    return false;
+                };
+
+                let job_name = job.name.clone();
+                let dispatcher = dispatcher.clone();
+                let client_manager = Arc::clone(&self.client_manager);
+
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+                    format!("Downloading full log for '{}'...", job_name),
+                    "Build Logs",
+                )));
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        match manager.clone_client(repo.host.as_deref()).await {
+                            Ok(c) => c,
+                            Err(e) => {
+                                dispatcher.dispatch(Action::error(
+                                    format!("Failed to download job log: {}", e),
+                                    "Build Logs",
+                                ));
+                                return;
+                            }
+                        }
+                    };
+
+                    let log = match client
+                        .download_job_logs(&repo.org, &repo.repo, job_id)
+                        .await
+                    {
+                        Ok(log) => log,
+                        Err(e) => {
+                            log::error!("Failed to download log for job {}: {}", job_id, e);
+                            dispatcher.dispatch(Action::error(
+                                format!("Failed to download job log: {}", e),
                                 "Build Logs",
-                            )));
+                            ));
+                            return;
                         }
+                    };
+
+                    let path =
+                        std::env::temp_dir().join(format!("gh-pr-lander-job-{}.log", job_id));
+                    if let Err(e) = tokio::fs::write(&path, &log).await {
+                        dispatcher.dispatch(Action::error(
+                            format!("Failed to write {}: {}", path.display(), e),
+                            "Build Logs",
+                        ));
+                        return;
                     }
+
+                    log::info!("Saved log of job {} to {}", job_id, path.display());
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                        format!(
+                            "Saved full log of '{}' ({} lines) to {}",
+                            job_name,
+                            log.lines().count(),
+                            path.display()
+                        ),
+                        "Build Logs",
+                    )));
+                    open_url(path.display().to_string()).await;
                 });
 
                 false // Consume action
@@ -1599,10 +2743,7 @@ impl Middleware for GitHubMiddleware {
                 let repo_idx = state.main_view.selected_repository;
                 let Some(repo) = state.main_view.repositories.get(repo_idx).cloned() else {
                     log::error!("No repository selected for review submission");
-                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                        "No repository selected",
-                        "Review",
-                    )));
+                    dispatcher.dispatch(Action::error("No repository selected", "Review"));
                     return false;
                 };
 
@@ -1611,6 +2752,33 @@ impl Middleware for GitHubMiddleware {
                 let dispatcher = dispatcher.clone();
                 let client_manager = self.client_manager_arc();
 
+                // Collect pending comments that are not yet on GitHub and map
+                // them to diff positions so they can be attached to the review
+                let (submitted, draft_comments) = state
+                    .diff_viewer
+                    .inner
+                    .as_ref()
+                    .map(collect_review_comments)
+                    .unwrap_or_default();
+
+                // Line comments are anchored to the head commit, GitHub rejects them without it
+                let head_sha = state
+                    .diff_viewer
+                    .head_sha
+                    .clone()
+                    .filter(|sha| !sha.is_empty());
+                if head_sha.is_none() && !draft_comments.is_empty() {
+                    log::error!(
+                        "Head commit of PR #{} unknown, cannot submit review comments",
+                        pr_number
+                    );
+                    dispatcher.dispatch(Action::error(
+                        "Head commit of the PR unknown, cannot submit review comments",
+                        "Review",
+                    ));
+                    return false;
+                }
+
                 // Convert gh_diff_viewer::ReviewEvent to gh_client::ReviewEvent
                 let api_event = match event {
                     gh_diff_viewer::ReviewEvent::Approve => ReviewEvent::Approve,
@@ -1636,29 +2804,53 @@ impl Middleware for GitHubMiddleware {
                             Ok(c) => c,
                             Err(e) => {
                                 log::error!("Failed to get client: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Review failed: {}", e),
                                     "Review",
-                                )));
+                                ));
                                 return;
                             }
                         }
                     };
 
-                    match client
-                        .create_review(&repo.org, &repo.repo, pr_number, api_event, None)
-                        .await
-                    {
+                    let result = match head_sha {
+                        Some(head_sha) if !draft_comments.is_empty() => {
+                            client
+                                .create_review_with_comments(
+                                    &repo.org,
+                                    &repo.repo,
+                                    pr_number,
+                                    &head_sha,
+                                    api_event,
+                                    None,
+                                    &draft_comments,
+                                )
+                                .await
+                        }
+                        _ => {
+                            client
+                                .create_review(&repo.org, &repo.repo, pr_number, api_event, None)
+                                .await
+                        }
+                    };
+
+                    match result {
                         Ok(()) => {
                             log::info!(
-                                "Successfully submitted {} review for PR #{}",
+                                "Successfully submitted {} review with {} comment(s) for PR #{}",
                                 event_name,
+                                draft_comments.len(),
                                 pr_number
                             );
                             dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
                                 format!("{} review submitted for PR #{}", event_name, pr_number),
                                 "Review",
                             )));
+                            dispatcher.dispatch(Action::DiffViewer(
+                                DiffViewerAction::ReviewSubmitted {
+                                    comments: submitted,
+                                },
+                            ));
                             // Update review decision locally based on event type
                             let decision = match event {
                                 gh_diff_viewer::ReviewEvent::Approve => {
@@ -1681,10 +2873,8 @@ impl Middleware for GitHubMiddleware {
                         }
                         Err(e) => {
                             log::error!("Review submission error: {}", e);
-                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                                format!("Review failed: {}", e),
-                                "Review",
-                            )));
+                            dispatcher
+                                .dispatch(Action::error(format!("Review failed: {}", e), "Review"));
                         }
                     }
                 });
@@ -1703,10 +2893,7 @@ impl Middleware for GitHubMiddleware {
                 let repo_idx = state.main_view.selected_repository;
                 let Some(repo) = state.main_view.repositories.get(repo_idx).cloned() else {
                     log::error!("No repository selected for comment submission");
-                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                        "No repository selected",
-                        "Comment",
-                    )));
+                    dispatcher.dispatch(Action::error("No repository selected", "Comment"));
                     return false;
                 };
 
@@ -1733,10 +2920,10 @@ impl Middleware for GitHubMiddleware {
                             Ok(c) => c,
                             Err(e) => {
                                 log::error!("Failed to get client: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Comment failed: {}", e),
                                     "Comment",
-                                )));
+                                ));
                                 return;
                             }
                         }
@@ -1772,10 +2959,10 @@ impl Middleware for GitHubMiddleware {
                         }
                         Err(e) => {
                             log::error!("Comment submission error: {}", e);
-                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                            dispatcher.dispatch(Action::error(
                                 format!("Comment failed: {}", e),
                                 "Comment",
-                            )));
+                            ));
                         }
                     }
                 });
@@ -1793,10 +2980,7 @@ impl Middleware for GitHubMiddleware {
                 let repo_idx = state.main_view.selected_repository;
                 let Some(repo) = state.main_view.repositories.get(repo_idx).cloned() else {
                     log::error!("No repository selected for comment deletion");
-                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
-                        "No repository selected",
-                        "Comment",
-                    )));
+                    dispatcher.dispatch(Action::error("No repository selected", "Comment"));
                     return false;
                 };
 
@@ -1830,10 +3014,10 @@ impl Middleware for GitHubMiddleware {
                             Ok(c) => c,
                             Err(e) => {
                                 log::error!("Failed to get client: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Delete failed: {}", e),
                                     "Comment",
-                                )));
+                                ));
                                 return;
                             }
                         }
@@ -1866,10 +3050,10 @@ impl Middleware for GitHubMiddleware {
                         }
                         Err(e) => {
                             log::error!("Comment deletion error: {}", e);
-                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                            dispatcher.dispatch(Action::error(
                                 format!("Delete failed: {}", e),
                                 "Comment",
-                            )));
+                            ));
                         }
                     }
                 });
@@ -1877,6 +3061,49 @@ impl Middleware for GitHubMiddleware {
                 false // Consume action
             }
 
+            Action::DiffViewer(DiffViewerAction::SetFileViewed { path, viewed })
+                if state.app_config.diff.sync_viewed =>
+            {
+                let repo_idx = state.main_view.selected_repository;
+                let (Some(repo), Some(pr_number)) = (
+                    state.main_view.repositories.get(repo_idx).cloned(),
+                    state.diff_viewer.pr_number,
+                ) else {
+                    return true;
+                };
+
+                let path = path.clone();
+                let viewed = *viewed;
+                let dispatcher = dispatcher.clone();
+                let client_manager = self.client_manager_arc();
+
+                self.runtime.spawn(async move {
+                    let client = {
+                        let mut manager = client_manager.lock().await;
+                        match manager.clone_client(repo.host.as_deref()).await {
+                            Ok(c) => c,
+                            Err(e) => {
+                                log::error!("Failed to get client: {}", e);
+                                return;
+                            }
+                        }
+                    };
+
+                    if let Err(e) = client
+                        .set_file_viewed(&repo.org, &repo.repo, pr_number, &path, viewed)
+                        .await
+                    {
+                        log::error!("Failed to sync viewed state of {}: {}", path, e);
+                        dispatcher.dispatch(Action::error(
+                            format!("Failed to sync viewed state of {}: {}", path, e),
+                            "Diff Viewer",
+                        ));
+                    }
+                });
+
+                true // Pass through to reducer
+            }
+
             Action::DiffViewer(DiffViewerAction::Open) => {
                 let repo_idx = state.main_view.selected_repository;
 
@@ -1934,10 +3161,10 @@ impl Middleware for GitHubMiddleware {
                             Ok(c) => c,
                             Err(e) => {
                                 log::error!("Failed to get client: {}", e);
-                                dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                dispatcher.dispatch(Action::error(
                                     format!("Failed to load diff: {}", e),
                                     "Diff Viewer",
-                                )));
+                                ));
                                 dispatcher.dispatch(Action::DiffViewer(
                                     DiffViewerAction::LoadError(e.to_string()),
                                 ));
@@ -2010,10 +3237,10 @@ impl Middleware for GitHubMiddleware {
                                             e
                                         )),
                                     ));
-                                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                                    dispatcher.dispatch(Action::error(
                                         format!("Failed to parse diff: {}", e),
                                         "Diff Viewer",
-                                    )));
+                                    ));
                                 }
                             }
                         }
@@ -2022,10 +3249,10 @@ impl Middleware for GitHubMiddleware {
                             dispatcher.dispatch(Action::DiffViewer(DiffViewerAction::LoadError(
                                 e.clone(),
                             )));
-                            dispatcher.dispatch(Action::StatusBar(StatusBarAction::error(
+                            dispatcher.dispatch(Action::error(
                                 format!("Failed to fetch diff: {}", e),
                                 "Diff Viewer",
-                            )));
+                            ));
                         }
                     }
                 });
@@ -2096,7 +3323,6 @@ async fn fetch_build_logs(
 
         #[derive(Debug, serde::Deserialize)]
         struct WorkflowJob {
-            #[allow(dead_code)]
             id: u64,
             name: String,
             html_url: String,
@@ -2168,6 +3394,8 @@ async fn fetch_build_logs(
                                 html_url: github_job
                                     .map(|j| j.html_url.clone())
                                     .unwrap_or_default(),
+                                job_id: github_job.map(|j| j.id),
+                                run_id: workflow_run.id,
                             });
 
                             // Convert job_log to JobNode using the parser's built-in function
@@ -2203,6 +3431,11 @@ async fn fetch_build_logs(
     Ok((all_workflows, all_job_metadata))
 }
 
+/// GitHub Actions runs page filtered to a PR's head branch
+fn build_ci_logs_url(repo: &Repository, head_branch: &str) -> String {
+    format!("{}/actions?query=branch%3A{}", repo.web_url(), head_branch)
+}
+
 /// Count errors in a job log
 fn count_errors_in_job(job_log: &gh_actions_log_parser::JobLog) -> usize {
     job_log
@@ -2244,6 +3477,42 @@ fn parse_duration(started: &str, completed: &str) -> Option<Duration> {
     }
 }
 
+/// Dispatch loaded PRs (`Loaded` replaces the list, `MorePrsLoaded` appends
+/// to it), then fetch their CI status and stats in the background
+///
+/// The PRs are dispatched first so they exist when BuildStatusUpdated arrives.
+fn dispatch_loaded_prs(
+    repo: &Repository,
+    prs: Vec<Pr>,
+    replace: bool,
+    dispatcher: &Dispatcher,
+    client: &CachedGitHubClient<OctocrabClient>,
+    client_manager: &Arc<TokioMutex<ClientManager>>,
+) {
+    let action = if replace {
+        PullRequestAction::Loaded {
+            repo: repo.clone(),
+            prs: prs.clone(),
+        }
+    } else {
+        PullRequestAction::MorePrsLoaded {
+            repo: repo.clone(),
+            prs: prs.clone(),
+        }
+    };
+    dispatcher.dispatch(Action::PullRequest(action));
+
+    dispatch_ci_status_checks(repo, &prs, dispatcher, Arc::clone(client_manager));
+    // The list endpoint lacks additions/deletions
+    dispatch_pr_stats_fetch(
+        repo,
+        &prs,
+        dispatcher,
+        client.clone(),
+        Arc::clone(client_manager),
+    );
+}
+
 /// Dispatch CheckBuildStatus actions for the given PRs
 fn dispatch_ci_status_checks(
     repo: &Repository,
@@ -2260,6 +3529,50 @@ fn dispatch_ci_status_checks(
     }
 }
 
+/// Collect pending comments that have not been posted yet, mapped to
+/// GitHub diff positions.
+///
+/// Returns the comments that will be submitted alongside their API payloads.
+/// Comments that cannot be mapped (e.g. on locally expanded context lines)
+/// are left pending.
+fn collect_review_comments(
+    inner: &gh_diff_viewer::DiffViewerState,
+) -> (Vec<gh_diff_viewer::PendingComment>, Vec<DraftReviewComment>) {
+    let mut submitted = Vec::new();
+    let mut drafts = Vec::new();
+
+    for comment in inner
+        .pending_comments
+        .iter()
+        .filter(|c| c.github_id.is_none())
+    {
+        let position = inner
+            .diff
+            .files
+            .iter()
+            .find(|f| f.path == comment.path)
+            .and_then(|f| f.diff_position(comment.position.side, comment.position.line));
+
+        match position {
+            Some(position) => {
+                drafts.push(DraftReviewComment {
+                    path: comment.path.clone(),
+                    position,
+                    body: comment.body.clone(),
+                });
+                submitted.push(comment.clone());
+            }
+            None => log::warn!(
+                "Cannot map comment at {}:{} to a diff position, keeping it pending",
+                comment.path,
+                comment.position.line
+            ),
+        }
+    }
+
+    (submitted, drafts)
+}
+
 /// Dispatch background fetch for PR stats (additions/deletions)
 ///
 /// The GitHub list PRs endpoint doesn't include additions/deletions, so we
@@ -2305,6 +3618,156 @@ fn dispatch_pr_stats_fetch(
     }
 }
 
+/// User-facing message for a failed PR load
+///
+/// A rejected token (e.g. an expired one from `gh auth token`) only shows up
+/// on the first API call, so it gets a hint to re-authenticate.
+fn describe_load_error(error: &anyhow::Error, host: Option<&str>) -> String {
+    if gh_client::is_unauthorized(error) {
+        let host = host.unwrap_or(gh_client::default_host());
+        format!(
+            "GitHub rejected the token for {} (401), it may have expired. \
+             Re-authenticate with 'gh auth login --hostname {}' or update your token",
+            host, host
+        )
+    } else {
+        format!("Failed to load PRs: {}", error)
+    }
+}
+
+/// Resolve GitHub App credentials when the config selects App auth
+///
+/// Each value is taken from its `GITHUB_APP_*` env var if set, else from the
+/// config. Returns `None` (token auth) for PAT mode or when the App setup is
+/// incomplete.
+pub(crate) fn app_credentials(auth: &AuthConfig) -> Option<AppCredentials> {
+    resolve_app_credentials(auth, |key| std::env::var(key).ok())
+}
+
+/// `app_credentials` with the env var lookup passed in
+fn resolve_app_credentials(
+    auth: &AuthConfig,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<AppCredentials> {
+    if auth.mode != AuthMode::App {
+        return None;
+    }
+
+    let env = |key: &str| env(key).filter(|value| !value.trim().is_empty());
+    let env_id = |key: &str| env(key).and_then(|value| value.trim().parse::<u64>().ok());
+
+    let app_id = env_id("GITHUB_APP_ID").or(auth.app_id);
+    let private_key_path = env("GITHUB_APP_PRIVATE_KEY_PATH")
+        .or_else(|| auth.private_key_path.clone())
+        .map(std::path::PathBuf::from);
+    let installation_id = env_id("GITHUB_APP_INSTALLATION_ID").or(auth.installation_id);
+
+    match (app_id, private_key_path) {
+        (Some(app_id), Some(private_key_path)) => Some(
+            AppCredentials::new(app_id, private_key_path).with_installation_id(installation_id),
+        ),
+        _ => {
+            log::warn!(
+                "auth.mode = \"app\" requires app_id and private_key_path \
+                 (or GITHUB_APP_ID and GITHUB_APP_PRIVATE_KEY_PATH), falling back to token auth"
+            );
+            None
+        }
+    }
+}
+
+/// Default branch of the repository, as configured on GitHub
+pub(crate) async fn default_branch(
+    client: &CachedGitHubClient<OctocrabClient>,
+    org: &str,
+    repo: &str,
+) -> anyhow::Result<String> {
+    let repository = client.inner().octocrab_arc().repos(org, repo).get().await?;
+    repository.default_branch.ok_or_else(|| {
+        anyhow::anyhow!(
+            "{}/{} has no default branch, pass OWNER/NAME@BRANCH",
+            org,
+            repo
+        )
+    })
+}
+
+/// Login of the authenticated user on the repository's host, fetched on first use
+///
+/// Failures are only logged: the filters depending on it fall back gracefully.
+async fn viewer_login(
+    client: &CachedGitHubClient<OctocrabClient>,
+    repo: &Repository,
+    viewer_logins: &ViewerLogins,
+) -> Option<String> {
+    let host = repo.effective_host().to_string();
+    let cached = viewer_logins.lock().unwrap().get(&host).cloned();
+    if cached.is_some() {
+        return cached;
+    }
+    match client.fetch_viewer_login().await {
+        Ok(login) => {
+            log::info!("Authenticated as {} on {}", login, host);
+            viewer_logins.lock().unwrap().insert(host, login.clone());
+            Some(login)
+        }
+        Err(e) => {
+            log::warn!("Failed to fetch the authenticated user on {}: {}", host, e);
+            None
+        }
+    }
+}
+
+/// Fetch one page of a repository's PR list, keeping at most `remaining` PRs
+///
+/// Returns the sorted domain PRs and whether this was the last page.
+pub(crate) async fn load_pr_page(
+    client: &CachedGitHubClient<OctocrabClient>,
+    repo: &Repository,
+    query: &PrListQuery,
+    page: u32,
+    remaining: usize,
+) -> anyhow::Result<(Vec<Pr>, bool)> {
+    let mut prs = client
+        .fetch_pull_requests_page(
+            &repo.org,
+            &repo.repo,
+            Some(&repo.branch),
+            query.state,
+            query.per_page,
+            page,
+        )
+        .await?;
+
+    let is_last_page = prs.len() < query.per_page as usize;
+    prs.truncate(remaining);
+    prs.sort_prs();
+    Ok((
+        prs.into_iter().map(convert_to_domain_pr).collect(),
+        is_last_page,
+    ))
+}
+
+/// Load a repository's PR list page by page, up to `query.max_prs`
+pub(crate) async fn load_prs(
+    client: &CachedGitHubClient<OctocrabClient>,
+    repo: &Repository,
+    query: &PrListQuery,
+) -> anyhow::Result<Vec<Pr>> {
+    let mut prs = Vec::new();
+    let mut page = 1u32;
+    while prs.len() < query.max_prs {
+        let (page_prs, is_last_page) =
+            load_pr_page(client, repo, query, page, query.max_prs - prs.len()).await?;
+        prs.extend(page_prs);
+        if is_last_page {
+            break;
+        }
+        page += 1;
+    }
+    Ok(prs)
+}
+
 /// Fetch PR diff from GitHub API using gh CLI
 async fn fetch_pr_diff(
     _octocrab: &Octocrab, // Not used currently, but kept for potential future use
@@ -2323,11 +3786,10 @@ async fn fetch_pr_diff(
     ];
 
     // Add --hostname for GitHub Enterprise hosts
-    if let Some(h) = host {
-        if h != gh_client::DEFAULT_HOST {
-            args.push("--hostname".to_string());
-            args.push(h.to_string());
-        }
+    let h = host.unwrap_or(gh_client::default_host());
+    if h != gh_client::DEFAULT_HOST {
+        args.push("--hostname".to_string());
+        args.push(h.to_string());
     }
 
     let output = tokio::process::Command::new("gh")
@@ -2347,6 +3809,155 @@ async fn fetch_pr_diff(
     Ok(diff_text)
 }
 
+/// Approve a PR with the rendered approval message
+async fn approve_pr<C: GitHubClient + ?Sized>(
+    client: &C,
+    repo: &Repository,
+    pr_number: u64,
+    message: Option<&str>,
+    dispatcher: &Dispatcher,
+) {
+    match client
+        .create_review(
+            &repo.org,
+            &repo.repo,
+            pr_number,
+            ReviewEvent::Approve,
+            message,
+        )
+        .await
+    {
+        Ok(()) => {
+            log::info!("Successfully approved PR #{}", pr_number);
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                format!("PR #{} approved", pr_number),
+                "Approve",
+            )));
+            // Update review decision locally
+            dispatcher.dispatch(Action::PullRequest(
+                PullRequestAction::ReviewDecisionUpdated {
+                    repo: repo.clone(),
+                    pr_number,
+                    decision: crate::domain_models::ReviewDecision::Approved,
+                },
+            ));
+        }
+        Err(e) => {
+            log::error!("Approve error: {}", e);
+            dispatcher.dispatch(Action::error(format!("Approve failed: {}", e), "Approve"));
+        }
+    }
+}
+
+/// One merge bot run over a confirmed plan
+struct MergeBotRun {
+    repo: Repository,
+    repo_idx: usize,
+    merge_method: MergeMethod,
+    /// Set on `MergeBotAction::Stop` (or when a new run starts)
+    stop: Arc<AtomicBool>,
+}
+
+impl MergeBotRun {
+    /// Carry out the plan once: merge the ready PRs and rebase the ones behind
+    ///
+    /// PRs waiting for CI stay queued, skipped PRs are never touched. Every
+    /// decision is logged to the debug console. A stopped run returns without
+    /// reporting anything further.
+    async fn run<C: GitHubClient + ?Sized>(
+        &self,
+        client: &C,
+        plan: &MergeBotPlan,
+        dispatcher: &Dispatcher,
+    ) {
+        let (owner, repo) = (self.repo.org.as_str(), self.repo.repo.as_str());
+        let (mut merged, mut rebased) = (0, 0);
+
+        for (pr_number, _, decision) in &plan.entries {
+            if self.stopped() {
+                log::info!("Merge bot: stopped before PR #{}", pr_number);
+                return;
+            }
+
+            match decision {
+                MergeBotDecision::Merge => {
+                    log::info!("Merge bot: merging PR #{}", pr_number);
+                    let (success, message) = match client
+                        .merge_pull_request(owner, repo, *pr_number, self.merge_method, None, None)
+                        .await
+                    {
+                        Ok(result) => (result.merged, result.message),
+                        Err(e) => (false, e.to_string()),
+                    };
+                    if success {
+                        merged += 1;
+                    } else {
+                        log::warn!("Merge bot: PR #{} not merged: {}", pr_number, message);
+                        dispatcher.dispatch(Action::error(
+                            format!("PR #{} not merged: {}", pr_number, message),
+                            "Merge Bot",
+                        ));
+                    }
+                    self.merge_complete(*pr_number, success, message, dispatcher);
+                }
+                MergeBotDecision::RebaseFirst => {
+                    log::info!("Merge bot: rebasing PR #{}", pr_number);
+                    match client
+                        .update_pull_request_branch(owner, repo, *pr_number)
+                        .await
+                    {
+                        Ok(()) => rebased += 1,
+                        Err(e) => {
+                            log::warn!("Merge bot: PR #{} not rebased: {}", pr_number, e);
+                            dispatcher.dispatch(Action::error(
+                                format!("PR #{} not rebased: {}", pr_number, e),
+                                "Merge Bot",
+                            ));
+                            self.merge_complete(*pr_number, false, e.to_string(), dispatcher);
+                        }
+                    }
+                }
+                MergeBotDecision::WaitForCi => {
+                    log::info!("Merge bot: PR #{} left queued until CI passed", pr_number);
+                }
+                MergeBotDecision::Skip => {
+                    log::info!("Merge bot: PR #{} skipped", pr_number);
+                }
+            }
+        }
+
+        dispatcher.dispatch(Action::MergeBot(MergeBotAction::Finished));
+        dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+            format!("Merge bot finished: {} merged, {} rebased", merged, rebased),
+            "Merge Bot",
+        )));
+        if merged + rebased > 0 {
+            dispatcher.dispatch(Action::PullRequest(PullRequestAction::RefreshStart {
+                repo: self.repo.clone(),
+            }));
+        }
+    }
+
+    fn stopped(&self) -> bool {
+        self.stop.load(Ordering::SeqCst)
+    }
+
+    fn merge_complete(
+        &self,
+        pr_number: u64,
+        success: bool,
+        message: String,
+        dispatcher: &Dispatcher,
+    ) {
+        dispatcher.dispatch(Action::MergeBot(MergeBotAction::MergeComplete(
+            self.repo_idx,
+            pr_number as usize,
+            success,
+            message,
+        )));
+    }
+}
+
 /// Convert gh-client PullRequest to domain Pr
 fn convert_to_domain_pr(pr: PullRequest) -> Pr {
     let mergeable = match pr.mergeable_state {
@@ -2368,6 +3979,7 @@ fn convert_to_domain_pr(pr: PullRequest) -> Pr {
         needs_rebase: matches!(mergeable, MergeableStatus::NeedsRebase),
         head_sha: pr.head_sha,
         head_branch: pr.head_branch,
+        head_owner: pr.head_owner,
         created_at: pr.created_at,
         updated_at: pr.updated_at,
         html_url: pr.html_url,
@@ -2375,5 +3987,168 @@ fn convert_to_domain_pr(pr: PullRequest) -> Pr {
         deletions: pr.deletions as usize,
         maturity: pr.maturity,
         review_decision: pr.review_decision,
+        requested_reviewers: pr.requested_reviewers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reducers::merge_bot_reducer::reduce_merge_bot;
+    use crate::state::{MergeBotState, MergeBotStatus};
+    use gh_client::mock_client::MockClient;
+    use std::sync::mpsc;
+
+    fn merge_bot_run(repo: &Repository) -> MergeBotRun {
+        MergeBotRun {
+            repo: repo.clone(),
+            repo_idx: 0,
+            merge_method: MergeMethod::default(),
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn plan(entries: &[(u64, MergeableStatus)]) -> MergeBotPlan {
+        MergeBotPlan {
+            entries: entries
+                .iter()
+                .map(|&(pr, status)| (pr, status, MergeBotDecision::for_status(status)))
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_bot_carries_out_the_plan() {
+        let repo = Repository::new("owner", "repo", "main");
+        let client = MockClient::default();
+        let plan = plan(&[
+            (1, MergeableStatus::Ready),
+            (2, MergeableStatus::NeedsRebase),
+            (3, MergeableStatus::Checking),
+            (4, MergeableStatus::Conflicted),
+        ]);
+        let mut bot_state = reduce_merge_bot(
+            MergeBotState::default(),
+            &MergeBotAction::Begin {
+                repo_idx: 0,
+                plan: plan.clone(),
+            },
+        );
+
+        let (tx, rx) = mpsc::channel();
+        merge_bot_run(&repo)
+            .run(&client, &plan, &Dispatcher::new(tx))
+            .await;
+
+        // #3 waits for CI and #4 is blocked, neither is touched
+        assert_eq!(client.calls(), vec!["merge #1", "rebase #2"]);
+
+        let mut refreshed = None;
+        for action in rx.try_iter() {
+            match action {
+                Action::MergeBot(action) => bot_state = reduce_merge_bot(bot_state, &action),
+                Action::PullRequest(PullRequestAction::RefreshStart { repo }) => {
+                    refreshed = Some(repo)
+                }
+                _ => {}
+            }
+        }
+        let statuses: Vec<(usize, MergeBotStatus)> = bot_state
+            .queue
+            .iter()
+            .map(|e| (e.pr_number, e.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, MergeBotStatus::Merged),
+                (2, MergeBotStatus::Queued),
+                (3, MergeBotStatus::Queued)
+            ]
+        );
+        assert!(!bot_state.active);
+        assert_eq!(refreshed, Some(repo));
+    }
+
+    #[tokio::test]
+    async fn test_approval_posts_the_rendered_message() {
+        let repo = Repository::new("owner", "repo", "main");
+        let prs = vec![Pr::new(2, "Fix the parser".to_string(), "bob", "sha")];
+        let client = MockClient::default();
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+
+        let (bodies, unrendered) =
+            message_template::review_bodies("Thanks @$AUTHOR for #$NUMBER", &[2, 3], &prs);
+        for (pr_number, message) in bodies {
+            approve_pr(&client, &repo, pr_number, message.as_deref(), &dispatcher).await;
+        }
+
+        assert_eq!(
+            client.calls(),
+            vec!["review #2 Approve: Thanks @bob for #2"]
+        );
+        assert_eq!(unrendered, vec![3]);
+        assert!(rx.try_iter().any(|action| matches!(
+            action,
+            Action::PullRequest(PullRequestAction::ReviewDecisionUpdated { pr_number: 2, .. })
+        )));
+    }
+
+    fn app_auth(app_id: Option<u64>, private_key_path: Option<&str>) -> AuthConfig {
+        AuthConfig {
+            mode: AuthMode::App,
+            app_id,
+            private_key_path: private_key_path.map(str::to_string),
+            installation_id: Some(7),
+        }
+    }
+
+    #[test]
+    fn test_app_credentials_merge_env_and_config_per_field() {
+        // The key path only comes from the env, the App ID from both
+        let env = |key: &str| match key {
+            "GITHUB_APP_ID" => Some("42".to_string()),
+            "GITHUB_APP_PRIVATE_KEY_PATH" => Some("/env/app.pem".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            resolve_app_credentials(&app_auth(Some(1), None), env),
+            Some(AppCredentials::new(42, "/env/app.pem").with_installation_id(Some(7)))
+        );
+
+        let env = |key: &str| (key == "GITHUB_APP_ID").then(|| "42".to_string());
+        assert_eq!(
+            resolve_app_credentials(&app_auth(None, Some("/etc/app.pem")), env),
+            Some(AppCredentials::new(42, "/etc/app.pem").with_installation_id(Some(7)))
+        );
+        assert_eq!(resolve_app_credentials(&app_auth(None, None), env), None);
+    }
+
+    #[test]
+    fn test_app_credentials_only_in_app_mode() {
+        let auth = AuthConfig {
+            mode: AuthMode::Pat,
+            ..app_auth(Some(1), Some("/etc/app.pem"))
+        };
+        assert_eq!(resolve_app_credentials(&auth, |_| None), None);
+    }
+
+    #[tokio::test]
+    async fn test_stopped_merge_bot_leaves_the_queue_alone() {
+        let repo = Repository::new("owner", "repo", "main");
+        let client = MockClient::default();
+        let bot = merge_bot_run(&repo);
+        bot.stop.store(true, Ordering::SeqCst);
+
+        let (tx, rx) = mpsc::channel();
+        let plan = plan(&[
+            (1, MergeableStatus::Ready),
+            (2, MergeableStatus::NeedsRebase),
+        ]);
+        bot.run(&client, &plan, &Dispatcher::new(tx)).await;
+
+        assert!(client.calls().is_empty());
+        assert!(rx.try_recv().is_err());
     }
 }
@@ -0,0 +1,201 @@
+//! Invariant Middleware
+//!
+//! Development aid that logs a warning when an action references a repository
+//! that is not (or no longer) part of `main_view.repositories`, e.g. because
+//! it was dispatched before the repository was added. These ordering bugs are
+//! otherwise silently swallowed by the reducers.
+//!
+//! Only active when the `GH_PR_LANDER_DEBUG` environment variable is set.
+//! Never consumes or alters actions. A violation is logged once when it first
+//! shows up, not again for every action while it persists.
+
+use crate::actions::{Action, MergeBotAction, PullRequestAction};
+use crate::dispatcher::Dispatcher;
+use crate::domain_models::Repository;
+use crate::middleware::Middleware;
+use crate::state::AppState;
+
+/// Environment variable enabling the invariant checks
+pub const DEBUG_ENV: &str = "GH_PR_LANDER_DEBUG";
+
+/// Invariant-check middleware - warns about actions out of sync with the state
+pub struct InvariantMiddleware {
+    enabled: bool,
+    /// Violations found for the previous action, already logged
+    reported: Vec<String>,
+}
+
+impl InvariantMiddleware {
+    pub fn new() -> Self {
+        let enabled = std::env::var_os(DEBUG_ENV).is_some();
+        if enabled {
+            log::info!("InvariantMiddleware: action invariant checks enabled");
+        }
+        Self {
+            enabled,
+            reported: Vec::new(),
+        }
+    }
+
+    /// Violations of this action that were not already reported for the previous one
+    fn new_violations(&mut self, action: &Action, state: &AppState) -> Vec<String> {
+        let current = violations(action, state);
+        let new = current
+            .iter()
+            .filter(|v| !self.reported.contains(v))
+            .cloned()
+            .collect();
+        self.reported = current;
+        new
+    }
+}
+
+impl Default for InvariantMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for InvariantMiddleware {
+    fn handle(&mut self, action: &Action, state: &AppState, _dispatcher: &Dispatcher) -> bool {
+        if self.enabled {
+            for violation in self.new_violations(action, state) {
+                log::warn!("Invariant violated: {}", violation);
+            }
+        }
+        true // Never consume
+    }
+}
+
+/// Invariant violations of an action against the (pre-reducer) state
+fn violations(action: &Action, state: &AppState) -> Vec<String> {
+    let main_view = &state.main_view;
+    let repo_count = main_view.repositories.len();
+    let mut violations = Vec::new();
+
+    if repo_count > 0 && main_view.selected_repository >= repo_count {
+        violations.push(format!(
+            "selected_repository {} out of range ({} repositories)",
+            main_view.selected_repository, repo_count
+        ));
+    }
+
+    if let Action::MergeBot(MergeBotAction::Begin { repo_idx, .. }) = action {
+        if *repo_idx >= repo_count {
+            violations.push(format!(
+                "{} references repo_idx {} out of range ({} repositories)",
+                action.name(),
+                repo_idx,
+                repo_count
+            ));
+        }
+    }
+
+    if let Some(repo) = referenced_repository(action) {
+        let known = main_view
+            .repositories
+            .iter()
+            .any(|r| r.org == repo.org && r.repo == repo.repo);
+        if !known {
+            violations.push(format!(
+                "{} references {}/{} which is not in the repository list",
+                action.name(),
+                repo.org,
+                repo.repo
+            ));
+        }
+    }
+
+    violations
+}
+
+/// The repository a PR action is addressed to, if any
+fn referenced_repository(action: &Action) -> Option<&Repository> {
+    match action {
+        Action::PullRequest(
+            PullRequestAction::LoadStart { repo }
+            | PullRequestAction::RefreshStart { repo }
+            | PullRequestAction::Loaded { repo, .. }
+            | PullRequestAction::MorePrsLoaded { repo, .. }
+            | PullRequestAction::HasMorePrs { repo, .. }
+            | PullRequestAction::LoadError { repo, .. }
+            | PullRequestAction::RestoreSnoozed { repo, .. }
+            | PullRequestAction::RestoreFilter { repo, .. }
+            | PullRequestAction::ViewerLoginLoaded { repo, .. },
+        ) => Some(repo),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MergeBotPlan;
+
+    fn repo(name: &str) -> Repository {
+        Repository::new("org", name, "main")
+    }
+
+    fn state_with_repos(names: &[&str]) -> AppState {
+        let mut state = AppState::default();
+        state.main_view.repositories = names.iter().map(|name| repo(name)).collect();
+        state
+    }
+
+    #[test]
+    fn test_known_repository_passes() {
+        let state = state_with_repos(&["a", "b"]);
+        let action = Action::PullRequest(PullRequestAction::LoadStart { repo: repo("b") });
+        assert!(violations(&action, &state).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_repository_is_reported() {
+        let state = state_with_repos(&["a"]);
+        let action = Action::PullRequest(PullRequestAction::Loaded {
+            repo: repo("b"),
+            prs: vec![],
+        });
+        let violations = violations(&action, &state);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("org/b"), "{}", violations[0]);
+    }
+
+    #[test]
+    fn test_repo_idx_out_of_range_is_reported() {
+        let state = state_with_repos(&["a"]);
+        let action = Action::MergeBot(MergeBotAction::Begin {
+            repo_idx: 1,
+            plan: MergeBotPlan { entries: vec![] },
+        });
+        assert_eq!(violations(&action, &state).len(), 1);
+    }
+
+    #[test]
+    fn test_selected_repository_out_of_range_is_reported() {
+        let mut state = state_with_repos(&["a"]);
+        state.main_view.selected_repository = 3;
+        let action = Action::PullRequest(PullRequestAction::Refresh);
+        assert_eq!(violations(&action, &state).len(), 1);
+    }
+
+    #[test]
+    fn test_persisting_violation_is_reported_once() {
+        let mut middleware = InvariantMiddleware {
+            enabled: true,
+            reported: Vec::new(),
+        };
+        let mut state = state_with_repos(&["a"]);
+        state.main_view.selected_repository = 3;
+        let action = Action::PullRequest(PullRequestAction::Refresh);
+
+        assert_eq!(middleware.new_violations(&action, &state).len(), 1);
+        assert!(middleware.new_violations(&action, &state).is_empty());
+
+        // Reported again once it was fixed and reappears
+        state.main_view.selected_repository = 0;
+        assert!(middleware.new_violations(&action, &state).is_empty());
+        state.main_view.selected_repository = 3;
+        assert_eq!(middleware.new_violations(&action, &state).len(), 1);
+    }
+}
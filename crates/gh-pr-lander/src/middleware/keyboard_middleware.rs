@@ -13,10 +13,19 @@
 //! ## Layer 3: Keymap + Gating
 //! Look up keys in the keymap, then check if the active view accepts the action.
 //! This prevents actions from "leaking" to reducers when a different view is active.
+//!
+//! While a two-key sequence is pending, `KeySequenceStarted`/`KeySequenceEnded`
+//! mirror it into state so the UI can show the possible follow-up keys.
+//! On `KeySequenceTimeout` the first key is re-interpreted as a standalone
+//! command, if one is bound.
+//!
+//! Repeatable commands are remembered in state; `RepeatLast` runs the last one
+//! again through the same gating, so it acts on the current selection/cursor.
 
-use crate::actions::{Action, GlobalAction, NavigationAction, TextInputAction};
+use crate::actions::{Action, GlobalAction, NavigationAction, StatusBarAction, TextInputAction};
+use crate::command_id::CommandId;
 use crate::dispatcher::Dispatcher;
-use crate::keybindings::PendingKey;
+use crate::keybindings::{PendingKey, SEQUENCE_TIMEOUT};
 use crate::middleware::Middleware;
 use crate::state::AppState;
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -56,14 +65,17 @@ impl KeyboardMiddleware {
 
         // Esc: Route based on capabilities
         // - If view accepts text input, send TextInputAction::Escape (view decides: cancel/close)
-        // - Otherwise, dispatch Global(Close) to close the view
+        // - If view is dismissible, dispatch Global(Close) to close the view
+        // - Otherwise, ignore it (Esc never quits the app from the main view)
         if key.code == KeyCode::Esc {
             if capabilities.accepts_text_input() {
                 log::debug!("Layer 1: Esc - routing to TextInput::Escape (view has TEXT_INPUT)");
                 dispatcher.dispatch(Action::TextInput(TextInputAction::Escape));
-            } else {
-                log::debug!("Layer 1: Esc - dispatching Close");
+            } else if capabilities.is_dismissible() {
+                log::debug!("Layer 1: Esc - dispatching Close (view is DISMISSIBLE)");
                 dispatcher.dispatch(Action::Global(GlobalAction::Close));
+            } else {
+                log::debug!("Layer 1: Esc - ignored (view is not dismissible)");
             }
             return false;
         }
@@ -168,10 +180,26 @@ impl KeyboardMiddleware {
                 "Layer 3: Waiting for second key in sequence (first: {})",
                 pending_char
             );
+            dispatcher.dispatch(Action::Global(GlobalAction::KeySequenceStarted(
+                pending_char,
+            )));
             return false; // Don't process further - waiting for second key
         }
 
         // If keymap matched, try each command until one is accepted (gating)
+        Self::dispatch_first_accepted(command_ids, state, dispatcher);
+
+        // Unhandled keys are consumed (not passed through)
+        false
+    }
+
+    /// Dispatch the first command the active view accepts
+    fn dispatch_first_accepted(
+        command_ids: Vec<CommandId>,
+        state: &AppState,
+        dispatcher: &Dispatcher,
+    ) {
+        let view = state.view_stack.last();
         for cmd_id in command_ids {
             let action = cmd_id.to_action();
 
@@ -183,7 +211,10 @@ impl KeyboardMiddleware {
                         cmd_id
                     );
                     dispatcher.dispatch(action);
-                    return false;
+                    if cmd_id.is_repeatable() {
+                        dispatcher.dispatch(Action::Global(GlobalAction::RememberCommand(cmd_id)));
+                    }
+                    return;
                 } else {
                     log::debug!(
                         "Layer 3: Command {:?} rejected by view {:?}, trying next",
@@ -194,12 +225,55 @@ impl KeyboardMiddleware {
             } else {
                 // No view - just dispatch (shouldn't normally happen)
                 dispatcher.dispatch(action);
-                return false;
+                return;
             }
         }
+    }
 
-        // Unhandled keys are consumed (not passed through)
-        false
+    /// Run the last repeatable command again, if the active view accepts it
+    fn repeat_last(state: &AppState, dispatcher: &Dispatcher) {
+        let warn = |message: String| {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                message, "Repeat",
+            )));
+        };
+        let Some(cmd_id) = state.last_command else {
+            warn("No command to repeat yet".to_string());
+            return;
+        };
+        if let Some(reason) = cmd_id.unavailable_reason(state) {
+            warn(format!("{} is unavailable: {}", cmd_id.title(), reason));
+            return;
+        }
+
+        let action = cmd_id.to_action();
+        if let Some(view) = state.view_stack.last() {
+            if !view.accepts_action(&action) {
+                log::debug!("Repeat: {:?} rejected by view {:?}", cmd_id, view.view_id());
+                warn(format!("{} can't be repeated in this view", cmd_id.title()));
+                return;
+            }
+        }
+        log::debug!("Repeat: dispatching {:?}", cmd_id);
+        dispatcher.dispatch(action);
+    }
+
+    /// Resolve a timed-out sequence by running the first key on its own
+    fn handle_sequence_timeout(&mut self, state: &AppState, dispatcher: &Dispatcher) {
+        let expired = self
+            .pending_key
+            .as_ref()
+            .is_some_and(|p| p.timestamp.elapsed() >= SEQUENCE_TIMEOUT);
+        if !expired {
+            return;
+        }
+        let Some(pending) = self.pending_key.take() else {
+            return;
+        };
+
+        log::debug!("Key sequence timed out (first: {})", pending.key);
+        let command_ids = state.keymap.standalone_commands(pending.key);
+        Self::dispatch_first_accepted(command_ids, state, dispatcher);
     }
 }
 
@@ -214,10 +288,102 @@ impl Middleware for KeyboardMiddleware {
         // Only intercept Global KeyPressed actions
         if let Action::Global(GlobalAction::KeyPressed(key)) = action {
             log::debug!("KeyboardMiddleware: key={:?}", key);
-            return self.handle_key(*key, state, dispatcher);
+            let was_pending = self.pending_key.is_some();
+            let result = self.handle_key(*key, state, dispatcher);
+            if was_pending && self.pending_key.is_none() {
+                dispatcher.dispatch(Action::Global(GlobalAction::KeySequenceEnded));
+            }
+            return result;
+        }
+
+        if let Action::Global(GlobalAction::KeySequenceTimeout) = action {
+            self.handle_sequence_timeout(state, dispatcher);
+            // Keep the indicator if a newer sequence has started in the meantime
+            if self.pending_key.is_none() {
+                dispatcher.dispatch(Action::Global(GlobalAction::KeySequenceEnded));
+            }
+            return false;
+        }
+
+        if let Action::Global(GlobalAction::RepeatLast) = action {
+            Self::repeat_last(state, dispatcher);
+            return false;
         }
 
         // All other actions pass through
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::{Pr, Repository};
+    use crate::reducers::pull_request_reducer::reduce_pull_request;
+    use crate::state::RepositoryData;
+    use crate::views::{KeyBindingsView, PullRequestView};
+    use std::sync::mpsc;
+
+    fn repeat(state: &AppState) -> Vec<Action> {
+        let (tx, rx) = mpsc::channel();
+        let consumed = !KeyboardMiddleware::new().handle(
+            &Action::Global(GlobalAction::RepeatLast),
+            state,
+            &Dispatcher::new(tx),
+        );
+        assert!(consumed);
+        rx.try_iter().collect()
+    }
+
+    fn snoozing_state() -> AppState {
+        let mut state = AppState {
+            view_stack: vec![Box::new(PullRequestView::new())],
+            last_command: Some(CommandId::PrToggleSnooze),
+            ..Default::default()
+        };
+        state.main_view.repositories = vec![Repository::new("org", "repo", "main")];
+        state.main_view.repo_data.insert(
+            0,
+            RepositoryData {
+                prs: (1..=3)
+                    .map(|n| Pr::new(n, format!("PR {}", n), "octocat", "sha"))
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        state
+    }
+
+    #[test]
+    fn test_repeat_acts_on_the_current_cursor() {
+        let mut state = snoozing_state();
+        for cursor_pr in [2, 1] {
+            let repo_data = state.main_view.repo_data.get_mut(&0).unwrap();
+            repo_data.selected_pr = repo_data.find_visible_pr(cursor_pr).unwrap();
+
+            let actions = repeat(&state);
+            assert_eq!(actions.len(), 1);
+            let Action::PullRequest(action) = &actions[0] else {
+                panic!("expected a PR action, got {:?}", actions[0]);
+            };
+            state.main_view = reduce_pull_request(state.main_view, action);
+        }
+
+        let snoozed = &state.main_view.repo_data[&0].snoozed;
+        assert!(snoozed.contains_key(&1) && snoozed.contains_key(&2));
+        assert!(!snoozed.contains_key(&3));
+    }
+
+    #[test]
+    fn test_repeat_rejected_by_the_view_warns() {
+        let mut state = snoozing_state();
+        state.view_stack.push(Box::new(KeyBindingsView::new()));
+
+        let actions = repeat(&state);
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::StatusBar(StatusBarAction::Push { message, .. })]
+                if message.contains("can't be repeated")
+        ));
+    }
+}
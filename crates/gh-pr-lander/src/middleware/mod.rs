@@ -3,14 +3,18 @@ use crate::dispatcher::Dispatcher;
 use crate::state::AppState;
 
 pub mod app_config_middleware;
+pub mod auto_refresh_middleware;
 pub mod bootstrap_middleware;
+pub mod build_log_middleware;
 pub mod command_palette_middleware;
 pub mod confirmation_popup_middleware;
 pub mod debug_console_middleware;
 pub mod diff_viewer_middleware;
 pub mod github_middleware;
+pub mod invariant_middleware;
 pub mod keyboard_middleware;
 pub mod navigation_middleware;
+pub mod notification_middleware;
 pub mod pull_request_middleware;
 pub mod repository_middleware;
 pub mod session_middleware;
@@ -1,11 +1,12 @@
 //! Navigation Translation Middleware
 //!
-//! Translates generic Navigation actions into view-specific actions
-//! using the active view's translate_navigation method.
+//! Translates generic Navigation actions and mouse clicks into view-specific
+//! actions using the active view's translate_navigation and
+//! translate_mouse_click methods.
 //!
 //! This ensures translated actions go through the full middleware chain.
 
-use crate::actions::Action;
+use crate::actions::{Action, GlobalAction};
 use crate::dispatcher::Dispatcher;
 use crate::middleware::Middleware;
 use crate::state::AppState;
@@ -44,6 +45,20 @@ impl Middleware for NavigationMiddleware {
             log::debug!("Navigation action not handled by active view: {:?}", nav);
         }
 
+        if let Action::Global(GlobalAction::MouseClicked(col, row)) = action {
+            if let Some(translated) = state.active_view().translate_mouse_click(*col, *row, state) {
+                log::debug!(
+                    "NavigationMiddleware: Translating click at ({}, {}) to {:?}",
+                    col,
+                    row,
+                    translated
+                );
+                dispatcher.dispatch(translated);
+            }
+            // Consume the click - it has no meaning beyond the translation
+            return false;
+        }
+
         // Pass through all other actions
         true
     }
@@ -0,0 +1,263 @@
+//! Notification Middleware
+//!
+//! Notifies when CI of a watched PR finishes, i.e. its build status turns
+//! from checking into ready or failed. Depending on `[notify] ci` this rings
+//! the terminal bell and/or shows a desktop notification, in addition to a
+//! status bar message.
+//!
+//! Only CI results (`BuildStatusUpdated`) are considered, so refreshes that
+//! reload a PR with an unchanged build do not notify again.
+
+use crate::actions::{Action, BootstrapAction, PullRequestAction, StatusBarAction};
+use crate::dispatcher::Dispatcher;
+use crate::domain_models::MergeableStatus;
+use crate::middleware::Middleware;
+use crate::state::AppState;
+use crate::utils::notify;
+use gh_pr_config::NotifyMode;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two notifications for the same PR, so a flapping
+/// CI status doesn't notify over and over
+const NOTIFY_DEBOUNCE: Duration = Duration::from_secs(60);
+
+/// PR identity across repositories and hosts: ("host/org/repo", PR number)
+type PrId = (String, usize);
+
+/// Middleware for CI completion notifications of watched PRs
+pub struct NotificationMiddleware {
+    mode: NotifyMode,
+    /// Last CI status reported per PR
+    ci_status: HashMap<PrId, MergeableStatus>,
+    /// When a PR last triggered a notification
+    last_notified: HashMap<PrId, Instant>,
+}
+
+impl NotificationMiddleware {
+    pub fn new() -> Self {
+        Self {
+            mode: NotifyMode::default(),
+            ci_status: HashMap::new(),
+            last_notified: HashMap::new(),
+        }
+    }
+
+    /// Record a CI status and decide whether it finishes a CI run worth notifying
+    ///
+    /// `fallback` is the PR's current status, used when no CI status was
+    /// reported for it before.
+    fn record(
+        &mut self,
+        id: PrId,
+        status: MergeableStatus,
+        fallback: Option<MergeableStatus>,
+        now: Instant,
+    ) -> bool {
+        let previous = self.ci_status.insert(id.clone(), status).or(fallback);
+        if !ci_finished(previous, status) {
+            return false;
+        }
+        if self
+            .last_notified
+            .get(&id)
+            .is_some_and(|at| now.duration_since(*at) < NOTIFY_DEBOUNCE)
+        {
+            log::debug!(
+                "NotificationMiddleware: debounced notification for {:?}",
+                id
+            );
+            return false;
+        }
+        self.last_notified.insert(id, now);
+        true
+    }
+
+    fn notify(&self, title: &str, message: &str) {
+        if matches!(self.mode, NotifyMode::Bell | NotifyMode::Both) {
+            notify::bell();
+        }
+        if matches!(self.mode, NotifyMode::Desktop | NotifyMode::Both) {
+            notify::desktop(title, message);
+        }
+    }
+}
+
+impl Default for NotificationMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a status change finishes a CI run (checking -> ready or failed)
+fn ci_finished(previous: Option<MergeableStatus>, status: MergeableStatus) -> bool {
+    previous == Some(MergeableStatus::Checking)
+        && matches!(
+            status,
+            MergeableStatus::Ready | MergeableStatus::BuildFailed
+        )
+}
+
+impl Middleware for NotificationMiddleware {
+    fn handle(&mut self, action: &Action, state: &AppState, dispatcher: &Dispatcher) -> bool {
+        match action {
+            Action::Bootstrap(BootstrapAction::ConfigLoaded(config)) => {
+                self.mode = config.notify.ci;
+            }
+
+            Action::PullRequest(PullRequestAction::ToggleWatch) => {
+                // Pre-reducer state: the toggle is not applied yet
+                let repo_data = state
+                    .main_view
+                    .repo_data
+                    .get(&state.main_view.selected_repository);
+                if let (Some(repo_data), Some(pr)) = (repo_data, state.main_view.focused_pr()) {
+                    let message = if repo_data.watched.contains(&pr.number) {
+                        format!("Stopped watching PR #{}", pr.number)
+                    } else if self.mode == NotifyMode::Off {
+                        format!(
+                            "Watching PR #{} (notifications are off in [notify])",
+                            pr.number
+                        )
+                    } else {
+                        format!("Watching CI of PR #{}", pr.number)
+                    };
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(message, "Watch")));
+                }
+            }
+
+            Action::PullRequest(PullRequestAction::BuildStatusUpdated {
+                repo,
+                pr_number,
+                status,
+            }) => {
+                let number = *pr_number as usize;
+                let repo_data = state
+                    .main_view
+                    .repositories
+                    .iter()
+                    .position(|r| {
+                        r.org == repo.org
+                            && r.repo == repo.repo
+                            && r.effective_host() == repo.effective_host()
+                    })
+                    .and_then(|idx| state.main_view.repo_data.get(&idx));
+                let current = repo_data
+                    .and_then(|data| data.prs.iter().find(|pr| pr.number == number))
+                    .map(|pr| pr.mergeable);
+                let id = (
+                    format!("{}/{}/{}", repo.effective_host(), repo.org, repo.repo),
+                    number,
+                );
+
+                let finished = self.record(id, *status, current, Instant::now());
+                let watched = repo_data.is_some_and(|data| data.watched.contains(&number));
+                if finished && watched {
+                    let passed = *status == MergeableStatus::Ready;
+                    let message = format!(
+                        "CI {} for #{} in {}/{}",
+                        if passed { "passed" } else { "failed" },
+                        number,
+                        repo.org,
+                        repo.repo
+                    );
+                    log::info!("NotificationMiddleware: {}", message);
+                    if self.mode != NotifyMode::Off {
+                        self.notify("gh-pr-lander", &message);
+                    }
+                    dispatcher.dispatch(Action::StatusBar(if passed {
+                        StatusBarAction::success(message, "Watch")
+                    } else {
+                        StatusBarAction::warning(message, "Watch")
+                    }));
+                }
+            }
+
+            _ => {}
+        }
+        true // Never consume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::Repository;
+    use std::sync::mpsc;
+
+    fn id(number: usize) -> PrId {
+        ("github.com/org/repo".to_string(), number)
+    }
+
+    #[test]
+    fn test_notifies_only_on_finished_ci() {
+        let mut middleware = NotificationMiddleware::new();
+        let now = Instant::now();
+
+        assert!(!middleware.record(id(1), MergeableStatus::Checking, None, now));
+        assert!(middleware.record(id(1), MergeableStatus::BuildFailed, None, now));
+
+        // A refresh reporting the same status again is no transition
+        let later = now + NOTIFY_DEBOUNCE * 2;
+        assert!(!middleware.record(id(1), MergeableStatus::BuildFailed, None, later));
+    }
+
+    #[test]
+    fn test_falls_back_to_current_status() {
+        let mut middleware = NotificationMiddleware::new();
+        let now = Instant::now();
+
+        let checking = Some(MergeableStatus::Checking);
+        assert!(middleware.record(id(1), MergeableStatus::Ready, checking, now));
+        assert!(!middleware.record(id(2), MergeableStatus::Ready, None, now));
+    }
+
+    #[test]
+    fn test_debounces_flapping_status() {
+        let mut middleware = NotificationMiddleware::new();
+        let now = Instant::now();
+
+        middleware.record(id(1), MergeableStatus::Checking, None, now);
+        assert!(middleware.record(id(1), MergeableStatus::Ready, None, now));
+        middleware.record(id(1), MergeableStatus::Checking, None, now);
+        let soon = now + Duration::from_secs(5);
+        assert!(!middleware.record(id(1), MergeableStatus::Ready, None, soon));
+
+        middleware.record(id(1), MergeableStatus::Checking, None, soon);
+        let later = now + NOTIFY_DEBOUNCE;
+        assert!(middleware.record(id(1), MergeableStatus::Ready, None, later));
+    }
+
+    #[test]
+    fn test_same_repository_on_another_host_is_tracked_separately() {
+        let github = Repository::new("org", "repo", "main");
+        let ghe = Repository::with_host("org", "repo", "main", Some("ghe.example.com".into()));
+        let mut state = AppState::default();
+        state.main_view.repositories = vec![github.clone(), ghe.clone()];
+        state
+            .main_view
+            .repo_data
+            .entry(0)
+            .or_default()
+            .watched
+            .insert(1);
+
+        let mut middleware = NotificationMiddleware::new();
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+        for (repo, status) in [
+            (ghe, MergeableStatus::Checking),
+            (github, MergeableStatus::Ready),
+        ] {
+            let action = Action::PullRequest(PullRequestAction::BuildStatusUpdated {
+                repo,
+                pr_number: 1,
+                status,
+            });
+            middleware.handle(&action, &state, &dispatcher);
+        }
+
+        // CI of the GHE PR is still running, github.com's never was
+        assert!(rx.try_recv().is_err());
+    }
+}
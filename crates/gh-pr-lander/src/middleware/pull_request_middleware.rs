@@ -1,21 +1,229 @@
 //! Pull Request Middleware
 //!
-//! Handles PR-specific side effects.
+//! Handles PR-specific side effects:
+//! - Refreshing the selected repository, or all of them staggered to avoid
+//!   hitting rate limits
+//! - Exporting the PR list of the current repository as JSON
+//! - Reporting "go to PR number" jumps to PRs that are not in the list
+//! - Copying the focused PR's branch name, or the selected PRs as markdown,
+//!   to the clipboard
 //!
 //! Note: Actual GitHub API calls are handled by GitHubMiddleware.
-//! Bulk loading coordination is handled by RepositoryMiddleware.
+//! Bulk loading coordination on startup is handled by RepositoryMiddleware.
 
-use crate::actions::Action;
+use crate::actions::{Action, PullRequestAction, StatusBarAction};
 use crate::dispatcher::Dispatcher;
 use crate::middleware::Middleware;
 use crate::state::AppState;
+use crate::utils::{clipboard, pr_export};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Delay between refreshing two repositories during a refresh-all
+const REFRESH_STAGGER: Duration = Duration::from_millis(500);
 
 /// Middleware for Pull Request side effects
-pub struct PullRequestMiddleware;
+pub struct PullRequestMiddleware {
+    /// Whether a staggered refresh of all repositories is in progress
+    refresh_all_active: Arc<AtomicBool>,
+}
 
 impl PullRequestMiddleware {
     pub fn new() -> Self {
-        Self
+        Self {
+            refresh_all_active: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Dispatch a `RefreshStart` for the selected repository
+    ///
+    /// The loader gets the repository itself, so it never looks up an index
+    /// that is not (yet) in the state.
+    fn refresh_selected(state: &AppState, dispatcher: &Dispatcher) {
+        match state
+            .main_view
+            .repositories
+            .get(state.main_view.selected_repository)
+        {
+            Some(repo) => {
+                dispatcher.dispatch(Action::PullRequest(PullRequestAction::RefreshStart {
+                    repo: repo.clone(),
+                }));
+            }
+            None => {
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                    "No repository selected",
+                    "Refresh",
+                )));
+            }
+        }
+    }
+
+    /// Dispatch a `RefreshStart` for every repository, one after another
+    fn refresh_all(&self, state: &AppState, dispatcher: &Dispatcher) {
+        let repos = state.main_view.repositories.clone();
+        if repos.is_empty() {
+            return;
+        }
+
+        if self.refresh_all_active.swap(true, Ordering::SeqCst) {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                "Refresh of all repositories already in progress",
+                "Refresh",
+            )));
+            return;
+        }
+
+        log::info!(
+            "PullRequestMiddleware: Refreshing {} repositories",
+            repos.len()
+        );
+        dispatcher.dispatch(Action::StatusBar(StatusBarAction::running(
+            format!("Refreshing {} repositories...", repos.len()),
+            "Refresh",
+        )));
+
+        // A single short-lived thread per refresh-all; it ends once every
+        // repository has been dispatched, and only one can run at a time
+        let refresh_all_active = Arc::clone(&self.refresh_all_active);
+        let dispatcher = dispatcher.clone();
+        thread::spawn(move || {
+            for (i, repo) in repos.into_iter().enumerate() {
+                if i > 0 {
+                    thread::sleep(REFRESH_STAGGER);
+                }
+                dispatcher.dispatch(Action::PullRequest(PullRequestAction::RefreshStart {
+                    repo,
+                }));
+            }
+            refresh_all_active.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// Warn if the entered PR number is not in the current (filtered) list
+    fn report_goto_pr_not_found(state: &AppState, dispatcher: &Dispatcher) {
+        let Some(number) = state
+            .main_view
+            .goto_pr_input
+            .as_deref()
+            .and_then(|input| input.parse::<usize>().ok())
+        else {
+            return;
+        };
+        let found = state
+            .main_view
+            .repo_data
+            .get(&state.main_view.selected_repository)
+            .and_then(|data| data.find_visible_pr(number))
+            .is_some();
+        if !found {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                format!("PR #{} not found", number),
+                "Go to PR",
+            )));
+        }
+    }
+
+    /// Copy the focused PR's head branch to the clipboard
+    fn copy_branch(state: &AppState, dispatcher: &Dispatcher) {
+        let Some(pr) = state.main_view.focused_pr() else {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                "No PR selected",
+                "Copy Branch",
+            )));
+            return;
+        };
+        if pr.head_branch.is_empty() {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                format!("Branch of PR #{} is not known", pr.number),
+                "Copy Branch",
+            )));
+            return;
+        }
+
+        let branch = pr.branch_ref();
+        match clipboard::copy_text(&branch) {
+            Ok(()) => {
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                    format!("Copied branch {} to clipboard", branch),
+                    "Copy Branch",
+                )));
+            }
+            Err(e) => {
+                log::warn!("PullRequestMiddleware: {}", e);
+                dispatcher.dispatch(Action::error(e, "Copy Branch"));
+            }
+        }
+    }
+
+    /// Copy the target PRs (selected PRs or cursor PR) as a markdown list
+    fn copy_markdown(state: &AppState, dispatcher: &Dispatcher) {
+        let prs = state
+            .main_view
+            .repo_data
+            .get(&state.main_view.selected_repository)
+            .map(|data| data.target_prs(state.app_config.pr.bulk_include_hidden))
+            .unwrap_or_default();
+        if prs.is_empty() {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                "No PR selected",
+                "Copy Markdown",
+            )));
+            return;
+        }
+
+        match clipboard::copy_text(&pr_export::to_markdown(&prs)) {
+            Ok(()) => {
+                let noun = if prs.len() == 1 { "PR" } else { "PRs" };
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                    format!("Copied {} {} as markdown", prs.len(), noun),
+                    "Copy Markdown",
+                )));
+            }
+            Err(e) => {
+                log::warn!("PullRequestMiddleware: {}", e);
+                dispatcher.dispatch(Action::error(e, "Copy Markdown"));
+            }
+        }
+    }
+
+    /// Write the current repository's PRs to a JSON file in the home directory
+    fn export_json(state: &AppState, dispatcher: &Dispatcher) {
+        let repo_idx = state.main_view.selected_repository;
+        let Some(repo) = state.main_view.repositories.get(repo_idx) else {
+            dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                "No repository selected",
+                "Export",
+            )));
+            return;
+        };
+        let prs = state
+            .main_view
+            .repo_data
+            .get(&repo_idx)
+            .map(|data| data.prs.as_slice())
+            .unwrap_or_default();
+
+        let result = gh_pr_config::pr_export_path(&repo.org, &repo.repo)
+            .and_then(|path| pr_export::write_json(&path, prs).map(|_| path));
+        match result {
+            Ok(path) => {
+                log::info!("Exported {} PRs to {}", prs.len(), path.display());
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::success(
+                    format!("Exported {} PRs to {}", prs.len(), path.display()),
+                    "Export",
+                )));
+            }
+            Err(e) => {
+                log::error!("Failed to export PRs: {}", e);
+                dispatcher.dispatch(Action::error(
+                    format!("Failed to export PRs: {}", e),
+                    "Export",
+                ));
+            }
+        }
     }
 }
 
@@ -26,9 +234,83 @@ impl Default for PullRequestMiddleware {
 }
 
 impl Middleware for PullRequestMiddleware {
-    fn handle(&mut self, _action: &Action, _state: &AppState, _dispatcher: &Dispatcher) -> bool {
-        // Currently all PR actions are handled by GitHubMiddleware
-        // This middleware can be extended for PR-specific side effects
-        true // Pass through all actions
+    fn handle(&mut self, action: &Action, state: &AppState, dispatcher: &Dispatcher) -> bool {
+        match action {
+            Action::PullRequest(PullRequestAction::Refresh) => {
+                Self::refresh_selected(state, dispatcher);
+                false // Consume - RefreshStart loads the PRs
+            }
+            Action::PullRequest(PullRequestAction::RefreshAll) => {
+                self.refresh_all(state, dispatcher);
+                false // Consume - each repository gets its own RefreshStart
+            }
+            Action::PullRequest(PullRequestAction::ExportJson) => {
+                Self::export_json(state, dispatcher);
+                false // Consume action
+            }
+            Action::PullRequest(PullRequestAction::CopyBranch) => {
+                Self::copy_branch(state, dispatcher);
+                false // Consume action
+            }
+            Action::PullRequest(PullRequestAction::CopyMarkdown) => {
+                Self::copy_markdown(state, dispatcher);
+                false // Consume action
+            }
+            Action::PullRequest(PullRequestAction::GotoPrConfirm) => {
+                Self::report_goto_pr_not_found(state, dispatcher);
+                true // Let the reducer move the cursor and close the input
+            }
+            Action::PullRequest(PullRequestAction::SetMergeMethod(method)) => {
+                dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                    format!("Merge method: {}", method.as_str()),
+                    "Merge",
+                )));
+                true // Let the reducer store the new method
+            }
+            _ => true, // Pass through all other actions
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::Repository;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_refresh_addresses_the_selected_repository() {
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+        let mut middleware = PullRequestMiddleware::new();
+        let mut state = AppState::default();
+        state.main_view.repositories = vec![
+            Repository::new("org", "a", "main"),
+            Repository::new("org", "b", "main"),
+        ];
+        state.main_view.selected_repository = 1;
+
+        let refresh = Action::PullRequest(PullRequestAction::Refresh);
+        assert!(!middleware.handle(&refresh, &state, &dispatcher));
+        let actions: Vec<Action> = rx.try_iter().collect();
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::PullRequest(PullRequestAction::RefreshStart { repo })] if repo.repo == "b"
+        ));
+    }
+
+    #[test]
+    fn test_refresh_without_repositories_warns() {
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+        let mut middleware = PullRequestMiddleware::new();
+
+        let refresh = Action::PullRequest(PullRequestAction::Refresh);
+        assert!(!middleware.handle(&refresh, &AppState::default(), &dispatcher));
+        let actions: Vec<Action> = rx.try_iter().collect();
+        assert!(matches!(
+            actions.as_slice(),
+            [Action::StatusBar(StatusBarAction::Push { .. })]
+        ));
     }
 }
@@ -1,41 +1,72 @@
 //! Repository Middleware
 //!
 //! Handles repository-related side effects:
-//! - Loading recent repositories from config on LoadRecentRepositories
+//! - Loading recent repositories from config on LoadRecentRepositories (added in
+//!   bulk, then loaded; completion is tracked per repository)
+//! - Adding and focusing the repository given via `--repo` on startup
 //! - Managing the add repository form view
 //! - Translating generic TextInput actions to AddRepository-specific actions
 //! - Opening repository URLs in the browser
+//! - Confirming removal of the current repository
 
 use std::collections::HashSet;
 
 use crate::actions::{
-    Action, BootstrapAction, PullRequestAction, RepositoryAction, StatusBarAction,
+    Action, BootstrapAction, ConfirmationPopupAction, GlobalAction, PullRequestAction,
+    RepositoryAction, StatusBarAction,
 };
+use crate::cli::RepoArg;
 use crate::dispatcher::Dispatcher;
 use crate::domain_models::Repository;
 use crate::middleware::Middleware;
 use crate::state::AppState;
 use crate::utils::browser::open_url;
-use gh_pr_config::load_recent_repositories;
+use gh_pr_config::{load_recent_repositories_checked, RecentRepositoriesLoad};
 use tokio::runtime::Runtime;
 
 /// Repository middleware - handles repository loading and add repository form
 pub struct RepositoryMiddleware {
     /// Tokio runtime for async operations (opening URLs)
     runtime: Runtime,
-    /// Track pending bulk load repository indices
-    /// When all are loaded, we dispatch LoadRecentRepositoriesDone
+    /// Repositories of the startup bulk load whose PRs are still loading
+    /// When all are loaded (or failed), we dispatch LoadRecentRepositoriesDone
     pending_bulk_load: HashSet<Repository>,
+    /// Repository to focus once the startup load is done (`--repo`)
+    startup_repo: Option<RepoArg>,
 }
 
 impl RepositoryMiddleware {
-    pub fn new() -> Self {
+    pub fn new(startup_repo: Option<RepoArg>) -> Self {
         Self {
             runtime: Runtime::new().expect("Failed to create tokio runtime"),
             pending_bulk_load: HashSet::new(),
+            startup_repo,
         }
     }
 
+    /// Count selected PRs of `repo` that are missing from its fully loaded list
+    fn dropped_selection_count(state: &AppState, repo: &Repository) -> usize {
+        let Some(repo_idx) = state
+            .main_view
+            .repositories
+            .iter()
+            .position(|r| r.org == repo.org && r.repo == repo.repo)
+        else {
+            return 0;
+        };
+        state
+            .main_view
+            .repo_data
+            .get(&repo_idx)
+            .map(|data| {
+                data.selected_pr_numbers
+                    .iter()
+                    .filter(|number| !data.prs.iter().any(|pr| pr.number == **number))
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
     /// Get the GitHub URL for the currently selected repository
     fn get_current_repo_url(state: &AppState) -> Option<String> {
         let repo_idx = state.main_view.selected_repository;
@@ -45,17 +76,34 @@ impl RepositoryMiddleware {
             .get(repo_idx)
             .map(|repo| repo.web_url())
     }
+    /// Add all repositories in one step, then load their PRs
+    ///
+    /// Pending loads are tracked from the repositories passed in, not from the
+    /// state, so completion does not depend on when the reducer catches up
+    /// nor on the order in which the loads finish.
+    fn start_bulk_load(&mut self, repositories: Vec<Repository>, dispatcher: &Dispatcher) {
+        self.pending_bulk_load.extend(repositories.iter().cloned());
+        dispatcher.dispatch(Action::Repository(RepositoryAction::AddRepositoriesBulk(
+            repositories.clone(),
+        )));
+        for repo in repositories {
+            dispatcher.dispatch(Action::Repository(RepositoryAction::LoadRepositoryData(
+                repo,
+            )));
+        }
+    }
+
     /// Mark a repository as done loading and check if bulk load is complete
     fn mark_bulk_load_done(&mut self, repo: Repository, dispatcher: &Dispatcher) {
         if self.pending_bulk_load.remove(&repo) {
             log::debug!(
-                "PullRequestMiddleware: Repo {} done, {} remaining in bulk load",
+                "RepositoryMiddleware: Repo {} done, {} remaining in bulk load",
                 repo.full_display_name(),
                 self.pending_bulk_load.len()
             );
 
             if self.pending_bulk_load.is_empty() {
-                log::info!("PullRequestMiddleware: All bulk repositories loaded");
+                log::info!("RepositoryMiddleware: All bulk repositories loaded");
                 dispatcher.dispatch(Action::Bootstrap(
                     BootstrapAction::LoadRecentRepositoriesDone,
                 ));
@@ -66,7 +114,7 @@ impl RepositoryMiddleware {
 
 impl Default for RepositoryMiddleware {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
@@ -77,30 +125,57 @@ impl Middleware for RepositoryMiddleware {
             Action::Bootstrap(BootstrapAction::LoadRecentRepositories) => {
                 log::info!("RepositoryMiddleware: Loading recent repositories from config");
 
-                let recent_repos = load_recent_repositories();
-                if !recent_repos.is_empty() {
-                    let repositories: Vec<Repository> = recent_repos
-                        .into_iter()
-                        .map(|r| Repository::with_host(r.org, r.repo, r.branch, r.host))
-                        .collect();
-                    log::info!(
-                        "RepositoryMiddleware: Found {} recent repositories",
-                        repositories.len()
-                    );
-                    log::info!("Adding {} repositories from config", repositories.len());
-
-                    for repo in repositories.into_iter() {
-                        dispatcher.dispatch(Action::Repository(RepositoryAction::AddRepository(
-                            repo.clone(),
-                        )));
+                let loaded = load_recent_repositories_checked();
+                if let RecentRepositoriesLoad::Corrupt { error, backup } = &loaded {
+                    // Start empty rather than refusing to start; the data is kept
+                    let message = match backup {
+                        Some(backup) => format!(
+                            "Repositories file is corrupt ({}), moved it to {}",
+                            error,
+                            backup.display()
+                        ),
+                        None => format!("Repositories file could not be loaded: {}", error),
+                    };
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        message,
+                        "Repositories",
+                    )));
+                }
 
-                        // when does it actually get removed?
-                        self.pending_bulk_load.insert(repo.clone());
+                let mut repositories: Vec<Repository> = loaded
+                    .into_repositories()
+                    .into_iter()
+                    .map(|r| Repository::with_host(r.org, r.repo, r.branch, r.host))
+                    .collect();
+                log::info!(
+                    "RepositoryMiddleware: Found {} recent repositories",
+                    repositories.len()
+                );
 
-                        dispatcher.dispatch(Action::Repository(
-                            RepositoryAction::LoadRepositoryData(repo.clone()),
-                        ));
+                if let Some(startup_repo) = &self.startup_repo {
+                    if !repositories.iter().any(|r| startup_repo.matches(r)) {
+                        match startup_repo.to_repository() {
+                            Some(repo) => {
+                                log::info!(
+                                    "RepositoryMiddleware: Adding startup repository {}",
+                                    repo.display_name()
+                                );
+                                repositories.push(repo);
+                            }
+                            // Added once its default branch is known
+                            None => dispatcher.dispatch(Action::Repository(
+                                RepositoryAction::ResolveStartupRepository {
+                                    org: startup_repo.owner.clone(),
+                                    repo: startup_repo.name.clone(),
+                                },
+                            )),
+                        }
                     }
+                }
+
+                if !repositories.is_empty() {
+                    log::info!("Adding {} repositories from config", repositories.len());
+                    self.start_bulk_load(repositories, dispatcher);
                 } else {
                     log::info!("RepositoryMiddleware: No recent repositories found");
                     // Even if no repos, signal that loading is done
@@ -112,17 +187,92 @@ impl Middleware for RepositoryMiddleware {
                 true // Let action pass through
             }
 
-            // When a single repository is added via form confirm
+            // Focus the `--repo` repository; dispatched after the session's
+            // RestoreSelection so it wins over the restored tab
+            Action::Bootstrap(BootstrapAction::LoadRecentRepositoriesDone) => {
+                if let Some(startup_repo) = self.startup_repo.take() {
+                    if let Some(idx) = state
+                        .main_view
+                        .repositories
+                        .iter()
+                        .position(|r| startup_repo.matches(r))
+                    {
+                        dispatcher.dispatch(Action::PullRequest(
+                            PullRequestAction::RepositorySelect(idx),
+                        ));
+                    }
+                }
+                true // Let action pass through
+            }
+
+            // The `--repo` repository on its default branch: add it unless
+            // tracked already, then focus it
+            Action::Repository(RepositoryAction::StartupRepositoryResolved(repo)) => {
+                let repositories = &state.main_view.repositories;
+                let idx = match repositories.iter().position(|r| r.is_duplicate_of(repo)) {
+                    Some(idx) => idx,
+                    None => {
+                        log::info!(
+                            "RepositoryMiddleware: Adding startup repository {}",
+                            repo.display_name()
+                        );
+                        dispatcher.dispatch(Action::Repository(RepositoryAction::AddRepository(
+                            repo.clone(),
+                        )));
+                        dispatcher.dispatch(Action::Repository(
+                            RepositoryAction::LoadRepositoryData(repo.clone()),
+                        ));
+                        repositories.len()
+                    }
+                };
+                dispatcher.dispatch(Action::PullRequest(PullRequestAction::RepositorySelect(
+                    idx,
+                )));
+                false // Consume action
+            }
+
+            // When a single repository is added via form confirm, check it exists first
             Action::Repository(RepositoryAction::FormConfirm) => {
-                if state.add_repo_form.is_valid() {
-                    let repo = state.add_repo_form.to_repository();
+                let form = &state.add_repo_form;
+                let repo = form.to_repository();
+
+                // Already tracked: select the existing tab instead of adding it again
+                if let Some(idx) = state
+                    .main_view
+                    .repositories
+                    .iter()
+                    .position(|r| r.is_duplicate_of(&repo))
+                {
+                    log::info!("Repository {} already added", repo.display_name());
+                    dispatcher.dispatch(Action::Global(GlobalAction::Close));
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::RepositorySelect(
+                        idx,
+                    )));
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                        format!("{} is already added", repo.display_name()),
+                        "Add Repository",
+                    )));
+                    return false; // Consume action
+                }
+
+                if form.is_valid() && !form.verifying {
+                    dispatcher
+                        .dispatch(Action::Repository(RepositoryAction::VerifyRepository(repo)));
+                }
+
+                true // Let action pass through to reducer
+            }
+
+            // The repository exists (and the form still shows it): add it
+            Action::Repository(RepositoryAction::RepositoryVerified(repo)) => {
+                if state.add_repo_form.is_verifying(repo) {
                     // First add the repository to the list
                     dispatcher.dispatch(Action::Repository(RepositoryAction::AddRepository(
                         repo.clone(),
                     )));
                     // Then load its data (PRs, etc.)
                     dispatcher.dispatch(Action::Repository(RepositoryAction::LoadRepositoryData(
-                        repo,
+                        repo.clone(),
                     )));
                     // Note: View closing is handled by the reducer, not here
                 }
@@ -159,12 +309,54 @@ impl Middleware for RepositoryMiddleware {
                 false // Consume action
             }
 
+            Action::Repository(RepositoryAction::RemoveCurrentRepositoryRequest) => {
+                let repo_idx = state.main_view.selected_repository;
+                if let Some(repo) = state.main_view.repositories.get(repo_idx) {
+                    // The popup is modal, so the selected repository is still the one shown
+                    dispatcher.dispatch(Action::ConfirmationPopup(
+                        ConfirmationPopupAction::request(
+                            "Remove Repository",
+                            format!(
+                                "Stop tracking {}? Its pull requests are not affected.",
+                                repo.display_name()
+                            ),
+                            Action::Repository(RepositoryAction::RemoveCurrentRepository),
+                        ),
+                    ));
+                } else {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::warning(
+                        "No repository selected",
+                        "Remove Repository",
+                    )));
+                }
+                false // Consume action
+            }
+
             // Handle PR loaded - check if bulk load is complete
             Action::PullRequest(PullRequestAction::Loaded { repo, .. }) => {
                 self.mark_bulk_load_done(repo.clone(), dispatcher);
                 true // Let action pass through to reducer
             }
 
+            // All pages arrived (state holds them): report selections of PRs
+            // that are gone, as the reducer drops them
+            Action::PullRequest(PullRequestAction::HasMorePrs {
+                repo,
+                has_more: false,
+            }) => {
+                let dropped = Self::dropped_selection_count(state, repo);
+                if dropped > 0 {
+                    dispatcher.dispatch(Action::StatusBar(StatusBarAction::info(
+                        format!(
+                            "{} selected PR(s) no longer open, removed from selection",
+                            dropped
+                        ),
+                        "Load",
+                    )));
+                }
+                true // Let action pass through to reducer
+            }
+
             // Handle PR load error - still counts as "done" for bulk tracking
             Action::PullRequest(PullRequestAction::LoadError { repo, .. }) => {
                 self.mark_bulk_load_done(repo.clone(), dispatcher);
@@ -176,3 +368,189 @@ impl Middleware for RepositoryMiddleware {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::Pr;
+    use crate::reducers::pull_request_reducer::reduce_pull_request;
+    use crate::reducers::repository_reducer::reduce_repository;
+    use crate::state::MainViewState;
+    use std::sync::mpsc;
+
+    fn repo(name: &str) -> Repository {
+        Repository::new("org", name, "main")
+    }
+
+    fn drain(rx: &mpsc::Receiver<Action>) -> Vec<Action> {
+        rx.try_iter().collect()
+    }
+
+    fn is_done(action: &Action) -> bool {
+        matches!(
+            action,
+            Action::Bootstrap(BootstrapAction::LoadRecentRepositoriesDone)
+        )
+    }
+
+    #[test]
+    fn test_bulk_load_adds_all_repositories_before_loading() {
+        let (tx, rx) = mpsc::channel();
+        let mut middleware = RepositoryMiddleware::new(None);
+        middleware.start_bulk_load(vec![repo("a"), repo("b")], &Dispatcher::new(tx));
+
+        let actions = drain(&rx);
+        assert_eq!(actions.len(), 3);
+        assert!(matches!(
+            &actions[0],
+            Action::Repository(RepositoryAction::AddRepositoriesBulk(repos)) if repos.len() == 2
+        ));
+        assert!(actions[1..].iter().all(|action| matches!(
+            action,
+            Action::Repository(RepositoryAction::LoadRepositoryData(_))
+        )));
+    }
+
+    #[test]
+    fn test_startup_repo_matches_the_tracked_repository() {
+        let (tx, rx) = mpsc::channel();
+        let mut middleware = RepositoryMiddleware::new(Some("Org/B".parse().unwrap()));
+        let mut state = AppState::default();
+        state.main_view.repositories = vec![repo("a"), repo("b")];
+
+        middleware.handle(
+            &Action::Bootstrap(BootstrapAction::LoadRecentRepositoriesDone),
+            &state,
+            &Dispatcher::new(tx),
+        );
+
+        assert!(drain(&rx).iter().any(|action| matches!(
+            action,
+            Action::PullRequest(PullRequestAction::RepositorySelect(1))
+        )));
+    }
+
+    #[test]
+    fn test_resolved_startup_repo_is_added_and_focused() {
+        let (tx, rx) = mpsc::channel();
+        let mut middleware = RepositoryMiddleware::new(Some("org/c".parse().unwrap()));
+        let mut state = AppState::default();
+        state.main_view.repositories = vec![repo("a"), repo("b")];
+
+        middleware.handle(
+            &Action::Repository(RepositoryAction::StartupRepositoryResolved(
+                Repository::new("org", "c", "trunk"),
+            )),
+            &state,
+            &Dispatcher::new(tx),
+        );
+
+        let actions = drain(&rx);
+        assert!(matches!(
+            &actions[0],
+            Action::Repository(RepositoryAction::AddRepository(repo)) if repo.branch == "trunk"
+        ));
+        assert!(matches!(
+            actions.last(),
+            Some(Action::PullRequest(PullRequestAction::RepositorySelect(2)))
+        ));
+    }
+
+    #[test]
+    fn test_bulk_load_completes_regardless_of_result_order() {
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+        let mut middleware = RepositoryMiddleware::new(None);
+        middleware.start_bulk_load(vec![repo("a"), repo("b"), repo("c")], &dispatcher);
+
+        // Replay the dispatched actions through the reducer, as the store would
+        let mut main_view = MainViewState::default();
+        for action in drain(&rx) {
+            if let Action::Repository(action) = action {
+                main_view = reduce_repository(main_view, &action);
+            }
+        }
+
+        // Results arrive out of order, one of them failed
+        let state = AppState::default();
+        let results = [
+            Action::PullRequest(PullRequestAction::Loaded {
+                repo: repo("c"),
+                prs: vec![Pr::new(3, "c".to_string(), "author", "sha")],
+            }),
+            Action::PullRequest(PullRequestAction::LoadError {
+                repo: repo("a"),
+                error: "boom".to_string(),
+            }),
+            Action::PullRequest(PullRequestAction::Loaded {
+                repo: repo("b"),
+                prs: vec![],
+            }),
+        ];
+        for (i, result) in results.iter().enumerate() {
+            assert!(middleware.handle(result, &state, &dispatcher));
+            let done = drain(&rx).iter().any(is_done);
+            assert_eq!(done, i == results.len() - 1, "after result {}", i);
+            if let Action::PullRequest(action) = result {
+                main_view = reduce_pull_request(main_view, action);
+            }
+        }
+
+        // "c" was in the list before its PRs arrived, so none were dropped
+        assert_eq!(main_view.repositories.len(), 3);
+        assert_eq!(main_view.repo_data[&2].prs.len(), 1);
+    }
+
+    #[test]
+    fn test_dropped_selections_are_reported_once_all_pages_arrived() {
+        let (tx, rx) = mpsc::channel();
+        let dispatcher = Dispatcher::new(tx);
+        let mut middleware = RepositoryMiddleware::new(None);
+        let mut state = AppState::default();
+        state.main_view.repositories.push(repo("a"));
+        state
+            .main_view
+            .repo_data
+            .entry(0)
+            .or_default()
+            .selected_pr_numbers
+            .extend([3, 2, 1]);
+        let pr = |n| Pr::new(n, format!("PR {}", n), "author", "sha");
+
+        // #1 was merged, #2 arrives with page 2
+        let pages = [
+            PullRequestAction::Loaded {
+                repo: repo("a"),
+                prs: vec![pr(3)],
+            },
+            PullRequestAction::MorePrsLoaded {
+                repo: repo("a"),
+                prs: vec![pr(2)],
+            },
+        ];
+        for page in pages {
+            middleware.handle(&Action::PullRequest(page.clone()), &state, &dispatcher);
+            state.main_view = reduce_pull_request(state.main_view, &page);
+        }
+        let status = |actions: Vec<Action>| {
+            actions
+                .into_iter()
+                .filter_map(|action| match action {
+                    Action::StatusBar(StatusBarAction::Push { message, .. }) => Some(message),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(status(drain(&rx)).is_empty());
+
+        let done = Action::PullRequest(PullRequestAction::HasMorePrs {
+            repo: repo("a"),
+            has_more: false,
+        });
+        middleware.handle(&done, &state, &dispatcher);
+        assert_eq!(
+            status(drain(&rx)),
+            vec!["1 selected PR(s) no longer open, removed from selection".to_string()]
+        );
+    }
+}
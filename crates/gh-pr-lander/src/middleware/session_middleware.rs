@@ -7,14 +7,31 @@
 //! - Loads session from disk during bootstrap
 //! - Dispatches `Session::Loaded` action to store pending selection
 //! - Dispatches `Session::RestoreSelection` after repositories load
-//! - Saves session on quit
+//! - Saves session on quit (including the diff viewer file tree width and
+//!   the last toggled build log timestamp display), also on Ctrl+C, panics
+//!   and termination signals via the exit hook (see [`SessionMiddleware::exit_hook`])
+//! - Saves the repository list when repositories are reordered or removed
+//! - Remembers files marked as viewed in the diff viewer per PR, and restores
+//!   them when the PR's diff is opened again
+//! - Remembers snoozed PRs, restores them when PRs load and forgets them once
+//!   the PR was updated after snoozing
+//! - Remembers the PR filter per repository and restores it when the
+//!   repository is added
+//! - Counts the commands run from the command palette, for its recent section
 //! - Uses local session file if it exists, otherwise global
 
-use crate::actions::{Action, BootstrapAction, GlobalAction, SessionAction};
+use crate::actions::{
+    Action, BootstrapAction, BuildLogAction, CommandPaletteAction, DiffViewerAction, GlobalAction,
+    PullRequestAction, RepositoryAction, SessionAction, StatusBarAction,
+};
+use crate::background::SharedState;
+use crate::command_id::CommandId;
 use crate::dispatcher::Dispatcher;
+use crate::domain_models::{Pr, Repository};
 use crate::middleware::Middleware;
-use crate::state::AppState;
-use gh_pr_config::{save_recent_repositories, RecentRepository, Session};
+use crate::state::{AppState, PrFilter};
+use gh_pr_config::{pr_key, repo_key, save_recent_repositories, RecentRepository, Session};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 /// Middleware for session state persistence
@@ -31,8 +48,29 @@ impl SessionMiddleware {
         }
     }
 
-    fn save_session(&self, state: &AppState) {
-        let mut session = self.session.lock().unwrap();
+    /// Exit hook persisting the selection of the latest state
+    ///
+    /// Ctrl+C quits without passing through the middleware chain, and panics
+    /// or signals end the app abruptly, so the session is saved from
+    /// `terminal_restore` instead. Locks are only tried, never waited for,
+    /// so a panic while one is held cannot dead-lock the exit.
+    pub fn exit_hook(&self, state: SharedState) -> impl Fn() + Send + Sync + 'static {
+        let session = Arc::clone(&self.session);
+        move || {
+            let (Ok(state), Ok(mut session)) = (state.try_read(), session.try_lock()) else {
+                log::warn!("SessionMiddleware: State busy on exit, session not saved");
+                return;
+            };
+            // Nothing loaded yet, keep the previous session
+            if state.main_view.repositories.is_empty() {
+                return;
+            }
+            log::info!("SessionMiddleware: Saving session on exit");
+            Self::save_session(&mut session, &state);
+        }
+    }
+
+    fn save_session(session: &mut Session, state: &AppState) {
         let selected_idx = state.main_view.selected_repository;
 
         // Save current selection
@@ -47,17 +85,95 @@ impl SessionMiddleware {
             }
         }
 
+        session.set_diff_file_tree_width(state.diff_viewer.file_tree_width);
+
+        if let Err(e) = session.save() {
+            log::error!("Failed to save session: {}", e);
+        }
+    }
+
+    /// Session key of a PR in the selected repository
+    fn pr_key(state: &AppState, pr_number: u64) -> Option<String> {
+        let repo = state
+            .main_view
+            .repositories
+            .get(state.main_view.selected_repository)?;
+        Some(Self::repo_pr_key(repo, pr_number))
+    }
+
+    fn repo_pr_key(repo: &Repository, pr_number: u64) -> String {
+        pr_key(repo.effective_host(), &repo.org, &repo.repo, pr_number)
+    }
+
+    fn repo_session_key(repo: &Repository) -> String {
+        repo_key(repo.effective_host(), &repo.org, &repo.repo)
+    }
+
+    /// Remember the filter the selected repository switches to
+    fn save_filter(&self, state: &AppState, filter: &PrFilter) {
+        let Some(repo) = state
+            .main_view
+            .repositories
+            .get(state.main_view.selected_repository)
+        else {
+            return;
+        };
+        let mut session = self.session.lock().unwrap();
+        session.set_repo_filter(
+            &Self::repo_session_key(repo),
+            filter.session_key().as_deref(),
+        );
         if let Err(e) = session.save() {
             log::error!("Failed to save session: {}", e);
         }
     }
 
+    /// Restore the snoozed PRs among freshly loaded ones, forgetting the ones
+    /// updated since they were snoozed
+    fn restore_snoozed(&self, repo: &Repository, prs: &[Pr], dispatcher: &Dispatcher) {
+        let mut session = self.session.lock().unwrap();
+        let mut snoozed = HashMap::new();
+        let mut woken = false;
+        for pr in prs {
+            let key = Self::repo_pr_key(repo, pr.number as u64);
+            match session.pr_snoozed_at(&key) {
+                Some(snoozed_at) if pr.updated_at > snoozed_at => {
+                    session.set_pr_snoozed(&key, None);
+                    woken = true;
+                }
+                Some(snoozed_at) => {
+                    snoozed.insert(pr.number, snoozed_at);
+                }
+                None => {}
+            }
+        }
+
+        if woken {
+            if let Err(e) = session.save() {
+                log::error!("Failed to save session: {}", e);
+            }
+        }
+        if !snoozed.is_empty() {
+            dispatcher.dispatch(Action::PullRequest(PullRequestAction::RestoreSnoozed {
+                repo: repo.clone(),
+                snoozed,
+            }));
+        }
+    }
+
     fn save_repositories(&self, state: &AppState) {
         let repos: Vec<RecentRepository> = state
             .main_view
             .repositories
             .iter()
-            .map(|r| RecentRepository::with_host(&r.org, &r.repo, &r.branch, r.host.clone()))
+            .map(|r| {
+                RecentRepository::with_host(
+                    &r.org,
+                    &r.repo,
+                    &r.branch,
+                    Some(r.effective_host().to_string()),
+                )
+            })
             .collect();
 
         if let Err(e) = save_recent_repositories(&repos) {
@@ -99,22 +215,186 @@ impl Middleware for SessionMiddleware {
                         selected_pr_no,
                     }));
 
+                    if let Some(width) = session.diff_file_tree_width() {
+                        dispatcher.dispatch(Action::DiffViewer(
+                            DiffViewerAction::SetFileTreeWidth(width),
+                        ));
+                    }
+
+                    // Commands renamed or removed since are dropped
+                    let usage: HashMap<CommandId, u32> = session
+                        .command_usage()
+                        .iter()
+                        .filter_map(|(name, count)| Some((CommandId::from_name(name)?, *count)))
+                        .collect();
+                    if !usage.is_empty() {
+                        dispatcher.dispatch(Action::CommandPalette(
+                            CommandPaletteAction::UsageLoaded(usage),
+                        ));
+                    }
+
                     *self.session.lock().unwrap() = session;
                     self.loaded = true;
                 }
                 true // Pass through
             }
 
+            // The config sets the timestamp default; dispatching after ConfigLoaded
+            // lets the last toggled value from the session win
+            Action::Bootstrap(BootstrapAction::ConfigLoaded(_)) => {
+                if let Some(show) = self.session.lock().unwrap().build_log_show_timestamps() {
+                    dispatcher.dispatch(Action::BuildLog(BuildLogAction::SetShowTimestamps(show)));
+                }
+                true // Pass through
+            }
+
+            // Remember the toggled value (state is pre-reducer, so invert it)
+            Action::BuildLog(BuildLogAction::ToggleTimestamps) => {
+                self.session
+                    .lock()
+                    .unwrap()
+                    .set_build_log_show_timestamps(!state.build_log.show_timestamps);
+                true // Pass through to reducer
+            }
+
+            Action::CommandPalette(CommandPaletteAction::CommandUsed(id)) => {
+                let mut session = self.session.lock().unwrap();
+                session.record_command_use(&id.name());
+                if let Err(e) = session.save() {
+                    log::error!("Failed to save session: {}", e);
+                }
+                true // Pass through to reducer
+            }
+
+            // Restore the viewed files once the reducer has loaded the diff
+            Action::DiffViewer(DiffViewerAction::Loaded { pr_number, .. }) => {
+                if let Some(key) = Self::pr_key(state, *pr_number) {
+                    let paths = self.session.lock().unwrap().viewed_files(&key);
+                    if !paths.is_empty() {
+                        dispatcher
+                            .dispatch(Action::DiffViewer(DiffViewerAction::SetViewedFiles(paths)));
+                    }
+                }
+                true // Pass through to reducer
+            }
+
+            Action::DiffViewer(DiffViewerAction::SetFileViewed { path, viewed }) => {
+                let key = state
+                    .diff_viewer
+                    .pr_number
+                    .and_then(|pr_number| Self::pr_key(state, pr_number));
+                if let Some(key) = key {
+                    let mut session = self.session.lock().unwrap();
+                    session.set_file_viewed(&key, path, *viewed);
+                    if let Err(e) = session.save() {
+                        log::error!("Failed to save session: {}", e);
+                    }
+                }
+                true // Pass through to reducer
+            }
+
+            Action::PullRequest(
+                PullRequestAction::Loaded { repo, prs }
+                | PullRequestAction::MorePrsLoaded { repo, prs },
+            ) => {
+                self.restore_snoozed(repo, prs, dispatcher);
+                true // Pass through to reducer
+            }
+
+            // Restore the repository's last-used filter; unknown or missing
+            // entries keep the default (all PRs)
+            Action::Repository(RepositoryAction::LoadRepositoryData(repo)) => {
+                let filter = self
+                    .session
+                    .lock()
+                    .unwrap()
+                    .repo_filter(&Self::repo_session_key(repo))
+                    .and_then(PrFilter::from_session_key);
+                if let Some(filter) = filter {
+                    dispatcher.dispatch(Action::PullRequest(PullRequestAction::RestoreFilter {
+                        repo: repo.clone(),
+                        filter,
+                    }));
+                }
+                true // Pass through
+            }
+
+            // Remember the filter of the selected repository (state is pre-reducer,
+            // so apply the change here as well)
+            Action::PullRequest(
+                action @ (PullRequestAction::SetFilter(_)
+                | PullRequestAction::CycleFilter
+                | PullRequestAction::ClearFilter),
+            ) => {
+                let current = state
+                    .main_view
+                    .repo_data
+                    .get(&state.main_view.selected_repository)
+                    .map(|data| &data.current_filter);
+                if let Some(current) = current {
+                    let filter = match action {
+                        PullRequestAction::SetFilter(filter) => filter.clone(),
+                        PullRequestAction::CycleFilter => current.next(),
+                        _ => PrFilter::All,
+                    };
+                    self.save_filter(state, &filter);
+                }
+                true // Pass through to reducer
+            }
+
+            // Remember the toggled snooze (state is pre-reducer)
+            Action::PullRequest(PullRequestAction::ToggleSnooze) => {
+                let repo_idx = state.main_view.selected_repository;
+                let target = state
+                    .main_view
+                    .repo_data
+                    .get(&repo_idx)
+                    .and_then(|data| data.prs.get(data.selected_pr).map(|pr| (data, pr)));
+                if let Some((repo_data, pr)) = target {
+                    let snooze = !repo_data.is_snoozed(pr);
+                    if let Some(key) = Self::pr_key(state, pr.number as u64) {
+                        let mut session = self.session.lock().unwrap();
+                        session.set_pr_snoozed(&key, snooze.then_some(pr.updated_at));
+                        if let Err(e) = session.save() {
+                            log::error!("Failed to save session: {}", e);
+                        }
+                    }
+                    let message = if snooze {
+                        format!("Snoozed PR #{} until it is updated", pr.number)
+                    } else {
+                        format!("Woke up PR #{}", pr.number)
+                    };
+                    dispatcher
+                        .dispatch(Action::StatusBar(StatusBarAction::info(message, "Snooze")));
+                }
+                true // Pass through to reducer
+            }
+
             // Trigger session restore after repositories are loaded
             Action::Bootstrap(BootstrapAction::LoadRecentRepositoriesDone) => {
                 dispatcher.dispatch(Action::Session(SessionAction::RestoreSelection));
                 true // Pass through
             }
 
+            // Persist the list once the reducer has applied the move/removal
+            Action::Repository(
+                RepositoryAction::MoveLeft
+                | RepositoryAction::MoveRight
+                | RepositoryAction::RemoveCurrentRepository,
+            ) => {
+                dispatcher.dispatch(Action::Repository(RepositoryAction::PersistRepositories));
+                true // Pass through to reducer
+            }
+
+            Action::Repository(RepositoryAction::PersistRepositories) => {
+                self.save_repositories(state);
+                false // Consume action
+            }
+
             // Save session and repositories on on close, when at root view
             Action::Global(GlobalAction::Close) if state.view_stack.len() == 1 => {
                 log::info!("SessionMiddleware: Saving state before quit");
-                self.save_session(state);
+                Self::save_session(&mut self.session.lock().unwrap(), state);
                 self.save_repositories(state);
                 true
             }
@@ -0,0 +1,88 @@
+//! Action History Reducer
+
+use crate::actions::ActionHistoryAction;
+use crate::capabilities::{PanelCapabilities, PanelCapabilityProvider};
+use crate::state::ActionHistoryState;
+
+/// Reducer for action history state.
+pub fn reduce_action_history(
+    mut state: ActionHistoryState,
+    action: &ActionHistoryAction,
+) -> ActionHistoryState {
+    let max_scroll = state.entries.len().saturating_sub(state.visible_height);
+
+    match action {
+        ActionHistoryAction::NavigateNext => {
+            // Scroll towards newer actions (decrease offset, towards 0)
+            state.scroll_offset = state.scroll_offset.min(max_scroll).saturating_sub(1);
+        }
+        ActionHistoryAction::NavigatePrevious => {
+            // Scroll towards older actions (increase offset, capped at max_scroll)
+            state.scroll_offset = (state.scroll_offset + 1).min(max_scroll);
+        }
+        ActionHistoryAction::NavigateToTop => {
+            state.scroll_offset = max_scroll;
+        }
+        ActionHistoryAction::NavigateToBottom => {
+            state.scroll_offset = 0;
+        }
+        ActionHistoryAction::SetVisibleHeight(height) => {
+            state.visible_height = *height;
+            let max_scroll = state.entries.len().saturating_sub(state.visible_height);
+            state.scroll_offset = state.scroll_offset.min(max_scroll);
+        }
+    }
+    state
+}
+
+impl PanelCapabilityProvider for ActionHistoryState {
+    fn capabilities(&self) -> PanelCapabilities {
+        PanelCapabilities::VIM_NAVIGATION_BINDINGS
+            | PanelCapabilities::SCROLL_VERTICAL
+            | PanelCapabilities::VIM_SCROLL_BINDINGS
+            | PanelCapabilities::DISMISSIBLE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{Action, GlobalAction};
+    use chrono::Local;
+
+    fn history(len: usize) -> ActionHistoryState {
+        let mut state = ActionHistoryState {
+            visible_height: 4,
+            ..Default::default()
+        };
+        for _ in 0..len {
+            state.record(&Action::Global(GlobalAction::CycleTheme), Local::now());
+        }
+        state
+    }
+
+    #[test]
+    fn test_scroll_stays_within_entries() {
+        let state = reduce_action_history(history(10), &ActionHistoryAction::NavigateToTop);
+        assert_eq!(state.scroll_offset, 6);
+
+        let state = reduce_action_history(state, &ActionHistoryAction::NavigatePrevious);
+        assert_eq!(state.scroll_offset, 6);
+
+        let state = reduce_action_history(state, &ActionHistoryAction::NavigateNext);
+        assert_eq!(state.scroll_offset, 5);
+
+        // Fewer entries than rows: nothing to scroll
+        let state = reduce_action_history(history(3), &ActionHistoryAction::NavigatePrevious);
+        assert_eq!(state.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_growing_height_clamps_scroll() {
+        let state = reduce_action_history(history(10), &ActionHistoryAction::NavigateToTop);
+        assert_eq!(state.scroll_offset, 6);
+
+        let state = reduce_action_history(state, &ActionHistoryAction::SetVisibleHeight(8));
+        assert_eq!(state.scroll_offset, 2);
+    }
+}
@@ -6,16 +6,18 @@
 //! - No ViewId matching - views own their action translation
 
 use crate::actions::{
-    Action, BootstrapAction, CommandPaletteAction, GlobalAction, KeyBindingsAction,
-    RepositoryAction,
+    Action, BootstrapAction, CommandPaletteAction, GlobalAction, RepositoryAction,
+    SetupWizardAction, StatusBarAction,
 };
+use crate::domain_models::TaskStatus;
 use crate::reducers::{
-    build_log_reducer, command_palette_reducer, confirmation_popup_reducer, debug_console_reducer,
-    diff_viewer_reducer, key_bindings_reducer, pull_request_reducer, repository_reducer,
-    session_reducer, splash_reducer, status_bar_reducer,
+    action_history_reducer, build_log_reducer, command_palette_reducer, commit_list_reducer,
+    confirmation_popup_reducer, debug_console_reducer, diff_viewer_reducer, key_bindings_reducer,
+    merge_bot_reducer, pull_request_reducer, repository_reducer, session_reducer,
+    setup_wizard_reducer, splash_reducer, status_bar_reducer,
 };
-use crate::state::AppState;
-use crate::views::DiffViewerView;
+use crate::state::{AppState, ErrorBannerState, PendingSequenceState, PrColumn, StatusKind};
+use crate::views::{CommandPaletteView, DiffViewerView, SetupWizardView, ViewId};
 
 /// Reducer - pure function that produces new state from current state + action
 ///
@@ -44,8 +46,10 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
 
         Action::Global(GlobalAction::Close) => {
             if state.view_stack.len() > 1 {
-                let popped = state.view_stack.pop();
-                log::debug!("Closed view: {:?}", popped.map(|v| v.view_id()));
+                if let Some(popped) = state.view_stack.pop() {
+                    log::debug!("Closed view: {:?}", popped.view_id());
+                    state = reset_closed_view(state, popped.view_id());
+                }
             } else {
                 log::debug!("Closing last view - quitting application");
                 state.running = false;
@@ -54,21 +58,32 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
         }
 
         Action::Global(GlobalAction::PushView(new_view)) => {
+            let view_id = new_view.view_id();
             // Check if this view is already the top-most view (toggle behavior)
             let is_duplicate = state
                 .view_stack
                 .last()
-                .map(|top| top.view_id() == new_view.view_id())
+                .map(|top| top.view_id() == view_id)
                 .unwrap_or(false);
+            // A view further down the stack is moved up instead of duplicated
+            let existing = state
+                .view_stack
+                .iter()
+                .position(|view| view.view_id() == view_id);
 
             if is_duplicate {
                 log::debug!(
                     "Popping view from the stack, because this view is on top already: {:?}",
-                    new_view.view_id()
+                    view_id
                 );
                 state.view_stack.pop();
+                state = reset_closed_view(state, view_id);
+            } else if let Some(idx) = existing {
+                log::debug!("Bringing view to the top of the stack: {:?}", view_id);
+                let view = state.view_stack.remove(idx);
+                state.view_stack.push(view);
             } else {
-                log::debug!("Pushing view onto stack: {:?}", new_view.view_id());
+                log::debug!("Pushing view onto stack: {:?}", view_id);
                 state.view_stack.push(new_view.clone());
             }
             state
@@ -88,8 +103,68 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
             state
         }
 
-        Action::Global(GlobalAction::KeyPressed(_)) => {
-            // Handled by keyboard middleware, not by reducer
+        Action::Global(GlobalAction::AnimationFrame(elapsed)) => {
+            state.spinner.elapsed = *elapsed;
+            state
+        }
+
+        Action::ErrorOccurred { context, message } => {
+            state.error_banner = Some(ErrorBannerState::new(context, message));
+            state.toasts.push(TaskStatus::error(message));
+            state.status_bar = status_bar_reducer::reduce_status_bar(
+                state.status_bar,
+                &StatusBarAction::error(message, context),
+            );
+            state
+        }
+
+        Action::Global(GlobalAction::DismissError) => {
+            state.error_banner = None;
+            state
+        }
+
+        Action::Global(GlobalAction::DismissToast) => {
+            state.toasts.dismiss();
+            state
+        }
+
+        Action::Global(GlobalAction::CycleTheme) => {
+            state.theme = state.theme.next_builtin();
+            log::info!("Switched to theme '{}'", state.theme.name);
+            state
+        }
+
+        Action::Global(GlobalAction::CycleRowDensity) => {
+            state.row_density = state.row_density.next();
+            log::info!("Switched to {} PR rows", state.row_density.label());
+            state
+        }
+
+        Action::Global(GlobalAction::KeySequenceStarted(key)) => {
+            state.pending_sequence = Some(PendingSequenceState::new(*key));
+            state
+        }
+
+        Action::Global(GlobalAction::RememberCommand(id)) => {
+            state.last_command = Some(*id);
+            state
+        }
+
+        Action::Global(GlobalAction::KeySequenceEnded) => {
+            state.pending_sequence = None;
+            state
+        }
+
+        Action::Global(GlobalAction::TerminalResized { width, height }) => {
+            state.terminal_size = (*width, *height);
+            state
+        }
+
+        Action::Global(GlobalAction::KeyPressed(_))
+        | Action::Global(GlobalAction::MouseClicked(..))
+        | Action::Global(GlobalAction::KeySequenceTimeout)
+        | Action::Global(GlobalAction::RepeatLast) => {
+            // Handled by keyboard/navigation middleware, not by reducer
             state
         }
 
@@ -142,15 +217,18 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
         }
 
         Action::CommandPalette(sub) => {
-            // Handle Close and Execute here for view stack management
-            if matches!(
-                sub,
-                CommandPaletteAction::Close | CommandPaletteAction::Execute
-            ) && state.view_stack.len() > 1
-            {
+            // Handle Execute here for view stack management
+            if matches!(sub, CommandPaletteAction::Execute) && state.view_stack.len() > 1 {
                 let popped = state.view_stack.pop();
                 log::debug!("Closed view: {:?}", popped.map(|v| v.view_id()));
             }
+            // A prefilled query opens the palette (or keeps it open) on top
+            if matches!(sub, CommandPaletteAction::OpenWithQuery(_)) {
+                state
+                    .view_stack
+                    .retain(|view| view.view_id() != ViewId::CommandPalette);
+                state.view_stack.push(Box::new(CommandPaletteView::new()));
+            }
             let all_commands = crate::commands::build_palette_commands(&state);
             state.command_palette = command_palette_reducer::reduce_command_palette(
                 state.command_palette,
@@ -161,11 +239,6 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
         }
 
         Action::KeyBindings(sub) => {
-            // Handle Close here for view stack management
-            if matches!(sub, KeyBindingsAction::Close) && state.view_stack.len() > 1 {
-                let popped = state.view_stack.pop();
-                log::debug!("Closed view: {:?}", popped.map(|v| v.view_id()));
-            }
             state.key_bindings_panel =
                 key_bindings_reducer::reduce_key_bindings(state.key_bindings_panel, sub);
             state
@@ -177,6 +250,12 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
             state
         }
 
+        Action::ActionHistory(sub) => {
+            state.action_history =
+                action_history_reducer::reduce_action_history(state.action_history, sub);
+            state
+        }
+
         Action::Splash(sub) => {
             state.splash = splash_reducer::reduce_splash(state.splash, sub);
             state
@@ -196,8 +275,16 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
                 }
                 BootstrapAction::ConfigLoaded(config) => {
                     state.app_config = config.clone();
+                    state.build_log.show_timestamps = config.log.show_timestamps;
+                    state.pr_columns = PrColumn::parse_list(&config.pr.columns);
                     log::info!("App config loaded into state");
                 }
+                BootstrapAction::KeymapLoaded(keymap) => {
+                    state.keymap = keymap.clone();
+                }
+                BootstrapAction::ThemeLoaded(theme) => {
+                    state.theme = theme.clone();
+                }
                 BootstrapAction::LoadRecentRepositories
                 | BootstrapAction::LoadRecentRepositoriesDone => {
                     // Handled by middleware
@@ -212,11 +299,35 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
             state
         }
 
-        // MergeBot actions - currently handled by middlewares (no state changes in reducer)
-        Action::MergeBot(_) => state,
+        // Setup wizard actions
+        Action::SetupWizard(sub) => {
+            // A missing token opens the wizard (unless it's already open)
+            if matches!(sub, SetupWizardAction::TokenMissing { .. })
+                && state.active_view().view_id() != ViewId::SetupWizard
+            {
+                log::debug!("Opening setup wizard");
+                state.view_stack.push(Box::new(SetupWizardView::new()));
+            }
+            state.setup_wizard = setup_wizard_reducer::reduce_setup_wizard(state.setup_wizard, sub);
+            state
+        }
+
+        // MergeBot actions
+        Action::MergeBot(sub) => {
+            state.merge_bot = merge_bot_reducer::reduce_merge_bot(state.merge_bot, sub);
+            state
+        }
 
         // Status bar actions
         Action::StatusBar(sub) => {
+            // Operation outcomes are also shown as toasts
+            if let StatusBarAction::Push { kind, message, .. } = sub {
+                match kind {
+                    StatusKind::Success => state.toasts.push(TaskStatus::success(message)),
+                    StatusKind::Error => state.toasts.push(TaskStatus::error(message)),
+                    _ => {}
+                }
+            }
             state.status_bar = status_bar_reducer::reduce_status_bar(state.status_bar, sub);
             state
         }
@@ -227,6 +338,12 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
             state
         }
 
+        // Commit list actions
+        Action::CommitList(sub) => {
+            state.commit_list = commit_list_reducer::reduce_commit_list(state.commit_list, sub);
+            state
+        }
+
         // Confirmation popup actions - delegate to dedicated reducer
         Action::ConfirmationPopup(sub) => {
             confirmation_popup_reducer::reduce_confirmation_popup(state, sub)
@@ -249,8 +366,9 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
         Action::Repository(sub) => {
             // View stack management for form actions
             let should_close = match sub {
-                RepositoryAction::FormClose => true,
-                RepositoryAction::FormConfirm => state.add_repo_form.is_valid(),
+                RepositoryAction::RepositoryVerified(repo) => {
+                    state.add_repo_form.is_verifying(repo)
+                }
                 _ => false,
             };
             if should_close && state.view_stack.len() > 1 {
@@ -268,3 +386,122 @@ pub fn reduce(mut state: AppState, action: &Action) -> AppState {
         Action::None => state,
     }
 }
+
+/// Reset the state owned by a view that was just closed, so it opens fresh next time
+fn reset_closed_view(mut state: AppState, view_id: ViewId) -> AppState {
+    match view_id {
+        ViewId::CommandPalette => {
+            state.command_palette.query.clear();
+            state.command_palette.selected_index = 0;
+        }
+        ViewId::KeyBindings => state.key_bindings_panel = Default::default(),
+        ViewId::AddRepository => state.add_repo_form.reset(),
+        ViewId::ConfirmationPopup => state.confirmation_popup = None,
+        _ => {}
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{ConfirmationPopupView, KeyBindingsView, PullRequestView, View};
+
+    fn push(state: AppState, view: impl View + 'static) -> AppState {
+        reduce(
+            state,
+            &Action::Global(GlobalAction::PushView(Box::new(view))),
+        )
+    }
+
+    fn stack_ids(state: &AppState) -> Vec<ViewId> {
+        state.view_stack.iter().map(|view| view.view_id()).collect()
+    }
+
+    fn main_view_state() -> AppState {
+        AppState {
+            view_stack: vec![Box::new(PullRequestView::new())],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_push_view_on_top_toggles() {
+        let state = push(main_view_state(), KeyBindingsView::new());
+        assert_eq!(
+            stack_ids(&state),
+            vec![ViewId::PullRequestView, ViewId::KeyBindings]
+        );
+
+        let state = push(state, KeyBindingsView::new());
+        assert_eq!(stack_ids(&state), vec![ViewId::PullRequestView]);
+    }
+
+    #[test]
+    fn test_push_view_brings_existing_instance_to_front() {
+        let state = push(main_view_state(), KeyBindingsView::new());
+        let state = push(state, ConfirmationPopupView::new());
+
+        let state = push(state, KeyBindingsView::new());
+        assert_eq!(
+            stack_ids(&state),
+            vec![
+                ViewId::PullRequestView,
+                ViewId::ConfirmationPopup,
+                ViewId::KeyBindings
+            ]
+        );
+    }
+
+    #[test]
+    fn test_close_resets_popup_state() {
+        let mut state = push(main_view_state(), KeyBindingsView::new());
+        state.key_bindings_panel.scroll_offset = 7;
+
+        let state = reduce(state, &Action::Global(GlobalAction::Close));
+        assert_eq!(stack_ids(&state), vec![ViewId::PullRequestView]);
+        assert_eq!(state.key_bindings_panel.scroll_offset, 0);
+        assert!(state.running);
+    }
+
+    #[test]
+    fn test_close_last_view_quits() {
+        let state = reduce(main_view_state(), &Action::Global(GlobalAction::Close));
+        assert_eq!(stack_ids(&state), vec![ViewId::PullRequestView]);
+        assert!(!state.running);
+    }
+
+    #[test]
+    fn test_open_with_query_keeps_a_single_palette() {
+        let open = Action::CommandPalette(CommandPaletteAction::OpenWithQuery("repo ".into()));
+
+        let state = reduce(main_view_state(), &open);
+        assert_eq!(
+            stack_ids(&state),
+            vec![ViewId::PullRequestView, ViewId::CommandPalette]
+        );
+        assert_eq!(state.command_palette.query, "repo ");
+
+        let state = reduce(state, &open);
+        assert_eq!(
+            stack_ids(&state),
+            vec![ViewId::PullRequestView, ViewId::CommandPalette]
+        );
+    }
+
+    #[test]
+    fn test_error_occurred_shows_a_toast() {
+        let state = reduce(
+            main_view_state(),
+            &Action::error("Merge failed: conflict", "Merge"),
+        );
+
+        let toast = state.toasts.current().expect("error toast");
+        assert_eq!(toast.status.message, "Merge failed: conflict");
+        assert_eq!(
+            toast.status.status_type,
+            crate::domain_models::TaskStatusType::Error
+        );
+        assert!(state.error_banner.is_some());
+    }
+}
@@ -35,6 +35,10 @@ pub fn reduce_build_log(mut state: BuildLogState, action: &BuildLogAction) -> Bu
             // Reset cursor
             state.cursor_path = vec![0];
             state.scroll_offset = 0;
+
+            // Re-run an active search against the new logs
+            state.search_matches = state.find_matches(&state.search_query);
+            state.search_match_index = None;
         }
 
         BuildLogAction::LoadError(error) => {
@@ -62,10 +66,7 @@ pub fn reduce_build_log(mut state: BuildLogState, action: &BuildLogAction) -> Bu
             if let Some(last) = visible.last() {
                 state.cursor_path = last.clone();
                 // Scroll to show the last item
-                let total = visible.len();
-                if total > state.viewport_height {
-                    state.scroll_offset = total - state.viewport_height;
-                }
+                ensure_cursor_visible(&mut state, visible.len() - 1);
             }
         }
 
@@ -75,33 +76,84 @@ pub fn reduce_build_log(mut state: BuildLogState, action: &BuildLogAction) -> Bu
 
         BuildLogAction::ExpandAll => {
             expand_all(&mut state);
+            keep_cursor_visible(&mut state);
         }
 
         BuildLogAction::CollapseAll => {
+            // Like the initial state: workflows stay expanded
+            state.expanded_nodes.clear();
+            expand_workflows(&mut state);
+            keep_cursor_visible(&mut state);
+        }
+
+        BuildLogAction::CollapseToErrors => {
             state.expanded_nodes.clear();
-            // Keep cursor at workflow level
-            if !state.cursor_path.is_empty() {
-                state.cursor_path = vec![state.cursor_path[0]];
+            expand_workflows(&mut state);
+            for path in collect_error_paths(&state) {
+                state.expand_ancestors(&path);
+                state
+                    .expanded_nodes
+                    .insert(BuildLogState::path_to_key(&path));
             }
-            state.scroll_offset = 0;
+            keep_cursor_visible(&mut state);
         }
 
         BuildLogAction::NextError => {
-            build_log_find_next_error(&mut state);
+            if state.has_search() {
+                build_log_jump_to_match(&mut state, true);
+            } else {
+                build_log_find_next_error(&mut state);
+            }
         }
 
         BuildLogAction::PrevError => {
-            build_log_find_prev_error(&mut state);
+            if state.has_search() {
+                build_log_jump_to_match(&mut state, false);
+            } else {
+                build_log_find_prev_error(&mut state);
+            }
+        }
+
+        BuildLogAction::StartSearch => {
+            state.search_editing = true;
         }
 
-        BuildLogAction::ScrollLeft => {
+        BuildLogAction::SearchChar(c) => {
+            state.search_query.push(*c);
+            build_log_update_search(&mut state);
+        }
+
+        BuildLogAction::SearchBackspace => {
+            state.search_query.pop();
+            build_log_update_search(&mut state);
+        }
+
+        BuildLogAction::SearchClear => {
+            state.search_query.clear();
+            build_log_update_search(&mut state);
+        }
+
+        BuildLogAction::SearchConfirm => {
+            state.search_editing = false;
+        }
+
+        BuildLogAction::SearchCancel => {
+            state.search_editing = false;
+            state.search_query.clear();
+            build_log_update_search(&mut state);
+        }
+
+        // Wrapped lines have nothing to scroll horizontally
+        BuildLogAction::ScrollLeft if !state.wrap_lines => {
             state.horizontal_scroll = state.horizontal_scroll.saturating_sub(4);
         }
 
-        BuildLogAction::ScrollRight => {
+        BuildLogAction::ScrollRight if !state.wrap_lines => {
             state.horizontal_scroll = state.horizontal_scroll.saturating_add(4);
         }
 
+        BuildLogAction::ScrollLeft | BuildLogAction::ScrollRight => {}
+
         BuildLogAction::PageDown => {
             let page_size = state.viewport_height.saturating_sub(2);
             for _ in 0..page_size {
@@ -120,17 +172,71 @@ pub fn reduce_build_log(mut state: BuildLogState, action: &BuildLogAction) -> Bu
             state.show_timestamps = !state.show_timestamps;
         }
 
-        BuildLogAction::SetViewportHeight(height) => {
+        BuildLogAction::SetShowTimestamps(show) => {
+            state.show_timestamps = *show;
+        }
+
+        BuildLogAction::ToggleFailedOnly => {
+            state.failed_only = !state.failed_only;
+            if state.failed_only {
+                // Snap to the first failing step (or job, if no step has errors)
+                state.scroll_offset = 0;
+                if let Some(path) = first_failing_leaf(&state) {
+                    state.expand_ancestors(&path);
+                    state.cursor_path = path;
+                } else if let Some(first) = state.flatten_visible_nodes().first() {
+                    state.cursor_path = first.clone();
+                }
+            }
+            keep_cursor_visible(&mut state);
+        }
+
+        BuildLogAction::ToggleWrap => {
+            state.wrap_lines = !state.wrap_lines;
+            // Wrapped lines show their full content
+            state.horizontal_scroll = 0;
+            keep_cursor_visible(&mut state);
+        }
+
+        BuildLogAction::SetViewport { width, height } => {
+            state.viewport_width = *width;
             state.viewport_height = *height;
+            keep_cursor_visible(&mut state);
         }
 
-        // Open is handled by middleware, not reducer
-        BuildLogAction::Open => {}
+        // Side effects are handled by middleware, not reducer
+        BuildLogAction::Open
+        | BuildLogAction::CopySelection
+        | BuildLogAction::DownloadJobLog
+        | BuildLogAction::RerunWorkflow => {}
     }
 
     state
 }
 
+/// Path of the deepest node of the first failing job: its first failing
+/// step, or the job itself if none of its steps has errors
+fn first_failing_leaf(state: &BuildLogState) -> Option<Vec<usize>> {
+    state
+        .workflows
+        .iter()
+        .enumerate()
+        .filter(|(_, workflow)| workflow.has_failures || workflow.total_errors > 0)
+        .find_map(|(w_idx, workflow)| {
+            let (j_idx, job) = workflow
+                .jobs
+                .iter()
+                .enumerate()
+                .find(|(_, job)| job.error_count > 0)?;
+            Some(
+                match job.steps.iter().position(|step| step.error_count > 0) {
+                    Some(s_idx) => vec![w_idx, j_idx, s_idx],
+                    None => vec![w_idx, j_idx],
+                },
+            )
+        })
+}
+
 /// Auto-expand workflows and nodes with errors
 fn auto_expand_errors(state: &mut BuildLogState) {
     for (w_idx, workflow) in state.workflows.iter().enumerate() {
@@ -154,6 +260,24 @@ fn auto_expand_errors(state: &mut BuildLogState) {
     }
 }
 
+/// Expand the top-level workflow nodes
+fn expand_workflows(state: &mut BuildLogState) {
+    for w_idx in 0..state.workflows.len() {
+        state.expanded_nodes.insert(w_idx.to_string());
+    }
+}
+
+/// Move the cursor to its closest visible ancestor after nodes were collapsed
+fn keep_cursor_visible(state: &mut BuildLogState) {
+    let visible = state.flatten_visible_nodes();
+    while state.cursor_path.len() > 1 && !visible.contains(&state.cursor_path) {
+        state.cursor_path.pop();
+    }
+    if let Some(idx) = visible.iter().position(|path| path == &state.cursor_path) {
+        ensure_cursor_visible(state, idx);
+    }
+}
+
 /// Expand all nodes in the tree
 fn expand_all(state: &mut BuildLogState) {
     for (w_idx, workflow) in state.workflows.iter().enumerate() {
@@ -185,11 +309,7 @@ fn build_log_navigate_down(state: &mut BuildLogState) {
             state.cursor_path = visible[new_idx].clone();
 
             // Auto-scroll to keep cursor visible
-            let max_visible_idx = state.scroll_offset + state.viewport_height.saturating_sub(1);
-            if new_idx > max_visible_idx {
-                state.scroll_offset =
-                    new_idx.saturating_sub(state.viewport_height.saturating_sub(1));
-            }
+            ensure_cursor_visible(state, new_idx);
         }
     }
 }
@@ -377,12 +497,236 @@ fn build_log_find_prev_error(state: &mut BuildLogState) {
     }
 }
 
+/// Recompute search matches after the query changed and jump to the first match
+/// at or after the cursor
+fn build_log_update_search(state: &mut BuildLogState) {
+    state.search_matches = state.find_matches(&state.search_query);
+    state.search_match_index = None;
+
+    if let Some(idx) = state
+        .search_matches
+        .iter()
+        .position(|path| path >= &state.cursor_path)
+        .or((!state.search_matches.is_empty()).then_some(0))
+    {
+        build_log_select_match(state, idx);
+    }
+}
+
+/// Jump to the next or previous search match relative to the cursor, wrapping around
+///
+/// Paths compare in tree order, so the cursor does not need to be on a match.
+fn build_log_jump_to_match(state: &mut BuildLogState, forward: bool) {
+    let matches = &state.search_matches;
+    if matches.is_empty() {
+        return;
+    }
+
+    let idx = if forward {
+        matches
+            .iter()
+            .position(|path| path > &state.cursor_path)
+            .unwrap_or(0)
+    } else {
+        matches
+            .iter()
+            .rposition(|path| path < &state.cursor_path)
+            .unwrap_or(matches.len() - 1)
+    };
+
+    build_log_select_match(state, idx);
+}
+
+/// Move the cursor to a search match, expanding its ancestors so it is visible
+fn build_log_select_match(state: &mut BuildLogState, match_idx: usize) {
+    let path = state.search_matches[match_idx].clone();
+    state.expand_ancestors(&path);
+
+    let visible = state.flatten_visible_nodes();
+    if let Some(idx) = visible.iter().position(|p| p == &path) {
+        state.cursor_path = path;
+        state.search_match_index = Some(match_idx);
+        ensure_cursor_visible(state, idx);
+    }
+}
+
 /// Ensure cursor is visible in viewport by adjusting scroll offset
+///
+/// Accounts for wrapped log lines taking more than one row: the scroll offset
+/// (in nodes) is advanced until the rows from it through the cursor fit.
 fn ensure_cursor_visible(state: &mut BuildLogState, cursor_idx: usize) {
-    let max_visible_idx = state.scroll_offset + state.viewport_height.saturating_sub(1);
-    if cursor_idx > max_visible_idx {
-        state.scroll_offset = cursor_idx.saturating_sub(state.viewport_height.saturating_sub(1));
-    } else if cursor_idx < state.scroll_offset {
+    if cursor_idx < state.scroll_offset {
         state.scroll_offset = cursor_idx;
+        return;
+    }
+
+    let visible = state.flatten_visible_nodes();
+    let mut rows: usize = visible
+        .get(state.scroll_offset..=cursor_idx)
+        .unwrap_or_default()
+        .iter()
+        .map(|path| state.node_rows(path))
+        .sum();
+    while rows > state.viewport_height && state.scroll_offset < cursor_idx {
+        rows -= state.node_rows(&visible[state.scroll_offset]);
+        state.scroll_offset += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gh_actions_log_parser::{JobNode, LogLine, StepNode, WorkflowNode};
+
+    fn step(name: &str, lines: &[&str]) -> StepNode {
+        StepNode {
+            name: name.to_string(),
+            lines: lines.iter().map(|l| LogLine::new(l.to_string())).collect(),
+            error_count: 0,
+        }
+    }
+
+    fn state_with_logs() -> BuildLogState {
+        BuildLogState {
+            workflows: vec![WorkflowNode {
+                name: "CI".to_string(),
+                jobs: vec![JobNode {
+                    name: "build".to_string(),
+                    steps: vec![
+                        step("checkout", &["Fetching origin", "HEAD is now at abc"]),
+                        step("test", &["running 3 tests", "test foo ... FAILED"]),
+                    ],
+                    error_count: 0,
+                }],
+                total_errors: 0,
+                has_failures: false,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn search(state: BuildLogState, query: &str) -> BuildLogState {
+        let state = reduce_build_log(state, &BuildLogAction::StartSearch);
+        let state = query.chars().fold(state, |state, c| {
+            reduce_build_log(state, &BuildLogAction::SearchChar(c))
+        });
+        reduce_build_log(state, &BuildLogAction::SearchConfirm)
+    }
+
+    #[test]
+    fn test_search_expands_collapsed_ancestors() {
+        let state = search(state_with_logs(), "failed");
+
+        assert_eq!(state.search_matches, vec![vec![0, 0, 1, 1]]);
+        assert_eq!(state.cursor_path, vec![0, 0, 1, 1]);
+        assert_eq!(state.search_match_index, Some(0));
+        assert!(state.is_expanded(&[0, 0, 1]));
+        assert!(!state.is_expanded(&[0, 0, 0]));
+        assert!(!state.search_editing);
+    }
+
+    #[test]
+    fn test_next_match_wraps_around() {
+        let state = search(state_with_logs(), "T");
+        assert_eq!(state.search_matches.len(), 4);
+        assert_eq!(state.cursor_path, vec![0, 0, 0, 0]);
+
+        let state = reduce_build_log(state, &BuildLogAction::PrevError);
+        assert_eq!(state.cursor_path, vec![0, 0, 1, 1]);
+        assert_eq!(state.search_match_index, Some(3));
+
+        let state = reduce_build_log(state, &BuildLogAction::NextError);
+        assert_eq!(state.cursor_path, vec![0, 0, 0, 0]);
+        assert_eq!(state.search_match_index, Some(0));
+    }
+
+    #[test]
+    fn test_search_cancel_clears_matches() {
+        let state = search(state_with_logs(), "tests");
+        let state = reduce_build_log(state, &BuildLogAction::StartSearch);
+        let state = reduce_build_log(state, &BuildLogAction::SearchCancel);
+
+        assert!(!state.has_search());
+        assert!(state.search_matches.is_empty());
+        assert_eq!(state.search_match_index, None);
+    }
+
+    #[test]
+    fn test_collapse_to_errors_keeps_cursor_visible() {
+        let mut state = state_with_logs();
+        state.workflows[0].jobs[0].error_count = 1;
+        state.workflows[0].jobs[0].steps[1].error_count = 1;
+
+        let mut state = reduce_build_log(state, &BuildLogAction::ExpandAll);
+        state.cursor_path = vec![0, 0, 0, 1];
+        let state = reduce_build_log(state, &BuildLogAction::CollapseToErrors);
+
+        assert!(state.is_expanded(&[0]));
+        assert!(state.is_expanded(&[0, 0]));
+        assert!(state.is_expanded(&[0, 0, 1]));
+        assert!(!state.is_expanded(&[0, 0, 0]));
+        assert_eq!(state.cursor_path, vec![0, 0, 0]);
+
+        let state = reduce_build_log(state, &BuildLogAction::CollapseAll);
+        assert_eq!(state.expanded_nodes.len(), 1);
+        assert_eq!(state.cursor_path, vec![0, 0]);
+    }
+
+    #[test]
+    fn test_wrapped_lines_scroll_by_display_rows() {
+        let state = reduce_build_log(state_with_logs(), &BuildLogAction::ExpandAll);
+        let state = reduce_build_log(
+            state,
+            &BuildLogAction::SetViewport {
+                width: 10,
+                height: 3,
+            },
+        );
+
+        // One row per node: the last three nodes fill the viewport
+        let unwrapped = reduce_build_log(state.clone(), &BuildLogAction::NavigateToBottom);
+        assert_eq!(unwrapped.scroll_offset, 5);
+
+        // Each log line wraps to three rows, so only the last one fits
+        let state = reduce_build_log(state, &BuildLogAction::ToggleWrap);
+        assert_eq!(state.node_rows(&[0, 0, 1, 1]), 3);
+        let state = reduce_build_log(state, &BuildLogAction::NavigateToBottom);
+        assert_eq!(state.scroll_offset, 7);
+        assert_eq!(state.visible_nodes_in_viewport(), vec![vec![0, 0, 1, 1]]);
+
+        let state = reduce_build_log(state, &BuildLogAction::NavigateUp);
+        assert_eq!(state.scroll_offset, 6);
+    }
+
+    #[test]
+    fn test_failed_only_hides_clean_subtrees() {
+        let mut state = state_with_logs();
+        state.workflows[0].has_failures = true;
+        state.workflows[0].jobs[0].error_count = 1;
+        state.workflows[0].jobs[0].steps[1].error_count = 1;
+        let state = reduce_build_log(state, &BuildLogAction::ExpandAll);
+
+        let state = reduce_build_log(state, &BuildLogAction::ToggleFailedOnly);
+        let visible = state.flatten_visible_nodes();
+        assert!(!visible.contains(&vec![0, 0, 0]));
+        assert!(visible.contains(&vec![0, 0, 1, 1]));
+        assert_eq!(state.cursor_path, vec![0, 0, 1]);
+
+        // Data stays intact for toggling back
+        let state = reduce_build_log(state, &BuildLogAction::ToggleFailedOnly);
+        assert!(state.flatten_visible_nodes().contains(&vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn test_failed_only_expands_to_the_first_failing_step() {
+        let mut state = state_with_logs();
+        state.workflows[0].has_failures = true;
+        state.workflows[0].jobs[0].error_count = 1;
+        state.workflows[0].jobs[0].steps[1].error_count = 1;
+        let state = reduce_build_log(state, &BuildLogAction::CollapseAll);
+
+        let state = reduce_build_log(state, &BuildLogAction::ToggleFailedOnly);
+        assert_eq!(state.cursor_path, vec![0, 0, 1]);
+        assert!(state.flatten_visible_nodes().contains(&vec![0, 0, 1]));
     }
 }
@@ -35,11 +35,16 @@ pub fn reduce_command_palette(
             state.selected_index = 0;
         }
 
-        CommandPaletteAction::Close | CommandPaletteAction::Execute => {
+        CommandPaletteAction::Execute => {
             state.query.clear();
             state.selected_index = 0;
         }
 
+        CommandPaletteAction::OpenWithQuery(query) => {
+            state.query = query.clone();
+            state.selected_index = 0;
+        }
+
         CommandPaletteAction::NavigateNext => {
             let filtered_len = filter_commands(all_commands, &state.query).len();
             if filtered_len > 0 {
@@ -52,6 +57,15 @@ pub fn reduce_command_palette(
                 state.selected_index -= 1;
             }
         }
+
+        CommandPaletteAction::CommandUsed(id) => {
+            let count = state.usage.entry(*id).or_default();
+            *count = count.saturating_add(1);
+        }
+
+        CommandPaletteAction::UsageLoaded(usage) => {
+            state.usage = usage.clone();
+        }
     }
 
     state
@@ -101,14 +115,29 @@ mod tests {
     }
 
     #[test]
-    fn close_clears_query_and_selection() {
+    fn execute_clears_query_and_selection() {
         let cmds = make_commands(5);
         let mut state = CommandPaletteState {
             query: "abc".into(),
             selected_index: 3,
+            ..Default::default()
         };
-        state = reduce_command_palette(state, &CommandPaletteAction::Close, &cmds);
+        state = reduce_command_palette(state, &CommandPaletteAction::Execute, &cmds);
         assert!(state.query.is_empty());
         assert_eq!(state.selected_index, 0);
     }
+
+    #[test]
+    fn command_used_counts_usage() {
+        let cmds = make_commands(1);
+        let mut state = CommandPaletteState::default();
+        for _ in 0..2 {
+            state = reduce_command_palette(
+                state,
+                &CommandPaletteAction::CommandUsed(CommandId::PrMerge),
+                &cmds,
+            );
+        }
+        assert_eq!(state.usage.get(&CommandId::PrMerge), Some(&2));
+    }
 }
@@ -0,0 +1,138 @@
+//! Commit List Reducer
+//!
+//! Handles state updates for the commit list popup.
+
+use crate::actions::CommitListAction;
+use crate::state::CommitListState;
+
+/// Reducer for commit list state
+pub fn reduce_commit_list(
+    mut state: CommitListState,
+    action: &CommitListAction,
+) -> CommitListState {
+    match action {
+        CommitListAction::LoadStart { pr_number } => {
+            state = CommitListState {
+                pr_number: Some(*pr_number),
+                loading: true,
+                ..Default::default()
+            };
+        }
+        // Responses for a previously opened PR arriving late are dropped
+        CommitListAction::Loaded { pr_number, commits } => {
+            if state.pr_number == Some(*pr_number) {
+                state.commits = commits.clone();
+                state.loading = false;
+                state.error = None;
+                state.cursor = 0;
+            }
+        }
+        CommitListAction::LoadError { pr_number, error } => {
+            if state.pr_number == Some(*pr_number) {
+                state.loading = false;
+                state.error = Some(error.clone());
+            }
+        }
+        CommitListAction::NavigateNext => {
+            if state.cursor + 1 < state.commits.len() {
+                state.cursor += 1;
+            }
+        }
+        CommitListAction::NavigatePrevious => {
+            state.cursor = state.cursor.saturating_sub(1);
+        }
+        CommitListAction::NavigateToTop => {
+            state.cursor = 0;
+        }
+        CommitListAction::NavigateToBottom => {
+            state.cursor = state.commits.len().saturating_sub(1);
+        }
+        // Handled by middleware
+        CommitListAction::Open | CommitListAction::OpenInBrowser => {}
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::PrCommit;
+
+    fn commit(sha: &str) -> PrCommit {
+        PrCommit {
+            sha: sha.to_string(),
+            message: format!("Commit {}", sha),
+            author: "octocat".to_string(),
+            date: chrono::Utc::now(),
+            html_url: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_navigation_is_clamped() {
+        let mut state = reduce_commit_list(
+            CommitListState::default(),
+            &CommitListAction::LoadStart { pr_number: 7 },
+        );
+        assert!(state.loading);
+
+        state = reduce_commit_list(
+            state,
+            &CommitListAction::Loaded {
+                pr_number: 7,
+                commits: vec![commit("a"), commit("b")],
+            },
+        );
+        assert!(!state.loading);
+
+        state = reduce_commit_list(state, &CommitListAction::NavigatePrevious);
+        assert_eq!(state.cursor, 0);
+        state = reduce_commit_list(state, &CommitListAction::NavigateNext);
+        state = reduce_commit_list(state, &CommitListAction::NavigateNext);
+        assert_eq!(state.cursor, 1);
+        assert_eq!(state.selected_commit().unwrap().sha, "b");
+
+        state = reduce_commit_list(state, &CommitListAction::NavigateToTop);
+        assert_eq!(state.cursor, 0);
+        state = reduce_commit_list(state, &CommitListAction::NavigateToBottom);
+        assert_eq!(state.cursor, 1);
+    }
+
+    #[test]
+    fn test_late_response_for_other_pr_is_dropped() {
+        let mut state = reduce_commit_list(
+            CommitListState::default(),
+            &CommitListAction::LoadStart { pr_number: 8 },
+        );
+
+        state = reduce_commit_list(
+            state,
+            &CommitListAction::Loaded {
+                pr_number: 7,
+                commits: vec![commit("a")],
+            },
+        );
+        assert!(state.loading);
+        assert!(state.commits.is_empty());
+
+        state = reduce_commit_list(
+            state,
+            &CommitListAction::LoadError {
+                pr_number: 7,
+                error: "boom".to_string(),
+            },
+        );
+        assert!(state.loading);
+        assert_eq!(state.error, None);
+
+        state = reduce_commit_list(
+            state,
+            &CommitListAction::Loaded {
+                pr_number: 8,
+                commits: vec![commit("b")],
+            },
+        );
+        assert!(!state.loading);
+        assert_eq!(state.commits.len(), 1);
+    }
+}
@@ -32,15 +32,6 @@ pub fn reduce_confirmation_popup(
             log::debug!("Showing confirmation popup: {:?}", intent);
         }
 
-        ConfirmationPopupAction::Cancel => {
-            // Clear state and pop view
-            state.confirmation_popup = None;
-            if state.view_stack.len() > 1 {
-                state.view_stack.pop();
-            }
-            log::debug!("Cancelled confirmation popup");
-        }
-
         ConfirmationPopupAction::Confirm => {
             // Handled by middleware - should not reach reducer
         }
@@ -2,7 +2,7 @@
 
 use crate::actions::DebugConsoleAction;
 use crate::capabilities::{PanelCapabilities, PanelCapabilityProvider};
-use crate::state::DebugConsoleState;
+use crate::state::{next_min_level, DebugConsoleState};
 
 /// Reducer for debug console state.
 ///
@@ -11,10 +11,12 @@ pub fn reduce_debug_console(
     mut state: DebugConsoleState,
     action: &DebugConsoleAction,
 ) -> DebugConsoleState {
+    // Scroll bounds follow the filtered lines, not the whole buffer
+    let total = state.filtered_len();
     let max_scroll = if state.visible_height > 0 {
-        state.lines.len().saturating_sub(state.visible_height)
+        total.saturating_sub(state.visible_height)
     } else {
-        state.lines.len()
+        total
     };
 
     match action {
@@ -48,9 +50,36 @@ pub fn reduce_debug_console(
             // Append delta to ring buffer (handles capacity internally)
             state.append_lines(new_lines.clone());
             // Keep scroll position valid
-            let new_max = state.lines.len().saturating_sub(state.visible_height);
+            let new_max = state.filtered_len().saturating_sub(state.visible_height);
             state.scroll_offset = state.scroll_offset.min(new_max);
         }
+        DebugConsoleAction::CycleMinLevel => {
+            state.min_level = next_min_level(state.min_level);
+            state.scroll_offset = 0;
+        }
+        DebugConsoleAction::StartFilter => {
+            state.filter_editing = true;
+        }
+        DebugConsoleAction::FilterChar(c) => {
+            state.filter_text.push(*c);
+            state.scroll_offset = 0;
+        }
+        DebugConsoleAction::FilterBackspace => {
+            state.filter_text.pop();
+            state.scroll_offset = 0;
+        }
+        DebugConsoleAction::FilterClear => {
+            state.filter_text.clear();
+            state.scroll_offset = 0;
+        }
+        DebugConsoleAction::FilterConfirm => {
+            state.filter_editing = false;
+        }
+        DebugConsoleAction::FilterCancel => {
+            state.filter_editing = false;
+            state.filter_text.clear();
+            state.scroll_offset = 0;
+        }
     }
     state
 }
@@ -58,8 +87,15 @@ pub fn reduce_debug_console(
 impl PanelCapabilityProvider for DebugConsoleState {
     fn capabilities(&self) -> PanelCapabilities {
         // Debug console supports vim navigation and vertical scrolling with vim bindings
-        PanelCapabilities::VIM_NAVIGATION_BINDINGS
+        let capabilities = PanelCapabilities::VIM_NAVIGATION_BINDINGS
             | PanelCapabilities::SCROLL_VERTICAL
             | PanelCapabilities::VIM_SCROLL_BINDINGS
+            | PanelCapabilities::DISMISSIBLE;
+        // Typing goes to the text filter while it is being edited
+        if self.filter_editing {
+            capabilities | PanelCapabilities::TEXT_INPUT
+        } else {
+            capabilities
+        }
     }
 }
@@ -6,6 +6,7 @@
 use crate::actions::DiffViewerAction;
 use crate::state::DiffViewerState;
 use gh_diff_viewer::model::{CommentPosition, DiffSide, PendingComment};
+use gh_diff_viewer::state::{MAX_FILE_TREE_WIDTH, MIN_FILE_TREE_WIDTH};
 use gh_diff_viewer::DiffAction;
 
 /// Reducer for diff viewer actions.
@@ -152,6 +153,9 @@ pub fn reduce_diff_viewer(
                 } else if inner.show_review_popup {
                     // Hide review popup if visible
                     forward_action(&mut state, DiffAction::HideReviewPopup);
+                } else if inner.show_comment_list {
+                    // Hide comment list if visible
+                    forward_action(&mut state, DiffAction::ToggleCommentList);
                 } else if !inner.nav.file_tree_focused {
                     // If in diff pane, switch to file tree
                     forward_action(&mut state, DiffAction::FocusFileTree);
@@ -186,9 +190,28 @@ pub fn reduce_diff_viewer(
                         'l' | 'L' => forward_action(&mut state, DiffAction::ReviewOptionNext),
                         _ => {} // Ignore other keys in review popup
                     }
+                } else if inner.show_comment_list {
+                    // In comment list: navigate, delete or close
+                    match c {
+                        'j' => forward_action(&mut state, DiffAction::CommentListNext),
+                        'k' => forward_action(&mut state, DiffAction::CommentListPrev),
+                        'd' | 'x' => forward_action(&mut state, DiffAction::CommentListDelete),
+                        'C' | 'q' => forward_action(&mut state, DiffAction::ToggleCommentList),
+                        _ => {} // Ignore other keys in comment list
+                    }
+                } else if let Some(bracket) = state.pending_bracket.take() {
+                    // Second key of a `]`/`[` motion
+                    match (bracket, c) {
+                        (']', 'f') => forward_action(&mut state, DiffAction::NextFileChange),
+                        ('[', 'f') => forward_action(&mut state, DiffAction::PrevFileChange),
+                        (']', 'c') => forward_action(&mut state, DiffAction::NextChange),
+                        ('[', 'c') => forward_action(&mut state, DiffAction::PrevChange),
+                        _ => {} // Unknown motion
+                    }
                 } else {
                     // Normal mode: route to navigation/commands
                     match c {
+                        ']' | '[' => state.pending_bracket = Some(*c),
                         'j' => forward_action(&mut state, DiffAction::CursorDown),
                         'k' => forward_action(&mut state, DiffAction::CursorUp),
                         'h' => forward_action(&mut state, DiffAction::FocusFileTree),
@@ -200,11 +223,20 @@ pub fn reduce_diff_viewer(
                         ' ' | '\t' => forward_action(&mut state, DiffAction::ToggleFocus),
                         'c' => forward_action(&mut state, DiffAction::StartComment),
                         'R' => forward_action(&mut state, DiffAction::ShowReviewPopup),
+                        'C' => forward_action(&mut state, DiffAction::ToggleCommentList),
+                        'F' => forward_action(&mut state, DiffAction::CycleFileStatusFilter),
                         'v' => forward_action(&mut state, DiffAction::EnterVisualMode),
+                        '<' => forward_action(&mut state, DiffAction::ShrinkFileTree),
+                        '>' => forward_action(&mut state, DiffAction::GrowFileTree),
+                        '#' => forward_action(&mut state, DiffAction::ToggleRelativeLineNumbers),
                         _ => {} // Ignore unknown keys
                     }
                 }
             }
+            // Remember the tree width for the next diff session
+            if let Some(ref inner) = state.inner {
+                state.file_tree_width = inner.file_tree_width;
+            }
             state
         }
 
@@ -226,6 +258,9 @@ pub fn reduce_diff_viewer(
                 } else if inner.show_review_popup {
                     // Submit review
                     forward_action(&mut state, DiffAction::SubmitReview);
+                } else if inner.show_comment_list {
+                    // Jump to the selected comment
+                    forward_action(&mut state, DiffAction::CommentListJump);
                 } else {
                     // Toggle file tree node or other confirm action
                     forward_action(&mut state, DiffAction::ToggleTreeNode);
@@ -291,6 +326,14 @@ pub fn reduce_diff_viewer(
             state
         }
 
+        DiffViewerAction::ReviewSubmitted { comments } => {
+            if let Some(ref mut inner) = state.inner {
+                inner.remove_submitted_comments(comments);
+            }
+            forward_action(&mut state, DiffAction::HideReviewPopup);
+            state
+        }
+
         DiffViewerAction::SubmitCommentRequest { .. } => {
             // Handled by middleware (GitHub API call), not by reducer
             state
@@ -339,7 +382,7 @@ pub fn reduce_diff_viewer(
                         && c.position.side.as_github_str() == side
                 });
                 if let Some(idx) = idx {
-                    inner.pending_comments.remove(idx);
+                    inner.delete_pending_comment(idx);
                     log::debug!("Removed pending comment at {}:{}", path, line);
                 }
             }
@@ -363,6 +406,30 @@ pub fn reduce_diff_viewer(
             );
             state
         }
+
+        DiffViewerAction::SetFileTreeWidth(width) => {
+            state.file_tree_width = (*width).clamp(MIN_FILE_TREE_WIDTH, MAX_FILE_TREE_WIDTH);
+            if let Some(ref mut inner) = state.inner {
+                inner.set_file_tree_width(*width);
+            }
+            state
+        }
+
+        DiffViewerAction::SetFileViewed { path, viewed } => {
+            if let Some(ref mut inner) = state.inner {
+                inner.set_file_viewed(path, *viewed);
+            }
+            state
+        }
+
+        DiffViewerAction::SetViewedFiles(paths) => {
+            if let Some(ref mut inner) = state.inner {
+                for path in paths {
+                    inner.set_file_viewed(path, true);
+                }
+            }
+            state
+        }
     }
 }
 
@@ -27,10 +27,6 @@ pub fn reduce_key_bindings(
             // Could be set to max_scroll if we had it, but for now just stay at current
             // View model will clamp it anyway
         }
-        KeyBindingsAction::Close => {
-            // Reset scroll when closing
-            state.scroll_offset = 0;
-        }
     }
     state
 }
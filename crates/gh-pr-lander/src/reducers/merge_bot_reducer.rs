@@ -0,0 +1,81 @@
+//! Merge Bot Reducer
+//!
+//! Handles merge bot queue state. Planning happens in the middleware before
+//! the bot starts; the reducer only tracks the confirmed queue and the merges
+//! reported while the middleware carries out the plan.
+
+use crate::actions::MergeBotAction;
+use crate::state::{MergeBotEntry, MergeBotState, MergeBotStatus};
+
+/// Reduce merge bot state
+pub fn reduce_merge_bot(mut state: MergeBotState, action: &MergeBotAction) -> MergeBotState {
+    match action {
+        MergeBotAction::Begin { repo_idx, plan } => {
+            let pr_numbers = plan.runnable();
+            log::info!(
+                "Merge bot: started with {} PR(s) in queue: {:?}",
+                pr_numbers.len(),
+                pr_numbers
+            );
+            state.active = true;
+            state.current = None;
+            state.queue = pr_numbers
+                .iter()
+                .map(|&pr_number| MergeBotEntry {
+                    repo_idx: *repo_idx,
+                    pr_number: pr_number as usize,
+                    status: MergeBotStatus::Queued,
+                    added_at: chrono::Local::now(),
+                })
+                .collect();
+        }
+        MergeBotAction::MergeComplete(repo_idx, pr_number, success, message) => {
+            log::info!("Merge bot: PR #{} done: {}", pr_number, message);
+            let status = if *success {
+                MergeBotStatus::Merged
+            } else {
+                MergeBotStatus::Failed
+            };
+            set_status(&mut state, *repo_idx, *pr_number, status);
+        }
+        MergeBotAction::Finished => {
+            let merged = state
+                .queue
+                .iter()
+                .filter(|entry| entry.status == MergeBotStatus::Merged)
+                .count();
+            log::info!(
+                "Merge bot: finished, {}/{} PR(s) merged",
+                merged,
+                state.queue.len()
+            );
+            state.active = false;
+            state.current = None;
+        }
+        MergeBotAction::Stop => {
+            log::info!("Merge bot: stopped, {} PR(s) dropped", state.queue.len());
+            state.active = false;
+            state.current = None;
+            state.queue.clear();
+        }
+        _ => {}
+    }
+    state
+}
+
+/// Update the status of a queued PR
+fn set_status(
+    state: &mut MergeBotState,
+    repo_idx: usize,
+    pr_number: usize,
+    status: MergeBotStatus,
+) {
+    let Some(entry) = state
+        .queue
+        .iter_mut()
+        .find(|entry| entry.repo_idx == repo_idx && entry.pr_number == pr_number)
+    else {
+        return;
+    };
+    entry.status = status;
+}
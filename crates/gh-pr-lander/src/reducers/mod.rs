@@ -1,12 +1,16 @@
+pub mod action_history_reducer;
 pub mod app_reducer;
 pub mod build_log_reducer;
 pub mod command_palette_reducer;
+pub mod commit_list_reducer;
 pub mod confirmation_popup_reducer;
 pub mod debug_console_reducer;
 pub mod diff_viewer_reducer;
 pub mod key_bindings_reducer;
+pub mod merge_bot_reducer;
 pub mod pull_request_reducer;
 pub mod repository_reducer;
 pub mod session_reducer;
+pub mod setup_wizard_reducer;
 pub mod splash_reducer;
 pub mod status_bar_reducer;
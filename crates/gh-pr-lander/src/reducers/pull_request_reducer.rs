@@ -5,6 +5,7 @@
 use crate::actions::PullRequestAction;
 use crate::domain_models::{LoadingState, Repository};
 use crate::state::MainViewState;
+use gh_client::types::SortPrsExt;
 
 /// Find repository index by Repository
 fn find_repo_idx(state: &MainViewState, repo: &Repository) -> Option<usize> {
@@ -18,8 +19,10 @@ fn find_repo_idx(state: &MainViewState, repo: &Repository) -> Option<usize> {
 ///
 /// Accepts only PullRequestAction, making it type-safe and focused.
 pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction) -> MainViewState {
+    let focused_before = state.focused_pr().map(|pr| pr.number);
+
     match action {
-        PullRequestAction::LoadStart { repo } => {
+        PullRequestAction::LoadStart { repo } | PullRequestAction::RefreshStart { repo } => {
             // Find repo index
             let Some(repo_idx) = find_repo_idx(&state, repo) else {
                 log::warn!(
@@ -32,6 +35,7 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             // Set loading state for the repository
             let repo_data = state.repo_data.entry(repo_idx).or_default();
             repo_data.loading_state = LoadingState::Loading;
+            repo_data.last_attempt = Some(chrono::Local::now());
             log::debug!(
                 "PR loading started for repository {}/{}",
                 repo.org,
@@ -51,12 +55,21 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             };
             // Update repository data with loaded PRs
             let repo_data = state.repo_data.entry(repo_idx).or_default();
+            let cursor_pr = repo_data.prs.get(repo_data.selected_pr).map(|pr| pr.number);
             repo_data.prs = prs.clone();
             repo_data.loading_state = LoadingState::Loaded;
+            repo_data.loading_pages = true;
             repo_data.last_updated = Some(chrono::Local::now());
-            repo_data.selected_pr = 0;
-            // Clear selection when PRs are reloaded
-            repo_data.selected_pr_numbers.clear();
+            // PRs updated since they were snoozed wake up, the rest go to the bottom
+            repo_data.prune_snoozed();
+            repo_data.sort_snoozed_last();
+            // A reload keeps the cursor on its PR, unless it is gone or hidden
+            match cursor_pr.and_then(|number| repo_data.find_visible_pr(number)) {
+                Some(idx) => repo_data.selected_pr = idx,
+                None => repo_data.cursor_to_first_visible(),
+            }
+            // The PR selected in the last session may be on this page
+            state.restore_session_pr(repo_idx);
             log::info!(
                 "Loaded {} PRs for repository {}/{}",
                 prs.len(),
@@ -65,6 +78,66 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             );
         }
 
+        PullRequestAction::MorePrsLoaded { repo, prs } => {
+            let Some(repo_idx) = find_repo_idx(&state, repo) else {
+                log::warn!(
+                    "MorePrsLoaded: Repository {}/{} not found in state",
+                    repo.org,
+                    repo.repo
+                );
+                return state;
+            };
+            let repo_data = state.repo_data.entry(repo_idx).or_default();
+            let cursor_pr = repo_data.prs.get(repo_data.selected_pr).map(|pr| pr.number);
+
+            for pr in prs {
+                if !repo_data.prs.iter().any(|p| p.number == pr.number) {
+                    repo_data.prs.push(pr.clone());
+                }
+            }
+            // Same order as the client: drafts last, then newest first
+            repo_data.prs.sort_prs();
+
+            // Keep the cursor on the same PR
+            if let Some(idx) =
+                cursor_pr.and_then(|number| repo_data.prs.iter().position(|p| p.number == number))
+            {
+                repo_data.selected_pr = idx;
+            }
+            repo_data.prune_snoozed();
+            repo_data.sort_snoozed_last();
+            log::info!(
+                "Loaded {} more PRs for repository {}/{} ({} total)",
+                prs.len(),
+                repo.org,
+                repo.repo,
+                repo_data.prs.len()
+            );
+            state.restore_session_pr(repo_idx);
+        }
+
+        PullRequestAction::HasMorePrs { repo, has_more } => {
+            let Some(repo_idx) = find_repo_idx(&state, repo) else {
+                log::warn!(
+                    "HasMorePrs: Repository {}/{} not found in state",
+                    repo.org,
+                    repo.repo
+                );
+                return state;
+            };
+            let repo_data = state.repo_data.entry(repo_idx).or_default();
+            repo_data.has_more_prs = *has_more;
+            repo_data.loading_pages = false;
+            // Keep selections and watches by PR number, dropping PRs that are gone;
+            // a PR missing from a cut-off list may just be on a later page
+            if !has_more {
+                repo_data.prune_selection();
+                repo_data.prune_watched();
+            }
+            // Without the session's PR on any page, the cursor stays put
+            state.restore_session_pr(repo_idx);
+        }
+
         PullRequestAction::LoadError { repo, error } => {
             // Find repo index
             let Some(repo_idx) = find_repo_idx(&state, repo) else {
@@ -78,6 +151,7 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             // Set error state for the repository
             let repo_data = state.repo_data.entry(repo_idx).or_default();
             repo_data.loading_state = LoadingState::Error(error.clone());
+            repo_data.loading_pages = false;
             log::error!(
                 "Failed to load PRs for repository {}/{}: {}",
                 repo.org,
@@ -87,44 +161,158 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
         }
 
         // Navigation actions (translated from NavigationAction)
+        // Navigation only visits PRs matching the current filter
         PullRequestAction::NavigateNext => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
-                if !repo_data.prs.is_empty() {
-                    repo_data.selected_pr = (repo_data.selected_pr + 1) % repo_data.prs.len();
-                }
+                repo_data.move_cursor(true);
             }
         }
 
         PullRequestAction::NavigatePrevious => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
-                if !repo_data.prs.is_empty() {
-                    repo_data.selected_pr = if repo_data.selected_pr == 0 {
-                        repo_data.prs.len() - 1
-                    } else {
-                        repo_data.selected_pr - 1
-                    };
-                }
+                repo_data.move_cursor(false);
             }
         }
 
         PullRequestAction::NavigateToTop => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
-                repo_data.selected_pr = 0;
+                repo_data.cursor_to_first_visible();
             }
         }
 
         PullRequestAction::NavigateToBottom => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
-                if !repo_data.prs.is_empty() {
-                    repo_data.selected_pr = repo_data.prs.len() - 1;
+                repo_data.cursor_to_last_visible();
+            }
+        }
+
+        PullRequestAction::NavigatePageDown
+        | PullRequestAction::NavigatePageUp
+        | PullRequestAction::NavigateHalfPageDown
+        | PullRequestAction::NavigateHalfPageUp => {
+            let page = state.pr_viewport_height.max(1) as isize;
+            let delta = match action {
+                PullRequestAction::NavigatePageDown => page,
+                PullRequestAction::NavigatePageUp => -page,
+                PullRequestAction::NavigateHalfPageDown => (page / 2).max(1),
+                _ => -(page / 2).max(1),
+            };
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                repo_data.move_cursor_by(delta);
+            }
+        }
+
+        PullRequestAction::SetPrViewportHeight(height) => {
+            state.pr_viewport_height = *height;
+        }
+
+        PullRequestAction::GotoPrStart => {
+            state.goto_pr_input = Some(String::new());
+        }
+
+        PullRequestAction::GotoPrChar(c) => {
+            if let Some(input) = state.goto_pr_input.as_mut() {
+                if c.is_ascii_digit() {
+                    input.push(*c);
                 }
             }
         }
 
+        PullRequestAction::GotoPrBackspace => {
+            if let Some(input) = state.goto_pr_input.as_mut() {
+                input.pop();
+            }
+        }
+
+        PullRequestAction::GotoPrClear => {
+            if let Some(input) = state.goto_pr_input.as_mut() {
+                input.clear();
+            }
+        }
+
+        PullRequestAction::GotoPrConfirm => {
+            // "Not found" is reported by the PullRequestMiddleware
+            let number = state
+                .goto_pr_input
+                .take()
+                .and_then(|input| input.parse::<usize>().ok());
+            let repo_idx = state.selected_repository;
+            if let (Some(number), Some(repo_data)) = (number, state.repo_data.get_mut(&repo_idx)) {
+                if let Some(idx) = repo_data.find_visible_pr(number) {
+                    repo_data.selected_pr = idx;
+                    repo_data.title_scroll = 0;
+                }
+            }
+        }
+
+        PullRequestAction::GotoPrCancel => {
+            state.goto_pr_input = None;
+        }
+
+        PullRequestAction::NavigateToRow(row) => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                if let Some(&pr_index) = repo_data.visible_pr_indices().get(*row) {
+                    repo_data.selected_pr = pr_index;
+                    repo_data.title_scroll = 0;
+                }
+            }
+        }
+
+        PullRequestAction::ScrollTitleLeft => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                repo_data.title_scroll = repo_data.title_scroll.saturating_sub(4);
+            }
+        }
+
+        PullRequestAction::ScrollTitleRight => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                // Keep at least one character of the title visible
+                let max_scroll = repo_data
+                    .prs
+                    .get(repo_data.selected_pr)
+                    .map(|pr| pr.title.chars().count().saturating_sub(1))
+                    .unwrap_or(0);
+                repo_data.title_scroll = (repo_data.title_scroll + 4).min(max_scroll);
+            }
+        }
+
+        PullRequestAction::ToggleDescription => {
+            state.show_description = !state.show_description;
+            state.description_scroll = 0;
+        }
+
+        PullRequestAction::SetMergeMethod(method) => {
+            state.merge_method = *method;
+        }
+
+        // Only the visible panel scrolls
+        PullRequestAction::ScrollDescriptionDown => {
+            if state.show_description {
+                state.description_scroll = state
+                    .description_scroll
+                    .saturating_add(1)
+                    .min(state.description_max_scroll());
+            }
+        }
+
+        PullRequestAction::ScrollDescriptionUp => {
+            if state.show_description {
+                state.description_scroll = state.description_scroll.saturating_sub(1);
+            }
+        }
+
+        PullRequestAction::SetDescriptionWidth(width) => {
+            state.description_width = *width;
+        }
+
         // Repository switching
         PullRequestAction::RepositoryNext => {
             let num_repos = state.repositories.len();
@@ -146,6 +334,59 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             }
         }
 
+        PullRequestAction::RepositorySelect(idx) => {
+            let num_repos = state.repositories.len();
+            if num_repos > 0 {
+                state.selected_repository = (*idx).min(num_repos - 1);
+                log::debug!("Switched to repository {}", state.selected_repository);
+            }
+        }
+
+        PullRequestAction::RestoreSnoozed { repo, snoozed } => {
+            let Some(repo_idx) = find_repo_idx(&state, repo) else {
+                return state;
+            };
+            let repo_data = state.repo_data.entry(repo_idx).or_default();
+            repo_data.snoozed.extend(snoozed);
+            repo_data.prune_snoozed();
+            repo_data.sort_snoozed_last();
+            // Don't leave the cursor on a PR that was just moved out of the way
+            if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
+                if repo_data.is_snoozed(pr) {
+                    repo_data.cursor_to_first_visible();
+                }
+            }
+        }
+
+        PullRequestAction::ToggleSnooze => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
+                    let (pr_number, updated_at) = (pr.number, pr.updated_at);
+                    if repo_data.is_snoozed(pr) {
+                        repo_data.snoozed.remove(&pr_number);
+                        log::debug!("Woke up PR #{}", pr_number);
+                    } else {
+                        repo_data.snoozed.insert(pr_number, updated_at);
+                        log::debug!("Snoozed PR #{}", pr_number);
+                    }
+                    repo_data.sort_snoozed_last();
+                }
+            }
+        }
+
+        PullRequestAction::ToggleWatch => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                if let Some(pr) = repo_data.prs.get(repo_data.selected_pr) {
+                    let pr_number = pr.number;
+                    if !repo_data.watched.remove(&pr_number) {
+                        repo_data.watched.insert(pr_number);
+                    }
+                }
+            }
+        }
+
         // Selection actions
         PullRequestAction::ToggleSelection => {
             let repo_idx = state.selected_repository;
@@ -159,12 +400,28 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
                         repo_data.selected_pr_numbers.insert(pr_number);
                         log::debug!("Selected PR #{}", pr_number);
                     }
-                    repo_data.selected_pr = (repo_data.selected_pr + 1) % repo_data.prs.len();
+                    repo_data.move_cursor(true);
                 }
             }
         }
 
         PullRequestAction::SelectAll => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                // Same visible set as the view model renders
+                repo_data.selected_pr_numbers = repo_data
+                    .visible_pr_indices()
+                    .into_iter()
+                    .map(|idx| repo_data.prs[idx].number)
+                    .collect();
+                log::debug!(
+                    "Selected all {} visible PRs",
+                    repo_data.selected_pr_numbers.len()
+                );
+            }
+        }
+
+        PullRequestAction::SelectAllUnfiltered => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
                 repo_data.selected_pr_numbers = repo_data.prs.iter().map(|pr| pr.number).collect();
@@ -172,6 +429,23 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             }
         }
 
+        PullRequestAction::InvertSelection => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                // Only visible PRs are inverted; hidden selections are kept
+                for idx in repo_data.visible_pr_indices() {
+                    let number = repo_data.prs[idx].number;
+                    if !repo_data.selected_pr_numbers.remove(&number) {
+                        repo_data.selected_pr_numbers.insert(number);
+                    }
+                }
+                log::debug!(
+                    "Inverted selection, {} PRs selected",
+                    repo_data.selected_pr_numbers.len()
+                );
+            }
+        }
+
         PullRequestAction::DeselectAll => {
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
@@ -186,7 +460,41 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
                 repo_data.current_filter = filter.clone();
-                repo_data.selected_pr = 0; // Reset selection when filter changes
+                repo_data.cursor_to_first_visible(); // Reset selection when filter changes
+            }
+        }
+
+        PullRequestAction::ViewerLoginLoaded { repo, login } => {
+            let Some(repo_idx) = find_repo_idx(&state, repo) else {
+                return state;
+            };
+            let repo_data = state.repo_data.entry(repo_idx).or_default();
+            repo_data.viewer_login = Some(login.clone());
+            // The filter may hide the PR under the cursor now
+            let cursor_hidden = repo_data
+                .prs
+                .get(repo_data.selected_pr)
+                .is_some_and(|pr| !repo_data.matches_filter(pr));
+            if cursor_hidden {
+                repo_data.cursor_to_first_visible();
+            }
+        }
+
+        PullRequestAction::RestoreFilter { repo, filter } => {
+            let Some(repo_idx) = find_repo_idx(&state, repo) else {
+                return state;
+            };
+            let repo_data = state.repo_data.entry(repo_idx).or_default();
+            repo_data.current_filter = filter.clone();
+            repo_data.cursor_to_first_visible();
+        }
+
+        PullRequestAction::CycleFilter => {
+            let repo_idx = state.selected_repository;
+            if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
+                repo_data.current_filter = repo_data.current_filter.next();
+                repo_data.cursor_to_first_visible();
+                log::debug!("Filter: {}", repo_data.current_filter.label());
             }
         }
 
@@ -194,25 +502,37 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
             let repo_idx = state.selected_repository;
             if let Some(repo_data) = state.repo_data.get_mut(&repo_idx) {
                 repo_data.current_filter = crate::state::PrFilter::All;
-                repo_data.selected_pr = 0;
+                repo_data.cursor_to_first_visible();
             }
         }
 
         // Operations that are handled by middleware (these just get dispatched)
         // The actual state changes happen via success/error callbacks
         PullRequestAction::OpenInBrowser
+        | PullRequestAction::OpenAuthorProfile
+        | PullRequestAction::CopyBranch
+        | PullRequestAction::CopyMarkdown
         | PullRequestAction::OpenInIDE
+        | PullRequestAction::CheckoutLocal
         | PullRequestAction::OpenBuildLogs
+        | PullRequestAction::OpenFailingBuildLogs
         | PullRequestAction::OpenRelatedIssue { .. }
         | PullRequestAction::Refresh
-        | PullRequestAction::CycleFilter
+        | PullRequestAction::AutoRefreshTick
+        | PullRequestAction::RefreshAll
+        | PullRequestAction::LoadMore
+        | PullRequestAction::ExportJson
         | PullRequestAction::MergeRequest
         | PullRequestAction::RebaseRequest
         | PullRequestAction::ApproveRequest
         | PullRequestAction::CommentRequest
         | PullRequestAction::RequestChangesRequest
         | PullRequestAction::CloseRequest
-        | PullRequestAction::RerunFailedJobs => {
+        | PullRequestAction::MarkReadyRequest
+        | PullRequestAction::ConvertToDraftRequest
+        | PullRequestAction::RerunFailedJobs
+        | PullRequestAction::RerunAllJobs
+        | PullRequestAction::CancelRuns => {
             // These are request actions - handled by middleware
         }
 
@@ -220,7 +540,8 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
         PullRequestAction::ApproveWithMessage { .. }
         | PullRequestAction::CommentOnPr { .. }
         | PullRequestAction::RequestChanges { .. }
-        | PullRequestAction::ClosePrWithMessage { .. } => {
+        | PullRequestAction::ClosePrWithMessage { .. }
+        | PullRequestAction::MergePrs { .. } => {
             // These are confirmation actions - handled by middleware
         }
 
@@ -231,7 +552,10 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
         | PullRequestAction::CommentStart { .. }
         | PullRequestAction::RequestChangesStart { .. }
         | PullRequestAction::CloseStart { .. }
-        | PullRequestAction::RerunStart { .. } => {
+        | PullRequestAction::MarkReadyStart { .. }
+        | PullRequestAction::ConvertToDraftStart { .. }
+        | PullRequestAction::RerunStart { .. }
+        | PullRequestAction::CancelRunStart { .. } => {
             // These could set operation-in-progress state if needed
         }
 
@@ -382,7 +706,547 @@ pub fn reduce_pull_request(mut state: MainViewState, action: &PullRequestAction)
                 );
             }
         }
+
+        PullRequestAction::MaturityUpdated {
+            repo,
+            pr_number,
+            maturity,
+        } => {
+            // Show the new state right away, the refresh follows
+            if let Some(pr) = find_repo_idx(&state, repo)
+                .and_then(|repo_idx| state.repo_data.get_mut(&repo_idx))
+                .and_then(|repo_data| repo_data.prs.iter_mut().find(|p| p.number == *pr_number))
+            {
+                pr.maturity = *maturity;
+            }
+        }
+    }
+
+    // A different PR got focused, start reading its description from the top
+    if state.focused_pr().map(|pr| pr.number) != focused_before {
+        state.description_scroll = 0;
     }
 
     state
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain_models::{MaturityState, Pr};
+    use crate::state::PrFilter;
+
+    fn prs(numbers: &[usize]) -> Vec<Pr> {
+        numbers
+            .iter()
+            .map(|&n| Pr::new(n, format!("PR {}", n), "author", "sha"))
+            .collect()
+    }
+
+    /// State with PRs #1-#4 where #2 and #4 are drafts
+    fn state_with_drafts(filter: PrFilter) -> MainViewState {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        let mut prs = prs(&[1, 2, 3, 4]);
+        prs[1].maturity = MaturityState::Draft;
+        prs[3].maturity = MaturityState::Draft;
+        state = reduce_pull_request(state, &PullRequestAction::Loaded { repo, prs });
+        reduce_pull_request(state, &PullRequestAction::SetFilter(filter))
+    }
+
+    fn selected(state: &MainViewState) -> Vec<usize> {
+        let mut numbers: Vec<usize> = state.repo_data[&0]
+            .selected_pr_numbers
+            .iter()
+            .copied()
+            .collect();
+        numbers.sort();
+        numbers
+    }
+
+    #[test]
+    fn test_select_all_respects_filter() {
+        let state = reduce_pull_request(
+            state_with_drafts(PrFilter::Drafts),
+            &PullRequestAction::SelectAll,
+        );
+        assert_eq!(selected(&state), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_select_all_without_filter() {
+        let state = reduce_pull_request(
+            state_with_drafts(PrFilter::All),
+            &PullRequestAction::SelectAll,
+        );
+        assert_eq!(selected(&state), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_invert_selection_twice_restores_selection() {
+        let mut state = state_with_drafts(PrFilter::Drafts);
+        state
+            .repo_data
+            .get_mut(&0)
+            .unwrap()
+            .selected_pr_numbers
+            .extend([1, 2]);
+
+        state = reduce_pull_request(state, &PullRequestAction::InvertSelection);
+        // Only the visible drafts are inverted, hidden #1 stays selected
+        assert_eq!(selected(&state), vec![1, 4]);
+
+        state = reduce_pull_request(state, &PullRequestAction::InvertSelection);
+        assert_eq!(selected(&state), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_restore_filter_applies_to_its_repository_only() {
+        let other = Repository::new("org", "other", "main");
+        let mut state = state_with_drafts(PrFilter::All);
+        state.repositories.push(other.clone());
+
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::RestoreFilter {
+                repo: other,
+                filter: PrFilter::Drafts,
+            },
+        );
+        // Restored before the repository's PRs were loaded
+        assert_eq!(state.repo_data[&1].current_filter, PrFilter::Drafts);
+        assert_eq!(state.repo_data[&0].current_filter, PrFilter::All);
+    }
+
+    #[test]
+    fn test_select_all_unfiltered_ignores_filter() {
+        let state = reduce_pull_request(
+            state_with_drafts(PrFilter::Drafts),
+            &PullRequestAction::SelectAllUnfiltered,
+        );
+        assert_eq!(selected(&state), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reload_prunes_selection_and_watches_of_merged_prs() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[1, 2, 3, 4]),
+            },
+        );
+        state
+            .repo_data
+            .get_mut(&0)
+            .unwrap()
+            .selected_pr_numbers
+            .extend([1, 2, 3]);
+        state.repo_data.get_mut(&0).unwrap().watched.extend([2, 4]);
+
+        // PR #2 was merged in the meantime
+        for action in [
+            PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[1, 3, 4]),
+            },
+            PullRequestAction::HasMorePrs {
+                repo,
+                has_more: false,
+            },
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+
+        let selected = &state.repo_data[&0].selected_pr_numbers;
+        assert_eq!(selected.len(), 2);
+        assert!(selected.contains(&1) && selected.contains(&3));
+        let watched = &state.repo_data[&0].watched;
+        assert_eq!(watched.len(), 1);
+        assert!(watched.contains(&4));
+    }
+
+    #[test]
+    fn test_reload_keeps_selection_and_watches_until_the_last_page() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        let data = state.repo_data.entry(0).or_default();
+        data.selected_pr_numbers.extend([4, 2, 1]);
+        data.watched.extend([2, 1]);
+        let selected = |state: &MainViewState| {
+            let mut numbers: Vec<usize> = state.repo_data[&0]
+                .selected_pr_numbers
+                .iter()
+                .copied()
+                .collect();
+            numbers.sort();
+            numbers
+        };
+
+        // Page 1 alone says nothing about the PRs on page 2
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[4, 3]),
+            },
+        );
+        assert_eq!(selected(&state), vec![1, 2, 4]);
+
+        // #1 was merged, #2 is on page 2
+        for action in [
+            PullRequestAction::MorePrsLoaded {
+                repo: repo.clone(),
+                prs: prs(&[2]),
+            },
+            PullRequestAction::HasMorePrs {
+                repo,
+                has_more: false,
+            },
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        assert_eq!(selected(&state), vec![2, 4]);
+        let watched: Vec<usize> = state.repo_data[&0].watched.iter().copied().collect();
+        assert_eq!(watched, vec![2]);
+    }
+
+    #[test]
+    fn test_cut_off_list_keeps_selection_of_unloaded_prs() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        state
+            .repo_data
+            .entry(0)
+            .or_default()
+            .selected_pr_numbers
+            .insert(1);
+
+        for action in [
+            PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[3, 2]),
+            },
+            PullRequestAction::HasMorePrs {
+                repo,
+                has_more: true,
+            },
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        assert!(state.repo_data[&0].selected_pr_numbers.contains(&1));
+    }
+
+    #[test]
+    fn test_page_navigation_is_clamped() {
+        let repo = Repository::new("org", "repo", "main");
+        let state = MainViewState {
+            repositories: vec![repo.clone()],
+            pr_viewport_height: 4,
+            ..Default::default()
+        };
+        let prs = prs(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let state = reduce_pull_request(state, &PullRequestAction::Loaded { repo, prs });
+        let cursor = |state: &MainViewState| state.repo_data[&0].selected_pr;
+
+        let state = reduce_pull_request(state, &PullRequestAction::NavigatePageDown);
+        assert_eq!(cursor(&state), 4);
+        let state = reduce_pull_request(state, &PullRequestAction::NavigateHalfPageDown);
+        assert_eq!(cursor(&state), 6);
+        let state = reduce_pull_request(state, &PullRequestAction::NavigatePageDown);
+        assert_eq!(cursor(&state), 9); // clamped to the last PR
+        let state = reduce_pull_request(state, &PullRequestAction::NavigateHalfPageUp);
+        assert_eq!(cursor(&state), 7);
+        let state = reduce_pull_request(state, &PullRequestAction::NavigatePageUp);
+        let state = reduce_pull_request(state, &PullRequestAction::NavigatePageUp);
+        assert_eq!(cursor(&state), 0); // clamped to the first PR
+    }
+
+    #[test]
+    fn test_goto_pr_moves_cursor_by_number() {
+        let mut state = state_with_drafts(PrFilter::All);
+        // Sort order does not matter, only the PR number
+        state.repo_data.get_mut(&0).unwrap().prs.reverse();
+
+        for action in [
+            PullRequestAction::GotoPrStart,
+            PullRequestAction::GotoPrChar('3'),
+            PullRequestAction::GotoPrChar('x'), // ignored
+            PullRequestAction::GotoPrConfirm,
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        let data = &state.repo_data[&0];
+        assert_eq!(data.prs[data.selected_pr].number, 3);
+        assert!(state.goto_pr_input.is_none());
+    }
+
+    #[test]
+    fn test_goto_pr_hidden_by_filter_keeps_cursor() {
+        let mut state = state_with_drafts(PrFilter::Drafts);
+        let before = state.repo_data[&0].selected_pr;
+        for action in [
+            PullRequestAction::GotoPrStart,
+            PullRequestAction::GotoPrChar('3'),
+            PullRequestAction::GotoPrConfirm,
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        assert_eq!(state.repo_data[&0].selected_pr, before);
+    }
+
+    #[test]
+    fn test_description_scroll_resets_when_cursor_moves() {
+        let mut state = state_with_drafts(PrFilter::All);
+        state.repo_data.get_mut(&0).unwrap().prs[0].body = "one\ntwo\nthree".to_string();
+        for action in [
+            PullRequestAction::ToggleDescription,
+            PullRequestAction::ScrollDescriptionDown,
+            PullRequestAction::ScrollDescriptionDown,
+            PullRequestAction::ScrollDescriptionUp,
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        assert!(state.show_description);
+        assert_eq!(state.description_scroll, 1);
+
+        state = reduce_pull_request(state, &PullRequestAction::NavigateNext);
+        assert_eq!(state.description_scroll, 0);
+    }
+
+    #[test]
+    fn test_description_scroll_is_clamped_and_ignored_while_hidden() {
+        let mut state = state_with_drafts(PrFilter::All);
+        state.description_width = 10;
+        // Three lines, the last one wraps once
+        state.repo_data.get_mut(&0).unwrap().prs[0].body = "one\ntwo\nthree is longer".to_string();
+
+        state = reduce_pull_request(state, &PullRequestAction::ScrollDescriptionDown);
+        assert_eq!(state.description_scroll, 0);
+
+        state = reduce_pull_request(state, &PullRequestAction::ToggleDescription);
+        for _ in 0..10 {
+            state = reduce_pull_request(state, &PullRequestAction::ScrollDescriptionDown);
+        }
+        assert_eq!(state.description_scroll, 3);
+
+        state = reduce_pull_request(state, &PullRequestAction::ScrollDescriptionUp);
+        assert_eq!(state.description_scroll, 2);
+    }
+
+    #[test]
+    fn test_title_scroll_is_clamped_and_resets_when_cursor_moves() {
+        let mut state = state_with_drafts(PrFilter::All);
+        state.repo_data.get_mut(&0).unwrap().prs[0].title = "Bump serde".to_string();
+        let scroll = |state: &MainViewState| state.repo_data[&0].title_scroll;
+
+        state = reduce_pull_request(state, &PullRequestAction::ScrollTitleRight);
+        assert_eq!(scroll(&state), 4);
+        state = reduce_pull_request(state, &PullRequestAction::ScrollTitleRight);
+        state = reduce_pull_request(state, &PullRequestAction::ScrollTitleRight);
+        assert_eq!(scroll(&state), 9); // the last character stays visible
+        state = reduce_pull_request(state, &PullRequestAction::ScrollTitleLeft);
+        assert_eq!(scroll(&state), 5);
+
+        state = reduce_pull_request(state, &PullRequestAction::NavigateNext);
+        assert_eq!(scroll(&state), 0);
+    }
+
+    #[test]
+    fn test_reload_keeps_the_cursor_on_its_pr() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        let cursor = |state: &MainViewState| {
+            let data = &state.repo_data[&0];
+            data.prs[data.selected_pr].number
+        };
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[4, 3, 2]),
+            },
+        );
+        state.repo_data.get_mut(&0).unwrap().selected_pr = 2;
+
+        // #5 was opened, #3 merged
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[5, 4, 2]),
+            },
+        );
+        assert_eq!(cursor(&state), 2);
+
+        // #2 merged as well
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo,
+                prs: prs(&[5, 4]),
+            },
+        );
+        assert_eq!(cursor(&state), 5);
+    }
+
+    #[test]
+    fn test_loaded_restores_session_pr_or_falls_back_to_top() {
+        let repo = Repository::new("org", "repo", "main");
+        for (pr_no, expected) in [(3, 3), (42, 1)] {
+            let mut state = MainViewState {
+                repositories: vec![repo.clone()],
+                pending_session_pr_no: Some(pr_no),
+                ..Default::default()
+            };
+            for action in [
+                PullRequestAction::Loaded {
+                    repo: repo.clone(),
+                    prs: prs(&[1, 2, 3, 4]),
+                },
+                PullRequestAction::HasMorePrs {
+                    repo: repo.clone(),
+                    has_more: false,
+                },
+            ] {
+                state = reduce_pull_request(state, &action);
+            }
+            let data = &state.repo_data[&0];
+            assert_eq!(data.prs[data.selected_pr].number, expected);
+            assert!(state.pending_session_pr_no.is_none());
+        }
+    }
+
+    #[test]
+    fn test_session_pr_on_a_later_page_is_restored() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            pending_session_pr_no: Some(2),
+            ..Default::default()
+        };
+        let cursor = |state: &MainViewState| {
+            let data = &state.repo_data[&0];
+            data.prs[data.selected_pr].number
+        };
+
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[4, 3]),
+            },
+        );
+        assert_eq!(cursor(&state), 4);
+        assert_eq!(state.pending_session_pr_no, Some(2));
+
+        for action in [
+            PullRequestAction::MorePrsLoaded {
+                repo: repo.clone(),
+                prs: prs(&[2, 1]),
+            },
+            PullRequestAction::HasMorePrs {
+                repo,
+                has_more: false,
+            },
+        ] {
+            state = reduce_pull_request(state, &action);
+        }
+        assert_eq!(cursor(&state), 2);
+        assert!(state.pending_session_pr_no.is_none());
+    }
+
+    #[test]
+    fn test_load_more_appends_and_tracks_remaining_prs() {
+        let repo = Repository::new("org", "repo", "main");
+        let mut state = MainViewState {
+            repositories: vec![repo.clone()],
+            ..Default::default()
+        };
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::Loaded {
+                repo: repo.clone(),
+                prs: prs(&[4, 3]),
+            },
+        );
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::HasMorePrs {
+                repo: repo.clone(),
+                has_more: true,
+            },
+        );
+        assert!(state.repo_data[&0].has_more_prs);
+
+        // The next page overlaps the loaded PRs by one
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::MorePrsLoaded {
+                repo: repo.clone(),
+                prs: prs(&[3, 2, 1]),
+            },
+        );
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::HasMorePrs {
+                repo,
+                has_more: false,
+            },
+        );
+
+        let numbers: Vec<usize> = state.repo_data[&0].prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, vec![4, 3, 2, 1]);
+        assert!(!state.repo_data[&0].has_more_prs);
+    }
+
+    #[test]
+    fn test_maturity_updated_applies_to_its_repository() {
+        let repo = Repository::new("org", "repo", "main");
+        let other = Repository::new("org", "other", "main");
+        let mut state = MainViewState {
+            repositories: vec![other.clone(), repo.clone()],
+            ..Default::default()
+        };
+        for repo in [&other, &repo] {
+            let mut prs = prs(&[2]);
+            prs[0].maturity = MaturityState::Draft;
+            state = reduce_pull_request(
+                state,
+                &PullRequestAction::Loaded {
+                    repo: repo.clone(),
+                    prs,
+                },
+            );
+        }
+
+        // Completed while the other repository is selected
+        state = reduce_pull_request(
+            state,
+            &PullRequestAction::MaturityUpdated {
+                repo,
+                pr_number: 2,
+                maturity: MaturityState::Ready,
+            },
+        );
+        assert_eq!(state.repo_data[&1].prs[0].maturity, MaturityState::Ready);
+        assert_eq!(state.repo_data[&0].prs[0].maturity, MaturityState::Draft);
+    }
+}
@@ -5,8 +5,19 @@
 //! - Add repository form state
 
 use crate::actions::RepositoryAction;
+use crate::domain_models::Repository;
 use crate::state::{AddRepoField, AddRepoFormState, MainViewState};
 
+/// Append a repository to the list unless it is already tracked
+fn add_repository(state: &mut MainViewState, repo: &Repository) {
+    if state.repositories.iter().any(|r| r.is_duplicate_of(repo)) {
+        log::info!("Repository {} already added", repo.display_name());
+    } else {
+        log::info!("Adding repository: {}", repo.display_name());
+        state.repositories.push(repo.clone());
+    }
+}
+
 /// Reduce repository list state
 pub fn reduce_repository(mut state: MainViewState, action: &RepositoryAction) -> MainViewState {
     match action {
@@ -14,8 +25,12 @@ pub fn reduce_repository(mut state: MainViewState, action: &RepositoryAction) ->
             // Side effect handled by middleware
         }
         RepositoryAction::AddRepository(repo) => {
-            log::info!("Adding repository: {}", repo.display_name());
-            state.repositories.push(repo.clone());
+            add_repository(&mut state, repo);
+        }
+        RepositoryAction::AddRepositoriesBulk(repos) => {
+            for repo in repos {
+                add_repository(&mut state, repo);
+            }
         }
         RepositoryAction::RemoveCurrentRepository => {
             if !state.repositories.is_empty() {
@@ -39,15 +54,27 @@ pub fn reduce_repository(mut state: MainViewState, action: &RepositoryAction) ->
                     }
                 }
 
-                // Adjust selected index if necessary
-                if state.selected_repository >= state.repositories.len()
-                    && !state.repositories.is_empty()
-                {
-                    state.selected_repository = state.repositories.len() - 1;
+                // Adjust selected index if necessary (0 once the list is empty)
+                if state.selected_repository >= state.repositories.len() {
+                    state.selected_repository = state.repositories.len().saturating_sub(1);
                 }
             }
         }
-        RepositoryAction::LoadRepositoryData(_) => {
+        RepositoryAction::MoveLeft => {
+            let idx = state.selected_repository;
+            if idx > 0 {
+                state.swap_repositories(idx, idx - 1);
+            }
+        }
+        RepositoryAction::MoveRight => {
+            let idx = state.selected_repository;
+            state.swap_repositories(idx, idx + 1);
+        }
+        RepositoryAction::RemoveCurrentRepositoryRequest
+        | RepositoryAction::LoadRepositoryData(_)
+        | RepositoryAction::ResolveStartupRepository { .. }
+        | RepositoryAction::StartupRepositoryResolved(_)
+        | RepositoryAction::PersistRepositories => {
             // Side effect handled by middleware
         }
         // Form actions don't affect MainViewState
@@ -57,7 +84,9 @@ pub fn reduce_repository(mut state: MainViewState, action: &RepositoryAction) ->
         | RepositoryAction::FormBackspace
         | RepositoryAction::FormClearField
         | RepositoryAction::FormConfirm
-        | RepositoryAction::FormClose => {}
+        | RepositoryAction::VerifyRepository(_)
+        | RepositoryAction::RepositoryVerified(_)
+        | RepositoryAction::RepositoryVerifyFailed { .. } => {}
     }
     state
 }
@@ -67,6 +96,17 @@ pub fn reduce_add_repo_form(
     mut state: AddRepoFormState,
     action: &RepositoryAction,
 ) -> AddRepoFormState {
+    // Editing drops a pending verification and the previous error
+    if matches!(
+        action,
+        RepositoryAction::FormChar(_)
+            | RepositoryAction::FormBackspace
+            | RepositoryAction::FormClearField
+    ) {
+        state.verifying = false;
+        state.error = None;
+    }
+
     match action {
         RepositoryAction::FormChar(c) => match state.focused_field {
             AddRepoField::Url => {
@@ -78,6 +118,7 @@ pub fn reduce_add_repo_form(
             }
             AddRepoField::Org => {
                 state.org.push(*c);
+                state.detect_url_in_org();
             }
             AddRepoField::Repo => {
                 state.repo.push(*c);
@@ -136,21 +177,171 @@ pub fn reduce_add_repo_form(
         }
 
         RepositoryAction::FormConfirm => {
-            if state.is_valid() {
+            // The form stays open until the repository is verified
+            if state.is_valid() && !state.verifying {
+                state.verifying = true;
+                state.error = None;
+            }
+        }
+
+        RepositoryAction::RepositoryVerified(repo) => {
+            if state.is_verifying(repo) {
                 state.reset();
             }
         }
 
-        RepositoryAction::FormClose => {
-            state.reset();
+        RepositoryAction::RepositoryVerifyFailed { repo, message } => {
+            if state.is_verifying(repo) {
+                state.verifying = false;
+                state.error = Some(message.clone());
+            }
         }
 
         // Non-form actions don't affect form state
         RepositoryAction::OpenRepositoryInBrowser
         | RepositoryAction::AddRepository(_)
+        | RepositoryAction::AddRepositoriesBulk(_)
+        | RepositoryAction::RemoveCurrentRepositoryRequest
         | RepositoryAction::RemoveCurrentRepository
-        | RepositoryAction::LoadRepositoryData(_) => {}
+        | RepositoryAction::MoveLeft
+        | RepositoryAction::MoveRight
+        | RepositoryAction::PersistRepositories
+        | RepositoryAction::LoadRepositoryData(_)
+        | RepositoryAction::ResolveStartupRepository { .. }
+        | RepositoryAction::StartupRepositoryResolved(_)
+        | RepositoryAction::VerifyRepository(_) => {}
     }
 
     state
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RepositoryData;
+
+    /// Three repos; only "a" and "c" have loaded data (tagged by PR cursor)
+    fn state(selected: usize) -> MainViewState {
+        let mut state = MainViewState {
+            repositories: vec![
+                Repository::new("org", "a", "main"),
+                Repository::new("org", "b", "main"),
+                Repository::new("org", "c", "main"),
+            ],
+            selected_repository: selected,
+            ..Default::default()
+        };
+        for (idx, tag) in [(0, 10), (2, 30)] {
+            state.repo_data.insert(
+                idx,
+                RepositoryData {
+                    selected_pr: tag,
+                    ..Default::default()
+                },
+            );
+        }
+        state
+    }
+
+    fn names(state: &MainViewState) -> Vec<&str> {
+        state.repositories.iter().map(|r| r.repo.as_str()).collect()
+    }
+
+    #[test]
+    fn test_move_right_rekeys_data_and_follows_selection() {
+        let state = reduce_repository(state(0), &RepositoryAction::MoveRight);
+        assert_eq!(names(&state), vec!["b", "a", "c"]);
+        assert_eq!(state.selected_repository, 1);
+        // "a"'s data moved with it, "b" had none
+        assert_eq!(state.repo_data[&1].selected_pr, 10);
+        assert!(!state.repo_data.contains_key(&0));
+        assert_eq!(state.repo_data[&2].selected_pr, 30);
+    }
+
+    #[test]
+    fn test_move_left_swaps_both_entries() {
+        let mut state = state(2);
+        state.repo_data.insert(
+            1,
+            RepositoryData {
+                selected_pr: 20,
+                ..Default::default()
+            },
+        );
+        let state = reduce_repository(state, &RepositoryAction::MoveLeft);
+        assert_eq!(names(&state), vec!["a", "c", "b"]);
+        assert_eq!(state.selected_repository, 1);
+        assert_eq!(state.repo_data[&1].selected_pr, 30);
+        assert_eq!(state.repo_data[&2].selected_pr, 20);
+    }
+
+    #[test]
+    fn test_move_at_edges_is_noop() {
+        let state = reduce_repository(state(0), &RepositoryAction::MoveLeft);
+        assert_eq!(names(&state), vec!["a", "b", "c"]);
+        assert_eq!(state.selected_repository, 0);
+
+        let state = reduce_repository(state(2), &RepositoryAction::MoveRight);
+        assert_eq!(names(&state), vec!["a", "b", "c"]);
+        assert_eq!(state.selected_repository, 2);
+        assert_eq!(state.repo_data[&2].selected_pr, 30);
+    }
+
+    fn confirmed_form() -> AddRepoFormState {
+        let form = AddRepoFormState {
+            org: "org".to_string(),
+            repo: "typo".to_string(),
+            ..Default::default()
+        };
+        reduce_add_repo_form(form, &RepositoryAction::FormConfirm)
+    }
+
+    #[test]
+    fn test_form_confirm_verifies_before_adding() {
+        let form = confirmed_form();
+        assert!(form.verifying);
+        let repo = form.to_repository();
+
+        // Not found: the form stays open with an inline error
+        let form = reduce_add_repo_form(
+            form,
+            &RepositoryAction::RepositoryVerifyFailed {
+                repo: repo.clone(),
+                message: "Repository not found or no access".to_string(),
+            },
+        );
+        assert!(!form.verifying);
+        assert_eq!(form.repo, "typo");
+        assert_eq!(
+            form.error.as_deref(),
+            Some("Repository not found or no access")
+        );
+
+        // Editing clears the error; a verified repository resets the form
+        let form = reduce_add_repo_form(form, &RepositoryAction::FormBackspace);
+        assert!(form.error.is_none());
+        let form = reduce_add_repo_form(form, &RepositoryAction::FormConfirm);
+        let repo = form.to_repository();
+        let form = reduce_add_repo_form(form, &RepositoryAction::RepositoryVerified(repo));
+        assert!(!form.verifying);
+        assert!(form.repo.is_empty());
+    }
+
+    #[test]
+    fn test_stale_verification_result_is_ignored() {
+        let form = confirmed_form();
+        let stale = Repository::new("org", "other", "main");
+        let form = reduce_add_repo_form(form, &RepositoryAction::RepositoryVerified(stale));
+        assert!(form.verifying);
+        assert_eq!(form.repo, "typo");
+    }
+
+    #[test]
+    fn test_add_repository_skips_normalized_duplicate() {
+        let state = reduce_repository(
+            state(0),
+            &RepositoryAction::AddRepository(Repository::new("ORG", "b.git", "main")),
+        );
+        assert_eq!(names(&state), vec!["a", "b", "c"]);
+    }
+}
@@ -27,7 +27,7 @@ pub fn reduce_session(mut state: MainViewState, action: &SessionAction) -> MainV
         SessionAction::RestoreSelection => {
             // Apply pending session selection if repositories match
             if let Some((org, name, branch, host)) = &state.pending_session_repo {
-                for (idx, repo) in state.repositories.iter().enumerate() {
+                let idx = state.repositories.iter().position(|repo| {
                     // Match all fields including host
                     // Treat None and Some(DEFAULT_HOST) as equivalent
                     let host_matches = match (&repo.host, host) {
@@ -37,46 +37,26 @@ pub fn reduce_session(mut state: MainViewState, action: &SessionAction) -> MainV
                         (Some(a), Some(b)) => a == b,
                         _ => false,
                     };
+                    repo.org == *org && repo.repo == *name && repo.branch == *branch && host_matches
+                });
 
-                    if repo.org == *org
-                        && repo.repo == *name
-                        && repo.branch == *branch
-                        && host_matches
-                    {
-                        log::info!(
-                            "Session: Restoring repository selection to index {} ({}/{})",
-                            idx,
-                            org,
-                            name
-                        );
-                        state.selected_repository = idx;
-
-                        // Restore PR selection by PR number (not index)
-                        if let Some(pr_no) = state.pending_session_pr_no {
-                            if let Some(repo_data) = state.repo_data.get_mut(&idx) {
-                                // Find the PR by number and get its index
-                                if let Some(pr_idx) =
-                                    repo_data.prs.iter().position(|pr| pr.number == pr_no)
-                                {
-                                    repo_data.selected_pr = pr_idx;
-                                    log::info!(
-                                        "Session: Restoring PR #{} at index {}",
-                                        pr_no,
-                                        pr_idx
-                                    );
-                                } else {
-                                    log::debug!(
-                                        "Session: PR #{} not found in repository, skipping PR restore",
-                                        pr_no
-                                    );
-                                }
-                            }
-                        }
-                        break;
-                    }
+                if let Some(idx) = idx {
+                    log::info!(
+                        "Session: Restoring repository selection to index {} ({}/{})",
+                        idx,
+                        org,
+                        name
+                    );
+                    state.selected_repository = idx;
+                    state.pending_session_repo = None;
+                    // Restore PR selection by PR number (not index). Usually
+                    // the PRs are not loaded yet, then the PR is restored
+                    // once a page containing it arrives (see `PullRequestAction::Loaded`)
+                    state.restore_session_pr(idx);
+                    return state;
                 }
             }
-            // Clear pending session data
+            // The repository is gone, so is the PR selection
             state.pending_session_repo = None;
             state.pending_session_pr_no = None;
         }
@@ -0,0 +1,76 @@
+//! Setup Wizard Reducer
+//!
+//! Handles state updates for the first-run setup wizard.
+
+use crate::actions::SetupWizardAction;
+use crate::state::SetupWizardState;
+
+/// Reducer for setup wizard state
+pub fn reduce_setup_wizard(
+    mut state: SetupWizardState,
+    action: &SetupWizardAction,
+) -> SetupWizardState {
+    match action {
+        SetupWizardAction::TokenMissing {
+            host,
+            env_key,
+            attempts,
+        } => {
+            // A failed retry keeps the wizard open, so tell the user why
+            if state.checking {
+                state.error = Some(format!("Still no token found for {}", host));
+            }
+            state.host = host.clone();
+            state.env_key = env_key.clone();
+            state.attempts = attempts.clone();
+            state.checking = false;
+        }
+        SetupWizardAction::GhAuthLoginFinished(Err(error)) => {
+            state.error = Some(error.clone());
+        }
+        SetupWizardAction::Retry => {
+            state.checking = true;
+            state.error = None;
+        }
+        // Handled by the main loop and middleware
+        SetupWizardAction::RunGhAuthLogin | SetupWizardAction::GhAuthLoginFinished(Ok(())) => {}
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_missing() -> SetupWizardAction {
+        SetupWizardAction::TokenMissing {
+            host: "github.com".to_string(),
+            env_key: "GITHUB_TOKEN_GITHUB_COM".to_string(),
+            attempts: vec!["gh CLI is not installed".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_first_missing_token_has_no_error() {
+        let state = reduce_setup_wizard(SetupWizardState::default(), &token_missing());
+
+        assert_eq!(state.host, "github.com");
+        assert_eq!(state.env_key, "GITHUB_TOKEN_GITHUB_COM");
+        assert_eq!(state.attempts, vec!["gh CLI is not installed"]);
+        assert!(state.error.is_none());
+    }
+
+    #[test]
+    fn test_failed_retry_reports_error() {
+        let state = reduce_setup_wizard(SetupWizardState::default(), &token_missing());
+        let state = reduce_setup_wizard(state, &SetupWizardAction::Retry);
+        assert!(state.checking);
+
+        let state = reduce_setup_wizard(state, &token_missing());
+        assert!(!state.checking);
+        assert_eq!(
+            state.error.as_deref(),
+            Some("Still no token found for github.com")
+        );
+    }
+}
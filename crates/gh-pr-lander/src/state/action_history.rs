@@ -0,0 +1,90 @@
+//! Action History State
+
+use crate::actions::{Action, GlobalAction};
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+
+/// Maximum number of actions to keep in the ring buffer
+pub const MAX_ACTION_HISTORY: usize = 500;
+
+/// A reduced action and when it was reduced
+#[derive(Debug, Clone)]
+pub struct ActionHistoryEntry {
+    pub timestamp: DateTime<Local>,
+    /// Action variant path (e.g. "PullRequest::MergeRequest"), without payload
+    pub name: String,
+}
+
+/// Action history state - the recent actions leading up to the current state
+///
+/// Only recorded while `GH_PR_LANDER_DEBUG` is set (see `enabled`).
+#[derive(Debug, Clone, Default)]
+pub struct ActionHistoryState {
+    /// Whether the store records actions
+    pub enabled: bool,
+    /// Ring buffer of actions (capped at MAX_ACTION_HISTORY), oldest first
+    pub entries: VecDeque<ActionHistoryEntry>,
+    /// Scroll offset within the entries (0 = bottom/newest)
+    pub scroll_offset: usize,
+    /// Visible height for scroll bounds
+    pub visible_height: usize,
+}
+
+impl ActionHistoryState {
+    /// Append an action to the ring buffer, trimming old ones if over capacity
+    ///
+    /// Periodic actions (ticks, animation frames) would push everything else
+    /// out of the buffer and are skipped.
+    pub fn record(&mut self, action: &Action, timestamp: DateTime<Local>) {
+        if matches!(
+            action,
+            Action::Global(GlobalAction::Tick | GlobalAction::AnimationFrame(_))
+        ) {
+            return;
+        }
+        self.entries.push_back(ActionHistoryEntry {
+            timestamp,
+            name: action.name(),
+        });
+        if self.entries.len() > MAX_ACTION_HISTORY {
+            self.entries.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::{SetupWizardAction, TextInputAction};
+
+    #[test]
+    fn test_action_name_drops_payload() {
+        assert_eq!(Action::Global(GlobalAction::Quit).name(), "Global::Quit");
+        assert_eq!(
+            Action::TextInput(TextInputAction::Char('x')).name(),
+            "TextInput::Char"
+        );
+        assert_eq!(
+            Action::SetupWizard(SetupWizardAction::TokenMissing {
+                host: "github.com".to_string(),
+                env_key: "GITHUB_TOKEN".to_string(),
+                attempts: vec!["ghp_secret".to_string()],
+            })
+            .name(),
+            "SetupWizard::TokenMissing"
+        );
+        assert_eq!(Action::error("secret", "context").name(), "ErrorOccurred");
+    }
+
+    #[test]
+    fn test_record_skips_ticks_and_caps_entries() {
+        let mut state = ActionHistoryState::default();
+        state.record(&Action::Global(GlobalAction::Tick), Local::now());
+        assert!(state.entries.is_empty());
+
+        for _ in 0..MAX_ACTION_HISTORY + 1 {
+            state.record(&Action::Global(GlobalAction::CycleTheme), Local::now());
+        }
+        assert_eq!(state.entries.len(), MAX_ACTION_HISTORY);
+    }
+}
@@ -42,11 +42,13 @@ impl AddRepoField {
 #[derive(Debug, Clone, Default)]
 pub struct AddRepoFormState {
     pub url: String,    // GitHub URL (for auto-parsing)
-    pub host: String,   // GitHub host (empty = github.com)
+    pub host: String,   // GitHub host (empty = default host)
     pub org: String,    // Organization/owner name
     pub repo: String,   // Repository name
     pub branch: String, // Branch name (default: "main")
     pub focused_field: AddRepoField,
+    pub verifying: bool,       // Existence check of the repository in progress
+    pub error: Option<String>, // Inline error (e.g. repository not found)
 }
 
 impl AddRepoFormState {
@@ -58,23 +60,50 @@ impl AddRepoFormState {
         self.repo.clear();
         self.branch.clear();
         self.focused_field = AddRepoField::default();
+        self.verifying = false;
+        self.error = None;
     }
 
-    /// Try to parse the URL and populate host/org/repo fields if valid
+    /// Whether a verification result belongs to the repository currently being verified
+    pub fn is_verifying(&self, repo: &Repository) -> bool {
+        self.verifying && self.to_repository() == *repo
+    }
+
+    /// Try to parse the URL and populate host/org/repo/branch fields if valid
     ///
     /// Supports formats:
     /// - `https://github.com/org/repo`
     /// - `https://github.example.com/org/repo`
+    /// - `https://github.com/org/repo/tree/branch`
     /// - `git@github.example.com:org/repo.git`
+    /// - `org/repo` (default host)
     pub fn parse_url_and_update(&mut self) {
         if let Some((host, org, repo)) = parse_github_url(&self.url) {
-            if let Some(h) = host {
-                self.host = h;
-            } else {
-                self.host.clear();
-            }
+            // A pasted URL always names its host, github.com included
+            self.host = host.unwrap_or_else(|| DEFAULT_HOST.to_string());
             self.org = org;
             self.repo = repo;
+        } else if let Some((org, repo)) = parse_shorthand(&self.url) {
+            self.host.clear();
+            self.org = org;
+            self.repo = repo;
+        } else {
+            return;
+        }
+
+        if let Some(branch) = parse_tree_branch(&self.url, &self.org, &self.repo) {
+            self.branch = branch;
+        }
+    }
+
+    /// Continue in the URL field if the org field contains a URL or `org/repo`
+    ///
+    /// The org field keeps the text as typed until it parses.
+    pub fn detect_url_in_org(&mut self) {
+        if self.org.contains('/') || self.org.starts_with("git@") {
+            self.url = self.org.clone();
+            self.focused_field = AddRepoField::Url;
+            self.parse_url_and_update();
         }
     }
 
@@ -92,20 +121,20 @@ impl AddRepoFormState {
         }
     }
 
-    /// Get the host as Option (None for github.com or empty)
+    /// Get the host as Option (None if empty, i.e. the default host)
     pub fn effective_host(&self) -> Option<String> {
-        if self.host.is_empty() || self.host == DEFAULT_HOST {
+        if self.host.is_empty() {
             None
         } else {
             Some(self.host.clone())
         }
     }
 
-    /// Create a Repository from this form
+    /// Create a Repository from this form (a trailing `.git` is dropped)
     pub fn to_repository(&self) -> Repository {
         Repository::with_host(
             &self.org,
-            &self.repo,
+            self.repo.strip_suffix(".git").unwrap_or(&self.repo),
             self.effective_branch(),
             self.effective_host(),
         )
@@ -173,6 +202,32 @@ fn parse_github_url(url: &str) -> Option<(Option<String>, String, String)> {
     None
 }
 
+/// Parse the `org/repo` shorthand (no host) into (org, repo)
+fn parse_shorthand(input: &str) -> Option<(String, String)> {
+    let input = input.trim();
+    let (org, repo) = input.split_once('/')?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+
+    // Owner names never contain dots, so `host/org` is not mistaken for `org/repo`
+    let valid = !org.is_empty()
+        && !repo.is_empty()
+        && !org.contains(['.', ':', '@'])
+        && !repo.contains('/');
+    valid.then(|| (org.to_string(), repo.to_string()))
+}
+
+/// Extract the branch from a `.../org/repo/tree/<branch>` URL
+///
+/// Branch names may contain slashes, so everything after `tree/` is the branch.
+fn parse_tree_branch(url: &str, org: &str, repo: &str) -> Option<String> {
+    let segments: Vec<&str> = url.trim().trim_end_matches('/').split('/').collect();
+    let idx = segments
+        .windows(3)
+        .position(|window| window == [org, repo, "tree"])?;
+    let branch = segments[idx + 3..].join("/");
+    (!branch.is_empty()).then_some(branch)
+}
+
 /// Parse "org/repo[.git]" into (org, repo)
 fn parse_org_repo_path(path: &str) -> Option<(String, String)> {
     // Remove trailing .git if present
@@ -323,4 +378,100 @@ mod tests {
         assert!(!repo.is_github_com());
         assert_eq!(repo.effective_host(), "ghe.example.com");
     }
+
+    #[test]
+    fn test_parse_url_keeps_github_com_host() {
+        let mut state = AddRepoFormState {
+            url: "https://github.com/rust-lang/rust".to_string(),
+            ..Default::default()
+        };
+        state.parse_url_and_update();
+
+        assert_eq!(state.host, "github.com");
+        assert_eq!(state.effective_host(), Some("github.com".to_string()));
+        assert_eq!(state.to_repository().effective_host(), "github.com");
+    }
+
+    fn parsed(url: &str) -> AddRepoFormState {
+        let mut state = AddRepoFormState {
+            url: url.to_string(),
+            ..Default::default()
+        };
+        state.parse_url_and_update();
+        state
+    }
+
+    #[test]
+    fn test_parse_url_and_update_https() {
+        let state = parsed("https://ghe.example.com/team/project.git");
+        assert_eq!(state.host, "ghe.example.com");
+        assert_eq!(state.org, "team");
+        assert_eq!(state.repo, "project");
+        assert_eq!(state.branch, "");
+    }
+
+    #[test]
+    fn test_parse_url_and_update_ssh() {
+        let state = parsed("git@github.com:rust-lang/rust.git");
+        assert_eq!(state.host, "github.com");
+        assert_eq!(state.org, "rust-lang");
+        assert_eq!(state.repo, "rust");
+    }
+
+    #[test]
+    fn test_parse_url_and_update_tree_branch() {
+        let state = parsed("https://github.com/org/repo/tree/feature/login");
+        assert_eq!(state.org, "org");
+        assert_eq!(state.repo, "repo");
+        assert_eq!(state.branch, "feature/login");
+
+        // A repository named "tree" is not mistaken for the tree path
+        let state = parsed("https://github.com/org/tree/tree/main");
+        assert_eq!(state.repo, "tree");
+        assert_eq!(state.branch, "main");
+    }
+
+    #[test]
+    fn test_parse_url_and_update_shorthand() {
+        let state = parsed("octocat/Hello-World");
+        assert_eq!(state.host, "");
+        assert_eq!(state.org, "octocat");
+        assert_eq!(state.repo, "Hello-World");
+    }
+
+    #[test]
+    fn test_parse_url_and_update_invalid_keeps_fields() {
+        let mut state = AddRepoFormState {
+            url: "not a url".to_string(),
+            org: "typed".to_string(),
+            ..Default::default()
+        };
+        state.parse_url_and_update();
+        assert_eq!(state.org, "typed");
+        assert_eq!(state.repo, "");
+    }
+
+    #[test]
+    fn test_detect_url_in_org() {
+        let mut state = AddRepoFormState {
+            focused_field: AddRepoField::Org,
+            org: "https://github.com/org/repo/tree/dev".to_string(),
+            ..Default::default()
+        };
+        state.detect_url_in_org();
+        assert_eq!(state.focused_field, AddRepoField::Url);
+        assert_eq!(state.org, "org");
+        assert_eq!(state.repo, "repo");
+        assert_eq!(state.branch, "dev");
+
+        // Plain org names stay in the org field
+        let mut state = AddRepoFormState {
+            focused_field: AddRepoField::Org,
+            org: "rust-lang".to_string(),
+            ..Default::default()
+        };
+        state.detect_url_in_org();
+        assert_eq!(state.focused_field, AddRepoField::Org);
+        assert_eq!(state.url, "");
+    }
 }
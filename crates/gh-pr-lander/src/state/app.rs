@@ -1,12 +1,14 @@
 //! Application State
 
+use crate::command_id::CommandId;
 use crate::keymap::{default_keymap, Keymap};
 use crate::views::{SplashView, View};
 
 use super::{
-    AddRepoFormState, BuildLogState, CommandPaletteState, ConfirmationPopupState,
-    DebugConsoleState, DiffViewerState, KeyBindingsPanelState, MainViewState, MergeBotState,
-    SplashState, StatusBarState,
+    ActionHistoryState, AddRepoFormState, BuildLogState, CommandPaletteState, CommitListState,
+    ConfirmationPopupState, DebugConsoleState, DiffViewerState, ErrorBannerState,
+    KeyBindingsPanelState, MainViewState, MergeBotState, PendingSequenceState, PrColumn,
+    RowDensity, SetupWizardState, SpinnerState, SplashState, StatusBarState, ToastState,
 };
 
 /// Application state
@@ -17,13 +19,33 @@ pub struct AppState {
     pub view_stack: Vec<Box<dyn View>>,
     pub splash: SplashState,
     pub main_view: MainViewState,
+    /// Layout of the PR table rows
+    pub row_density: RowDensity,
+    /// Columns of the PR table in display order (`[pr] columns`)
+    pub pr_columns: Vec<PrColumn>,
     pub debug_console: DebugConsoleState,
+    /// Recent actions, recorded only while `GH_PR_LANDER_DEBUG` is set
+    pub action_history: ActionHistoryState,
     pub command_palette: CommandPaletteState,
     pub add_repo_form: AddRepoFormState,
     pub merge_bot: MergeBotState,
     pub key_bindings_panel: KeyBindingsPanelState,
     pub status_bar: StatusBarState,
+    /// Most recent error, shown as a banner until it expires or is dismissed
+    pub error_banner: Option<ErrorBannerState>,
+    /// Queued results of recent operations, shown one after another as toasts
+    pub toasts: ToastState,
+    /// Loading spinner animation
+    pub spinner: SpinnerState,
+    /// Terminal size (width, height), used for mouse hit-testing
+    pub terminal_size: (u16, u16),
+    /// Two-key sequence in progress (present only while waiting for the second key)
+    pub pending_sequence: Option<PendingSequenceState>,
+    /// Last repeatable command, run again by `RepeatLast`
+    pub last_command: Option<CommandId>,
     pub build_log: BuildLogState,
+    pub commit_list: CommitListState,
+    pub setup_wizard: SetupWizardState,
     pub diff_viewer: DiffViewerState,
     /// Confirmation popup state (present only when popup is shown)
     pub confirmation_popup: Option<ConfirmationPopupState>,
@@ -42,6 +64,20 @@ impl AppState {
             .expect("View stack should never be empty")
             .as_ref()
     }
+
+    /// Whether something on screen animates and needs periodic redraws
+    /// (e.g. the loading indicator of repository tabs, or the which-key hint
+    /// that must disappear once its sequence times out)
+    pub fn is_animating(&self) -> bool {
+        self.main_view
+            .repo_data
+            .values()
+            .any(|data| data.loading_state == crate::domain_models::LoadingState::Loading)
+            || self
+                .pending_sequence
+                .as_ref()
+                .is_some_and(|pending| !pending.is_expired())
+    }
 }
 
 impl std::fmt::Debug for AppState {
@@ -51,13 +87,24 @@ impl std::fmt::Debug for AppState {
             .field("view_stack", &format!("{} views", self.view_stack.len()))
             .field("splash", &self.splash)
             .field("main_view", &self.main_view)
+            .field("row_density", &self.row_density)
+            .field("pr_columns", &self.pr_columns)
             .field("debug_console", &self.debug_console)
+            .field("action_history", &self.action_history)
             .field("command_palette", &self.command_palette)
             .field("add_repo_form", &self.add_repo_form)
             .field("merge_bot", &self.merge_bot)
             .field("key_bindings_panel", &self.key_bindings_panel)
             .field("status_bar", &self.status_bar)
+            .field("error_banner", &self.error_banner)
+            .field("toasts", &self.toasts)
+            .field("spinner", &self.spinner)
+            .field("terminal_size", &self.terminal_size)
+            .field("pending_sequence", &self.pending_sequence)
+            .field("last_command", &self.last_command)
             .field("build_log", &self.build_log)
+            .field("commit_list", &self.commit_list)
+            .field("setup_wizard", &self.setup_wizard)
             .field("diff_viewer", &self.diff_viewer)
             .field("confirmation_popup", &self.confirmation_popup)
             .field("theme", &"<theme>")
@@ -73,13 +120,24 @@ impl Clone for AppState {
             view_stack: self.view_stack.clone(),
             splash: self.splash.clone(),
             main_view: self.main_view.clone(),
+            row_density: self.row_density,
+            pr_columns: self.pr_columns.clone(),
             debug_console: self.debug_console.clone(),
+            action_history: self.action_history.clone(),
             command_palette: self.command_palette.clone(),
             add_repo_form: self.add_repo_form.clone(),
             merge_bot: self.merge_bot.clone(),
             key_bindings_panel: self.key_bindings_panel.clone(),
             status_bar: self.status_bar.clone(),
+            error_banner: self.error_banner.clone(),
+            toasts: self.toasts.clone(),
+            spinner: self.spinner.clone(),
+            terminal_size: self.terminal_size,
+            pending_sequence: self.pending_sequence.clone(),
+            last_command: self.last_command,
             build_log: self.build_log.clone(),
+            commit_list: self.commit_list.clone(),
+            setup_wizard: self.setup_wizard.clone(),
             diff_viewer: self.diff_viewer.clone(),
             confirmation_popup: self.confirmation_popup.clone(),
             theme: self.theme.clone(),
@@ -96,13 +154,24 @@ impl Default for AppState {
             view_stack: vec![Box::new(SplashView::new())],
             splash: SplashState::default(),
             main_view: MainViewState::default(),
+            row_density: RowDensity::default(),
+            pr_columns: PrColumn::ALL.to_vec(),
             debug_console: DebugConsoleState::default(),
+            action_history: ActionHistoryState::default(),
             command_palette: CommandPaletteState::default(),
             add_repo_form: AddRepoFormState::default(),
             merge_bot: MergeBotState::default(),
             key_bindings_panel: KeyBindingsPanelState::default(),
             status_bar: StatusBarState::default(),
+            error_banner: None,
+            toasts: ToastState::default(),
+            spinner: SpinnerState::default(),
+            terminal_size: (0, 0),
+            pending_sequence: None,
+            last_command: None,
             build_log: BuildLogState::default(),
+            commit_list: CommitListState::default(),
+            setup_wizard: SetupWizardState::default(),
             diff_viewer: DiffViewerState::default(),
             confirmation_popup: None,
             theme: gh_pr_lander_theme::Theme::default(),
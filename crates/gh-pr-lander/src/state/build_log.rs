@@ -5,6 +5,9 @@
 use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
+/// Tree prefix in front of log lines (│ below workflow's ▼, │ below job's ▼)
+pub const LOG_LINE_PREFIX: &str = "│  │  ";
+
 /// Job execution status for build logs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BuildLogJobStatus {
@@ -44,6 +47,10 @@ pub struct BuildLogJobMetadata {
     pub error_count: usize,
     pub duration: Option<Duration>,
     pub html_url: String,
+    /// GitHub job ID (None if the job could not be matched to the API)
+    pub job_id: Option<u64>,
+    /// ID of the workflow run the job belongs to
+    pub run_id: u64,
 }
 
 /// PR context for build log header display
@@ -88,14 +95,35 @@ pub struct BuildLogState {
     /// Show timestamps toggle
     pub show_timestamps: bool,
 
-    /// Viewport height (set during rendering)
+    /// Wrap long log lines across multiple rows instead of scrolling horizontally
+    pub wrap_lines: bool,
+
+    /// Only show workflows, jobs and steps that contain errors
+    pub failed_only: bool,
+
+    /// Viewport height in rows (synced with the terminal size)
     pub viewport_height: usize,
 
+    /// Viewport width in columns (synced with the terminal size)
+    pub viewport_width: usize,
+
     /// PR context for header
     pub pr_context: BuildLogPrContext,
 
     /// Loading state
     pub loading_state: BuildLogLoadingState,
+
+    /// Free-text search query (case-insensitive)
+    pub search_query: String,
+
+    /// Whether the search query is being edited
+    pub search_editing: bool,
+
+    /// Paths of log lines matching the search query, in tree order
+    pub search_matches: Vec<Vec<usize>>,
+
+    /// Index into `search_matches` of the match under the cursor
+    pub search_match_index: Option<usize>,
 }
 
 impl Default for BuildLogState {
@@ -108,9 +136,16 @@ impl Default for BuildLogState {
             scroll_offset: 0,
             horizontal_scroll: 0,
             show_timestamps: false,
+            wrap_lines: false,
+            failed_only: false,
             viewport_height: 20,
+            viewport_width: 80,
             pr_context: BuildLogPrContext::default(),
             loading_state: BuildLogLoadingState::Idle,
+            search_query: String::new(),
+            search_editing: false,
+            search_matches: Vec::new(),
+            search_match_index: None,
         }
     }
 }
@@ -142,10 +177,15 @@ impl BuildLogState {
     /// Flatten tree to list of visible node paths
     ///
     /// Returns paths as vectors: \[workflow\], \[workflow, job\], \[workflow, job, step\], etc.
+    /// With `failed_only`, subtrees without errors are left out.
     pub fn flatten_visible_nodes(&self) -> Vec<Vec<usize>> {
         let mut result = Vec::new();
 
         for (w_idx, workflow) in self.workflows.iter().enumerate() {
+            if self.failed_only && !workflow.has_failures && workflow.total_errors == 0 {
+                continue;
+            }
+
             // Workflow node
             result.push(vec![w_idx]);
 
@@ -154,6 +194,10 @@ impl BuildLogState {
             }
 
             for (j_idx, job) in workflow.jobs.iter().enumerate() {
+                if self.failed_only && job.error_count == 0 {
+                    continue;
+                }
+
                 // Job node
                 result.push(vec![w_idx, j_idx]);
 
@@ -162,6 +206,10 @@ impl BuildLogState {
                 }
 
                 for (s_idx, step) in job.steps.iter().enumerate() {
+                    if self.failed_only && step.error_count == 0 {
+                        continue;
+                    }
+
                     // Step node
                     result.push(vec![w_idx, j_idx, s_idx]);
 
@@ -182,14 +230,49 @@ impl BuildLogState {
 
     /// Get visible nodes within viewport
     pub fn visible_nodes_in_viewport(&self) -> Vec<Vec<usize>> {
-        let all_visible = self.flatten_visible_nodes();
-        all_visible
+        let mut rows = 0;
+        self.flatten_visible_nodes()
             .into_iter()
             .skip(self.scroll_offset)
-            .take(self.viewport_height)
+            .take_while(|path| {
+                // Always show at least the first node, even if it is taller than the viewport
+                let fits = rows == 0 || rows + self.node_rows(path) <= self.viewport_height;
+                rows += self.node_rows(path);
+                fits
+            })
             .collect()
     }
 
+    /// Number of characters a log line occupies when rendered, including
+    /// the tree prefix and the optional timestamp
+    pub fn line_display_width(&self, line: &gh_actions_log_parser::LogLine) -> usize {
+        let timestamp_width = match line.timestamp {
+            Some(ref timestamp) if self.show_timestamps => timestamp.chars().count() + 3,
+            _ => 0,
+        };
+        LOG_LINE_PREFIX.chars().count() + timestamp_width + line.display_content.chars().count()
+    }
+
+    /// Number of display rows a node occupies (more than one only for wrapped log lines)
+    pub fn node_rows(&self, path: &[usize]) -> usize {
+        if !self.wrap_lines || path.len() != 4 || self.viewport_width == 0 {
+            return 1;
+        }
+        let line = self
+            .workflows
+            .get(path[0])
+            .and_then(|w| w.jobs.get(path[1]))
+            .and_then(|j| j.steps.get(path[2]))
+            .and_then(|s| s.lines.get(path[3]));
+        match line {
+            Some(line) => self
+                .line_display_width(line)
+                .div_ceil(self.viewport_width)
+                .max(1),
+            None => 1,
+        }
+    }
+
     /// Find index of cursor in flattened visible nodes
     pub fn cursor_index(&self) -> Option<usize> {
         let visible = self.flatten_visible_nodes();
@@ -200,4 +283,94 @@ impl BuildLogState {
     pub fn total_visible_nodes(&self) -> usize {
         self.flatten_visible_nodes().len()
     }
+
+    /// Display text of the node under the cursor, one entry per log line
+    ///
+    /// A log line yields itself; a step, job or workflow yields all of its lines.
+    pub fn selected_lines(&self) -> Vec<&str> {
+        let path = &self.cursor_path;
+        let Some(workflow) = path.first().and_then(|&w| self.workflows.get(w)) else {
+            return Vec::new();
+        };
+
+        let jobs = match path.get(1) {
+            Some(&j) => workflow.jobs.get(j..=j).unwrap_or_default(),
+            None => &workflow.jobs[..],
+        };
+
+        let mut lines = Vec::new();
+        for job in jobs {
+            let steps = match path.get(2) {
+                Some(&s) => job.steps.get(s..=s).unwrap_or_default(),
+                None => &job.steps[..],
+            };
+            for step in steps {
+                let step_lines = match path.get(3) {
+                    Some(&l) => step.lines.get(l..=l).unwrap_or_default(),
+                    None => &step.lines[..],
+                };
+                lines.extend(step_lines.iter().map(|line| line.display_content.as_str()));
+            }
+        }
+
+        lines
+    }
+
+    /// Metadata of the job at or above the cursor
+    pub fn cursor_job_metadata(&self) -> Option<&BuildLogJobMetadata> {
+        let workflow = self.workflows.get(*self.cursor_path.first()?)?;
+        let job = workflow.jobs.get(*self.cursor_path.get(1)?)?;
+        self.job_metadata
+            .get(&format!("{}:{}", workflow.name, job.name))
+    }
+
+    /// Name and run ID of the workflow at or above the cursor
+    ///
+    /// The run ID is taken from the metadata of the workflow's jobs.
+    pub fn cursor_workflow_run(&self) -> Option<(&str, u64)> {
+        let workflow = self.workflows.get(*self.cursor_path.first()?)?;
+        workflow.jobs.iter().find_map(|job| {
+            self.job_metadata
+                .get(&format!("{}:{}", workflow.name, job.name))
+                .map(|meta| (workflow.name.as_str(), meta.run_id))
+        })
+    }
+
+    /// Whether a search query is active
+    pub fn has_search(&self) -> bool {
+        !self.search_query.is_empty()
+    }
+
+    /// Paths of all log lines whose display content contains `query` (case-insensitive)
+    ///
+    /// Collapsed nodes are searched too; paths are returned in tree order.
+    pub fn find_matches(&self, query: &str) -> Vec<Vec<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let needle = query.to_lowercase();
+        let mut result = Vec::new();
+
+        for (w_idx, workflow) in self.workflows.iter().enumerate() {
+            for (j_idx, job) in workflow.jobs.iter().enumerate() {
+                for (s_idx, step) in job.steps.iter().enumerate() {
+                    for (l_idx, line) in step.lines.iter().enumerate() {
+                        if line.display_content.to_lowercase().contains(&needle) {
+                            result.push(vec![w_idx, j_idx, s_idx, l_idx]);
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Expand all ancestors of a node so that it becomes visible
+    pub fn expand_ancestors(&mut self, path: &[usize]) {
+        for depth in 1..path.len() {
+            self.expanded_nodes
+                .insert(Self::path_to_key(&path[..depth]));
+        }
+    }
 }
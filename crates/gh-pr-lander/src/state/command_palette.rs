@@ -1,5 +1,8 @@
 //! Command Palette State
 
+use crate::command_id::CommandId;
+use std::collections::HashMap;
+
 /// Command palette state.
 ///
 /// Only domain state lives here. The visible window (`offset`) is derived in
@@ -9,4 +12,6 @@
 pub struct CommandPaletteState {
     pub query: String,
     pub selected_index: usize,
+    /// How often each command was run from the palette (persisted in the session)
+    pub usage: HashMap<CommandId, u32>,
 }
@@ -0,0 +1,27 @@
+//! Commit List State
+//!
+//! State for the popup listing the commits of a PR.
+
+use crate::domain_models::PrCommit;
+
+/// Commit list popup state
+#[derive(Debug, Clone, Default)]
+pub struct CommitListState {
+    /// PR whose commits are shown
+    pub pr_number: Option<usize>,
+    /// Commits, oldest first
+    pub commits: Vec<PrCommit>,
+    /// Whether the commits are being fetched
+    pub loading: bool,
+    /// Error message if loading failed
+    pub error: Option<String>,
+    /// Index of the commit under the cursor
+    pub cursor: usize,
+}
+
+impl CommitListState {
+    /// The commit under the cursor
+    pub fn selected_commit(&self) -> Option<&PrCommit> {
+        self.commits.get(self.cursor)
+    }
+}
@@ -3,8 +3,11 @@
 //! State for a reusable confirmation popup with text input.
 //! Used for PR actions that require user confirmation and optional message editing.
 
+use super::MergeBotPlan;
+use crate::actions::Action;
+
 /// The intent of the confirmation - determines what action to execute on confirm
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub enum ConfirmationIntent {
     /// Approve the specified PRs with a message
     Approve { pr_numbers: Vec<u64> },
@@ -14,8 +17,37 @@ pub enum ConfirmationIntent {
     RequestChanges { pr_numbers: Vec<u64> },
     /// Close the specified PRs with a message
     Close { pr_numbers: Vec<u64> },
+    /// Merge the specified PRs, some of which are not ready (failing/pending CI, conflicts)
+    /// or selected PRs are hidden by the current filter
+    Merge {
+        pr_numbers: Vec<u64>,
+        /// PRs whose merge is risky
+        unready: Vec<u64>,
+        /// Summary of why they are not ready: "1 build failed · 1 conflicts: #12, #15",
+        /// empty if all are ready
+        warning: String,
+        /// What happens to selected PRs hidden by the filter, if there are any
+        hidden_note: Option<String>,
+    },
+    /// Start the merge bot with the planned queue
+    StartMergeBot {
+        plan: MergeBotPlan,
+        /// Summary of the skipped PRs: "1 conflicts: #15", empty if none are skipped
+        warning: String,
+    },
+    /// Generic yes/no confirmation that dispatches `on_confirm` when confirmed
+    Request {
+        title: String,
+        message: String,
+        on_confirm: Box<Action>,
+    },
 }
 
+/// Merge choice: merge only the PRs that are ready
+pub const MERGE_READY_ONLY: &str = "ready";
+/// Merge choice: merge all PRs, including the ones that are not ready
+pub const MERGE_ALL: &str = "all";
+
 impl ConfirmationIntent {
     /// Get the PR numbers for this intent
     pub fn pr_numbers(&self) -> &[u64] {
@@ -23,7 +55,9 @@ impl ConfirmationIntent {
             Self::Approve { pr_numbers }
             | Self::Comment { pr_numbers }
             | Self::RequestChanges { pr_numbers }
-            | Self::Close { pr_numbers } => pr_numbers,
+            | Self::Close { pr_numbers }
+            | Self::Merge { pr_numbers, .. } => pr_numbers,
+            Self::StartMergeBot { .. } | Self::Request { .. } => &[],
         }
     }
 
@@ -34,26 +68,80 @@ impl ConfirmationIntent {
             Self::Comment { .. } => "Commenting on",
             Self::RequestChanges { .. } => "Requesting changes on",
             Self::Close { .. } => "Closing",
+            Self::Merge { .. } => "Merging",
+            Self::StartMergeBot { .. } => "Queueing",
+            Self::Request { .. } => "Confirming",
         }
     }
 
     /// Get the title for the popup
-    pub fn popup_title(&self) -> &'static str {
+    pub fn popup_title(&self) -> &str {
         match self {
             Self::Approve { .. } => "Approve Pull Request",
             Self::Comment { .. } => "Comment on Pull Request",
             Self::RequestChanges { .. } => "Request Changes",
             Self::Close { .. } => "Close Pull Request",
+            Self::Merge { .. } => "Merge Pull Request",
+            Self::StartMergeBot { .. } => "Start Merge Bot",
+            Self::Request { title, .. } => title,
         }
     }
 
     /// Get the instruction text
-    pub fn instructions(&self) -> &'static str {
+    pub fn instructions(&self) -> &str {
         match self {
             Self::Approve { .. } => "Enter your approval message:",
             Self::Comment { .. } => "Enter your comment:",
             Self::RequestChanges { .. } => "Enter your change request message:",
             Self::Close { .. } => "Enter a closing comment (optional):",
+            Self::Merge { .. } => "Type 'ready' to merge only ready PRs, or 'all' to merge anyway:",
+            Self::StartMergeBot { .. } => "Start the merge bot with this queue?",
+            Self::Request { message, .. } => message,
+        }
+    }
+
+    /// Get the label of the input field
+    pub fn input_label(&self) -> &'static str {
+        match self {
+            Self::Merge { .. } => "Merge:",
+            _ => "Message:",
+        }
+    }
+
+    /// Whether the popup shows a text input
+    pub fn has_input(&self) -> bool {
+        !matches!(self, Self::StartMergeBot { .. } | Self::Request { .. })
+    }
+
+    /// Get the warning to show above the instructions, if any
+    pub fn warning(&self) -> Option<&str> {
+        match self {
+            Self::Merge { warning, .. } | Self::StartMergeBot { warning, .. }
+                if !warning.is_empty() =>
+            {
+                Some(warning)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get additional detail lines shown below the warning
+    pub fn details(&self) -> Vec<String> {
+        match self {
+            Self::Merge { hidden_note, .. } => hidden_note
+                .iter()
+                .map(|note| format!("⚠ {}", note))
+                .collect(),
+            Self::StartMergeBot { plan, .. } => plan.summary_lines(),
+            _ => vec![],
+        }
+    }
+
+    /// Get the hint shown while the input is invalid
+    pub fn validation_hint(&self) -> &'static str {
+        match self {
+            Self::Merge { .. } => "Type 'ready' or 'all'",
+            _ => "Message is required",
         }
     }
 }
@@ -81,6 +169,13 @@ impl ConfirmationPopupState {
 
     /// Format the target info string (e.g., "PR #123" or "PR #123, #321, #453")
     pub fn target_info(&self) -> String {
+        if let ConfirmationIntent::StartMergeBot { plan, .. } = &self.intent {
+            return format!("{} PR(s)", plan.entries.len());
+        }
+        if let ConfirmationIntent::Request { .. } = &self.intent {
+            return self.repo_context.clone();
+        }
+
         let pr_numbers = self.intent.pr_numbers();
         if pr_numbers.is_empty() {
             return String::new();
@@ -95,12 +190,12 @@ impl ConfirmationPopupState {
     }
 
     /// Get the full title including context
-    pub fn title(&self) -> &'static str {
+    pub fn title(&self) -> &str {
         self.intent.popup_title()
     }
 
     /// Get the instructions text
-    pub fn instructions(&self) -> &'static str {
+    pub fn instructions(&self) -> &str {
         self.intent.instructions()
     }
 
@@ -116,14 +211,21 @@ impl ConfirmationPopupState {
             ConfirmationIntent::Comment { .. } => true,
             // Request changes requires a message
             ConfirmationIntent::RequestChanges { .. } => true,
+            // Merge requires an explicit choice
+            ConfirmationIntent::Merge { .. } => true,
             // Approve and close can have empty messages
             ConfirmationIntent::Approve { .. } | ConfirmationIntent::Close { .. } => false,
+            // Starting the merge bot and generic requests have no message at all
+            ConfirmationIntent::StartMergeBot { .. } | ConfirmationIntent::Request { .. } => false,
         }
     }
 
     /// Check if the form is valid for submission
     pub fn is_valid(&self) -> bool {
-        if self.requires_input() {
+        if let ConfirmationIntent::Merge { .. } = self.intent {
+            let choice = self.input_value.trim();
+            choice == MERGE_READY_ONLY || choice == MERGE_ALL
+        } else if self.requires_input() {
             !self.input_value.trim().is_empty()
         } else {
             true
@@ -203,4 +305,38 @@ mod tests {
         assert!(!state_empty.is_valid()); // Empty comment is invalid
         assert!(state_with_msg.is_valid()); // Non-empty comment is valid
     }
+
+    #[test]
+    fn test_merge_requires_choice() {
+        let merge = ConfirmationIntent::Merge {
+            pr_numbers: vec![1, 2],
+            unready: vec![2],
+            warning: "1 build failed: #2".to_string(),
+            hidden_note: None,
+        };
+        let state = |input: &str| {
+            ConfirmationPopupState::new(merge.clone(), input.to_string(), "owner/repo".to_string())
+        };
+
+        assert!(state(MERGE_READY_ONLY).is_valid());
+        assert!(state(MERGE_ALL).is_valid());
+        assert!(!state("").is_valid());
+        assert!(!state("yes").is_valid());
+    }
+
+    #[test]
+    fn test_merge_with_hidden_selection_only() {
+        let merge = ConfirmationIntent::Merge {
+            pr_numbers: vec![1, 2],
+            unready: vec![],
+            warning: String::new(),
+            hidden_note: Some("1 selected PR is hidden by the filter".to_string()),
+        };
+
+        assert_eq!(merge.warning(), None);
+        assert_eq!(
+            merge.details(),
+            vec!["⚠ 1 selected PR is hidden by the filter"]
+        );
+    }
 }
@@ -1,5 +1,7 @@
 //! Debug Console State
 
+use crate::log_reader::LogLine;
+use log::LevelFilter;
 use std::collections::VecDeque;
 
 /// Maximum number of log lines to keep in the ring buffer
@@ -9,11 +11,17 @@ pub const MAX_LOG_LINES: usize = 10_000;
 #[derive(Debug, Clone)]
 pub struct DebugConsoleState {
     /// Ring buffer of log lines (capped at MAX_LOG_LINES)
-    pub lines: VecDeque<String>,
-    /// Scroll offset (0 = bottom/newest)
+    pub lines: VecDeque<LogLine>,
+    /// Scroll offset within the filtered lines (0 = bottom/newest)
     pub scroll_offset: usize,
     /// Visible height for scroll bounds
     pub visible_height: usize,
+    /// Minimum level of displayed lines
+    pub min_level: LevelFilter,
+    /// Case-insensitive substring displayed lines must contain (empty = no filter)
+    pub filter_text: String,
+    /// Whether the text filter is being edited
+    pub filter_editing: bool,
 }
 
 impl Default for DebugConsoleState {
@@ -22,13 +30,16 @@ impl Default for DebugConsoleState {
             lines: VecDeque::with_capacity(MAX_LOG_LINES),
             scroll_offset: 0,
             visible_height: 0,
+            min_level: LevelFilter::Trace,
+            filter_text: String::new(),
+            filter_editing: false,
         }
     }
 }
 
 impl DebugConsoleState {
     /// Append new lines to the ring buffer, trimming old ones if over capacity
-    pub fn append_lines(&mut self, new_lines: Vec<String>) {
+    pub fn append_lines(&mut self, new_lines: Vec<LogLine>) {
         for line in new_lines {
             self.lines.push_back(line);
             if self.lines.len() > MAX_LOG_LINES {
@@ -36,4 +47,62 @@ impl DebugConsoleState {
             }
         }
     }
+
+    /// Lines passing the level and text filters, oldest first
+    ///
+    /// Lines without a known level are always shown by the level filter.
+    pub fn filtered_lines(&self) -> impl Iterator<Item = &LogLine> {
+        let needle = self.filter_text.to_lowercase();
+        self.lines.iter().filter(move |line| {
+            line.level.is_none_or(|level| level <= self.min_level)
+                && (needle.is_empty() || line.text.to_lowercase().contains(&needle))
+        })
+    }
+
+    /// Number of lines passing the active filters
+    pub fn filtered_len(&self) -> usize {
+        self.filtered_lines().count()
+    }
+}
+
+/// Next minimum level in the cycle trace → debug → info → warn → error → trace
+pub fn next_min_level(level: LevelFilter) -> LevelFilter {
+    match level {
+        LevelFilter::Trace | LevelFilter::Off => LevelFilter::Debug,
+        LevelFilter::Debug => LevelFilter::Info,
+        LevelFilter::Info => LevelFilter::Warn,
+        LevelFilter::Warn => LevelFilter::Error,
+        LevelFilter::Error => LevelFilter::Trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Level;
+
+    fn line(level: Level, text: &str) -> LogLine {
+        LogLine {
+            level: Some(level),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_filtered_lines_by_level_and_text() {
+        let mut state = DebugConsoleState::default();
+        state.append_lines(vec![
+            line(Level::Debug, "loading repo"),
+            line(Level::Warn, "Slow Repo response"),
+            line(Level::Error, "merge failed"),
+        ]);
+        assert_eq!(state.filtered_len(), 3);
+
+        state.min_level = LevelFilter::Warn;
+        assert_eq!(state.filtered_len(), 2);
+
+        state.filter_text = "repo".to_string();
+        let texts: Vec<_> = state.filtered_lines().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["Slow Repo response"]);
+    }
 }
@@ -3,6 +3,7 @@
 //! Wrapper state for the diff viewer panel in gh-pr-lander.
 //! This delegates to gh_diff_viewer's DiffViewerState for the actual diff logic.
 
+use gh_diff_viewer::state::DEFAULT_FILE_TREE_WIDTH;
 use gh_diff_viewer::{DiffHighlighter, DiffViewerState as InnerState, PullRequestDiff};
 
 /// Loading state for the diff viewer
@@ -33,6 +34,10 @@ pub struct DiffViewerState {
     pub pr_title: Option<String>,
     /// Head SHA for API calls (comments)
     pub head_sha: Option<String>,
+    /// File tree width (percent), kept across diff sessions
+    pub file_tree_width: u16,
+    /// First key of a `]`/`[` motion waiting for its second key
+    pub pending_bracket: Option<char>,
 }
 
 impl Default for DiffViewerState {
@@ -44,6 +49,8 @@ impl Default for DiffViewerState {
             pr_number: None,
             pr_title: None,
             head_sha: None,
+            file_tree_width: DEFAULT_FILE_TREE_WIDTH,
+            pending_bracket: None,
         }
     }
 }
@@ -57,6 +64,8 @@ impl Clone for DiffViewerState {
             pr_number: self.pr_number,
             pr_title: self.pr_title.clone(),
             head_sha: self.head_sha.clone(),
+            file_tree_width: self.file_tree_width,
+            pending_bracket: self.pending_bracket,
         }
     }
 }
@@ -75,7 +84,9 @@ impl DiffViewerState {
         pr_title: String,
         head_sha: String,
     ) {
-        self.inner = Some(InnerState::new(diff));
+        let mut inner = InnerState::new(diff);
+        inner.set_file_tree_width(self.file_tree_width);
+        self.inner = Some(inner);
         self.loading = DiffViewerLoadingState::Loaded;
         self.pr_number = Some(pr_number);
         self.pr_title = Some(pr_title);
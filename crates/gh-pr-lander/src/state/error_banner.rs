@@ -0,0 +1,35 @@
+//! Error Banner State
+//!
+//! The most recent error, shown as a banner at the top of the screen until it
+//! expires or is dismissed. Full details stay in the debug console log.
+
+use std::time::{Duration, Instant};
+
+/// How long an error banner stays visible
+pub const ERROR_BANNER_DURATION: Duration = Duration::from_secs(8);
+
+/// An error currently shown in the banner
+#[derive(Debug, Clone)]
+pub struct ErrorBannerState {
+    /// Where the error happened (e.g., "Load", "Merge")
+    pub context: String,
+    /// Human-readable error message
+    pub message: String,
+    /// When the error occurred
+    pub shown_at: Instant,
+}
+
+impl ErrorBannerState {
+    pub fn new(context: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+            message: message.into(),
+            shown_at: Instant::now(),
+        }
+    }
+
+    /// Whether the banner has been shown long enough
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= ERROR_BANNER_DURATION
+    }
+}
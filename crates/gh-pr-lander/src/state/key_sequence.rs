@@ -0,0 +1,35 @@
+//! Key Sequence State
+//!
+//! Tracks a two-key sequence in progress (e.g. `p` pressed, waiting for `m`),
+//! so the UI can hint at the possible follow-up keys.
+
+use crate::keybindings::SEQUENCE_TIMEOUT;
+use std::time::{Duration, Instant};
+
+/// A two-key sequence waiting for its second key
+#[derive(Debug, Clone)]
+pub struct PendingSequenceState {
+    /// First key of the sequence
+    pub key: char,
+    /// When the first key was pressed
+    pub started_at: Instant,
+}
+
+impl PendingSequenceState {
+    pub fn new(key: char) -> Self {
+        Self {
+            key,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Time left before the sequence times out
+    pub fn remaining(&self) -> Duration {
+        SEQUENCE_TIMEOUT.saturating_sub(self.started_at.elapsed())
+    }
+
+    /// Whether the sequence timed out
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
@@ -1,6 +1,8 @@
 //! Main View State
 
-use crate::domain_models::Repository;
+use crate::domain_models::{
+    LoadingState, MaturityState, MergeMethod, MergeableStatus, Pr, Repository, ReviewDecision,
+};
 
 /// Main view state
 #[derive(Debug, Clone, Default)]
@@ -8,6 +10,12 @@ pub struct MainViewState {
     pub selected_repository: usize, // Currently selected repository index
     pub repositories: Vec<Repository>, // List of tracked repositories
     pub repo_data: std::collections::HashMap<usize, RepositoryData>, // PR data per repository
+    pub pr_viewport_height: usize,  // Number of PR rows visible in the table
+    pub goto_pr_input: Option<String>, // "Go to PR number" input, while open
+    pub show_description: bool,     // Whether the PR description panel is visible
+    pub description_scroll: u16,    // Vertical scroll offset of the description panel
+    pub description_width: u16,     // Inner width of the description panel, for wrapping
+    pub merge_method: MergeMethod,  // How PRs are merged (squash by default)
 
     // Session restoration - pending selection to apply after repositories load
     /// Pending repository selection from session (org, name, branch, host)
@@ -17,6 +25,89 @@ pub struct MainViewState {
     pub pending_session_pr_no: Option<usize>,
 }
 
+impl MainViewState {
+    /// The PR under the cursor in the selected repository
+    pub fn focused_pr(&self) -> Option<&Pr> {
+        let repo_data = self.repo_data.get(&self.selected_repository)?;
+        repo_data.prs.get(repo_data.selected_pr)
+    }
+
+    /// Last description scroll offset that still shows the end of the focused
+    /// PR's body, wrapped at the panel width
+    pub fn description_max_scroll(&self) -> u16 {
+        let width = self.description_width.max(1) as usize;
+        let wrapped_lines: usize = self
+            .focused_pr()
+            .map(|pr| {
+                pr.body
+                    .trim()
+                    .lines()
+                    .map(|line| line.chars().count().div_ceil(width).max(1))
+                    .sum()
+            })
+            .unwrap_or(0);
+        wrapped_lines
+            .saturating_sub(1)
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+
+    /// Swap two repositories, keeping their PR data and the selection with them
+    pub fn swap_repositories(&mut self, a: usize, b: usize) {
+        if a == b || a >= self.repositories.len() || b >= self.repositories.len() {
+            return;
+        }
+        self.repositories.swap(a, b);
+
+        // repo_data is keyed by index, so re-key both entries
+        let data_a = self.repo_data.remove(&a);
+        let data_b = self.repo_data.remove(&b);
+        if let Some(data) = data_a {
+            self.repo_data.insert(b, data);
+        }
+        if let Some(data) = data_b {
+            self.repo_data.insert(a, data);
+        }
+
+        if self.selected_repository == a {
+            self.selected_repository = b;
+        } else if self.selected_repository == b {
+            self.selected_repository = a;
+        }
+    }
+
+    /// Move the cursor to the PR selected in the last session, once the
+    /// session's repository is selected and a loaded page contains the PR
+    ///
+    /// The PR is given up once all pages arrived without it (the cursor
+    /// stays where it is, usually at the top).
+    pub fn restore_session_pr(&mut self, repo_idx: usize) {
+        if repo_idx != self.selected_repository || self.pending_session_repo.is_some() {
+            return;
+        }
+        let (Some(pr_no), Some(repo_data)) = (
+            self.pending_session_pr_no,
+            self.repo_data.get_mut(&repo_idx),
+        ) else {
+            return;
+        };
+        if repo_data.loading_state != LoadingState::Loaded {
+            return;
+        }
+        if let Some(pr_idx) = repo_data.find_visible_pr(pr_no) {
+            log::info!("Session: Restoring PR #{} at index {}", pr_no, pr_idx);
+            repo_data.selected_pr = pr_idx;
+            self.pending_session_pr_no = None;
+        } else if !repo_data.loading_pages {
+            log::debug!(
+                "Session: PR #{} not found in repository, keeping the cursor",
+                pr_no
+            );
+            self.pending_session_pr_no = None;
+        }
+    }
+}
+
 /// Data for a single repository (PRs, loading state, etc.)
 #[derive(Debug, Clone, Default)]
 pub struct RepositoryData {
@@ -30,8 +121,206 @@ pub struct RepositoryData {
     pub selected_pr_numbers: std::collections::HashSet<usize>,
     /// Timestamp of last successful load
     pub last_updated: Option<chrono::DateTime<chrono::Local>>,
+    /// Timestamp of the last load attempt, successful or not
+    pub last_attempt: Option<chrono::DateTime<chrono::Local>>,
     /// Current filter for displaying PRs
     pub current_filter: PrFilter,
+    /// Horizontal scroll offset (in chars) of the focused row's title
+    pub title_scroll: usize,
+    /// Snoozed PR numbers with the PR's `updated_at` when it was snoozed
+    pub snoozed: std::collections::HashMap<usize, chrono::DateTime<chrono::Utc>>,
+    /// PR numbers watched for CI completion notifications
+    pub watched: std::collections::HashSet<usize>,
+    /// Whether loading stopped at the PR limit while GitHub has more PRs
+    pub has_more_prs: bool,
+    /// Whether later pages of the PR list are still arriving
+    pub loading_pages: bool,
+    /// Login of the authenticated user on this repository's host, once known
+    pub viewer_login: Option<String>,
+}
+
+impl RepositoryData {
+    /// Whether a PR passes the current filter
+    pub fn matches_filter(&self, pr: &Pr) -> bool {
+        self.current_filter
+            .matches(pr, self.viewer_login.as_deref())
+    }
+
+    /// Indices into `prs` of the PRs matching the current filter
+    pub fn visible_pr_indices(&self) -> Vec<usize> {
+        self.prs
+            .iter()
+            .enumerate()
+            .filter(|(_, pr)| self.matches_filter(pr))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Index of the PR with the given number, if it matches the current filter
+    pub fn find_visible_pr(&self, number: usize) -> Option<usize> {
+        self.visible_pr_indices()
+            .into_iter()
+            .find(|&idx| self.prs[idx].number == number)
+    }
+
+    /// Move the cursor to the first PR matching the current filter
+    pub fn cursor_to_first_visible(&mut self) {
+        self.selected_pr = self.visible_pr_indices().first().copied().unwrap_or(0);
+        self.title_scroll = 0;
+    }
+
+    /// Move the cursor to the last PR matching the current filter
+    pub fn cursor_to_last_visible(&mut self) {
+        self.selected_pr = self.visible_pr_indices().last().copied().unwrap_or(0);
+        self.title_scroll = 0;
+    }
+
+    /// PRs targeted by bulk operations: the selected PRs, or else the PR under the cursor
+    ///
+    /// Selected PRs hidden by the current filter are only included with
+    /// `include_hidden` (the `[pr] bulk_include_hidden` setting).
+    pub fn target_prs(&self, include_hidden: bool) -> Vec<&Pr> {
+        if self.selected_pr_numbers.is_empty() {
+            return self.prs.get(self.selected_pr).into_iter().collect();
+        }
+        self.prs
+            .iter()
+            .filter(|pr| self.selected_pr_numbers.contains(&pr.number))
+            .filter(|pr| include_hidden || self.matches_filter(pr))
+            .collect()
+    }
+
+    /// PRs whose CI logs to open: the selected PRs, or else every visible PR
+    /// with a failed build
+    pub fn failing_build_targets(&self, include_hidden: bool) -> Vec<&Pr> {
+        if !self.selected_pr_numbers.is_empty() {
+            return self.target_prs(include_hidden);
+        }
+        self.prs
+            .iter()
+            .filter(|pr| pr.mergeable == MergeableStatus::BuildFailed)
+            .filter(|pr| self.matches_filter(pr))
+            .collect()
+    }
+
+    /// Number of PRs with a failed build hidden by the current filter
+    pub fn hidden_failing_build_count(&self) -> usize {
+        self.prs
+            .iter()
+            .filter(|pr| pr.mergeable == MergeableStatus::BuildFailed)
+            .filter(|pr| !self.matches_filter(pr))
+            .count()
+    }
+
+    /// Number of selected PRs hidden by the current filter
+    pub fn hidden_selection_count(&self) -> usize {
+        self.prs
+            .iter()
+            .filter(|pr| self.selected_pr_numbers.contains(&pr.number))
+            .filter(|pr| !self.matches_filter(pr))
+            .count()
+    }
+
+    /// Drop selections for PRs that are no longer in the list (merged or closed)
+    ///
+    /// Returns the number of selections removed.
+    pub fn prune_selection(&mut self) -> usize {
+        let before = self.selected_pr_numbers.len();
+        let prs = &self.prs;
+        self.selected_pr_numbers
+            .retain(|number| prs.iter().any(|pr| pr.number == *number));
+        before - self.selected_pr_numbers.len()
+    }
+
+    /// Stop watching PRs that are no longer in the list (merged or closed)
+    pub fn prune_watched(&mut self) {
+        let prs = &self.prs;
+        self.watched
+            .retain(|number| prs.iter().any(|pr| pr.number == *number));
+    }
+
+    /// Whether a PR is snoozed and has not been updated since
+    pub fn is_snoozed(&self, pr: &Pr) -> bool {
+        self.snoozed
+            .get(&pr.number)
+            .is_some_and(|snoozed_at| pr.updated_at <= *snoozed_at)
+    }
+
+    /// Un-snooze PRs that were updated after they were snoozed
+    ///
+    /// Returns the numbers of the PRs that woke up.
+    pub fn prune_snoozed(&mut self) -> Vec<usize> {
+        let woken: Vec<usize> = self
+            .prs
+            .iter()
+            .filter(|pr| {
+                self.snoozed
+                    .get(&pr.number)
+                    .is_some_and(|snoozed_at| pr.updated_at > *snoozed_at)
+            })
+            .map(|pr| pr.number)
+            .collect();
+        for number in &woken {
+            self.snoozed.remove(number);
+        }
+        woken
+    }
+
+    /// Move snoozed PRs to the bottom of the list, keeping the cursor on its PR
+    pub fn sort_snoozed_last(&mut self) {
+        let cursor_pr = self.prs.get(self.selected_pr).map(|pr| pr.number);
+        let snoozed = self.snoozed.clone();
+        self.prs.sort_by_key(|pr| {
+            snoozed
+                .get(&pr.number)
+                .is_some_and(|snoozed_at| pr.updated_at <= *snoozed_at)
+        });
+        if let Some(idx) = cursor_pr.and_then(|n| self.prs.iter().position(|pr| pr.number == n)) {
+            self.selected_pr = idx;
+        }
+    }
+
+    /// Move the cursor by `delta` PRs matching the current filter, clamped to
+    /// the first and last one (no wrapping, unlike `move_cursor`)
+    pub fn move_cursor_by(&mut self, delta: isize) {
+        self.title_scroll = 0;
+        let visible = self.visible_pr_indices();
+        if visible.is_empty() {
+            return;
+        }
+
+        // A cursor on a hidden PR starts from the nearest visible one
+        let current = self.selected_pr;
+        let pos = visible
+            .iter()
+            .position(|&idx| idx >= current)
+            .unwrap_or(visible.len() - 1);
+        let target = pos.saturating_add_signed(delta).min(visible.len() - 1);
+        self.selected_pr = visible[target];
+    }
+
+    /// Move the cursor to the next (or previous) PR matching the current filter,
+    /// wrapping around at either end
+    pub fn move_cursor(&mut self, forward: bool) {
+        self.title_scroll = 0;
+        let visible = self.visible_pr_indices();
+        if visible.is_empty() {
+            return;
+        }
+
+        let current = self.selected_pr;
+        let next = match visible.iter().position(|&idx| idx == current) {
+            Some(pos) if forward => (pos + 1) % visible.len(),
+            Some(pos) => (pos + visible.len() - 1) % visible.len(),
+            // Cursor is on a hidden PR: jump to the nearest visible one
+            None if forward => visible.iter().position(|&idx| idx > current).unwrap_or(0),
+            None => visible
+                .iter()
+                .rposition(|&idx| idx < current)
+                .unwrap_or(visible.len() - 1),
+        };
+        self.selected_pr = visible[next];
+    }
 }
 
 /// PR filter for displaying only matching PRs
@@ -48,6 +337,13 @@ pub enum PrFilter {
     BuildFailed,
     /// Show only PRs authored by the current user
     MyPRs,
+    /// Show only PRs that are ready for review (hide drafts)
+    ReadyForReview,
+    /// Show only draft PRs
+    Drafts,
+    /// Show PRs that need the current user: review requested from them, or
+    /// authored by them with changes requested or a failed build
+    Attention,
     /// Custom text filter (matches title or author)
     Custom(String),
 }
@@ -61,10 +357,74 @@ impl PrFilter {
             Self::NeedsRebase => "Needs Rebase",
             Self::BuildFailed => "Build Failed",
             Self::MyPRs => "My PRs",
+            Self::ReadyForReview => "Ready for Review",
+            Self::Drafts => "Drafts",
+            Self::Attention => "Attention",
             Self::Custom(_) => "Custom",
         }
     }
 
+    /// Check whether a PR passes this filter
+    ///
+    /// `viewer`: login of the authenticated user, if known yet
+    pub fn matches(&self, pr: &Pr, viewer: Option<&str>) -> bool {
+        match self {
+            Self::All => true,
+            Self::ReadyToMerge => pr.mergeable == MergeableStatus::Ready,
+            Self::NeedsRebase => pr.needs_rebase || pr.mergeable == MergeableStatus::NeedsRebase,
+            Self::BuildFailed => pr.mergeable == MergeableStatus::BuildFailed,
+            // Until the user's login is known, nothing is hidden
+            Self::MyPRs => match viewer {
+                Some(login) => pr.author.eq_ignore_ascii_case(login),
+                None => true,
+            },
+            Self::ReadyForReview => pr.maturity != MaturityState::Draft,
+            Self::Drafts => pr.maturity == MaturityState::Draft,
+            // Until the user's login is known, nothing needs their attention
+            Self::Attention => viewer.is_some_and(|login| needs_attention(pr, login)),
+            Self::Custom(query) => {
+                let query = query.to_lowercase();
+                pr.title.to_lowercase().contains(&query)
+                    || pr.author.to_lowercase().contains(&query)
+            }
+        }
+    }
+
+    /// Key persisting this filter in the session (None for `All`)
+    pub fn session_key(&self) -> Option<String> {
+        let key = match self {
+            Self::All => return None,
+            Self::ReadyToMerge => "ready_to_merge",
+            Self::NeedsRebase => "needs_rebase",
+            Self::BuildFailed => "build_failed",
+            Self::MyPRs => "my_prs",
+            Self::ReadyForReview => "ready_for_review",
+            Self::Drafts => "drafts",
+            Self::Attention => "attention",
+            Self::Custom(query) => return Some(format!("custom:{}", query)),
+        };
+        Some(key.to_string())
+    }
+
+    /// Parse a filter persisted with [`PrFilter::session_key`]
+    ///
+    /// Unknown keys (e.g. written by a newer version) yield None.
+    pub fn from_session_key(key: &str) -> Option<Self> {
+        if let Some(query) = key.strip_prefix("custom:") {
+            return Some(Self::Custom(query.to_string()));
+        }
+        match key {
+            "ready_to_merge" => Some(Self::ReadyToMerge),
+            "needs_rebase" => Some(Self::NeedsRebase),
+            "build_failed" => Some(Self::BuildFailed),
+            "my_prs" => Some(Self::MyPRs),
+            "ready_for_review" => Some(Self::ReadyForReview),
+            "drafts" => Some(Self::Drafts),
+            "attention" => Some(Self::Attention),
+            _ => None,
+        }
+    }
+
     /// Cycle to the next filter in the preset sequence
     pub fn next(&self) -> Self {
         match self {
@@ -72,8 +432,398 @@ impl PrFilter {
             Self::ReadyToMerge => Self::NeedsRebase,
             Self::NeedsRebase => Self::BuildFailed,
             Self::BuildFailed => Self::MyPRs,
-            Self::MyPRs => Self::All,
+            Self::MyPRs => Self::ReadyForReview,
+            Self::ReadyForReview => Self::Drafts,
+            Self::Drafts => Self::Attention,
+            Self::Attention => Self::All,
             Self::Custom(_) => Self::All,
         }
     }
 }
+
+/// Whether a PR waits on `login`: their review is requested (GitHub drops
+/// reviewers from the request once they reviewed), or it is their PR and
+/// changes were requested or the build failed
+fn needs_attention(pr: &Pr, login: &str) -> bool {
+    let review_requested = pr
+        .requested_reviewers
+        .iter()
+        .any(|reviewer| reviewer.eq_ignore_ascii_case(login));
+    let own_pr_blocked = pr.author.eq_ignore_ascii_case(login)
+        && (pr.review_decision == ReviewDecision::ChangesRequested
+            || pr.mergeable == MergeableStatus::BuildFailed);
+    review_requested || own_pr_blocked
+}
+
+/// Layout of the rows in the PR table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RowDensity {
+    /// One line per PR with all columns
+    #[default]
+    Compact,
+    /// Two lines per PR: title, then author, age, status and branch
+    Detailed,
+}
+
+impl RowDensity {
+    /// Get the display label for this density
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Compact => "Compact",
+            Self::Detailed => "Detailed",
+        }
+    }
+
+    /// Terminal lines taken by a single PR row
+    pub fn row_height(self) -> u16 {
+        match self {
+            Self::Compact => 1,
+            Self::Detailed => 2,
+        }
+    }
+
+    /// Cycle to the next density
+    pub fn next(self) -> Self {
+        match self {
+            Self::Compact => Self::Detailed,
+            Self::Detailed => Self::Compact,
+        }
+    }
+}
+
+/// Column of the PR table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrColumn {
+    /// Selection marker and PR number
+    Number,
+    /// Title (and the details line of detailed rows)
+    Title,
+    Author,
+    /// Lines added and deleted
+    Delta,
+    /// Draft marker
+    Draft,
+    /// Review decision
+    Review,
+    /// Time since the last update
+    Age,
+    /// Mergeable status
+    Status,
+}
+
+impl PrColumn {
+    /// All columns in their default order
+    pub const ALL: [PrColumn; 8] = [
+        Self::Number,
+        Self::Title,
+        Self::Author,
+        Self::Delta,
+        Self::Draft,
+        Self::Review,
+        Self::Age,
+        Self::Status,
+    ];
+
+    /// Name used in the config (`[pr] columns`)
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Number => "number",
+            Self::Title => "title",
+            Self::Author => "author",
+            Self::Delta => "delta",
+            Self::Draft => "draft",
+            Self::Review => "review",
+            Self::Age => "age",
+            Self::Status => "status",
+        }
+    }
+
+    /// Whether detailed rows show this column on their second line instead
+    pub fn on_details_line(self) -> bool {
+        matches!(self, Self::Author | Self::Age | Self::Status)
+    }
+
+    /// Parse the configured columns, keeping their order
+    ///
+    /// Unknown and repeated names are logged and skipped. An empty list (or
+    /// one without any known column) shows all columns.
+    pub fn parse_list(names: &[String]) -> Vec<PrColumn> {
+        let mut columns = Vec::new();
+        for name in names {
+            let column = Self::ALL
+                .into_iter()
+                .find(|column| column.name().eq_ignore_ascii_case(name.trim()));
+            match column {
+                Some(column) if columns.contains(&column) => {
+                    log::warn!("PR table column \"{}\" listed twice, ignoring", name);
+                }
+                Some(column) => columns.push(column),
+                None => log::warn!("Unknown PR table column \"{}\", ignoring", name),
+            }
+        }
+        if columns.is_empty() {
+            if !names.is_empty() {
+                log::warn!("No valid PR table columns configured, showing all");
+            }
+            return Self::ALL.to_vec();
+        }
+        columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: usize, maturity: MaturityState) -> Pr {
+        let mut pr = Pr::new(number, format!("PR {}", number), "author", "sha");
+        pr.maturity = maturity;
+        pr
+    }
+
+    fn repo_data(filter: PrFilter) -> RepositoryData {
+        RepositoryData {
+            prs: vec![
+                pr(1, MaturityState::Ready),
+                pr(2, MaturityState::Draft),
+                pr(3, MaturityState::Ready),
+                pr(4, MaturityState::Draft),
+            ],
+            current_filter: filter,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_draft_filters() {
+        assert_eq!(repo_data(PrFilter::Drafts).visible_pr_indices(), vec![1, 3]);
+        assert_eq!(
+            repo_data(PrFilter::ReadyForReview).visible_pr_indices(),
+            vec![0, 2]
+        );
+        assert_eq!(
+            repo_data(PrFilter::All).visible_pr_indices(),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_cycle_includes_draft_filters() {
+        let mut filter = PrFilter::All;
+        let mut seen = vec![];
+        loop {
+            filter = filter.next();
+            if filter == PrFilter::All {
+                break;
+            }
+            seen.push(filter.clone());
+        }
+        assert!(seen.contains(&PrFilter::MyPRs));
+        assert!(seen.contains(&PrFilter::ReadyForReview));
+        assert!(seen.contains(&PrFilter::Drafts));
+        assert!(seen.contains(&PrFilter::Attention));
+    }
+
+    #[test]
+    fn test_move_cursor_skips_hidden_prs() {
+        let mut data = repo_data(PrFilter::Drafts);
+        data.cursor_to_first_visible();
+        assert_eq!(data.selected_pr, 1);
+
+        data.move_cursor(true);
+        assert_eq!(data.selected_pr, 3);
+        data.move_cursor(true);
+        assert_eq!(data.selected_pr, 1); // wraps
+
+        data.move_cursor(false);
+        assert_eq!(data.selected_pr, 3); // wraps backwards
+
+        // Cursor on a hidden PR jumps to the nearest visible one
+        data.selected_pr = 2;
+        data.move_cursor(true);
+        assert_eq!(data.selected_pr, 3);
+        data.selected_pr = 2;
+        data.move_cursor(false);
+        assert_eq!(data.selected_pr, 1);
+    }
+
+    #[test]
+    fn test_row_density_cycles() {
+        let density = RowDensity::default();
+        assert_eq!(density.row_height(), 1);
+        assert_eq!(density.next(), RowDensity::Detailed);
+        assert_eq!(density.next().row_height(), 2);
+        assert_eq!(density.next().next(), RowDensity::Compact);
+    }
+
+    fn numbers(prs: &[&Pr]) -> Vec<usize> {
+        prs.iter().map(|pr| pr.number).collect()
+    }
+
+    #[test]
+    fn test_target_prs_skip_hidden_selection_by_default() {
+        let mut data = repo_data(PrFilter::Drafts);
+        // Selected before the drafts filter was applied
+        data.selected_pr_numbers = [1, 2, 4].into_iter().collect();
+
+        assert_eq!(data.hidden_selection_count(), 1);
+        assert_eq!(numbers(&data.target_prs(false)), vec![2, 4]);
+        assert_eq!(numbers(&data.target_prs(true)), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_target_prs_with_only_hidden_selection() {
+        let mut data = repo_data(PrFilter::Drafts);
+        data.selected_pr = 1;
+        data.selected_pr_numbers = [1, 3].into_iter().collect();
+
+        // The selection still wins over the cursor PR
+        assert_eq!(data.hidden_selection_count(), 2);
+        assert!(data.target_prs(false).is_empty());
+        assert_eq!(numbers(&data.target_prs(true)), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_failing_build_targets() {
+        let mut data = repo_data(PrFilter::ReadyForReview);
+        data.prs[0].mergeable = MergeableStatus::BuildFailed;
+        data.prs[1].mergeable = MergeableStatus::BuildFailed; // hidden draft
+        data.prs[2].mergeable = MergeableStatus::Ready;
+
+        assert_eq!(numbers(&data.failing_build_targets(false)), vec![1]);
+        assert_eq!(data.hidden_failing_build_count(), 1);
+
+        // The selection wins, whatever its build state
+        data.selected_pr_numbers = [3].into_iter().collect();
+        assert_eq!(numbers(&data.failing_build_targets(false)), vec![3]);
+    }
+
+    #[test]
+    fn test_target_prs_fall_back_to_cursor() {
+        let mut data = repo_data(PrFilter::Drafts);
+        data.selected_pr = 3;
+
+        assert_eq!(data.hidden_selection_count(), 0);
+        assert_eq!(numbers(&data.target_prs(false)), vec![4]);
+    }
+
+    #[test]
+    fn test_find_visible_pr_respects_filter() {
+        let data = repo_data(PrFilter::Drafts);
+        assert_eq!(data.find_visible_pr(4), Some(3));
+        assert_eq!(data.find_visible_pr(3), None); // hidden by the filter
+        assert_eq!(data.find_visible_pr(42), None);
+    }
+
+    #[test]
+    fn test_snoozed_prs_sort_last_and_wake_up_on_update() {
+        let mut data = repo_data(PrFilter::All);
+        data.selected_pr = 2; // PR 3
+        data.snoozed.insert(1, data.prs[0].updated_at);
+        data.sort_snoozed_last();
+
+        let numbers: Vec<usize> = data.prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, vec![2, 3, 4, 1]);
+        assert_eq!(data.prs[data.selected_pr].number, 3);
+        assert!(data.is_snoozed(&data.prs[3]));
+
+        // Nothing changed yet
+        assert!(data.prune_snoozed().is_empty());
+
+        // An update after snoozing wakes the PR up
+        data.prs[3].updated_at += chrono::Duration::minutes(1);
+        assert!(!data.is_snoozed(&data.prs[3]));
+        assert_eq!(data.prune_snoozed(), vec![1]);
+        assert!(data.snoozed.is_empty());
+    }
+
+    #[test]
+    fn test_filter_session_key_round_trip() {
+        let filters = [
+            PrFilter::ReadyToMerge,
+            PrFilter::NeedsRebase,
+            PrFilter::BuildFailed,
+            PrFilter::MyPRs,
+            PrFilter::ReadyForReview,
+            PrFilter::Drafts,
+            PrFilter::Attention,
+            PrFilter::Custom("fix: parser".to_string()),
+        ];
+        for filter in filters {
+            let key = filter.session_key().unwrap();
+            assert_eq!(PrFilter::from_session_key(&key), Some(filter));
+        }
+        assert_eq!(PrFilter::All.session_key(), None);
+        assert_eq!(PrFilter::from_session_key("from_the_future"), None);
+    }
+
+    fn authored_by(author: &str) -> Pr {
+        Pr::new(1, "PR", author, "sha")
+    }
+
+    #[test]
+    fn test_attention_when_review_requested() {
+        let mut pr = authored_by("alice");
+        assert!(!PrFilter::Attention.matches(&pr, Some("me")));
+        pr.requested_reviewers = vec!["Me".to_string()];
+        assert!(PrFilter::Attention.matches(&pr, Some("me")));
+    }
+
+    #[test]
+    fn test_attention_when_changes_requested_on_own_pr() {
+        let mut pr = authored_by("me");
+        assert!(!PrFilter::Attention.matches(&pr, Some("me")));
+        pr.review_decision = ReviewDecision::ChangesRequested;
+        assert!(PrFilter::Attention.matches(&pr, Some("me")));
+        // Someone else's PR with changes requested is not my business
+        pr.author = "alice".to_string();
+        assert!(!PrFilter::Attention.matches(&pr, Some("me")));
+    }
+
+    #[test]
+    fn test_attention_when_build_failed_on_own_pr() {
+        let mut pr = authored_by("me");
+        pr.mergeable = MergeableStatus::BuildFailed;
+        assert!(PrFilter::Attention.matches(&pr, Some("me")));
+        pr.author = "alice".to_string();
+        assert!(!PrFilter::Attention.matches(&pr, Some("me")));
+    }
+
+    #[test]
+    fn test_viewer_dependent_filters_without_login() {
+        let mut pr = authored_by("alice");
+        pr.requested_reviewers = vec!["me".to_string()];
+        assert!(!PrFilter::Attention.matches(&pr, None));
+        assert!(PrFilter::MyPRs.matches(&pr, None));
+        assert!(!PrFilter::MyPRs.matches(&pr, Some("me")));
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_columns_keep_configured_order() {
+        assert_eq!(
+            PrColumn::parse_list(&names(&["status", "Number", "title"])),
+            vec![PrColumn::Status, PrColumn::Number, PrColumn::Title]
+        );
+    }
+
+    #[test]
+    fn test_columns_skip_unknown_and_repeated_names() {
+        assert_eq!(
+            PrColumn::parse_list(&names(&["number", "labels", "number", "age"])),
+            vec![PrColumn::Number, PrColumn::Age]
+        );
+    }
+
+    #[test]
+    fn test_columns_default_to_all() {
+        assert_eq!(PrColumn::parse_list(&[]), PrColumn::ALL.to_vec());
+        assert_eq!(
+            PrColumn::parse_list(&names(&["labels"])),
+            PrColumn::ALL.to_vec()
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Merge Bot State
 
+use crate::domain_models::{MergeableStatus, Pr};
+
 /// Merge bot state
 #[derive(Debug, Clone, Default)]
 pub struct MergeBotState {
@@ -39,3 +41,145 @@ pub enum MergeBotStatus {
     /// Failed to merge
     Failed,
 }
+
+/// What the merge bot will do with a queued PR, decided before it starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeBotDecision {
+    /// Mergeable right away
+    Merge,
+    /// Branch is behind and gets rebased before merging
+    RebaseFirst,
+    /// CI is still running (or unknown), wait for it before merging
+    WaitForCi,
+    /// Blocked by failing CI, conflicts or reviews - skipped
+    Skip,
+}
+
+impl MergeBotDecision {
+    /// Decide what to do with a PR in the given mergeable state
+    pub fn for_status(status: MergeableStatus) -> Self {
+        match status {
+            MergeableStatus::Ready => Self::Merge,
+            MergeableStatus::NeedsRebase => Self::RebaseFirst,
+            MergeableStatus::BuildFailed
+            | MergeableStatus::Conflicted
+            | MergeableStatus::Blocked => Self::Skip,
+            MergeableStatus::Unknown
+            | MergeableStatus::Checking
+            | MergeableStatus::Rebasing
+            | MergeableStatus::Merging => Self::WaitForCi,
+        }
+    }
+
+    /// Get the display label for this decision
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Merge => "Merge now",
+            Self::RebaseFirst => "Rebase first",
+            Self::WaitForCi => "Wait for CI",
+            Self::Skip => "Skip (blocked)",
+        }
+    }
+}
+
+/// Dry-run summary of a merge bot queue, shown for confirmation before the bot starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeBotPlan {
+    /// Queued PRs with their mergeable state and the bot's decision
+    pub entries: Vec<(u64, MergeableStatus, MergeBotDecision)>,
+}
+
+impl MergeBotPlan {
+    /// Plan the queue for the given PRs
+    pub fn from_prs<'a>(prs: impl IntoIterator<Item = &'a Pr>) -> Self {
+        let entries = prs
+            .into_iter()
+            .map(|pr| {
+                (
+                    pr.number as u64,
+                    pr.mergeable,
+                    MergeBotDecision::for_status(pr.mergeable),
+                )
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// All queued PR numbers
+    pub fn pr_numbers(&self) -> Vec<u64> {
+        self.entries.iter().map(|(pr, _, _)| *pr).collect()
+    }
+
+    /// PR numbers the bot will process (everything but the skipped ones)
+    pub fn runnable(&self) -> Vec<u64> {
+        self.entries
+            .iter()
+            .filter(|(_, _, decision)| *decision != MergeBotDecision::Skip)
+            .map(|(pr, _, _)| *pr)
+            .collect()
+    }
+
+    /// PR numbers with the given decision
+    pub fn with_decision(&self, decision: MergeBotDecision) -> Vec<u64> {
+        self.entries
+            .iter()
+            .filter(|(_, _, d)| *d == decision)
+            .map(|(pr, _, _)| *pr)
+            .collect()
+    }
+
+    /// One line per non-empty decision: "Merge now: #1, #2"
+    pub fn summary_lines(&self) -> Vec<String> {
+        [
+            MergeBotDecision::Merge,
+            MergeBotDecision::RebaseFirst,
+            MergeBotDecision::WaitForCi,
+            MergeBotDecision::Skip,
+        ]
+        .iter()
+        .filter_map(|decision| {
+            let numbers: Vec<String> = self
+                .with_decision(*decision)
+                .iter()
+                .map(|pr| format!("#{}", pr))
+                .collect();
+            (!numbers.is_empty()).then(|| format!("{}: {}", decision.label(), numbers.join(", ")))
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: usize, mergeable: MergeableStatus) -> Pr {
+        let mut pr = Pr::new(number, format!("PR {}", number), "author", "sha");
+        pr.mergeable = mergeable;
+        pr
+    }
+
+    #[test]
+    fn test_plan_classifies_queue() {
+        let prs = [
+            pr(1, MergeableStatus::Ready),
+            pr(2, MergeableStatus::NeedsRebase),
+            pr(3, MergeableStatus::Conflicted),
+            pr(4, MergeableStatus::Checking),
+            pr(5, MergeableStatus::Ready),
+        ];
+        let plan = MergeBotPlan::from_prs(&prs);
+
+        assert_eq!(plan.pr_numbers(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(plan.runnable(), vec![1, 2, 4, 5]);
+        assert_eq!(
+            plan.summary_lines(),
+            vec![
+                "Merge now: #1, #5",
+                "Rebase first: #2",
+                "Wait for CI: #4",
+                "Skip (blocked): #3",
+            ]
+        );
+    }
+}
@@ -2,30 +2,47 @@
 //!
 //! Contains all state types used by the application, organized by feature.
 
+mod action_history;
 mod add_repo;
 mod app;
 mod build_log;
 mod command_palette;
+mod commit_list;
 mod confirmation_popup;
 mod debug_console;
 mod diff_viewer;
+mod error_banner;
 mod key_bindings;
+mod key_sequence;
 mod main_view;
 mod merge_bot;
+mod setup_wizard;
+mod spinner;
 mod splash;
 mod status_bar;
+mod toast;
 
+pub use action_history::{ActionHistoryEntry, ActionHistoryState};
 pub use add_repo::{AddRepoField, AddRepoFormState};
 pub use app::AppState;
 pub use build_log::{
     BuildLogJobMetadata, BuildLogJobStatus, BuildLogLoadingState, BuildLogPrContext, BuildLogState,
+    LOG_LINE_PREFIX,
 };
 pub use command_palette::CommandPaletteState;
-pub use confirmation_popup::{ConfirmationIntent, ConfirmationPopupState};
-pub use debug_console::DebugConsoleState;
+pub use commit_list::CommitListState;
+pub use confirmation_popup::{
+    ConfirmationIntent, ConfirmationPopupState, MERGE_ALL, MERGE_READY_ONLY,
+};
+pub use debug_console::{next_min_level, DebugConsoleState};
 pub use diff_viewer::DiffViewerState;
+pub use error_banner::{ErrorBannerState, ERROR_BANNER_DURATION};
 pub use key_bindings::KeyBindingsPanelState;
-pub use main_view::{MainViewState, PrFilter, RepositoryData};
-pub use merge_bot::MergeBotState;
+pub use key_sequence::PendingSequenceState;
+pub use main_view::{MainViewState, PrColumn, PrFilter, RepositoryData, RowDensity};
+pub use merge_bot::{MergeBotDecision, MergeBotEntry, MergeBotPlan, MergeBotState, MergeBotStatus};
+pub use setup_wizard::SetupWizardState;
+pub use spinner::SpinnerState;
 pub use splash::SplashState;
 pub use status_bar::{StatusBarState, StatusKind, StatusMessage};
+pub use toast::ToastState;
@@ -0,0 +1,18 @@
+//! Setup Wizard State
+//!
+//! State for the first-run wizard shown when no GitHub token is found.
+
+/// Setup wizard state
+#[derive(Debug, Clone, Default)]
+pub struct SetupWizardState {
+    /// Host a token is missing for
+    pub host: String,
+    /// Host-specific env var that would provide the token
+    pub env_key: String,
+    /// Why each token source failed in the last lookup
+    pub attempts: Vec<String>,
+    /// Whether a token lookup is in progress
+    pub checking: bool,
+    /// Outcome of the last attempt, if it failed
+    pub error: Option<String>,
+}
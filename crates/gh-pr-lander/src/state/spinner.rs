@@ -0,0 +1,53 @@
+//! Spinner State
+//!
+//! Elapsed-time counter driving the loading spinner animation.
+
+use std::time::Duration;
+
+/// Braille spinner frames
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// How long each spinner frame is shown
+const FRAME_DURATION: Duration = Duration::from_millis(80);
+
+/// State for the loading spinner
+#[derive(Debug, Clone, Default)]
+pub struct SpinnerState {
+    /// Time elapsed since the application started (updated every frame)
+    pub elapsed: Duration,
+}
+
+impl SpinnerState {
+    /// Frame index for the given elapsed time
+    pub fn frame_index_at(elapsed: Duration) -> usize {
+        (elapsed.as_millis() / FRAME_DURATION.as_millis()) as usize % FRAMES.len()
+    }
+
+    /// Current spinner frame
+    pub fn frame(&self) -> &'static str {
+        FRAMES[Self::frame_index_at(self.elapsed)]
+    }
+
+    /// Whether advancing to `elapsed` would show a different frame
+    pub fn needs_update(&self, elapsed: Duration) -> bool {
+        Self::frame_index_at(elapsed) != Self::frame_index_at(self.elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_advance_and_wrap() {
+        let spinner = SpinnerState::default();
+        assert_eq!(spinner.frame(), "⠋");
+        assert!(!spinner.needs_update(Duration::from_millis(79)));
+        assert!(spinner.needs_update(Duration::from_millis(80)));
+
+        let spinner = SpinnerState {
+            elapsed: FRAME_DURATION * FRAMES.len() as u32,
+        };
+        assert_eq!(spinner.frame(), "⠋");
+    }
+}
@@ -0,0 +1,101 @@
+//! Toast State
+//!
+//! Transient notifications for the outcome of the most recent operations
+//! ("Merged PR #42"). Unlike the error banner, results queue up and are shown
+//! one after another, each for a few seconds.
+
+use crate::domain_models::TaskStatus;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How long a single toast stays visible
+pub const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// Maximum number of toasts waiting to be shown (the oldest are dropped)
+const MAX_QUEUED_TOASTS: usize = 5;
+
+/// A single toast notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Outcome message and its status type
+    pub status: TaskStatus,
+    /// When the toast became visible (None while still queued)
+    pub shown_at: Option<Instant>,
+}
+
+/// Queue of toasts - the front one is currently visible
+#[derive(Debug, Clone, Default)]
+pub struct ToastState {
+    pub queue: VecDeque<Toast>,
+}
+
+impl ToastState {
+    /// Queue a toast, showing it right away if nothing else is visible
+    pub fn push(&mut self, status: TaskStatus) {
+        // Drop the oldest waiting toast, but never the visible one
+        if self.queue.len() > MAX_QUEUED_TOASTS {
+            self.queue.remove(1);
+        }
+        let shown_at = self.queue.is_empty().then(Instant::now);
+        self.queue.push_back(Toast { status, shown_at });
+    }
+
+    /// The currently visible toast
+    pub fn current(&self) -> Option<&Toast> {
+        self.queue.front()
+    }
+
+    /// Whether the visible toast has been shown long enough
+    pub fn is_expired(&self) -> bool {
+        self.current()
+            .and_then(|toast| toast.shown_at)
+            .is_some_and(|shown_at| shown_at.elapsed() >= TOAST_DURATION)
+    }
+
+    /// Hide the visible toast and show the next queued one
+    pub fn dismiss(&mut self) {
+        self.queue.pop_front();
+        if let Some(next) = self.queue.front_mut() {
+            next.shown_at = Some(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_queue_instead_of_clobbering() {
+        let mut toasts = ToastState::default();
+        toasts.push(TaskStatus::success("Merged PR #42"));
+        toasts.push(TaskStatus::error("Rebase failed: conflict"));
+
+        let current = toasts.current().unwrap();
+        assert_eq!(current.status.message, "Merged PR #42");
+        assert!(current.shown_at.is_some());
+        assert!(toasts.queue[1].shown_at.is_none());
+        assert!(!toasts.is_expired());
+
+        toasts.dismiss();
+        let current = toasts.current().unwrap();
+        assert_eq!(current.status.message, "Rebase failed: conflict");
+        assert!(current.shown_at.is_some());
+
+        toasts.dismiss();
+        assert!(toasts.current().is_none());
+    }
+
+    #[test]
+    fn test_queue_drops_oldest_waiting_toast() {
+        let mut toasts = ToastState::default();
+        for i in 0..10 {
+            toasts.push(TaskStatus::success(format!("toast {}", i)));
+        }
+
+        assert_eq!(toasts.queue.len(), MAX_QUEUED_TOASTS + 1);
+        // The visible toast is kept, the newest ones win
+        assert_eq!(toasts.queue[0].status.message, "toast 0");
+        assert_eq!(toasts.queue.back().unwrap().status.message, "toast 9");
+    }
+}
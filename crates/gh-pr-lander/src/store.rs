@@ -8,12 +8,15 @@ use crate::state::AppState;
 /// The store only handles reducer logic on the main thread.
 pub struct Store {
     state: AppState,
+    /// Set when an action was reduced since the last render
+    dirty: bool,
 }
 
 impl Store {
     pub fn new(initial_state: AppState) -> Self {
         Self {
             state: initial_state,
+            dirty: true,
         }
     }
 
@@ -31,7 +34,19 @@ impl Store {
     /// Process an action through reducer ONLY (no middleware)
     ///
     /// Middleware runs on background thread, so this is just reducer logic.
+    /// Every action is recorded in the action history when it is enabled.
     pub fn dispatch(&mut self, action: Action) {
+        if self.state.action_history.enabled {
+            self.state
+                .action_history
+                .record(&action, chrono::Local::now());
+        }
         self.state = reduce(self.state.clone(), &action);
+        self.dirty = true;
+    }
+
+    /// Whether state may have changed since the last call, resetting the flag
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
     }
 }
@@ -0,0 +1,30 @@
+//! Terminal background detection
+//!
+//! Queries the terminal's background color (OSC 11) to choose a readable
+//! default theme. Must run before entering the alternate screen.
+
+use std::time::Duration;
+
+/// How long to wait for the terminal to answer the background color query
+const DETECTION_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Name of the built-in theme matching the terminal background
+///
+/// Returns `"light"` for light backgrounds and `"dark"` otherwise, including
+/// when the terminal doesn't respond within the timeout.
+pub fn detect_theme_name() -> &'static str {
+    match termbg::theme(DETECTION_TIMEOUT) {
+        Ok(termbg::Theme::Light) => {
+            log::info!("Detected light terminal background");
+            "light"
+        }
+        Ok(termbg::Theme::Dark) => {
+            log::info!("Detected dark terminal background");
+            "dark"
+        }
+        Err(e) => {
+            log::debug!("Terminal background detection failed: {:?}", e);
+            "dark"
+        }
+    }
+}
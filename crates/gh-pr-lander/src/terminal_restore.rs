@@ -0,0 +1,122 @@
+//! Terminal setup and restoration
+//!
+//! Restoring the terminal is needed on every way out of the app: normal exit,
+//! panics (the default handler would print into the alternate screen in raw
+//! mode) and termination signals sent from outside. The same goes for the
+//! exit hook, which persists state (e.g. the session) before the app ends.
+
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::{
+    cursor::Show,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::Terminal;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+type ExitHook = Box<dyn Fn() + Send + Sync>;
+
+/// Work to finish on every way out of the app
+static EXIT_HOOK: OnceLock<ExitHook> = OnceLock::new();
+/// Whether the exit hook already ran
+static EXIT_HOOK_RAN: AtomicBool = AtomicBool::new(false);
+
+/// Register the work to run before the app exits (normally, on panics and
+/// on termination signals)
+pub fn set_exit_hook(hook: impl Fn() + Send + Sync + 'static) {
+    if EXIT_HOOK.set(Box::new(hook)).is_err() {
+        log::warn!("Exit hook already set, ignoring");
+    }
+}
+
+/// Run the registered exit hook
+///
+/// Safe to call more than once, the hook only runs the first time.
+pub fn run_exit_hook() {
+    if EXIT_HOOK_RAN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if let Some(hook) = EXIT_HOOK.get() {
+        hook();
+    }
+}
+
+/// Leave raw mode and the alternate screen, and show the cursor again
+///
+/// Safe to call more than once.
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        Show
+    )
+}
+
+/// Hand the terminal to `run` (e.g. an interactive child process) and take
+/// it back afterwards, forcing a full redraw
+pub fn suspend<T>(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    run: impl FnOnce() -> T,
+) -> io::Result<T> {
+    restore()?;
+    let result = run();
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+    Ok(result)
+}
+
+/// Run the exit hook and restore the terminal before the default panic
+/// handler prints the message
+///
+/// Must be called from the main (UI) thread. Panics on other threads (e.g. a
+/// failing background task) don't end the app: they are only logged, so the
+/// terminal stays set up and the exit hook still runs when the app ends.
+pub fn install_panic_hook() {
+    let main_thread = std::thread::current().id();
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().id() != main_thread {
+            log::error!(
+                "Panic in thread '{}': {}",
+                std::thread::current().name().unwrap_or("<unnamed>"),
+                info
+            );
+            return;
+        }
+        run_exit_hook();
+        let _ = restore();
+        default_hook(info);
+    }));
+}
+
+/// Run the exit hook, restore the terminal and exit on SIGINT/SIGTERM sent
+/// from outside the app
+///
+/// (Ctrl+C inside the app arrives as a key event in raw mode, not as a signal.)
+#[cfg(unix)]
+pub fn install_signal_handler() -> io::Result<()> {
+    use signal_hook::consts::{SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            log::warn!("Received signal {}, restoring terminal and exiting", signal);
+            run_exit_hook();
+            let _ = restore();
+            std::process::exit(128 + signal);
+        }
+    });
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn install_signal_handler() -> io::Result<()> {
+    Ok(())
+}
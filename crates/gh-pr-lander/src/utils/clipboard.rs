@@ -0,0 +1,51 @@
+//! Clipboard utilities
+//!
+//! Copies text to the system clipboard. Failures (e.g. no display server in a
+//! headless session) are returned as errors instead of panicking.
+
+use regex::Regex;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+thread_local! {
+    /// Clipboard handle, kept alive so that X11/Wayland can keep serving
+    /// the copied content after `copy_text` returns
+    static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = const { RefCell::new(None) };
+}
+
+/// Copy text to the system clipboard
+pub fn copy_text(text: &str) -> Result<(), String> {
+    CLIPBOARD.with(|cell| {
+        let mut clipboard = cell.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = Some(
+                arboard::Clipboard::new()
+                    .map_err(|e| format!("Clipboard is not available: {}", e))?,
+            );
+        }
+        clipboard
+            .as_mut()
+            .expect("clipboard was just initialized")
+            .set_text(text.to_string())
+            .map_err(|e| format!("Failed to copy to clipboard: {}", e))
+    })
+}
+
+/// Remove ANSI escape sequences from text
+pub fn strip_ansi(text: &str) -> String {
+    static ANSI: OnceLock<Regex> = OnceLock::new();
+    let re = ANSI
+        .get_or_init(|| Regex::new(r"\x1b\[[0-9;?]*[ -/]*[@-~]").expect("valid ANSI escape regex"));
+    re.replace_all(text, "").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[31merror:\x1b[0m failed"), "error: failed");
+        assert_eq!(strip_ansi("plain text"), "plain text");
+    }
+}
@@ -0,0 +1,23 @@
+//! GitHub CLI authentication
+//!
+//! Runs the interactive `gh auth login` flow for the setup wizard.
+
+use std::io;
+use std::process::Command;
+
+/// Run `gh auth login` for `host` with the terminal's stdio
+///
+/// Blocks until `gh` exits; the caller has to hand over the terminal first.
+pub fn login(host: &str) -> Result<(), String> {
+    match Command::new("gh")
+        .args(["auth", "login", "--hostname", host])
+        .status()
+    {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("gh auth login failed ({})", status)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Err("GitHub CLI (gh) not found, install it from https://cli.github.com".to_string())
+        }
+        Err(e) => Err(format!("Failed to run gh auth login: {}", e)),
+    }
+}
@@ -0,0 +1,316 @@
+//! Markdown rendering
+//!
+//! Converts the markdown of PR descriptions and comments into styled
+//! `ratatui` text. Supports headings, bullet and numbered lists, fenced code
+//! blocks, inline code, bold, italic and links. Anything else is kept as
+//! plain text, so unsupported or malformed markdown still reads fine.
+
+use gh_pr_lander_theme::Theme;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// Render markdown into styled lines
+pub fn render_markdown(markdown: &str, theme: &Theme) -> Text<'static> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in markdown.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", raw),
+                code_style(theme),
+            )));
+            continue;
+        }
+
+        lines.push(render_line(raw, theme));
+    }
+
+    Text::from(lines)
+}
+
+/// Render a single line outside of code blocks
+fn render_line(raw: &str, theme: &Theme) -> Line<'static> {
+    let trimmed = raw.trim_start();
+    let indent = &raw[..raw.len() - trimmed.len()];
+    let mut spans = Vec::new();
+
+    if let Some((level, heading)) = heading(trimmed) {
+        let style = heading_style(theme, level);
+        parse_inline(heading, style, theme, &mut spans);
+    } else if let Some(item) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))
+    {
+        spans.push(Span::styled(format!("{}• ", indent), theme.accent()));
+        parse_inline(item, theme.text(), theme, &mut spans);
+    } else if let Some((number, item)) = numbered_item(trimmed) {
+        spans.push(Span::styled(
+            format!("{}{}. ", indent, number),
+            theme.accent(),
+        ));
+        parse_inline(item, theme.text(), theme, &mut spans);
+    } else {
+        parse_inline(raw, theme.text(), theme, &mut spans);
+    }
+
+    Line::from(spans)
+}
+
+/// Heading level and text of an ATX heading ("## Title")
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|text| (level, text.trim_end_matches('#').trim()))
+}
+
+/// Number and text of a numbered list item ("1. Item" or "1) Item")
+fn numbered_item(line: &str) -> Option<(&str, &str)> {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = &line[digits..];
+    let item = rest
+        .strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))?;
+    Some((&line[..digits], item))
+}
+
+/// Parse inline markup, appending spans styled on top of `base`
+fn parse_inline(text: &str, base: Style, theme: &Theme, spans: &mut Vec<Span<'static>>) {
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        // Inline code: `code`
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                flush(&mut plain, base, spans);
+                spans.push(Span::styled(rest[1..=end].to_string(), code_style(theme)));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+
+        // Bold: **text** or __text__
+        if rest.starts_with("**") || rest.starts_with("__") {
+            let delimiter = &rest[..2];
+            if let Some(end) = rest[2..].find(delimiter).filter(|&end| end > 0) {
+                flush(&mut plain, base, spans);
+                let inner = &rest[2..2 + end];
+                parse_inline(inner, base.add_modifier(Modifier::BOLD), theme, spans);
+                rest = &rest[end + 4..];
+                continue;
+            }
+        }
+
+        // Italic: *text* or _text_ (underscores only at word starts, so
+        // snake_case identifiers stay intact)
+        if (c == '*' || (c == '_' && !plain.ends_with(|p: char| p.is_alphanumeric())))
+            && !rest[1..].starts_with(c)
+        {
+            if let Some(end) = rest[1..].find(c).filter(|&end| end > 0) {
+                flush(&mut plain, base, spans);
+                let inner = &rest[1..=end];
+                parse_inline(inner, base.add_modifier(Modifier::ITALIC), theme, spans);
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+
+        // Link: [text](url)
+        if c == '[' {
+            if let Some((label, url, len)) = link(rest) {
+                flush(&mut plain, base, spans);
+                parse_inline(label, link_style(theme), theme, spans);
+                spans.push(Span::styled(format!(" ({})", url), theme.muted()));
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    flush(&mut plain, base, spans);
+}
+
+/// Label, URL and byte length of a link at the start of `text`
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = text.find("](")?;
+    let url_end = label_end + 2 + text[label_end + 2..].find(')')?;
+    let label = &text[1..label_end];
+    let url = &text[label_end + 2..url_end];
+    (!label.contains('[') && !url.contains(' ')).then_some((label, url, url_end + 1))
+}
+
+/// Push the accumulated plain text as a span
+fn flush(plain: &mut String, style: Style, spans: &mut Vec<Span<'static>>) {
+    if !plain.is_empty() {
+        spans.push(Span::styled(std::mem::take(plain), style));
+    }
+}
+
+fn heading_style(theme: &Theme, level: usize) -> Style {
+    let style = Style::default().add_modifier(Modifier::BOLD);
+    if level == 1 {
+        style
+            .fg(theme.accent_primary)
+            .add_modifier(Modifier::UNDERLINED)
+    } else {
+        style.fg(theme.accent_primary)
+    }
+}
+
+fn code_style(theme: &Theme) -> Style {
+    Style::default()
+        .fg(theme.text_secondary)
+        .bg(theme.bg_tertiary)
+}
+
+fn link_style(theme: &Theme) -> Style {
+    Style::default()
+        .fg(theme.accent_secondary)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Content and style of every span of a rendered line
+    fn spans(line: &Line) -> Vec<(String, Style)> {
+        line.spans
+            .iter()
+            .map(|span| (span.content.to_string(), span.style))
+            .collect()
+    }
+
+    #[test]
+    fn test_headings() {
+        let theme = Theme::default();
+        let text = render_markdown("# Title\n### Details ##", &theme);
+
+        assert_eq!(
+            spans(&text.lines[0]),
+            vec![("Title".to_string(), heading_style(&theme, 1))]
+        );
+        assert_eq!(
+            spans(&text.lines[1]),
+            vec![("Details".to_string(), heading_style(&theme, 3))]
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let theme = Theme::default();
+        let text = render_markdown("- first\n  * nested\n2. second", &theme);
+
+        assert_eq!(
+            spans(&text.lines[0]),
+            vec![
+                ("• ".to_string(), theme.accent()),
+                ("first".to_string(), theme.text()),
+            ]
+        );
+        assert_eq!(
+            spans(&text.lines[1])[0],
+            ("  • ".to_string(), theme.accent())
+        );
+        assert_eq!(
+            spans(&text.lines[2]),
+            vec![
+                ("2. ".to_string(), theme.accent()),
+                ("second".to_string(), theme.text()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        let theme = Theme::default();
+        let text = render_markdown("Run `cargo test` **now** or *later*", &theme);
+
+        assert_eq!(
+            spans(&text.lines[0]),
+            vec![
+                ("Run ".to_string(), theme.text()),
+                ("cargo test".to_string(), code_style(&theme)),
+                (" ".to_string(), theme.text()),
+                ("now".to_string(), theme.text().add_modifier(Modifier::BOLD)),
+                (" or ".to_string(), theme.text()),
+                (
+                    "later".to_string(),
+                    theme.text().add_modifier(Modifier::ITALIC)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_link_dims_url() {
+        let theme = Theme::default();
+        let text = render_markdown("See [the docs](https://example.com).", &theme);
+
+        assert_eq!(
+            spans(&text.lines[0]),
+            vec![
+                ("See ".to_string(), theme.text()),
+                ("the docs".to_string(), link_style(&theme)),
+                (" (https://example.com)".to_string(), theme.muted()),
+                (".".to_string(), theme.text()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fenced_code_block() {
+        let theme = Theme::default();
+        let text = render_markdown("```rust\nlet x = **1**;\n```\nafter", &theme);
+
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(
+            spans(&text.lines[0]),
+            vec![("  let x = **1**;".to_string(), code_style(&theme))]
+        );
+        assert_eq!(
+            spans(&text.lines[1]),
+            vec![("after".to_string(), theme.text())]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_markup_degrades_to_plain_text() {
+        let theme = Theme::default();
+        for source in [
+            "**unclosed bold",
+            "a snake_case_name",
+            "[not a link] (x)",
+            "| table | row |",
+            "#hashtag",
+        ] {
+            let text = render_markdown(source, &theme);
+            assert_eq!(
+                spans(&text.lines[0]),
+                vec![(source.to_string(), theme.text())],
+                "{}",
+                source
+            );
+        }
+    }
+}
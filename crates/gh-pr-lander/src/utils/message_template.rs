@@ -0,0 +1,100 @@
+//! Review message templates
+//!
+//! Review messages (e.g. `approval_message`) may contain placeholders that
+//! are rendered per PR, so a bulk approval posts a tailored comment on each:
+//!
+//! - `$NUMBER`: PR number
+//! - `$AUTHOR`: PR author login
+//! - `$TITLE`: PR title
+
+use crate::domain_models::Pr;
+
+/// Substitute the PR placeholders in a message template
+pub fn render(template: &str, pr: &Pr) -> String {
+    template
+        .replace("$NUMBER", &pr.number.to_string())
+        .replace("$AUTHOR", &pr.author)
+        .replace("$TITLE", &pr.title)
+}
+
+/// Review bodies per target PR, as passed to `create_review`
+///
+/// An empty template yields no body. PRs that are not in `prs` (e.g. removed
+/// by a refresh in the meantime) cannot be rendered if the template uses
+/// `$AUTHOR` or `$TITLE`; they are returned separately instead of posting the
+/// placeholders literally.
+pub fn review_bodies(
+    template: &str,
+    pr_numbers: &[u64],
+    prs: &[Pr],
+) -> (Vec<(u64, Option<String>)>, Vec<u64>) {
+    let needs_pr = template.contains("$AUTHOR") || template.contains("$TITLE");
+    let mut bodies = Vec::new();
+    let mut unrendered = Vec::new();
+
+    for &number in pr_numbers {
+        if template.is_empty() {
+            bodies.push((number, None));
+            continue;
+        }
+        match prs.iter().find(|pr| pr.number as u64 == number) {
+            Some(pr) => bodies.push((number, Some(render(template, pr)))),
+            None if needs_pr => unrendered.push(number),
+            None => bodies.push((
+                number,
+                Some(template.replace("$NUMBER", &number.to_string())),
+            )),
+        }
+    }
+    (bodies, unrendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr(number: usize, title: &str, author: &str) -> Pr {
+        Pr::new(number, title.to_string(), author, "sha")
+    }
+
+    #[test]
+    fn test_render_substitutes_placeholders() {
+        let pr = pr(42, "Fix the parser", "octocat");
+        assert_eq!(
+            render("Thanks @$AUTHOR for #$NUMBER ($TITLE)", &pr),
+            "Thanks @octocat for #42 (Fix the parser)"
+        );
+        assert_eq!(render("LGTM", &pr), "LGTM");
+    }
+
+    #[test]
+    fn test_review_bodies_render_per_pr() {
+        let prs = vec![pr(1, "First", "alice"), pr(2, "Second", "bob")];
+        let (bodies, unrendered) = review_bodies("Approved #$NUMBER by $AUTHOR", &[2, 1, 3], &prs);
+        assert_eq!(
+            bodies,
+            vec![
+                (2, Some("Approved #2 by bob".to_string())),
+                (1, Some("Approved #1 by alice".to_string())),
+            ]
+        );
+        // #3 is unknown, its author can't be filled in
+        assert_eq!(unrendered, vec![3]);
+    }
+
+    #[test]
+    fn test_review_bodies_number_only_renders_unknown_prs() {
+        let (bodies, unrendered) = review_bodies("LGTM #$NUMBER", &[3], &[]);
+        assert_eq!(bodies, vec![(3, Some("LGTM #3".to_string()))]);
+        assert!(unrendered.is_empty());
+    }
+
+    #[test]
+    fn test_review_bodies_empty_template_has_no_body() {
+        let prs = vec![pr(1, "First", "alice")];
+        assert_eq!(
+            review_bodies("", &[1, 2], &prs),
+            (vec![(1, None), (2, None)], vec![])
+        );
+    }
+}
@@ -3,4 +3,13 @@
 //! Common utilities used across the application.
 
 pub mod browser;
+pub mod clipboard;
+pub mod gh_auth;
 pub mod issue_extractor;
+pub mod markdown;
+pub mod message_template;
+pub mod notify;
+pub mod pr_export;
+pub mod pr_list_query;
+pub mod relative_time;
+pub mod viewport_sync;
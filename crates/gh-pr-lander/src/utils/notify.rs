@@ -0,0 +1,46 @@
+//! Notification utilities
+//!
+//! Terminal bell and desktop notifications, e.g. when CI of a watched PR
+//! finished.
+
+use std::io::Write;
+
+/// Ring the terminal bell
+pub fn bell() {
+    let mut stdout = std::io::stdout();
+    if let Err(e) = stdout.write_all(b"\x07").and_then(|_| stdout.flush()) {
+        log::warn!("Failed to ring the terminal bell: {}", e);
+    }
+}
+
+/// Show a desktop notification
+///
+/// Uses platform-specific commands:
+/// - macOS: `osascript`
+/// - Linux: `notify-send`
+/// - Windows: not supported (logged only)
+pub fn desktop(title: &str, body: &str) {
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("osascript")
+        .arg("-e")
+        .arg(format!(
+            "display notification {:?} with title {:?}",
+            body, title
+        ))
+        .spawn();
+
+    #[cfg(target_os = "linux")]
+    let result = std::process::Command::new("notify-send")
+        .args(["--app-name", "gh-pr-lander", title, body])
+        .spawn();
+
+    #[cfg(target_os = "windows")]
+    let result: std::io::Result<std::process::Child> = Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "desktop notifications are not supported on Windows",
+    ));
+
+    if let Err(e) = result {
+        log::warn!("Failed to show desktop notification: {}", e);
+    }
+}
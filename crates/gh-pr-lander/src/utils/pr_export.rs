@@ -0,0 +1,130 @@
+//! PR list export
+//!
+//! Serializes the PR list of a repository to JSON for scripting and reporting.
+//! Uses a dedicated export struct so the file format stays decoupled from the
+//! internal `Pr` domain model. PRs can also be rendered as a markdown list,
+//! e.g. for standup notes.
+
+use crate::domain_models::{MaturityState, MergeableStatus, Pr, ReviewDecision};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+
+/// A single PR as written to the export file
+#[derive(Debug, Serialize)]
+pub struct PrExport<'a> {
+    pub number: usize,
+    pub title: &'a str,
+    pub author: &'a str,
+    pub mergeable: &'static str,
+    pub draft: bool,
+    pub review_decision: ReviewDecision,
+    pub url: &'a str,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl<'a> From<&'a Pr> for PrExport<'a> {
+    fn from(pr: &'a Pr) -> Self {
+        Self {
+            number: pr.number,
+            title: &pr.title,
+            author: &pr.author,
+            mergeable: mergeable_key(pr.mergeable),
+            draft: pr.maturity == MaturityState::Draft,
+            review_decision: pr.review_decision,
+            url: &pr.html_url,
+            created_at: pr.created_at,
+            updated_at: pr.updated_at,
+        }
+    }
+}
+
+/// Stable, machine-readable name of a mergeable status
+fn mergeable_key(status: MergeableStatus) -> &'static str {
+    match status {
+        MergeableStatus::Unknown => "unknown",
+        MergeableStatus::Checking => "checking",
+        MergeableStatus::Ready => "ready",
+        MergeableStatus::NeedsRebase => "needs_rebase",
+        MergeableStatus::BuildFailed => "build_failed",
+        MergeableStatus::Conflicted => "conflicted",
+        MergeableStatus::Blocked => "blocked",
+        MergeableStatus::Rebasing => "rebasing",
+        MergeableStatus::Merging => "merging",
+    }
+}
+
+/// Serialize PRs to pretty-printed JSON
+pub fn to_json(prs: &[Pr]) -> serde_json::Result<String> {
+    let export: Vec<PrExport> = prs.iter().map(PrExport::from).collect();
+    serde_json::to_string_pretty(&export)
+}
+
+/// Write PRs as JSON to the given file
+pub fn write_json(path: &Path, prs: &[Pr]) -> anyhow::Result<()> {
+    std::fs::write(path, to_json(prs)?)?;
+    Ok(())
+}
+
+/// Render PRs as markdown list items: `- [#123](url) Title — status`
+pub fn to_markdown(prs: &[&Pr]) -> String {
+    prs.iter()
+        .map(|pr| {
+            // Keep titles from breaking out of the link line
+            let title = pr.title.replace(['\n', '\r'], " ");
+            let link = if pr.html_url.is_empty() {
+                format!("#{}", pr.number)
+            } else {
+                format!("[#{}]({})", pr.number, pr.html_url)
+            };
+            format!("- {} {} — {}", link, title.trim(), pr.mergeable.label())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_list() {
+        let mut first = Pr::new(1, "Add feature", "octocat", "abc")
+            .with_html_url("https://github.com/org/repo/pull/1");
+        first.mergeable = MergeableStatus::Ready;
+        let mut second = Pr::new(2, "Fix bug", "octocat", "def");
+        second.mergeable = MergeableStatus::BuildFailed;
+
+        assert_eq!(
+            to_markdown(&[&first, &second]),
+            "- [#1](https://github.com/org/repo/pull/1) Add feature — Ready\n\
+             - #2 Fix bug — Build Failed"
+        );
+    }
+
+    #[test]
+    fn test_serialize_sample_pr() {
+        let mut pr = Pr::new(42, "Fix flaky test", "octocat", "abc123")
+            .with_html_url("https://github.com/org/repo/pull/42");
+        pr.mergeable = MergeableStatus::NeedsRebase;
+        pr.maturity = MaturityState::Draft;
+        pr.review_decision = ReviewDecision::ChangesRequested;
+        pr.body = "internal field".to_string();
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[pr]).unwrap()).unwrap();
+        let exported = &json[0];
+        assert_eq!(exported["number"], 42);
+        assert_eq!(exported["title"], "Fix flaky test");
+        assert_eq!(exported["author"], "octocat");
+        assert_eq!(exported["mergeable"], "needs_rebase");
+        assert_eq!(exported["draft"], true);
+        assert_eq!(exported["review_decision"], "changes_requested");
+        assert_eq!(exported["url"], "https://github.com/org/repo/pull/42");
+        assert!(exported["created_at"].is_string());
+        assert!(exported["updated_at"].is_string());
+        // Internal fields are not part of the export format
+        assert!(exported.get("body").is_none());
+        assert!(exported.get("head_sha").is_none());
+    }
+}
@@ -0,0 +1,95 @@
+//! PR list query resolution
+//!
+//! Resolves the `[pr_list]` config (state, page size, max PRs) for a
+//! repository, applying the first matching per-repo override.
+
+use crate::domain_models::Repository;
+use crate::utils::issue_extractor::glob_match;
+use gh_client::PrStateFilter;
+use gh_pr_config::{PrListConfig, PrListState};
+
+/// GitHub's maximum page size for list endpoints
+const MAX_PER_PAGE: u8 = 100;
+
+/// Effective PR list query for a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrListQuery {
+    /// Which PRs to list
+    pub state: PrStateFilter,
+    /// Page size for API requests
+    pub per_page: u8,
+    /// Maximum number of PRs to load
+    pub max_prs: usize,
+}
+
+impl PrListQuery {
+    /// Resolve the query for `repo` from config
+    pub fn for_repo(config: &PrListConfig, repo: &Repository) -> Self {
+        let repo_path = format!("{}/{}", repo.org, repo.repo);
+        let repo_override = config.repo.iter().find(|o| {
+            o.repos
+                .iter()
+                .any(|pattern| glob_match(pattern, &repo_path))
+        });
+
+        let state = repo_override.and_then(|o| o.state).unwrap_or(config.state);
+        let per_page = repo_override
+            .and_then(|o| o.per_page)
+            .unwrap_or(config.per_page);
+        let max_prs = repo_override
+            .and_then(|o| o.max_prs)
+            .unwrap_or(config.max_prs);
+
+        Self {
+            state: match state {
+                PrListState::Open => PrStateFilter::Open,
+                PrListState::Closed => PrStateFilter::Closed,
+                PrListState::All => PrStateFilter::All,
+            },
+            per_page: per_page.clamp(1, MAX_PER_PAGE),
+            max_prs: max_prs.max(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gh_pr_config::PrListOverride;
+
+    #[test]
+    fn test_defaults_without_overrides() {
+        let config = PrListConfig::default();
+        let query = PrListQuery::for_repo(&config, &Repository::new("org", "repo", "main"));
+        assert_eq!(query.state, PrStateFilter::Open);
+        assert_eq!(query.per_page, 30);
+        assert_eq!(query.max_prs, 50);
+    }
+
+    #[test]
+    fn test_first_matching_override_wins() {
+        let config = PrListConfig {
+            per_page: 0,
+            repo: vec![
+                PrListOverride {
+                    repos: vec!["other/*".to_string()],
+                    state: Some(PrListState::Closed),
+                    ..Default::default()
+                },
+                PrListOverride {
+                    repos: vec!["org/*".to_string()],
+                    state: Some(PrListState::All),
+                    max_prs: Some(500),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let query = PrListQuery::for_repo(&config, &Repository::new("org", "repo", "main"));
+        assert_eq!(query.state, PrStateFilter::All);
+        assert_eq!(query.max_prs, 500);
+        // Falls back to the global page size, clamped to a valid value
+        assert_eq!(query.per_page, 1);
+    }
+}
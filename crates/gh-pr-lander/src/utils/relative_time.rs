@@ -0,0 +1,81 @@
+//! Compact relative time formatting
+//!
+//! Formats durations as short labels ("5m", "3h", "2d", "1w", "4mo", "2y")
+//! suitable for narrow table columns.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Format a duration as a compact relative age.
+///
+/// Negative durations (timestamps in the future, e.g. due to clock skew)
+/// are treated as "now".
+pub fn format_compact_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    let hours = duration.num_hours();
+    let days = duration.num_days();
+
+    if minutes < 1 {
+        "now".to_string()
+    } else if hours < 1 {
+        format!("{}m", minutes)
+    } else if days < 1 {
+        format!("{}h", hours)
+    } else if days < 7 {
+        format!("{}d", days)
+    } else if days < 30 {
+        format!("{}w", days / 7)
+    } else if days < 365 {
+        format!("{}mo", days / 30)
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+/// Format the age of a timestamp relative to now.
+pub fn format_age(timestamp: &DateTime<Utc>) -> String {
+    format_compact_duration(Utc::now().signed_duration_since(*timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now() {
+        assert_eq!(format_compact_duration(Duration::seconds(0)), "now");
+        assert_eq!(format_compact_duration(Duration::seconds(59)), "now");
+        assert_eq!(format_compact_duration(Duration::seconds(-30)), "now");
+    }
+
+    #[test]
+    fn test_minutes() {
+        assert_eq!(format_compact_duration(Duration::minutes(1)), "1m");
+        assert_eq!(format_compact_duration(Duration::minutes(59)), "59m");
+    }
+
+    #[test]
+    fn test_hours() {
+        assert_eq!(format_compact_duration(Duration::minutes(60)), "1h");
+        assert_eq!(format_compact_duration(Duration::hours(23)), "23h");
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(format_compact_duration(Duration::hours(24)), "1d");
+        assert_eq!(format_compact_duration(Duration::days(6)), "6d");
+    }
+
+    #[test]
+    fn test_weeks() {
+        assert_eq!(format_compact_duration(Duration::days(7)), "1w");
+        assert_eq!(format_compact_duration(Duration::days(29)), "4w");
+    }
+
+    #[test]
+    fn test_months_and_years() {
+        assert_eq!(format_compact_duration(Duration::days(30)), "1mo");
+        assert_eq!(format_compact_duration(Duration::days(364)), "12mo");
+        assert_eq!(format_compact_duration(Duration::days(365)), "1y");
+        assert_eq!(format_compact_duration(Duration::days(800)), "2y");
+    }
+}
@@ -0,0 +1,174 @@
+//! Viewport synchronization
+//!
+//! Keeps terminal-size dependent state (terminal size, debug console and
+//! action history heights, PR table, description panel, diff viewer and build
+//! log viewports) in sync with the terminal. Drag-resizing reports a new size on
+//! every frame, so sizes are debounced and each dimension is only dispatched
+//! when it actually changed from the last dispatched value.
+
+use crate::actions::{
+    Action, ActionHistoryAction, BuildLogAction, DebugConsoleAction, DiffViewerAction,
+    GlobalAction, PullRequestAction,
+};
+use crate::state::AppState;
+use std::time::{Duration, Instant};
+
+/// How long the terminal size must stay unchanged before it is applied
+const RESIZE_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Tracks the terminal size and the viewport dimensions last dispatched
+#[derive(Debug)]
+pub struct ViewportSync {
+    /// Most recently observed terminal size and when it was first seen
+    observed: Option<((u16, u16), Instant)>,
+    /// Last size that stayed stable for the debounce interval
+    settled: Option<(u16, u16)>,
+    /// Diff viewer viewport last dispatched (width, height)
+    last_diff_viewport: Option<(u16, u16)>,
+}
+
+impl ViewportSync {
+    pub fn new() -> Self {
+        Self {
+            observed: None,
+            settled: None,
+            last_diff_viewport: None,
+        }
+    }
+
+    /// Record the terminal size seen this frame and return the settled size
+    ///
+    /// The very first size is applied immediately so the initial layout is
+    /// correct; later changes are applied once stable for `RESIZE_DEBOUNCE`.
+    fn observe(&mut self, size: (u16, u16), now: Instant) -> Option<(u16, u16)> {
+        match self.observed {
+            Some((observed, _)) if observed != size => self.observed = Some((size, now)),
+            Some((_, since)) if now.duration_since(since) >= RESIZE_DEBOUNCE => {
+                self.settled = Some(size)
+            }
+            Some(_) => {}
+            None => {
+                self.observed = Some((size, now));
+                self.settled = Some(size);
+            }
+        }
+        self.settled
+    }
+
+    /// Actions needed to bring the viewport state in line with the terminal size
+    pub fn actions(&mut self, size: (u16, u16), state: &AppState, now: Instant) -> Vec<Action> {
+        let Some((width, height)) = self.observe(size, now) else {
+            return vec![];
+        };
+        let mut actions = vec![];
+
+        if state.terminal_size != (width, height) {
+            actions.push(Action::Global(GlobalAction::TerminalResized {
+                width,
+                height,
+            }));
+        }
+
+        // Debug console: 70% of screen height minus 2 for borders
+        let debug_console_height = ((height as usize) * 70 / 100).saturating_sub(2);
+        if state.debug_console.visible_height != debug_console_height {
+            actions.push(Action::DebugConsole(DebugConsoleAction::SetVisibleHeight(
+                debug_console_height,
+            )));
+        }
+
+        // Action history: same drop-down layout as the debug console
+        if state.action_history.visible_height != debug_console_height {
+            actions.push(Action::ActionHistory(
+                ActionHistoryAction::SetVisibleHeight(debug_console_height),
+            ));
+        }
+
+        // PR table rows: minus tab bar, shortcut bar, status bar, table borders and header row
+        let pr_viewport_height =
+            (height as usize).saturating_sub(6) / state.row_density.row_height() as usize;
+        if state.main_view.pr_viewport_height != pr_viewport_height {
+            actions.push(Action::PullRequest(PullRequestAction::SetPrViewportHeight(
+                pr_viewport_height,
+            )));
+        }
+
+        // Description panel: 40% of the width minus its borders
+        let description_width = ((width as usize) * 40 / 100).saturating_sub(2) as u16;
+        if state.main_view.description_width != description_width {
+            actions.push(Action::PullRequest(PullRequestAction::SetDescriptionWidth(
+                description_width,
+            )));
+        }
+
+        // Build log: minus status bar, PR header (3) and table borders; width minus borders
+        let build_log_viewport = (
+            (width as usize).saturating_sub(2),
+            (height as usize).saturating_sub(6),
+        );
+        if (
+            state.build_log.viewport_width,
+            state.build_log.viewport_height,
+        ) != build_log_viewport
+        {
+            actions.push(Action::BuildLog(BuildLogAction::SetViewport {
+                width: build_log_viewport.0,
+                height: build_log_viewport.1,
+            }));
+        }
+
+        // Diff viewer: full height minus 4 for stats header, status bar and borders
+        let diff_viewport = (width, height.saturating_sub(4));
+        match state.diff_viewer.inner {
+            Some(ref inner)
+                if inner.viewport_height != diff_viewport.1 as usize
+                    || self.last_diff_viewport != Some(diff_viewport) =>
+            {
+                self.last_diff_viewport = Some(diff_viewport);
+                actions.push(Action::DiffViewer(DiffViewerAction::SetViewport {
+                    width: diff_viewport.0,
+                    height: diff_viewport.1,
+                }));
+            }
+            Some(_) => {}
+            // Re-send the viewport when the diff viewer opens again
+            None => self.last_diff_viewport = None,
+        }
+
+        actions
+    }
+}
+
+impl Default for ViewportSync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_size_applies_immediately() {
+        let mut sync = ViewportSync::new();
+        assert_eq!(sync.observe((80, 24), Instant::now()), Some((80, 24)));
+    }
+
+    #[test]
+    fn test_resize_applies_once_stable() {
+        let mut sync = ViewportSync::new();
+        let start = Instant::now();
+        sync.observe((80, 24), start);
+
+        // Sizes changing every frame during a drag are not applied
+        let frame = Duration::from_millis(16);
+        assert_eq!(sync.observe((90, 24), start + frame), Some((80, 24)));
+        assert_eq!(sync.observe((100, 30), start + frame * 2), Some((80, 24)));
+        assert_eq!(sync.observe((100, 30), start + frame * 3), Some((80, 24)));
+
+        // Stable for the debounce interval: applied
+        let settled = start + frame * 2 + RESIZE_DEBOUNCE;
+        assert_eq!(sync.observe((100, 30), settled), Some((100, 30)));
+    }
+}
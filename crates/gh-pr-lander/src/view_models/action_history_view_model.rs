@@ -0,0 +1,98 @@
+//! Action History View Model
+
+use crate::keybindings::Keymap;
+use crate::keymap::CommandId;
+use crate::state::{ActionHistoryEntry, ActionHistoryState};
+
+/// Pre-computed footer hints for keyboard shortcuts
+#[derive(Debug, Clone)]
+pub struct ActionHistoryFooterHints {
+    /// Combined scroll hint (e.g., "j/↓/k/↑")
+    pub scroll: String,
+    /// Combined top/bottom hint (e.g., "gg/G")
+    pub top_bottom: String,
+    /// Debug console hint (e.g., "`")
+    pub console: String,
+    /// Close hint (e.g., "~")
+    pub close: String,
+}
+
+/// View model for the action history - handles presentation logic
+pub struct ActionHistoryViewModel<'a> {
+    state: &'a ActionHistoryState,
+    /// Pre-computed footer hints
+    pub footer_hints: ActionHistoryFooterHints,
+}
+
+impl<'a> ActionHistoryViewModel<'a> {
+    pub fn new(state: &'a ActionHistoryState, keymap: &Keymap) -> Self {
+        let footer_hints = ActionHistoryFooterHints {
+            scroll: format!(
+                "{}/{}",
+                keymap
+                    .compact_hint_for_command(CommandId::NavigateNext)
+                    .unwrap_or_else(|| "j/↓".to_string()),
+                keymap
+                    .compact_hint_for_command(CommandId::NavigatePrevious)
+                    .unwrap_or_else(|| "k/↑".to_string()),
+            ),
+            top_bottom: format!(
+                "{}/{}",
+                keymap
+                    .compact_hint_for_command(CommandId::NavigateToTop)
+                    .unwrap_or_else(|| "gg".to_string()),
+                keymap
+                    .compact_hint_for_command(CommandId::NavigateToBottom)
+                    .unwrap_or_else(|| "G".to_string()),
+            ),
+            console: keymap
+                .compact_hint_for_command(CommandId::DebugToggleConsoleView)
+                .unwrap_or_else(|| "`".to_string()),
+            close: keymap
+                .compact_hint_for_command(CommandId::DebugToggleActionHistory)
+                .unwrap_or_else(|| "~".to_string()),
+        };
+
+        Self {
+            state,
+            footer_hints,
+        }
+    }
+
+    /// Get the visible entries based on scroll offset and available height
+    ///
+    /// scroll_offset = 0 means we're at the bottom (showing the newest actions)
+    pub fn visible_entries(&self, available_height: usize) -> Vec<&ActionHistoryEntry> {
+        let total = self.state.entries.len();
+        if total == 0 || available_height == 0 {
+            return Vec::new();
+        }
+
+        let max_scroll = total.saturating_sub(available_height);
+        let end = total.saturating_sub(self.state.scroll_offset.min(max_scroll));
+        let start = end.saturating_sub(available_height);
+
+        self.state.entries.range(start..end).collect()
+    }
+
+    /// Text shown instead of the list when there is nothing to show
+    pub fn empty_message(&self) -> Option<&'static str> {
+        if !self.state.enabled {
+            Some("Start with GH_PR_LANDER_DEBUG=1 to record the dispatched actions")
+        } else if self.state.entries.is_empty() {
+            Some("No actions recorded yet")
+        } else {
+            None
+        }
+    }
+
+    /// Get the title with entry count and scroll indicator
+    pub fn title(&self) -> String {
+        let mut title = format!(" Action History ({})", self.state.entries.len());
+        if self.state.scroll_offset > 0 {
+            title.push_str(&format!(" - ↓{}", self.state.scroll_offset));
+        }
+        title.push(' ');
+        title
+    }
+}
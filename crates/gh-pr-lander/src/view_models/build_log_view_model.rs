@@ -2,8 +2,11 @@
 //!
 //! Pre-computes presentation data for the build log panel.
 
-use crate::state::{BuildLogJobMetadata, BuildLogJobStatus, BuildLogLoadingState, BuildLogState};
+use crate::state::{
+    BuildLogJobMetadata, BuildLogJobStatus, BuildLogLoadingState, BuildLogState, LOG_LINE_PREFIX,
+};
 use ratatui::style::Color;
+use std::time::Duration;
 
 /// View model for rendering the build log panel
 #[derive(Debug, Clone)]
@@ -22,6 +25,15 @@ pub struct BuildLogViewModel {
     /// Loading state
     pub is_loading: bool,
     pub error_message: Option<String>,
+
+    /// Search indicator for the title (e.g., "/error 3/12"), None without a search
+    pub search_status: Option<String>,
+
+    /// Column width to wrap log lines at (None = no wrapping)
+    pub wrap_width: Option<usize>,
+
+    /// Whether only failed nodes are shown
+    pub failed_only: bool,
 }
 
 /// PR header view model for build log
@@ -48,6 +60,12 @@ pub struct BuildLogTreeRowViewModel {
     /// Whether this row is under cursor
     pub is_cursor: bool,
 
+    /// Number of display rows (more than one for wrapped log lines)
+    pub height: usize,
+
+    /// Byte ranges in `text` matching the search query, to be highlighted
+    pub match_ranges: Vec<(usize, usize)>,
+
     /// Pre-determined style
     pub style: BuildLogRowStyle,
 
@@ -64,6 +82,8 @@ pub enum BuildLogRowStyle {
     Normal,
     Error,   // Red text for errors
     Success, // Green for success
+    Warning, // Yellow for in-progress jobs
+    Muted,   // Dimmed for cancelled/skipped jobs
     #[allow(dead_code)]
     Selected, // Highlighted background
 }
@@ -99,7 +119,11 @@ impl BuildLogViewModel {
         let mut rows = Vec::new();
 
         for path in visible_paths.iter() {
-            let row = Self::build_row_view_model(state, path);
+            let mut row = Self::build_row_view_model(state, path);
+            row.height = state.node_rows(path);
+            if row.node_type == BuildLogNodeType::LogLine {
+                row.match_ranges = Self::find_match_ranges(&row.text, &state.search_query);
+            }
             rows.push(row);
         }
 
@@ -110,9 +134,53 @@ impl BuildLogViewModel {
             viewport_height: state.viewport_height,
             is_loading,
             error_message,
+            search_status: Self::format_search_status(state),
+            wrap_width: state.wrap_lines.then_some(state.viewport_width),
+            failed_only: state.failed_only,
         }
     }
 
+    /// Format the search indicator, e.g. "/error 3/12" or "/error▏ no matches"
+    fn format_search_status(state: &BuildLogState) -> Option<String> {
+        if !state.search_editing && !state.has_search() {
+            return None;
+        }
+
+        let cursor = if state.search_editing { "▏" } else { "" };
+        let count = if state.search_query.is_empty() {
+            String::new()
+        } else if state.search_matches.is_empty() {
+            " no matches".to_string()
+        } else {
+            let current = state
+                .search_match_index
+                .map(|idx| (idx + 1).to_string())
+                .unwrap_or_else(|| "-".to_string());
+            format!(" {}/{}", current, state.search_matches.len())
+        };
+
+        Some(format!("/{}{}{}", state.search_query, cursor, count))
+    }
+
+    /// Byte ranges of case-insensitive occurrences of `query` in `text`
+    ///
+    /// Lowercasing can change byte lengths for some non-ASCII text; such rows
+    /// are left unhighlighted rather than risk splitting a character.
+    fn find_match_ranges(text: &str, query: &str) -> Vec<(usize, usize)> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let haystack = text.to_lowercase();
+        if haystack.len() != text.len() {
+            return Vec::new();
+        }
+        let needle = query.to_lowercase();
+        haystack
+            .match_indices(&needle)
+            .map(|(start, m)| (start, start + m.len()))
+            .collect()
+    }
+
     fn build_row_view_model(state: &BuildLogState, path: &[usize]) -> BuildLogTreeRowViewModel {
         let indent_level = path.len().saturating_sub(1);
 
@@ -148,12 +216,21 @@ impl BuildLogViewModel {
                     String::new()
                 };
 
-                let text = format!("{} {} {}{}", icon, status_icon, workflow.name, error_info);
+                let duration_info = Self::workflow_duration(state, workflow)
+                    .map(Self::format_duration)
+                    .unwrap_or_default();
+
+                let text = format!(
+                    "{} {} {}{}{}",
+                    icon, status_icon, workflow.name, error_info, duration_info
+                );
 
                 BuildLogTreeRowViewModel {
                     text,
                     indent_level,
                     is_cursor: path == state.cursor_path,
+                    height: 1,
+                    match_ranges: Vec::new(),
                     style: if workflow.has_failures {
                         BuildLogRowStyle::Error
                     } else {
@@ -178,20 +255,7 @@ impl BuildLogViewModel {
                     "▶"
                 };
 
-                // Get actual job status from metadata (or infer from error count)
-                let key = format!("{}:{}", workflow.name, job.name);
-                let status = state
-                    .job_metadata
-                    .get(&key)
-                    .map(|m| m.status)
-                    .unwrap_or_else(|| {
-                        if job.error_count > 0 {
-                            BuildLogJobStatus::Failure
-                        } else {
-                            BuildLogJobStatus::Success
-                        }
-                    });
-
+                let status = Self::job_status(state, workflow, job);
                 let status_icon = status.icon();
 
                 let error_info = if job.error_count > 0 {
@@ -200,7 +264,9 @@ impl BuildLogViewModel {
                     String::new()
                 };
 
-                let duration_info = Self::format_job_duration(&state.job_metadata, workflow, job);
+                let duration_info = Self::job_duration(&state.job_metadata, workflow, job)
+                    .map(Self::format_duration)
+                    .unwrap_or_default();
 
                 // ├─ directly below the ▼ of workflow
                 let text = format!(
@@ -212,6 +278,8 @@ impl BuildLogViewModel {
                     text,
                     indent_level,
                     is_cursor: path == state.cursor_path,
+                    height: 1,
+                    match_ranges: Vec::new(),
                     style: Self::job_status_style(status),
                     path: path.to_vec(),
                     node_type: BuildLogNodeType::Job,
@@ -233,11 +301,8 @@ impl BuildLogViewModel {
                     "▶"
                 };
 
-                let status_icon = if step.error_count > 0 {
-                    BuildLogJobStatus::Failure.icon()
-                } else {
-                    BuildLogJobStatus::Success.icon()
-                };
+                let status = Self::step_status(state, workflow, job, step);
+                let status_icon = status.icon();
 
                 let error_info = if step.error_count > 0 {
                     format!(" ({} errors)", step.error_count)
@@ -252,11 +317,9 @@ impl BuildLogViewModel {
                     text,
                     indent_level,
                     is_cursor: path == state.cursor_path,
-                    style: if step.error_count > 0 {
-                        BuildLogRowStyle::Error
-                    } else {
-                        BuildLogRowStyle::Normal
-                    },
+                    height: 1,
+                    match_ranges: Vec::new(),
+                    style: Self::job_status_style(status),
                     path: path.to_vec(),
                     node_type: BuildLogNodeType::Step,
                 }
@@ -278,7 +341,7 @@ impl BuildLogViewModel {
                 };
 
                 // Tree prefix: │ at position 0, │ at position 3, then 2 spaces for content
-                let prefix = "│  │  ";
+                let prefix = LOG_LINE_PREFIX;
 
                 // Add timestamp if available
                 let timestamp_part = if state.show_timestamps {
@@ -291,8 +354,8 @@ impl BuildLogViewModel {
                     String::new()
                 };
 
-                // Apply horizontal scroll to content
-                let content = if state.horizontal_scroll > 0 {
+                // Apply horizontal scroll to content (wrapped lines are shown in full)
+                let content = if state.horizontal_scroll > 0 && !state.wrap_lines {
                     line.display_content
                         .chars()
                         .skip(state.horizontal_scroll)
@@ -313,6 +376,8 @@ impl BuildLogViewModel {
                     text,
                     indent_level,
                     is_cursor: path == state.cursor_path,
+                    height: 1,
+                    match_ranges: Vec::new(),
                     style,
                     path: path.to_vec(),
                     node_type: BuildLogNodeType::LogLine,
@@ -323,6 +388,8 @@ impl BuildLogViewModel {
                 text: String::new(),
                 indent_level: 0,
                 is_cursor: false,
+                height: 1,
+                match_ranges: Vec::new(),
                 style: BuildLogRowStyle::Normal,
                 path: path.to_vec(),
                 node_type: BuildLogNodeType::LogLine,
@@ -330,26 +397,78 @@ impl BuildLogViewModel {
         }
     }
 
-    /// Format job duration for display
-    fn format_job_duration(
+    /// Duration of a job from its metadata (None if unknown or skipped)
+    fn job_duration(
         metadata: &std::collections::HashMap<String, BuildLogJobMetadata>,
         workflow: &gh_actions_log_parser::WorkflowNode,
         job: &gh_actions_log_parser::JobNode,
-    ) -> String {
+    ) -> Option<Duration> {
         let key = format!("{}:{}", workflow.name, job.name);
+        metadata
+            .get(&key)
+            .filter(|meta| meta.status != BuildLogJobStatus::Skipped)
+            .and_then(|meta| meta.duration)
+    }
 
-        if let Some(meta) = metadata.get(&key) {
-            if let Some(duration) = meta.duration {
-                let secs = duration.as_secs();
-                return if secs >= 60 {
-                    format!(" ({}m {}s)", secs / 60, secs % 60)
-                } else {
-                    format!(" ({}s)", secs)
-                };
-            }
+    /// Summed duration of all jobs of a workflow with a known duration
+    /// (None if no job duration is known)
+    fn workflow_duration(
+        state: &BuildLogState,
+        workflow: &gh_actions_log_parser::WorkflowNode,
+    ) -> Option<Duration> {
+        workflow
+            .jobs
+            .iter()
+            .filter_map(|job| Self::job_duration(&state.job_metadata, workflow, job))
+            .reduce(|total, duration| total + duration)
+    }
+
+    /// Format a duration for display, e.g. " (2m 15s)" or " (42s)"
+    fn format_duration(duration: Duration) -> String {
+        let secs = duration.as_secs();
+        if secs >= 60 {
+            format!(" ({}m {}s)", secs / 60, secs % 60)
+        } else {
+            format!(" ({}s)", secs)
         }
+    }
+
+    /// Job status from metadata (keyed by "workflow:job"), or inferred from the error count
+    fn job_status(
+        state: &BuildLogState,
+        workflow: &gh_actions_log_parser::WorkflowNode,
+        job: &gh_actions_log_parser::JobNode,
+    ) -> BuildLogJobStatus {
+        let key = format!("{}:{}", workflow.name, job.name);
+        state
+            .job_metadata
+            .get(&key)
+            .map(|m| m.status)
+            .unwrap_or_else(|| {
+                if job.error_count > 0 {
+                    BuildLogJobStatus::Failure
+                } else {
+                    BuildLogJobStatus::Success
+                }
+            })
+    }
 
-        String::new()
+    /// Step status: failed if it has errors, otherwise that of its job
+    /// (steps carry no status of their own)
+    fn step_status(
+        state: &BuildLogState,
+        workflow: &gh_actions_log_parser::WorkflowNode,
+        job: &gh_actions_log_parser::JobNode,
+        step: &gh_actions_log_parser::StepNode,
+    ) -> BuildLogJobStatus {
+        if step.error_count > 0 {
+            return BuildLogJobStatus::Failure;
+        }
+        match Self::job_status(state, workflow, job) {
+            // A failed job's other steps succeeded
+            BuildLogJobStatus::Failure => BuildLogJobStatus::Success,
+            status => status,
+        }
     }
 
     /// Get row style for job status
@@ -357,8 +476,8 @@ impl BuildLogViewModel {
         match status {
             BuildLogJobStatus::Success => BuildLogRowStyle::Success,
             BuildLogJobStatus::Failure => BuildLogRowStyle::Error,
-            BuildLogJobStatus::Cancelled | BuildLogJobStatus::Skipped => BuildLogRowStyle::Normal,
-            BuildLogJobStatus::InProgress => BuildLogRowStyle::Normal,
+            BuildLogJobStatus::Cancelled | BuildLogJobStatus::Skipped => BuildLogRowStyle::Muted,
+            BuildLogJobStatus::InProgress => BuildLogRowStyle::Warning,
             BuildLogJobStatus::Unknown => BuildLogRowStyle::Normal,
         }
     }
@@ -375,3 +494,136 @@ impl BuildLogViewModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::BuildLogAction;
+    use crate::reducers::build_log_reducer::reduce_build_log;
+    use crate::state::BuildLogPrContext;
+    use gh_actions_log_parser::{JobNode, LogLine, StepNode, WorkflowNode};
+
+    fn step(name: &str, error_count: usize) -> StepNode {
+        StepNode {
+            name: name.to_string(),
+            lines: vec![LogLine::new(format!("{} output", name))],
+            error_count,
+        }
+    }
+
+    fn job(name: &str, steps: Vec<StepNode>) -> JobNode {
+        JobNode {
+            name: name.to_string(),
+            error_count: steps.iter().map(|s| s.error_count).sum(),
+            steps,
+        }
+    }
+
+    fn meta(job: &str, status: BuildLogJobStatus, secs: Option<u64>) -> BuildLogJobMetadata {
+        BuildLogJobMetadata {
+            name: job.to_string(),
+            workflow_name: "CI".to_string(),
+            status,
+            error_count: 0,
+            duration: secs.map(Duration::from_secs),
+            html_url: String::new(),
+            job_id: None,
+            run_id: 1,
+        }
+    }
+
+    /// Fully expanded view model of a loaded "CI" workflow
+    fn view_model(jobs: Vec<JobNode>, job_metadata: Vec<BuildLogJobMetadata>) -> BuildLogViewModel {
+        let workflow = WorkflowNode {
+            name: "CI".to_string(),
+            total_errors: jobs.iter().map(|j| j.error_count).sum(),
+            has_failures: jobs.iter().any(|j| j.error_count > 0),
+            jobs,
+        };
+        let state = reduce_build_log(
+            BuildLogState::default(),
+            &BuildLogAction::Loaded {
+                workflows: vec![workflow],
+                job_metadata,
+                pr_context: BuildLogPrContext::default(),
+            },
+        );
+        let state = reduce_build_log(state, &BuildLogAction::ExpandAll);
+        BuildLogViewModel::from_state(&state, &gh_pr_lander_theme::Theme::default())
+    }
+
+    fn style_at(vm: &BuildLogViewModel, path: &[usize]) -> BuildLogRowStyle {
+        vm.rows
+            .iter()
+            .find(|row| row.path == path)
+            .map(|row| row.style)
+            .expect("row is visible")
+    }
+
+    #[test]
+    fn test_job_and_step_rows_are_styled_by_status() {
+        let vm = view_model(
+            vec![
+                job("build", vec![step("checkout", 0), step("test", 2)]),
+                job("lint", vec![step("clippy", 0)]),
+                job("docs", vec![step("rustdoc", 0)]),
+                job("fmt", vec![step("rustfmt", 0)]),
+            ],
+            vec![
+                meta("build", BuildLogJobStatus::Failure, Some(90)),
+                meta("lint", BuildLogJobStatus::InProgress, None),
+                meta("docs", BuildLogJobStatus::Skipped, None),
+            ],
+        );
+
+        assert_eq!(style_at(&vm, &[0, 0]), BuildLogRowStyle::Error);
+        // Only the failing step of a failed job is an error
+        assert_eq!(style_at(&vm, &[0, 0, 0]), BuildLogRowStyle::Success);
+        assert_eq!(style_at(&vm, &[0, 0, 1]), BuildLogRowStyle::Error);
+        // Steps take over the status of their running or skipped job
+        assert_eq!(style_at(&vm, &[0, 1]), BuildLogRowStyle::Warning);
+        assert_eq!(style_at(&vm, &[0, 1, 0]), BuildLogRowStyle::Warning);
+        assert_eq!(style_at(&vm, &[0, 2]), BuildLogRowStyle::Muted);
+        assert_eq!(style_at(&vm, &[0, 2, 0]), BuildLogRowStyle::Muted);
+        // Without metadata the status is inferred from the error count
+        assert_eq!(style_at(&vm, &[0, 3]), BuildLogRowStyle::Success);
+        assert_eq!(style_at(&vm, &[0, 3, 0]), BuildLogRowStyle::Success);
+    }
+
+    #[test]
+    fn test_workflow_row_shows_summed_job_durations() {
+        let vm = view_model(
+            vec![
+                job("build", vec![step("test", 0)]),
+                job("lint", vec![step("clippy", 0)]),
+                job("docs", vec![step("rustdoc", 0)]),
+                job("fmt", vec![step("rustfmt", 0)]),
+            ],
+            vec![
+                meta("build", BuildLogJobStatus::Success, Some(95)),
+                meta("lint", BuildLogJobStatus::Success, Some(40)),
+                // Skipped jobs and jobs without a duration don't count
+                meta("docs", BuildLogJobStatus::Skipped, Some(300)),
+                meta("fmt", BuildLogJobStatus::InProgress, None),
+            ],
+        );
+
+        let row = |path: &[usize]| {
+            vm.rows
+                .iter()
+                .find(|row| row.path == path)
+                .map(|row| row.text.clone())
+                .expect("row is visible")
+        };
+        assert!(row(&[0]).ends_with("CI (2m 15s)"));
+        assert!(row(&[0, 0]).ends_with("build (1m 35s)"));
+        assert!(row(&[0, 2]).ends_with("docs"));
+        assert!(row(&[0, 3]).ends_with("fmt"));
+    }
+
+    #[test]
+    fn test_workflow_row_without_known_durations() {
+        let vm = view_model(vec![job("build", vec![step("test", 0)])], vec![]);
+        assert!(vm.rows[0].text.ends_with("CI"));
+    }
+}
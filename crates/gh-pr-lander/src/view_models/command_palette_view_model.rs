@@ -4,7 +4,7 @@
 //! data preparation from rendering logic.
 
 use crate::command_id::CommandId;
-use crate::commands::{build_palette_commands, filter_commands};
+use crate::commands::{build_palette_commands, filter_commands, parse_argument_query};
 use crate::state::AppState;
 use ratatui::style::Color;
 
@@ -17,6 +17,8 @@ pub struct CommandPaletteViewModel {
     pub input_text: String,
     /// Is input empty (for placeholder styling)
     pub input_is_empty: bool,
+    /// Expected argument shown after the keyword until it is typed (e.g. "<name>")
+    pub argument_hint: Option<String>,
     /// Visible command rows (after filtering)
     pub visible_rows: Vec<CommandRow>,
     /// Currently selected command details
@@ -87,6 +89,10 @@ impl CommandPaletteViewModel {
         // Pre-format input text
         let input_text = state.command_palette.query.clone();
         let input_is_empty = input_text.is_empty();
+        let argument_hint = parse_argument_query(&state.command_palette.query)
+            .filter(|(_, argument)| argument.is_empty())
+            .and_then(|(id, _)| id.argument_placeholder())
+            .map(str::to_string);
 
         // Calculate max category width for right-alignment
         // Add 2 for brackets [] and 2 for padding
@@ -137,8 +143,13 @@ impl CommandPaletteViewModel {
                 };
 
                 // Format shortcut hint (13 chars: 12 for hint + 1 space)
+                // Commands with many bindings are truncated to keep columns aligned
                 let shortcut_hint = if let Some(ref hint) = cmd.shortcut_hint {
-                    format!("{:12} ", hint)
+                    if hint.chars().count() > 12 {
+                        format!("{}… ", hint.chars().take(11).collect::<String>())
+                    } else {
+                        format!("{:12} ", hint)
+                    }
                 } else {
                     "             ".to_string()
                 };
@@ -147,12 +158,14 @@ impl CommandPaletteViewModel {
                 let category = format!("[{}]", cmd.category());
                 let category = format!("{:>width$}", category, width = max_category_width as usize);
 
-                // Colors
-                let (fg_color, bg_color) = if is_selected {
+                // Colors - commands unavailable in the current state are grayed out
+                let is_enabled = cmd.unavailable_reason(state).is_none();
+                let (fg_color, bg_color) = match (is_selected, is_enabled) {
                     // Use active_fg (yellow) for text and selected_bg for background
-                    (theme.active_fg, theme.selected_bg)
-                } else {
-                    (theme.text().fg.unwrap_or(Color::White), Color::Reset)
+                    (true, true) => (theme.active_fg, theme.selected_bg),
+                    (true, false) => (theme.text_muted, theme.selected_bg),
+                    (false, true) => (theme.text().fg.unwrap_or(Color::White), Color::Reset),
+                    (false, false) => (theme.text_muted, Color::Reset),
                 };
 
                 CommandRow {
@@ -167,12 +180,15 @@ impl CommandPaletteViewModel {
             })
             .collect();
 
-        // Get selected command details
+        // Get selected command details, or why it is unavailable
         let selected_command =
             filtered_commands
                 .get(selected_index)
                 .map(|cmd| SelectedCommandDetails {
-                    description: cmd.description().to_string(),
+                    description: match cmd.unavailable_reason(state) {
+                        Some(reason) => format!("Unavailable: {}", reason),
+                        None => cmd.description().to_string(),
+                    },
                 });
 
         // Build footer hints from keymap. The command palette has TEXT_INPUT
@@ -199,6 +215,7 @@ impl CommandPaletteViewModel {
             total_commands,
             input_text,
             input_is_empty,
+            argument_hint,
             visible_rows,
             selected_command,
             max_category_width,
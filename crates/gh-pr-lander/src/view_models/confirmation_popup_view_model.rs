@@ -13,8 +13,14 @@ pub struct ConfirmationPopupViewModel {
     pub title: String,
     /// Target info line (e.g., "Approving: PR #123" or "Approving: 3 PRs")
     pub target_line: String,
+    /// Warning shown above the instructions (e.g., PRs with failing CI)
+    pub warning: Option<String>,
+    /// Detail lines below the warning (e.g., the merge bot's plan)
+    pub details: Vec<String>,
     /// Instructions text (e.g., "Enter your approval message:")
     pub instructions: String,
+    /// Whether to show the text input
+    pub show_input: bool,
     /// Input label (e.g., "Message:")
     pub input_label: String,
     /// Current input value
@@ -62,15 +68,23 @@ impl ConfirmationPopupViewModel {
     /// Build view model from confirmation popup state
     pub fn from_state(state: &ConfirmationPopupState, theme: &gh_pr_lander_theme::Theme) -> Self {
         let title = state.title().to_string();
-        let target_line = format!("{}: {}", state.action_verb(), state.target_info());
+        let target_info = state.target_info();
+        let target_line = if target_info.is_empty() {
+            String::new()
+        } else {
+            format!("{}: {}", state.action_verb(), target_info)
+        };
+        let warning = state.intent.warning().map(|w| format!("⚠ {}", w));
+        let details = state.intent.details();
         let instructions = state.instructions().to_string();
-        let input_label = "Message:".to_string();
+        let show_input = state.intent.has_input();
+        let input_label = state.intent.input_label().to_string();
         let input_value = state.input_value.clone();
         let input_is_empty = input_value.is_empty();
         let is_valid = state.is_valid();
 
         let validation_hint = if !is_valid && state.requires_input() {
-            Some("Message is required".to_string())
+            Some(state.intent.validation_hint().to_string())
         } else {
             None
         };
@@ -95,7 +109,10 @@ impl ConfirmationPopupViewModel {
         Self {
             title,
             target_line,
+            warning,
+            details,
             instructions,
+            show_input,
             input_label,
             input_value,
             input_is_empty,
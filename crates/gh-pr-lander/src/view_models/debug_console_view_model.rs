@@ -2,7 +2,9 @@
 
 use crate::keybindings::Keymap;
 use crate::keymap::CommandId;
+use crate::log_reader::LogLine;
 use crate::state::DebugConsoleState;
+use log::LevelFilter;
 
 /// Pre-computed footer hints for keyboard shortcuts
 #[derive(Debug, Clone)]
@@ -13,6 +15,10 @@ pub struct DebugConsoleFooterHints {
     pub top_bottom: String,
     /// Close hint (e.g., "`")
     pub close: String,
+    /// Cycle minimum level hint (e.g., "L")
+    pub level: String,
+    /// Text filter hint (e.g., "/")
+    pub filter: String,
 }
 
 /// View model for debug console - handles presentation logic
@@ -46,6 +52,12 @@ impl<'a> DebugConsoleViewModel<'a> {
             close: keymap
                 .compact_hint_for_command(CommandId::DebugToggleConsoleView)
                 .unwrap_or_else(|| "`".to_string()),
+            level: keymap
+                .compact_hint_for_command(CommandId::DebugCycleLogLevel)
+                .unwrap_or_else(|| "L".to_string()),
+            filter: keymap
+                .compact_hint_for_command(CommandId::DebugFilterLogs)
+                .unwrap_or_else(|| "/".to_string()),
         };
 
         Self {
@@ -58,8 +70,8 @@ impl<'a> DebugConsoleViewModel<'a> {
     ///
     /// scroll_offset = 0 means we're at the bottom (showing newest logs)
     /// scroll_offset > 0 means we've scrolled up (showing older logs)
-    pub fn visible_lines(&self, available_height: usize) -> Vec<&String> {
-        let total = self.state.lines.len();
+    pub fn visible_lines(&self, available_height: usize) -> Vec<&LogLine> {
+        let total = self.state.filtered_len();
 
         if total == 0 || available_height == 0 {
             return Vec::new();
@@ -76,22 +88,27 @@ impl<'a> DebugConsoleViewModel<'a> {
         let start = end.saturating_sub(available_height);
 
         self.state
-            .lines
-            .iter()
+            .filtered_lines()
             .skip(start)
             .take(end - start)
             .collect()
     }
 
-    /// Get the title for the debug console with scroll indicator
+    /// Get the title for the debug console with filter and scroll indicators
     pub fn title(&self) -> String {
+        let mut title = " Debug Console (c to clear)".to_string();
+        if self.state.min_level != LevelFilter::Trace {
+            title.push_str(&format!(" - ≥{}", self.state.min_level));
+        }
+        if self.state.filter_editing {
+            title.push_str(&format!(" - /{}▏", self.state.filter_text));
+        } else if !self.state.filter_text.is_empty() {
+            title.push_str(&format!(" - /{}", self.state.filter_text));
+        }
         if self.state.scroll_offset > 0 {
-            format!(
-                " Debug Console (c to clear) - ↓{} ",
-                self.state.scroll_offset
-            )
-        } else {
-            " Debug Console (c to clear) ".to_string()
+            title.push_str(&format!(" - ↓{}", self.state.scroll_offset));
         }
+        title.push(' ');
+        title
     }
 }
@@ -0,0 +1,33 @@
+//! Error Banner View Model
+//!
+//! Pre-computes the text of the error banner shown at the top of the screen.
+
+use crate::command_id::CommandId;
+use crate::state::AppState;
+
+/// View model for the error banner
+#[derive(Debug, Clone)]
+pub struct ErrorBannerViewModel {
+    /// Banner text (e.g., " ✗ Load: Failed to load PRs: timeout ")
+    pub text: String,
+    /// Dismiss hint (e.g., "x dismiss"), if the command is bound
+    pub dismiss_hint: Option<String>,
+}
+
+impl ErrorBannerViewModel {
+    /// Build the view model, if an error is shown and hasn't expired yet
+    pub fn from_state(state: &AppState) -> Option<Self> {
+        let banner = state
+            .error_banner
+            .as_ref()
+            .filter(|banner| !banner.is_expired())?;
+
+        Some(Self {
+            text: format!(" ✗ {}: {} ", banner.context, banner.message),
+            dismiss_hint: state
+                .keymap
+                .hint_for_command(CommandId::ErrorDismiss)
+                .map(|hint| format!(" {} dismiss ", hint)),
+        })
+    }
+}
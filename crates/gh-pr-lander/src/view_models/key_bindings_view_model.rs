@@ -10,7 +10,7 @@ use std::collections::BTreeMap;
 /// A single binding row in the panel
 #[derive(Debug, Clone)]
 pub struct BindingRow {
-    /// Key hint (e.g., "j/↓", "Ctrl+P", "p → a")
+    /// Key hints of all bindings of the command (e.g., "j / ↓", "Ctrl+P", "p → a")
     pub keys: String,
     /// Description of what the binding does
     pub description: String,
@@ -99,24 +99,25 @@ impl KeyBindingsPanelViewModel {
     fn build_sections(keymap: &Keymap) -> Vec<BindingSection> {
         // Use BTreeMap for consistent ordering
         let mut by_category: BTreeMap<&'static str, Vec<BindingRow>> = BTreeMap::new();
+        let mut seen: Vec<CommandId> = Vec::new();
 
         for binding in keymap.bindings() {
+            // One row per command, listing all of its bindings
+            if seen.contains(&binding.command) {
+                continue;
+            }
+            seen.push(binding.command);
+
             // Filter out commands that shouldn't be shown
-            if !binding.command.show_in_palette()
-                && !matches!(
-                    binding.command,
-                    CommandId::NavigateNext
-                        | CommandId::NavigatePrevious
-                        | CommandId::NavigateToTop
-                        | CommandId::NavigateToBottom
-                )
-            {
+            if !binding.command.show_in_help() {
                 continue;
             }
 
             let category = binding.command.category();
             let row = BindingRow {
-                keys: binding.hint.clone(),
+                keys: keymap
+                    .all_hints_for_command(binding.command)
+                    .unwrap_or_else(|| binding.hint.clone()),
                 description: binding.command.description().to_string(),
             };
 
@@ -130,52 +131,24 @@ impl KeyBindingsPanelViewModel {
 
         for category in &category_order {
             if let Some(bindings) = by_category.remove(*category) {
-                // Deduplicate bindings by description (combine keys for same action)
-                let deduped = Self::deduplicate_bindings(bindings);
-                if !deduped.is_empty() {
-                    sections.push(BindingSection {
-                        category: (*category).to_string(),
-                        bindings: deduped,
-                    });
-                }
+                sections.push(BindingSection {
+                    category: (*category).to_string(),
+                    bindings,
+                });
             }
         }
 
         // Add any remaining categories not in the defined order
         for (category, bindings) in by_category {
-            let deduped = Self::deduplicate_bindings(bindings);
-            if !deduped.is_empty() {
-                sections.push(BindingSection {
-                    category: category.to_string(),
-                    bindings: deduped,
-                });
-            }
+            sections.push(BindingSection {
+                category: category.to_string(),
+                bindings,
+            });
         }
 
         sections
     }
 
-    /// Deduplicate bindings by description, combining keys
-    fn deduplicate_bindings(bindings: Vec<BindingRow>) -> Vec<BindingRow> {
-        let mut result: Vec<BindingRow> = Vec::new();
-
-        for binding in bindings {
-            if let Some(existing) = result
-                .iter_mut()
-                .find(|b| b.description == binding.description)
-            {
-                // Combine keys if not already present
-                if !existing.keys.contains(&binding.keys) {
-                    existing.keys = format!("{}/{}", existing.keys, binding.keys);
-                }
-            } else {
-                result.push(binding);
-            }
-        }
-
-        result
-    }
-
     /// Calculate max scroll offset based on visible height
     #[allow(dead_code)]
     pub fn max_scroll(&self, visible_height: usize) -> usize {
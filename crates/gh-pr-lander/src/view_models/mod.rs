@@ -1,16 +1,24 @@
+pub mod action_history_view_model;
 pub mod build_log_view_model;
 pub mod command_palette_view_model;
 pub mod confirmation_popup_view_model;
 pub mod debug_console_view_model;
+pub mod error_banner_view_model;
 pub mod key_bindings_view_model;
+pub mod pr_description_view_model;
 pub mod pull_request_view_model;
 pub mod repository_tabs_view_model;
+pub mod shortcut_bar_view_model;
 pub mod status_bar;
+pub mod toast_view_model;
+pub mod which_key_view_model;
 
 pub use build_log_view_model::{BuildLogRowStyle, BuildLogViewModel};
 pub use command_palette_view_model::CommandPaletteViewModel;
 pub use confirmation_popup_view_model::ConfirmationPopupViewModel;
+pub use error_banner_view_model::ErrorBannerViewModel;
 pub use key_bindings_view_model::KeyBindingsPanelViewModel;
+pub use pr_description_view_model::PrDescriptionViewModel;
 #[allow(unused_imports)]
 pub use pull_request_view_model::PrTableViewModel;
 pub use repository_tabs_view_model::{
@@ -18,4 +26,7 @@ pub use repository_tabs_view_model::{
 };
 #[allow(unused_imports)]
 pub use repository_tabs_view_model::{TabHintViewModel, TabViewModel};
+pub use shortcut_bar_view_model::ShortcutBarViewModel;
 pub use status_bar::StatusBarViewModel;
+pub use toast_view_model::ToastViewModel;
+pub use which_key_view_model::WhichKeyViewModel;
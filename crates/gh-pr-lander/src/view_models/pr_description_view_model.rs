@@ -0,0 +1,63 @@
+//! PR Description View Model
+//!
+//! Pre-computes the title, head branch and the rendered markdown body shown
+//! in the description panel next to the PR table.
+
+use crate::state::AppState;
+use crate::utils::markdown::render_markdown;
+use ratatui::text::{Line, Text};
+
+/// View model for the PR description panel
+#[derive(Debug, Clone)]
+pub struct PrDescriptionViewModel {
+    /// Panel title (e.g., " #42 Description ")
+    pub title: String,
+    /// Head branch of the PR (`owner:branch` for forks), if known
+    pub branch: Option<String>,
+    /// Rendered PR body, or a placeholder when it is empty
+    pub body: Text<'static>,
+    /// Vertical scroll offset
+    pub scroll: u16,
+}
+
+impl PrDescriptionViewModel {
+    /// Build the view model for the focused PR
+    pub fn from_state(state: &AppState) -> Self {
+        let theme = &state.theme;
+        let pr = state.main_view.focused_pr();
+        let body = pr.map(|pr| pr.body.trim()).unwrap_or_default();
+
+        Self {
+            title: match pr {
+                Some(pr) => format!(" #{} Description ", pr.number),
+                None => " Description ".to_string(),
+            },
+            body: if body.is_empty() {
+                Text::from(Line::styled("No description provided.", theme.muted()))
+            } else {
+                render_markdown(body, theme)
+            },
+            branch: pr
+                .filter(|pr| !pr.head_branch.is_empty())
+                .map(|pr| pr.branch_ref()),
+            scroll: state.main_view.description_scroll,
+        }
+    }
+
+    /// Scroll offset clamped so the last wrapped line stays visible
+    pub fn clamped_scroll(&self, width: u16) -> u16 {
+        let width = width.max(1) as usize;
+        let wrapped_lines: usize = self
+            .body
+            .lines
+            .iter()
+            .map(|line| line.width().div_ceil(width).max(1))
+            .sum();
+        self.scroll.min(
+            wrapped_lines
+                .saturating_sub(1)
+                .try_into()
+                .unwrap_or(u16::MAX),
+        )
+    }
+}
@@ -4,9 +4,10 @@
 //! Pre-computes all display text, colors, and styles in the view model.
 
 use crate::domain_models::{
-    LoadingState, MaturityState, MergeableStatus, Pr, Repository, ReviewDecision,
+    LoadingState, MaturityState, MergeableStatus, MergeableSummary, Pr, Repository, ReviewDecision,
 };
-use crate::state::RepositoryData;
+use crate::state::{PrFilter, RepositoryData, RowDensity};
+use crate::utils::relative_time::format_age;
 use gh_pr_lander_theme::Theme;
 use ratatui::style::Color;
 
@@ -19,6 +20,8 @@ pub struct PrTableViewModel {
     pub rows: Vec<PrRowViewModel>,
     /// Current cursor position (for keyboard navigation)
     pub selected_index: usize,
+    /// Row layout (compact single-line or detailed two-line rows)
+    pub density: RowDensity,
 }
 
 /// View model for table header
@@ -30,6 +33,12 @@ pub struct PrTableHeaderViewModel {
     pub status_text: String,
     /// Status color (from theme)
     pub status_color: Color,
+    /// Summary of the multi-selection: "5 selected · 3 ready · 1 needs rebase"
+    pub selection_summary: Option<String>,
+    /// Color of the selection summary (red if a bulk merge would partially fail)
+    pub selection_summary_color: Color,
+    /// Warning that the list stopped at the PR limit: "showing the first 50 PRs"
+    pub truncation: Option<String>,
 }
 
 /// View model for a single PR row
@@ -42,6 +51,8 @@ pub struct PrRowViewModel {
     pub maturity_text: String, // "Draft" or ""
     pub review_text: String,   // "✓", "!", "○", "?"
     pub status_text: String,   // "✓ Ready"
+    pub age_text: String,      // "3d" (since last update)
+    pub details: String,       // "sassman · 3d · ✓ Ready · feature/x" (detailed rows)
 
     /// Pre-computed styles
     pub bg_color: Color, // Background (alternating, selected, etc.)
@@ -49,40 +60,82 @@ pub struct PrRowViewModel {
     pub maturity_color: Color, // Maturity-specific color
     pub review_color: Color,   // Review-specific color
     pub status_color: Color,   // Status-specific color
+    pub age_color: Color,      // Staleness color
     pub additions: usize,      // Raw additions count (for coloring)
     pub deletions: usize,      // Raw deletions count (for coloring)
 }
 
 impl PrTableViewModel {
     /// Transform state into display-ready view model
-    pub fn from_repo_data(repo_data: &RepositoryData, repo: &Repository, theme: &Theme) -> Self {
+    ///
+    /// `include_hidden`: whether bulk operations include selected PRs hidden
+    /// by the filter (`[pr] bulk_include_hidden`)
+    pub fn from_repo_data(
+        repo_data: &RepositoryData,
+        repo: &Repository,
+        density: RowDensity,
+        include_hidden: bool,
+        theme: &Theme,
+    ) -> Self {
         // Build header
-        let header = Self::build_header(repo_data, repo, theme);
+        let header = Self::build_header(repo_data, repo, include_hidden, theme);
 
-        // Build rows
-        let rows = repo_data
-            .prs
+        // Build rows for the PRs matching the current filter
+        let visible = repo_data.visible_pr_indices();
+        let rows = visible
             .iter()
             .enumerate()
-            .map(|(index, pr)| {
+            .map(|(row_index, &pr_index)| {
+                let pr = &repo_data.prs[pr_index];
                 let is_multi_selected = repo_data.selected_pr_numbers.contains(&pr.number);
-                Self::build_row(pr, index, repo_data.selected_pr, is_multi_selected, theme)
+                let mut row = Self::build_row(
+                    pr,
+                    row_index,
+                    pr_index == repo_data.selected_pr,
+                    is_multi_selected,
+                    repo_data.is_snoozed(pr),
+                    repo_data.title_scroll,
+                    theme,
+                );
+                // Watched for CI completion notifications
+                if repo_data.watched.contains(&pr.number) {
+                    row.title = format!("👁 {}", row.title);
+                }
+                row
             })
             .collect();
 
+        // Cursor position within the visible rows
+        let selected_index = visible
+            .iter()
+            .position(|&idx| idx == repo_data.selected_pr)
+            .unwrap_or(0);
+
         Self {
             header,
             rows,
-            selected_index: repo_data.selected_pr,
+            selected_index,
+            density,
         }
     }
 
     fn build_header(
         repo_data: &RepositoryData,
         repo: &Repository,
+        include_hidden: bool,
         theme: &Theme,
     ) -> PrTableHeaderViewModel {
-        let title = format!("  {}/{}@{} ", repo.org, repo.repo, repo.branch);
+        let title = if repo_data.current_filter == PrFilter::All {
+            format!("  {}/{}@{} ", repo.org, repo.repo, repo.branch)
+        } else {
+            format!(
+                "  {}/{}@{} [{}] ",
+                repo.org,
+                repo.repo,
+                repo.branch,
+                repo_data.current_filter.label()
+            )
+        };
 
         let (status_text, status_color) = Self::format_loading_state(
             &repo_data.loading_state,
@@ -90,26 +143,86 @@ impl PrTableViewModel {
             theme,
         );
 
+        let (selection_summary, selection_summary_color) =
+            Self::build_selection_summary(repo_data, include_hidden, theme);
+
+        // GitHub doesn't tell the total, only that there is more
+        let truncation = repo_data
+            .has_more_prs
+            .then(|| format!("showing the first {} PRs", repo_data.prs.len()));
+
         PrTableHeaderViewModel {
             title,
             status_text,
             status_color,
+            selection_summary,
+            selection_summary_color,
+            truncation,
+        }
+    }
+
+    /// Summarize the mergeable states of the PRs a bulk operation would target
+    fn build_selection_summary(
+        repo_data: &RepositoryData,
+        include_hidden: bool,
+        theme: &Theme,
+    ) -> (Option<String>, Color) {
+        if repo_data.selected_pr_numbers.is_empty() {
+            return (None, Color::Reset);
+        }
+
+        // Selections made before the filter was applied
+        let hidden = repo_data.hidden_selection_count();
+        let hidden_text = match (hidden, include_hidden) {
+            (0, _) => String::new(),
+            (_, true) => format!("· {} hidden by filter, included ", hidden),
+            (_, false) => format!("· {} hidden by filter, skipped ", hidden),
+        };
+
+        let summary = MergeableSummary::from_prs(repo_data.target_prs(include_hidden));
+        if summary.total == 0 {
+            if hidden == 0 {
+                return (None, Color::Reset);
+            }
+            let text = format!(" {} selected, all hidden by filter ", hidden);
+            return (Some(text), theme.status_warning);
         }
+
+        let color = if summary.all_ready() {
+            theme.status_success
+        } else if summary.has_blockers() {
+            theme.status_error
+        } else {
+            theme.status_warning
+        };
+
+        let text = format!(
+            " {} selected · {} {}",
+            summary.total,
+            summary.label(),
+            hidden_text
+        );
+        (Some(text), color)
     }
 
     fn build_row(
         pr: &Pr,
         index: usize,
-        cursor_index: usize,
+        is_cursor: bool,
         is_multi_selected: bool,
+        is_snoozed: bool,
+        title_scroll: usize,
         theme: &Theme,
     ) -> PrRowViewModel {
-        let is_cursor = index == cursor_index;
-
         // Pre-compute display text with selection indicator
         let selection_indicator = if is_multi_selected { "●" } else { " " };
         let pr_number = format!("{} #{}", selection_indicator, pr.number);
-        let title = pr.title.clone();
+        // Only the focused row scrolls horizontally; others stay truncated
+        let title = if is_cursor && title_scroll > 0 {
+            pr.title.chars().skip(title_scroll).collect()
+        } else {
+            pr.title.clone()
+        };
         let author = pr.author.clone();
 
         // Format maturity (Draft/Ready)
@@ -124,6 +237,19 @@ impl PrTableViewModel {
         let status_text = format!("{} {}", pr.mergeable.icon(), pr.mergeable.label());
         let status_color = Self::mergeable_status_color(pr.mergeable, theme);
 
+        // Format age since last update, highlighting stale PRs
+        let age_text = format_age(&pr.updated_at);
+        let age_color = Self::age_color(chrono::Utc::now() - pr.updated_at, theme);
+
+        // Second line of detailed rows
+        let mut details = format!("{} · {} · {}", author, age_text, status_text);
+        if !pr.head_branch.is_empty() {
+            details.push_str(&format!(" · {}", pr.head_branch));
+        }
+        if pr.comments > 0 {
+            details.push_str(&format!(" · 💬 {}", pr.comments));
+        }
+
         // Compute colors - multi-selected rows get highlighted differently
         let (fg_color, bg_color) = if is_cursor {
             (theme.active_fg, theme.selected_bg)
@@ -140,7 +266,13 @@ impl PrTableViewModel {
             } else {
                 Color::Rgb(30, 30, 40) // Subtle alternate row color
             };
-            (theme.text().fg.unwrap_or(Color::White), bg)
+            // Snoozed PRs are dimmed
+            let fg = if is_snoozed {
+                theme.muted().fg.unwrap_or(Color::Gray)
+            } else {
+                theme.text().fg.unwrap_or(Color::White)
+            };
+            (fg, bg)
         };
 
         PrRowViewModel {
@@ -155,6 +287,9 @@ impl PrTableViewModel {
             bg_color,
             fg_color,
             status_color,
+            age_text,
+            age_color,
+            details,
             additions: pr.additions,
             deletions: pr.deletions,
         }
@@ -212,6 +347,17 @@ impl PrTableViewModel {
         }
     }
 
+    /// Get color for PR age (stale PRs stand out)
+    fn age_color(age: chrono::Duration, theme: &Theme) -> Color {
+        if age.num_days() > 30 {
+            theme.status_error
+        } else if age.num_days() > 7 {
+            theme.status_warning
+        } else {
+            theme.muted().fg.unwrap_or(Color::Gray)
+        }
+    }
+
     // --- Presentation helpers for MaturityState ---
 
     fn maturity_status_text(maturity: MaturityState) -> &'static str {
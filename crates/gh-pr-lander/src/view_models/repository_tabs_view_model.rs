@@ -7,9 +7,6 @@ use crate::domain_models::LoadingState;
 use crate::state::AppState;
 use ratatui::style::{Color, Modifier, Style};
 
-/// Hourglass icon for loading state
-const HOURGLASS_ICON: &str = "⏳";
-
 /// View model for the entire repository tab bar
 #[derive(Debug, Clone)]
 pub struct RepositoryTabsViewModel {
@@ -78,12 +75,16 @@ impl RepositoryTabsViewModel {
                 let is_selected = idx == selected_index;
                 let is_loading = Self::is_repo_loading(state, idx);
 
-                // Build title: "org/repo@branch"
-                let title = repo.repo.to_string();
+                // Build title: "1 repo", numbered for the tabs reachable via 1-9 and 0
+                let title = if idx < 10 {
+                    format!("{} {}", (idx + 1) % 10, repo.repo)
+                } else {
+                    repo.repo.to_string()
+                };
 
-                // Add loading icon if needed
+                // Add animated spinner if loading
                 let display_text = if is_loading {
-                    format!("{} {}", HOURGLASS_ICON, title)
+                    format!("{} {}", state.spinner.frame(), title)
                 } else {
                     title
                 };
@@ -157,6 +158,25 @@ impl RepositoryTabsViewModel {
         }
     }
 
+    /// Index of the tab at column `x` of a tab bar `width` columns wide
+    ///
+    /// Mirrors the layout of `RepositoryTabsWidget`: help hint first, then the
+    /// tabs back to back, stopping at the first tab that doesn't fit.
+    pub fn tab_at(&self, x: u16, width: u16) -> Option<usize> {
+        let mut start = self.help_hint.width;
+        for (idx, tab) in self.tabs.iter().enumerate() {
+            let end = start + tab.width;
+            if end > width {
+                return None;
+            }
+            if (start..end).contains(&x) {
+                return Some(idx);
+            }
+            start = end;
+        }
+        None
+    }
+
     /// Check if a repository is in a loading state
     fn is_repo_loading(state: &AppState, repo_idx: usize) -> bool {
         state.main_view.repo_data.get(&repo_idx).is_none_or(|data| {
@@ -199,55 +219,70 @@ pub enum MainContentViewModel {
 pub struct EmptyStateViewModel {
     /// Message to display
     pub message: String,
+    /// Call-to-action hints below the message: (key, description)
+    pub hints: Vec<(String, &'static str)>,
     /// Border color
     pub border_color: Color,
     /// Text style
     pub text_style: Style,
+    /// Style of the keys in the hints
+    pub key_style: Style,
 }
 
 impl EmptyStateViewModel {
     /// Create view model for "no repositories" state
+    ///
+    /// Hints use the actual keybindings, so they stay correct under custom
+    /// keymaps; unbound commands are left out.
     pub fn no_repos(state: &AppState) -> Self {
-        let theme = &state.theme;
-        let key = state
-            .keymap
-            .compact_hint_for_command(crate::keymap::CommandId::RepositoryAdd)
-            .unwrap_or("p → a".to_string());
+        use crate::keymap::CommandId;
+
+        let hints = [
+            (CommandId::RepositoryAdd, "to add a repository"),
+            (CommandId::CommandPaletteOpen, "to open the command palette"),
+            (CommandId::KeyBindingsToggleView, "to show all key bindings"),
+        ]
+        .into_iter()
+        .filter_map(|(command, description)| {
+            let key = state.keymap.hint_for_command(command)?;
+            Some((key.to_string(), description))
+        })
+        .collect();
+
         Self {
-            message: format!("No repositories configured. Press '{key}' to add one."),
-            border_color: theme.accent_primary,
-            text_style: theme.muted(),
+            hints,
+            ..Self::with_message(state, "No repositories configured yet.".to_string())
         }
     }
 
-    /// Create view model for loading state
-    pub fn loading(state: &AppState) -> Self {
+    /// Plain message without hints
+    fn with_message(state: &AppState, message: String) -> Self {
         let theme = &state.theme;
         Self {
-            message: "Loading pull requests...".to_string(),
+            message,
+            hints: Vec::new(),
             border_color: theme.accent_primary,
             text_style: theme.muted(),
+            key_style: theme.key_hint(),
         }
     }
 
+    /// Create view model for loading state
+    pub fn loading(state: &AppState) -> Self {
+        Self::with_message(state, "Loading pull requests...".to_string())
+    }
+
     /// Create view model for "no PRs" state
     pub fn no_prs(state: &AppState) -> Self {
-        let theme = &state.theme;
-        Self {
-            message: "No open pull requests found.".to_string(),
-            border_color: theme.accent_primary,
-            text_style: theme.muted(),
-        }
+        Self::with_message(state, "No open pull requests found.".to_string())
     }
 
     /// Create view model for error state
     pub fn error(state: &AppState, error_msg: &str) -> Self {
-        let theme = &state.theme;
-        Self {
-            message: format!("Error: {}. Press Ctrl+r to retry.", error_msg),
-            border_color: theme.accent_primary,
-            text_style: theme.muted(),
-        }
+        Self::with_message(
+            state,
+            format!("Error: {}. Press Ctrl+r to retry.", error_msg),
+        )
     }
 }
 
@@ -0,0 +1,110 @@
+//! Shortcut Bar View Model
+//!
+//! Derives the shortcuts of the active view: its own `available_actions`
+//! followed by the generic actions implied by its capabilities, each labelled
+//! with its key hints from the keymap. Since the hints come from the keymap,
+//! user overrides show up here and unbound commands are left out.
+
+use crate::actions::{ActionCategory, AvailableAction};
+use crate::capabilities::PanelCapabilities;
+use crate::command_id::CommandId;
+use crate::keymap::Keymap;
+use crate::state::AppState;
+use ratatui::style::{Color, Style};
+
+/// A single `key label` chip
+#[derive(Debug, Clone)]
+pub struct ShortcutChip {
+    /// Key hint (e.g., "j/↓")
+    pub keys: String,
+    /// Short label (e.g., "Down")
+    pub label: &'static str,
+}
+
+/// View model for the shortcut bar
+#[derive(Debug, Clone)]
+pub struct ShortcutBarViewModel {
+    /// Chips in display order (primary, selection, navigation)
+    pub chips: Vec<ShortcutChip>,
+    /// Style for the key hints
+    pub key_style: Style,
+    /// Style for the labels
+    pub label_style: Style,
+    /// Background color for the bar
+    pub bg_color: Color,
+}
+
+impl ShortcutBarViewModel {
+    /// Build the shortcuts of the top-most view
+    pub fn from_state(state: &AppState) -> Self {
+        let theme = &state.theme;
+        let chips = match state.view_stack.last() {
+            Some(view) => shortcut_chips(
+                &state.keymap,
+                view.available_actions(state),
+                view.capabilities(state),
+            ),
+            None => Vec::new(),
+        };
+
+        Self {
+            chips,
+            key_style: theme.key_hint(),
+            label_style: theme.muted(),
+            bg_color: theme.bg_primary,
+        }
+    }
+}
+
+/// Resolve actions to chips, dropping duplicates and commands without a key
+fn shortcut_chips(
+    keymap: &Keymap,
+    mut actions: Vec<AvailableAction>,
+    capabilities: PanelCapabilities,
+) -> Vec<ShortcutChip> {
+    actions.extend(AvailableAction::for_capabilities(capabilities));
+    // Stable sort keeps the view's own order within a category
+    actions.sort_by_key(|action| match action.category {
+        ActionCategory::Primary => 0,
+        ActionCategory::Selection => 1,
+        ActionCategory::Navigation => 2,
+    });
+
+    // Keys typed into a text input never reach the keymap
+    let text_input = capabilities.accepts_text_input();
+    let mut seen = Vec::new();
+    actions
+        .into_iter()
+        .filter(|action| {
+            let first = !seen.contains(&action.command);
+            seen.push(action.command);
+            first
+        })
+        .filter_map(|action| {
+            let keys = keymap
+                .compact_hint_for_command_filtered(action.command, |hint| {
+                    !text_input || !is_typed_hint(hint)
+                })
+                .or_else(|| context_hint(action.command).map(str::to_string))?;
+            Some(ShortcutChip {
+                keys,
+                label: action.label,
+            })
+        })
+        .collect()
+}
+
+/// Keys of context actions, which the keyboard layer routes without a keymap entry
+fn context_hint(command: CommandId) -> Option<&'static str> {
+    match command {
+        CommandId::Confirm => Some("Enter"),
+        CommandId::ToggleSelect => Some("Space"),
+        _ => None,
+    }
+}
+
+/// Whether a hint is made of plain characters (e.g. "q", "gg", "p -> m")
+fn is_typed_hint(hint: &str) -> bool {
+    hint.contains(' ')
+        || (hint.is_ascii() && (hint.len() == 1 || hint.chars().all(|c| c.is_ascii_lowercase())))
+}
@@ -3,6 +3,8 @@
 //! Pre-computes presentation data for the status bar.
 
 use crate::state::{AppState, StatusKind};
+use crate::utils::relative_time::format_compact_duration;
+use chrono::Local;
 use ratatui::style::{Color, Modifier, Style};
 
 /// View model for rendering the status bar
@@ -24,11 +26,25 @@ pub struct StatusBarViewModel {
     pub bg_color: Color,
     /// Style for metadata (timestamp, source)
     pub metadata_style: Style,
+    /// Pending two-key sequence with remaining time (e.g., "g… 1.4s")
+    pub pending_sequence: Option<String>,
+    /// Style for the pending sequence indicator
+    pub pending_sequence_style: Style,
+    /// Last refresh of the active repository and local time (e.g., "↻ 3m ago · 14:32"),
+    /// shown in the right corner
+    pub clock: String,
 }
 
 impl StatusBarViewModel {
     pub fn from_state(state: &AppState) -> Self {
         let theme = &state.theme;
+        let pending_sequence = state
+            .pending_sequence
+            .as_ref()
+            .filter(|pending| !pending.is_expired())
+            .map(|pending| format!("{}… {:.1}s", pending.key, pending.remaining().as_secs_f32()));
+        let pending_sequence_style = theme.key_hint();
+        let clock = Self::clock_text(state);
 
         if let Some(msg) = state.status_bar.latest() {
             let fg_color = match msg.kind {
@@ -48,6 +64,9 @@ impl StatusBarViewModel {
                 message_style: Style::default().fg(fg_color).add_modifier(Modifier::BOLD),
                 bg_color: theme.bg_primary,
                 metadata_style: Style::default().fg(theme.text_muted),
+                pending_sequence,
+                pending_sequence_style,
+                clock,
             }
         } else {
             // Welcome message when no status messages
@@ -62,7 +81,29 @@ impl StatusBarViewModel {
                     .add_modifier(Modifier::ITALIC),
                 bg_color: theme.bg_primary,
                 metadata_style: Style::default().fg(theme.text_muted),
+                pending_sequence,
+                pending_sequence_style,
+                clock,
             }
         }
     }
+
+    /// Time since the active repository was last refreshed, followed by the
+    /// local wall-clock time
+    fn clock_text(state: &AppState) -> String {
+        let now = Local::now();
+        let time = now.format("%H:%M").to_string();
+        let last_updated = state
+            .main_view
+            .repo_data
+            .get(&state.main_view.selected_repository)
+            .and_then(|data| data.last_updated);
+        match last_updated {
+            Some(last_updated) => match format_compact_duration(now - last_updated).as_str() {
+                "now" => format!("↻ just now · {}", time),
+                age => format!("↻ {} ago · {}", age, time),
+            },
+            None => time,
+        }
+    }
 }
@@ -0,0 +1,43 @@
+//! Toast View Model
+//!
+//! Pre-computes the text and colors of the toast shown for the most recent
+//! operation result.
+
+use crate::domain_models::TaskStatusType;
+use crate::state::AppState;
+use ratatui::style::Color;
+
+/// View model for the toast notification
+#[derive(Debug, Clone)]
+pub struct ToastViewModel {
+    /// Toast text (e.g., " ✓ Merged PR #42 ")
+    pub text: String,
+    /// Foreground color
+    pub fg: Color,
+    /// Background color, by status type
+    pub bg: Color,
+}
+
+impl ToastViewModel {
+    /// Build the view model, if a toast is visible
+    pub fn from_state(state: &AppState) -> Option<Self> {
+        let toast = state
+            .toasts
+            .current()
+            .filter(|_| !state.toasts.is_expired())?;
+        let theme = &state.theme;
+
+        let (icon, bg) = match toast.status.status_type {
+            TaskStatusType::Success => ("✓", theme.status_success),
+            TaskStatusType::Error => ("✗", theme.status_error),
+            TaskStatusType::Warning => ("⚠", theme.status_warning),
+            TaskStatusType::Running => ("…", theme.status_info),
+        };
+
+        Some(Self {
+            text: format!(" {} {} ", icon, toast.status.message),
+            fg: theme.bg_primary,
+            bg,
+        })
+    }
+}
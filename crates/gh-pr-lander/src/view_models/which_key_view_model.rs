@@ -0,0 +1,60 @@
+//! Which-Key View Model
+//!
+//! Pre-computes the follow-up keys of a pending two-key sequence for the
+//! which-key hint popup.
+
+use crate::state::AppState;
+
+/// A possible second key and the command it triggers
+#[derive(Debug, Clone)]
+pub struct WhichKeyRow {
+    /// Second key of the sequence (e.g., "m")
+    pub key: String,
+    /// Command title (e.g., "Merge PR")
+    pub title: String,
+}
+
+/// View model for the which-key hint popup
+#[derive(Debug, Clone)]
+pub struct WhichKeyViewModel {
+    /// Popup title showing the pending prefix (e.g., " p … ")
+    pub title: String,
+    /// Follow-up keys, in keymap order
+    pub rows: Vec<WhichKeyRow>,
+}
+
+impl WhichKeyViewModel {
+    /// Build the view model, if a sequence is pending and has follow-ups
+    /// the active view accepts
+    pub fn from_state(state: &AppState) -> Option<Self> {
+        let pending = state
+            .pending_sequence
+            .as_ref()
+            .filter(|pending| !pending.is_expired())?;
+        let view = state.active_view();
+
+        let mut rows: Vec<WhichKeyRow> = Vec::new();
+        for (second, binding) in state.keymap.continuations(pending.key) {
+            let key = second.to_string();
+            // The first binding of a key wins, like in key matching
+            if rows.iter().any(|row| row.key == key)
+                || !view.accepts_action(&binding.command.to_action())
+            {
+                continue;
+            }
+            rows.push(WhichKeyRow {
+                key,
+                title: binding.command.title().to_string(),
+            });
+        }
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            title: format!(" {} … ", pending.key),
+            rows,
+        })
+    }
+}
@@ -0,0 +1,160 @@
+//! Action History View
+
+use crate::actions::{
+    Action, ActionHistoryAction, AvailableAction, ContextAction, NavigationAction,
+};
+use crate::capabilities::{PanelCapabilities, PanelCapabilityProvider};
+use crate::command_id::CommandId;
+use crate::keybindings::Keymap;
+use crate::state::{ActionHistoryState, AppState};
+use crate::view_models::action_history_view_model::ActionHistoryViewModel;
+use crate::views::View;
+use gh_pr_lander_theme::Theme;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Action history view - recent actions overlaid on the main view, laid out
+/// like the debug console so both can be switched between
+#[derive(Debug, Clone)]
+pub struct ActionHistoryView;
+
+impl ActionHistoryView {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl View for ActionHistoryView {
+    fn view_id(&self) -> crate::views::ViewId {
+        crate::views::ViewId::ActionHistory
+    }
+
+    fn render(&self, state: &AppState, area: Rect, f: &mut Frame) {
+        render(&state.action_history, &state.theme, &state.keymap, area, f);
+    }
+
+    fn capabilities(&self, state: &AppState) -> PanelCapabilities {
+        state.action_history.capabilities()
+    }
+
+    fn clone_box(&self) -> Box<dyn View> {
+        Box::new(self.clone())
+    }
+
+    fn translate_navigation(&self, nav: NavigationAction) -> Option<Action> {
+        let action = match nav {
+            NavigationAction::Next => ActionHistoryAction::NavigateNext,
+            NavigationAction::Previous => ActionHistoryAction::NavigatePrevious,
+            NavigationAction::ToTop => ActionHistoryAction::NavigateToTop,
+            NavigationAction::ToBottom => ActionHistoryAction::NavigateToBottom,
+            NavigationAction::Left
+            | NavigationAction::Right
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
+        };
+        Some(Action::ActionHistory(action))
+    }
+
+    fn translate_context_action(
+        &self,
+        _action: ContextAction,
+        _state: &AppState,
+    ) -> Option<Action> {
+        // Read-only list, no context actions apply
+        None
+    }
+
+    fn accepts_action(&self, action: &Action) -> bool {
+        matches!(
+            action,
+            Action::ActionHistory(_)
+                | Action::ViewContext(_)
+                | Action::Navigate(_)
+                | Action::Global(_)
+        )
+    }
+
+    fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
+        vec![AvailableAction::primary(
+            CommandId::DebugToggleConsoleView,
+            "Console",
+        )]
+    }
+}
+
+/// Render the action history (Quake-style drop-down, like the debug console)
+fn render(state: &ActionHistoryState, theme: &Theme, keymap: &Keymap, area: Rect, f: &mut Frame) {
+    // Render dimmed overlay over the entire screen to create modal effect
+    let overlay = Block::default().style(
+        ratatui::style::Style::default()
+            .bg(ratatui::style::Color::Black)
+            .add_modifier(ratatui::style::Modifier::DIM),
+    );
+    f.render_widget(overlay, area);
+
+    // Same height as the debug console (see `ViewportSync`)
+    let history_height = (area.height * 70) / 100;
+    let history_area = Rect {
+        x: area.x,
+        y: area.y,
+        width: area.width,
+        height: history_height.min(area.height),
+    };
+
+    f.render_widget(Clear, history_area);
+
+    let view_model = ActionHistoryViewModel::new(state, keymap);
+
+    let footer_hint = Line::from(vec![
+        Span::styled(
+            format!(" {}", view_model.footer_hints.scroll),
+            theme.key_hint().bold(),
+        ),
+        Span::styled(" scroll  ", theme.muted()),
+        Span::styled(&view_model.footer_hints.top_bottom, theme.key_hint().bold()),
+        Span::styled(" top/bottom  ", theme.muted()),
+        Span::styled(&view_model.footer_hints.console, theme.key_hint().bold()),
+        Span::styled(" console  ", theme.muted()),
+        Span::styled(&view_model.footer_hints.close, theme.key_hint().bold()),
+        Span::styled(" close ", theme.muted()),
+    ]);
+
+    let block = Block::default()
+        .title(view_model.title())
+        .borders(Borders::ALL)
+        .border_style(theme.panel_border())
+        .title_style(theme.panel_title())
+        .title_bottom(footer_hint)
+        .title_alignment(Alignment::Center);
+
+    let lines: Vec<Line> = if let Some(message) = view_model.empty_message() {
+        vec![Line::from(Span::styled(message, theme.muted().italic()))]
+    } else {
+        let available_height = history_height.saturating_sub(2) as usize; // -2 for borders
+        view_model
+            .visible_entries(available_height)
+            .into_iter()
+            .map(|entry| {
+                Line::from(vec![
+                    Span::styled(
+                        entry.timestamp.format("%H:%M:%S%.3f ").to_string(),
+                        theme.muted(),
+                    ),
+                    Span::styled(entry.name.as_str(), theme.text()),
+                ])
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(theme.panel_background());
+
+    f.render_widget(paragraph, history_area);
+}
@@ -3,8 +3,12 @@
 //! A floating form for adding a new repository to track.
 //! Supports both URL parsing and manual field entry.
 
-use crate::actions::{Action, ContextAction, NavigationAction, RepositoryAction, TextInputAction};
+use crate::actions::{
+    Action, AvailableAction, ContextAction, GlobalAction, NavigationAction, RepositoryAction,
+    TextInputAction,
+};
 use crate::capabilities::PanelCapabilities;
+use crate::command_id::CommandId;
 use crate::state::{AddRepoField, AddRepoFormState, AppState};
 use crate::views::View;
 use gh_pr_lander_theme::Theme;
@@ -37,7 +41,7 @@ impl View for AddRepositoryView {
 
     fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
         // Add repository form accepts text input
-        PanelCapabilities::TEXT_INPUT
+        PanelCapabilities::TEXT_INPUT | PanelCapabilities::DISMISSIBLE
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -52,7 +56,11 @@ impl View for AddRepositoryView {
             NavigationAction::Left
             | NavigationAction::Right
             | NavigationAction::ToTop
-            | NavigationAction::ToBottom => return None,
+            | NavigationAction::ToBottom
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
         };
         Some(Action::Repository(action))
     }
@@ -62,7 +70,8 @@ impl View for AddRepositoryView {
             TextInputAction::Char(c) => RepositoryAction::FormChar(c),
             TextInputAction::Backspace => RepositoryAction::FormBackspace,
             TextInputAction::ClearLine => RepositoryAction::FormClearField,
-            TextInputAction::Escape => RepositoryAction::FormClose,
+            // Dismissed like any other popup
+            TextInputAction::Escape => return Some(Action::Global(GlobalAction::Close)),
             TextInputAction::Confirm => RepositoryAction::FormConfirm,
         };
         Some(Action::Repository(action))
@@ -87,6 +96,13 @@ impl View for AddRepositoryView {
                 | Action::Global(_)
         )
     }
+
+    fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
+        vec![
+            AvailableAction::primary(CommandId::Confirm, "Add"),
+            AvailableAction::navigation(CommandId::NavigateNext, "Next Field"),
+        ]
+    }
 }
 
 /// Render the add repository popup as a centered floating window
@@ -101,7 +117,7 @@ fn render(form: &AddRepoFormState, theme: &Theme, area: Rect, f: &mut Frame) {
 
     // Calculate centered area (65% width, fixed height)
     let popup_width = (area.width * 65 / 100).clamp(55, 80);
-    let popup_height = 17; // Fixed height for the form (includes host field)
+    let popup_height = 17; // Fixed height for the form (includes host field and status)
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -160,6 +176,8 @@ fn render(form: &AddRepoFormState, theme: &Theme, area: Rect, f: &mut Frame) {
             Constraint::Length(1), // Org field
             Constraint::Length(1), // Repo field
             Constraint::Length(1), // Branch field
+            Constraint::Length(1), // Spacing
+            Constraint::Length(1), // Verification status / error
             Constraint::Min(0),    // Remaining
         ])
         .split(inner);
@@ -235,6 +253,18 @@ fn render(form: &AddRepoFormState, theme: &Theme, area: Rect, f: &mut Frame) {
         theme,
         Some("default: main"),
     );
+
+    // Verification status or error
+    let status = if form.verifying {
+        Some(Span::styled("Verifying...", theme.muted()))
+    } else {
+        form.error
+            .as_ref()
+            .map(|error| Span::styled(format!("✗ {}", error), theme.error()))
+    };
+    if let Some(status) = status {
+        f.render_widget(Paragraph::new(Line::from(status)), chunks[9]);
+    }
 }
 
 /// Render a single form field
@@ -2,7 +2,9 @@
 //!
 //! Renders the build log panel with tree navigation.
 
-use crate::actions::{Action, AvailableAction, BuildLogAction, ContextAction, NavigationAction};
+use crate::actions::{
+    Action, AvailableAction, BuildLogAction, ContextAction, NavigationAction, TextInputAction,
+};
 use crate::capabilities::PanelCapabilities;
 use crate::command_id::CommandId;
 use crate::state::AppState;
@@ -51,12 +53,19 @@ impl View for BuildLogView {
         f.render_widget(StatusBarWidget(&status_vm), chunks[1]);
     }
 
-    fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
-        PanelCapabilities::SCROLL_VERTICAL
+    fn capabilities(&self, state: &AppState) -> PanelCapabilities {
+        let capabilities = PanelCapabilities::SCROLL_VERTICAL
             | PanelCapabilities::SCROLL_HORIZONTAL
             | PanelCapabilities::VIM_SCROLL_BINDINGS
             | PanelCapabilities::VIM_NAVIGATION_BINDINGS
             | PanelCapabilities::ITEM_NAVIGATION
+            | PanelCapabilities::DISMISSIBLE;
+        // Typing goes to the search query while it is being edited
+        if state.build_log.search_editing {
+            capabilities | PanelCapabilities::TEXT_INPUT
+        } else {
+            capabilities
+        }
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -71,6 +80,21 @@ impl View for BuildLogView {
             NavigationAction::ToBottom => BuildLogAction::NavigateToBottom,
             NavigationAction::Left => BuildLogAction::ScrollLeft,
             NavigationAction::Right => BuildLogAction::ScrollRight,
+            NavigationAction::PageDown => BuildLogAction::PageDown,
+            NavigationAction::PageUp => BuildLogAction::PageUp,
+            NavigationAction::HalfPageDown | NavigationAction::HalfPageUp => return None,
+        };
+        Some(Action::BuildLog(action))
+    }
+
+    fn translate_text_input(&self, input: TextInputAction) -> Option<Action> {
+        // Only reached while the search query is being edited (TEXT_INPUT capability)
+        let action = match input {
+            TextInputAction::Char(c) => BuildLogAction::SearchChar(c),
+            TextInputAction::Backspace => BuildLogAction::SearchBackspace,
+            TextInputAction::ClearLine => BuildLogAction::SearchClear,
+            TextInputAction::Confirm => BuildLogAction::SearchConfirm,
+            TextInputAction::Escape => BuildLogAction::SearchCancel,
         };
         Some(Action::BuildLog(action))
     }
@@ -97,8 +121,7 @@ impl View for BuildLogView {
         vec![
             AvailableAction::primary(CommandId::Confirm, "Toggle"),
             AvailableAction::primary(CommandId::BuildLogNextError, "Next Error"),
-            AvailableAction::navigation(CommandId::NavigateNext, "Down"),
-            AvailableAction::navigation(CommandId::GlobalClose, "Close"),
+            AvailableAction::primary(CommandId::BuildLogSearch, "Search"),
         ]
     }
 }
@@ -234,10 +257,16 @@ fn render_build_log_tree(
 
     // Build table rows - simple iteration, no complex logic!
     let mut rows = Vec::new();
-    let start = view_model.scroll_offset;
-    let end = (start + visible_height).min(view_model.rows.len());
+    let start = view_model.scroll_offset.min(view_model.rows.len());
+    let mut used_height = 0;
+
+    for row_vm in &view_model.rows[start..] {
+        // Wrapped rows take more than one line; stop once the viewport is full
+        if used_height > 0 && used_height + row_vm.height > visible_height {
+            break;
+        }
+        used_height += row_vm.height;
 
-    for row_vm in &view_model.rows[start..end] {
         // Apply style based on pre-determined row style
         let style = match row_vm.style {
             BuildLogRowStyle::Normal => {
@@ -269,7 +298,23 @@ fn render_build_log_tree(
                         .fg(theme.text_primary)
                         .bg(theme.selected_bg)
                 } else {
-                    Style::default().fg(theme.text_primary).bg(theme.bg_panel)
+                    Style::default().fg(theme.status_success).bg(theme.bg_panel)
+                }
+            }
+            BuildLogRowStyle::Warning => {
+                if row_vm.is_cursor {
+                    Style::default()
+                        .fg(theme.status_warning)
+                        .bg(theme.selected_bg)
+                } else {
+                    Style::default().fg(theme.status_warning).bg(theme.bg_panel)
+                }
+            }
+            BuildLogRowStyle::Muted => {
+                if row_vm.is_cursor {
+                    Style::default().fg(theme.text_muted).bg(theme.selected_bg)
+                } else {
+                    Style::default().fg(theme.text_muted).bg(theme.bg_panel)
                 }
             }
             BuildLogRowStyle::Selected => Style::default()
@@ -277,18 +322,42 @@ fn render_build_log_tree(
                 .bg(theme.selected_bg),
         };
 
-        // Text is pre-formatted - just display it!
-        rows.push(Row::new(vec![Cell::from(row_vm.text.clone())]).style(style));
+        // Text is pre-formatted - just display it, highlighting search matches
+        let lines = match view_model.wrap_width {
+            Some(width) if row_vm.height > 1 => {
+                wrap_line(&row_vm.text, &row_vm.match_ranges, width, theme)
+            }
+            _ => vec![highlight_matches(&row_vm.text, &row_vm.match_ranges, theme)],
+        };
+        rows.push(
+            Row::new(vec![Cell::from(Text::from(lines))])
+                .height(row_vm.height as u16)
+                .style(style),
+        );
     }
 
+    // todo: the navigation hints should be dynamic based on keymap, like in other views done
+    let name = if view_model.failed_only {
+        "Build Logs (failed only)"
+    } else {
+        "Build Logs"
+    };
+    let title = match view_model.search_status {
+        Some(ref search) => format!(
+            " {} | {} | n/N: next/prev match, Enter: toggle, Esc: close ",
+            name, search
+        ),
+        None => format!(
+            " {} | j/k: navigate, Enter: toggle, n/N: next/prev error, /: search, w: wrap, f: failed only, Esc: close ",
+            name
+        ),
+    };
+
     let table = Table::new(rows, vec![Constraint::Percentage(100)])
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                // todo: the navigation hints should be dynamic based on keymap, like in other views done
-                .title(
-                    " Build Logs | j/k: navigate, Enter: toggle, n/N: next/prev error, Esc: close ",
-                )
+                .title(title)
                 .border_style(Style::default().fg(theme.accent_primary))
                 .style(Style::default().bg(theme.bg_panel)),
         )
@@ -296,3 +365,62 @@ fn render_build_log_tree(
 
     f.render_widget(table, area);
 }
+
+/// Split a row into spans, highlighting the given byte ranges
+fn highlight_matches<'a>(
+    text: &'a str,
+    ranges: &[(usize, usize)],
+    theme: &gh_pr_lander_theme::Theme,
+) -> Line<'a> {
+    if ranges.is_empty() {
+        return Line::from(text);
+    }
+
+    let highlight = Style::default()
+        .fg(theme.bg_panel)
+        .bg(theme.status_warning)
+        .add_modifier(Modifier::BOLD);
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for &(start, end) in ranges {
+        if start > pos {
+            spans.push(Span::raw(&text[pos..start]));
+        }
+        spans.push(Span::styled(&text[start..end], highlight));
+        pos = end;
+    }
+    if pos < text.len() {
+        spans.push(Span::raw(&text[pos..]));
+    }
+    Line::from(spans)
+}
+
+/// Split a row into lines of at most `width` characters, keeping search highlights
+fn wrap_line<'a>(
+    text: &'a str,
+    ranges: &[(usize, usize)],
+    width: usize,
+    theme: &gh_pr_lander_theme::Theme,
+) -> Vec<Line<'a>> {
+    let mut boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .step_by(width.max(1))
+        .collect();
+    boundaries.push(text.len());
+
+    boundaries
+        .windows(2)
+        .map(|chunk| {
+            let (start, end) = (chunk[0], chunk[1]);
+            // Clip the highlight ranges to this chunk and make them chunk-relative
+            let chunk_ranges: Vec<(usize, usize)> = ranges
+                .iter()
+                .filter(|&&(s, e)| s < end && e > start)
+                .map(|&(s, e)| (s.max(start) - start, e.min(end) - start))
+                .collect();
+            highlight_matches(&text[start..end], &chunk_ranges, theme)
+        })
+        .collect()
+}
@@ -1,7 +1,9 @@
 use crate::actions::{
-    Action, CommandPaletteAction, ContextAction, NavigationAction, TextInputAction,
+    Action, AvailableAction, CommandPaletteAction, ContextAction, GlobalAction, NavigationAction,
+    TextInputAction,
 };
 use crate::capabilities::PanelCapabilities;
+use crate::command_id::CommandId;
 use crate::state::AppState;
 use crate::view_models::CommandPaletteViewModel;
 use crate::views::View;
@@ -36,7 +38,9 @@ impl View for CommandPaletteView {
         // Command palette accepts text input and supports item navigation
         // TEXT_INPUT means character keys go to the input field, not keybindings
         // ITEM_NAVIGATION enables arrow key navigation through results
-        PanelCapabilities::TEXT_INPUT | PanelCapabilities::ITEM_NAVIGATION
+        PanelCapabilities::TEXT_INPUT
+            | PanelCapabilities::ITEM_NAVIGATION
+            | PanelCapabilities::DISMISSIBLE
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -51,7 +55,11 @@ impl View for CommandPaletteView {
             NavigationAction::Left
             | NavigationAction::Right
             | NavigationAction::ToTop
-            | NavigationAction::ToBottom => return None,
+            | NavigationAction::ToBottom
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
         };
         Some(Action::CommandPalette(action))
     }
@@ -61,7 +69,8 @@ impl View for CommandPaletteView {
             TextInputAction::Char(c) => CommandPaletteAction::Char(c),
             TextInputAction::Backspace => CommandPaletteAction::Backspace,
             TextInputAction::ClearLine => CommandPaletteAction::Clear,
-            TextInputAction::Escape => CommandPaletteAction::Close,
+            // Dismissed like any other popup
+            TextInputAction::Escape => return Some(Action::Global(GlobalAction::Close)),
             TextInputAction::Confirm => CommandPaletteAction::Execute,
         };
         Some(Action::CommandPalette(action))
@@ -86,6 +95,10 @@ impl View for CommandPaletteView {
                 | Action::Global(_)
         )
     }
+
+    fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
+        vec![AvailableAction::primary(CommandId::Confirm, "Run")]
+    }
 }
 
 /// Render the command palette as a centered floating panel
@@ -174,6 +187,11 @@ fn render(state: &AppState, area: Rect, f: &mut Frame) {
             "Type to search commands...",
             theme.muted().italic(),
         )])
+    } else if let Some(ref hint) = vm.argument_hint {
+        Line::from(vec![
+            Span::styled(&vm.input_text, theme.text()),
+            Span::styled(hint, theme.muted().italic()),
+        ])
     } else {
         Line::from(vec![Span::styled(&vm.input_text, theme.text())])
     };
@@ -0,0 +1,184 @@
+//! Commit List Popup View
+//!
+//! Lists the commits of the selected PR; Enter opens one in the browser.
+
+use crate::actions::{Action, CommitListAction, ContextAction, NavigationAction};
+use crate::capabilities::PanelCapabilities;
+use crate::state::AppState;
+use crate::utils::relative_time::format_age;
+use crate::views::View;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Commit list popup view
+#[derive(Debug, Clone)]
+pub struct CommitListView;
+
+impl CommitListView {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl View for CommitListView {
+    fn view_id(&self) -> crate::views::ViewId {
+        crate::views::ViewId::CommitList
+    }
+
+    fn render(&self, state: &AppState, area: Rect, f: &mut Frame) {
+        render(state, area, f);
+    }
+
+    fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
+        PanelCapabilities::SCROLL_VERTICAL
+            | PanelCapabilities::VIM_NAVIGATION_BINDINGS
+            | PanelCapabilities::DISMISSIBLE
+    }
+
+    fn clone_box(&self) -> Box<dyn View> {
+        Box::new(self.clone())
+    }
+
+    fn translate_navigation(&self, nav: NavigationAction) -> Option<Action> {
+        let action = match nav {
+            NavigationAction::Next => CommitListAction::NavigateNext,
+            NavigationAction::Previous => CommitListAction::NavigatePrevious,
+            NavigationAction::ToTop => CommitListAction::NavigateToTop,
+            NavigationAction::ToBottom => CommitListAction::NavigateToBottom,
+            NavigationAction::Left
+            | NavigationAction::Right
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
+        };
+        Some(Action::CommitList(action))
+    }
+
+    fn translate_context_action(&self, action: ContextAction, _state: &AppState) -> Option<Action> {
+        match action {
+            ContextAction::Confirm => Some(Action::CommitList(CommitListAction::OpenInBrowser)),
+            _ => None,
+        }
+    }
+
+    fn accepts_action(&self, action: &Action) -> bool {
+        matches!(
+            action,
+            Action::CommitList(_)
+                | Action::ViewContext(_)
+                | Action::Navigate(_)
+                | Action::Global(_)
+        )
+    }
+}
+
+/// Render the commit list popup
+fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let theme = &state.theme;
+    let commit_list = &state.commit_list;
+
+    // Calculate panel size (70% width, 60% height, centered)
+    let panel_width = (area.width * 70) / 100;
+    let panel_height = (area.height * 60) / 100;
+
+    let panel_area = Rect {
+        x: area.x + (area.width - panel_width) / 2,
+        y: area.y + (area.height - panel_height) / 2,
+        width: panel_width,
+        height: panel_height,
+    };
+
+    // Render dimmed overlay over the entire screen
+    let overlay = Block::default().style(
+        ratatui::style::Style::default()
+            .bg(ratatui::style::Color::Black)
+            .add_modifier(ratatui::style::Modifier::DIM),
+    );
+    f.render_widget(overlay, area);
+    f.render_widget(Clear, panel_area);
+
+    let title = match commit_list.pr_number {
+        Some(pr_number) => format!(" Commits of PR #{} ", pr_number),
+        None => " Commits ".to_string(),
+    };
+
+    let footer_hint = Line::from(vec![
+        Span::styled(" Enter", theme.key_hint().bold()),
+        Span::styled(" open  ", theme.muted()),
+        Span::styled("q", theme.key_hint().bold()),
+        Span::styled(" close ", theme.muted()),
+    ]);
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme.panel_border())
+        .title_style(theme.panel_title())
+        .title_alignment(Alignment::Center)
+        .title_bottom(footer_hint);
+
+    let inner_area = block.inner(panel_area);
+    let visible_height = inner_area.height as usize;
+
+    let lines: Vec<Line> = if commit_list.loading {
+        vec![Line::from(Span::styled(
+            " Loading commits...",
+            theme.muted(),
+        ))]
+    } else if let Some(error) = &commit_list.error {
+        vec![Line::from(Span::styled(
+            format!(" {}", error),
+            theme.error(),
+        ))]
+    } else if commit_list.commits.is_empty() {
+        vec![Line::from(Span::styled(" No commits", theme.muted()))]
+    } else {
+        // Keep the cursor visible
+        let scroll_offset = (commit_list.cursor + 1).saturating_sub(visible_height);
+        let width = inner_area.width as usize;
+
+        commit_list
+            .commits
+            .iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(visible_height)
+            .map(|(index, commit)| {
+                let age = format_age(&commit.date);
+                // " sha  summary  author  age "
+                let fixed = 1 + 7 + 2 + 2 + commit.author.len() + 2 + age.len() + 1;
+                let summary: String = commit
+                    .summary()
+                    .chars()
+                    .take(width.saturating_sub(fixed))
+                    .collect();
+                let padding = width.saturating_sub(fixed + summary.chars().count());
+
+                let line = Line::from(vec![
+                    Span::styled(format!(" {}", commit.short_sha()), theme.accent()),
+                    Span::styled(format!("  {}", summary), theme.text()),
+                    Span::raw(" ".repeat(padding)),
+                    Span::styled(format!("  {}", commit.author), theme.text_secondary()),
+                    Span::styled(format!("  {} ", age), theme.muted()),
+                ]);
+
+                if index == commit_list.cursor {
+                    line.style(theme.table_selected())
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(theme.panel_background());
+
+    f.render_widget(paragraph, panel_area);
+}
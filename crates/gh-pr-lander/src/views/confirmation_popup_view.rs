@@ -4,9 +4,11 @@
 //! Used for approve, comment, request changes, and close actions.
 
 use crate::actions::{
-    Action, ConfirmationPopupAction, ContextAction, NavigationAction, TextInputAction,
+    Action, AvailableAction, ConfirmationPopupAction, ContextAction, GlobalAction,
+    NavigationAction, TextInputAction,
 };
 use crate::capabilities::PanelCapabilities;
+use crate::command_id::CommandId;
 use crate::state::AppState;
 use crate::view_models::ConfirmationPopupViewModel;
 use crate::views::{View, ViewId};
@@ -48,7 +50,7 @@ impl View for ConfirmationPopupView {
 
     fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
         // Confirmation popup accepts text input
-        PanelCapabilities::TEXT_INPUT
+        PanelCapabilities::TEXT_INPUT | PanelCapabilities::DISMISSIBLE
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -65,7 +67,8 @@ impl View for ConfirmationPopupView {
             TextInputAction::Char(c) => ConfirmationPopupAction::Char(c),
             TextInputAction::Backspace => ConfirmationPopupAction::Backspace,
             TextInputAction::ClearLine => ConfirmationPopupAction::ClearInput,
-            TextInputAction::Escape => ConfirmationPopupAction::Cancel,
+            // Dismissed like any other popup
+            TextInputAction::Escape => return Some(Action::Global(GlobalAction::Close)),
             TextInputAction::Confirm => ConfirmationPopupAction::Confirm,
         };
         Some(Action::ConfirmationPopup(action))
@@ -92,6 +95,10 @@ impl View for ConfirmationPopupView {
                 | Action::Global(_)
         )
     }
+
+    fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
+        vec![AvailableAction::primary(CommandId::Confirm, "Confirm")]
+    }
 }
 
 /// Render the confirmation popup as a centered floating window
@@ -109,9 +116,9 @@ fn render_popup(
     );
     f.render_widget(overlay, area);
 
-    // Calculate centered area (60% width, fixed height)
+    // Calculate centered area (60% width, fixed height plus detail lines)
     let popup_width = (area.width * 60 / 100).clamp(50, 70);
-    let popup_height = 10; // Fixed height for the popup
+    let popup_height = 10 + vm.details.len() as u16;
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -165,7 +172,8 @@ fn render_popup(
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // Target info (e.g., "Approving: PR #123")
-            Constraint::Length(1), // Spacing
+            Constraint::Length(1), // Warning (or spacing)
+            Constraint::Length(vm.details.len() as u16), // Details
             Constraint::Length(1), // Instructions
             Constraint::Length(1), // Spacing
             Constraint::Length(1), // Input field
@@ -183,15 +191,36 @@ fn render_popup(
     ));
     f.render_widget(Paragraph::new(target_line), chunks[0]);
 
+    // Warning (e.g., PRs with failing CI)
+    if let Some(ref warning) = vm.warning {
+        let warning_line = Line::from(Span::styled(
+            warning,
+            Style::default()
+                .fg(vm.colors.error_fg)
+                .add_modifier(Modifier::BOLD),
+        ));
+        f.render_widget(Paragraph::new(warning_line), chunks[1]);
+    }
+
+    // Details (e.g., the merge bot's plan)
+    let details: Vec<Line> = vm
+        .details
+        .iter()
+        .map(|detail| Line::from(Span::styled(detail, theme.text())))
+        .collect();
+    f.render_widget(Paragraph::new(details), chunks[2]);
+
     // Instructions
     let instructions = Line::from(Span::styled(
         &vm.instructions,
         Style::default().fg(vm.colors.instructions_fg),
     ));
-    f.render_widget(Paragraph::new(instructions), chunks[2]);
+    f.render_widget(Paragraph::new(instructions), chunks[3]);
 
     // Input field
-    render_input_field(f, chunks[4], vm, theme);
+    if vm.show_input {
+        render_input_field(f, chunks[5], vm, theme);
+    }
 
     // Validation hint (if present)
     if let Some(ref hint) = vm.validation_hint {
@@ -201,7 +230,7 @@ fn render_popup(
                 .fg(vm.colors.error_fg)
                 .add_modifier(Modifier::ITALIC),
         ));
-        f.render_widget(Paragraph::new(hint_line), chunks[5]);
+        f.render_widget(Paragraph::new(hint_line), chunks[6]);
     }
 }
 
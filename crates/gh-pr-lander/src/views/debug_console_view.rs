@@ -1,7 +1,10 @@
 //! Debug Console View
 
-use crate::actions::{Action, ContextAction, DebugConsoleAction, NavigationAction};
+use crate::actions::{
+    Action, AvailableAction, ContextAction, DebugConsoleAction, NavigationAction, TextInputAction,
+};
 use crate::capabilities::{PanelCapabilities, PanelCapabilityProvider};
+use crate::command_id::CommandId;
 use crate::keybindings::Keymap;
 use crate::state::AppState;
 use crate::state::DebugConsoleState;
@@ -51,7 +54,24 @@ impl View for DebugConsoleView {
             NavigationAction::ToTop => DebugConsoleAction::NavigateToTop,
             NavigationAction::ToBottom => DebugConsoleAction::NavigateToBottom,
             // Debug console doesn't use horizontal navigation
-            NavigationAction::Left | NavigationAction::Right => return None,
+            NavigationAction::Left
+            | NavigationAction::Right
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
+        };
+        Some(Action::DebugConsole(action))
+    }
+
+    fn translate_text_input(&self, input: TextInputAction) -> Option<Action> {
+        // Only reached while the text filter is being edited (TEXT_INPUT capability)
+        let action = match input {
+            TextInputAction::Char(c) => DebugConsoleAction::FilterChar(c),
+            TextInputAction::Backspace => DebugConsoleAction::FilterBackspace,
+            TextInputAction::ClearLine => DebugConsoleAction::FilterClear,
+            TextInputAction::Confirm => DebugConsoleAction::FilterConfirm,
+            TextInputAction::Escape => DebugConsoleAction::FilterCancel,
         };
         Some(Action::DebugConsole(action))
     }
@@ -74,6 +94,15 @@ impl View for DebugConsoleView {
                 | Action::Global(_)
         )
     }
+
+    fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
+        vec![
+            AvailableAction::primary(CommandId::DebugFilterLogs, "Filter"),
+            AvailableAction::primary(CommandId::DebugCycleLogLevel, "Level"),
+            AvailableAction::primary(CommandId::DebugClearLogs, "Clear"),
+            AvailableAction::primary(CommandId::DebugToggleActionHistory, "Actions"),
+        ]
+    }
 }
 
 /// Render the debug console (Quake-style drop-down)
@@ -110,6 +139,10 @@ fn render(state: &DebugConsoleState, theme: &Theme, keymap: &Keymap, area: Rect,
         Span::styled(" scroll  ", theme.muted()),
         Span::styled(&view_model.footer_hints.top_bottom, theme.key_hint().bold()),
         Span::styled(" top/bottom  ", theme.muted()),
+        Span::styled(&view_model.footer_hints.level, theme.key_hint().bold()),
+        Span::styled(" level  ", theme.muted()),
+        Span::styled(&view_model.footer_hints.filter, theme.key_hint().bold()),
+        Span::styled(" filter  ", theme.muted()),
         Span::styled(&view_model.footer_hints.close, theme.key_hint().bold()),
         Span::styled(" close ", theme.muted()),
     ]);
@@ -129,7 +162,17 @@ fn render(state: &DebugConsoleState, theme: &Theme, keymap: &Keymap, area: Rect,
     let visible_lines = view_model.visible_lines(available_height);
     let formatted_lines: Vec<Line> = visible_lines
         .into_iter()
-        .map(|line| Line::from(Span::styled(line.as_str(), theme.text())))
+        .map(|line| {
+            let style = match line.level {
+                Some(log::Level::Error) => theme.log_error(),
+                Some(log::Level::Warn) => theme.log_warning(),
+                Some(log::Level::Info) => theme.log_info(),
+                Some(log::Level::Debug) => theme.log_debug(),
+                Some(log::Level::Trace) => theme.muted(),
+                None => theme.text(),
+            };
+            Line::from(Span::styled(line.text.as_str(), style))
+        })
         .collect();
 
     let paragraph = Paragraph::new(formatted_lines)
@@ -11,7 +11,7 @@ use crate::state::AppState;
 use crate::view_models::StatusBarViewModel;
 use crate::views::status_bar::StatusBarWidget;
 use crate::views::{View, ViewId};
-use gh_diff_viewer::{DiffHighlighter, DiffViewer, FooterHint, ThemeProvider};
+use gh_diff_viewer::{DiffHighlighter, DiffViewer, FooterHint, PullRequestDiff, ThemeProvider};
 use ratatui::{prelude::*, widgets::*};
 
 /// Diff viewer view - displays PR diff with syntax highlighting
@@ -103,6 +103,43 @@ impl ThemeProvider for LanderThemeAdapter<'_> {
     }
 }
 
+/// Render the stats header: files changed, additions, deletions and a large PR warning
+fn render_stats_header(state: &AppState, diff: &PullRequestDiff, area: Rect, f: &mut Frame) {
+    let theme = &state.theme;
+    let file_count = diff.files.len();
+    let mut spans = vec![
+        Span::styled(
+            format!(
+                " {} file{} changed ",
+                file_count,
+                if file_count == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(theme.text_primary),
+        ),
+        Span::styled(
+            format!("+{} ", diff.total_additions),
+            Style::default().fg(theme.status_success),
+        ),
+        Span::styled(
+            format!("-{}", diff.total_deletions),
+            Style::default().fg(theme.status_error),
+        ),
+    ];
+
+    let config = &state.app_config.diff;
+    if diff.is_large(config.large_pr_files, config.large_pr_changes) {
+        spans.push(Span::styled(
+            "  ⚠ Large PR - consider reviewing in smaller chunks",
+            Style::default()
+                .fg(theme.status_warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let header = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme.bg_panel));
+    f.render_widget(header, area);
+}
+
 impl View for DiffViewerView {
     fn view_id(&self) -> ViewId {
         ViewId::DiffViewer
@@ -159,6 +196,16 @@ impl View for DiffViewerView {
                 .alignment(Alignment::Center);
             f.render_widget(error_msg, chunks[0]);
         } else if let Some(ref inner_state) = state.diff_viewer.inner {
+            // Split off a one-line stats header above the diff
+            let diff_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(1), // Stats header
+                    Constraint::Min(0),    // Diff
+                ])
+                .split(chunks[0]);
+            render_stats_header(state, &inner_state.diff, diff_chunks[0], f);
+
             // Create theme adapter
             let theme_adapter = LanderThemeAdapter(&state.theme);
 
@@ -181,6 +228,9 @@ impl View for DiffViewerView {
                 vec![
                     FooterHint::new("c", "Comment"),
                     FooterHint::new("R", "Review"),
+                    FooterHint::new("C", "Comments"),
+                    FooterHint::new("m", "Viewed"),
+                    FooterHint::new("</>", "Tree Width"),
                     FooterHint::new("q", "Close"),
                     FooterHint::new("Ctrl+f", "Page Down"),
                     FooterHint::new("Ctrl+b", "Page Up"),
@@ -192,7 +242,7 @@ impl View for DiffViewerView {
 
             // We need to clone the inner state for rendering since render_with_state requires &mut
             let mut render_state = inner_state.clone();
-            widget.render_with_state(chunks[0], f.buffer_mut(), &mut render_state);
+            widget.render_with_state(diff_chunks[1], f.buffer_mut(), &mut render_state);
         } else {
             // No diff loaded - show empty state
             let empty_msg = Paragraph::new("No diff loaded. Press 'd d' on a PR to view its diff.")
@@ -225,6 +275,7 @@ impl View for DiffViewerView {
             | PanelCapabilities::ITEM_NAVIGATION
             | PanelCapabilities::TEXT_INPUT
             | PanelCapabilities::PANE_SWITCHING
+            | PanelCapabilities::DISMISSIBLE
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -239,6 +290,9 @@ impl View for DiffViewerView {
             NavigationAction::Right => DiffViewerAction::NavigateRight,
             NavigationAction::ToTop => DiffViewerAction::NavigateToTop,
             NavigationAction::ToBottom => DiffViewerAction::NavigateToBottom,
+            NavigationAction::PageDown => DiffViewerAction::PageDown,
+            NavigationAction::PageUp => DiffViewerAction::PageUp,
+            NavigationAction::HalfPageDown | NavigationAction::HalfPageUp => return None,
         };
         Some(Action::DiffViewer(action))
     }
@@ -0,0 +1,52 @@
+//! Error Banner
+//!
+//! One-line banner at the top of the screen for the most recent error. Like
+//! the which-key popup, it is not part of the view stack and never captures
+//! input; it disappears on its own or via the dismiss command.
+
+use crate::state::AppState;
+use crate::view_models::ErrorBannerViewModel;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Render the error banner across the top row
+pub fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let Some(vm) = ErrorBannerViewModel::from_state(state) else {
+        return;
+    };
+    if area.height == 0 {
+        return;
+    }
+    let theme = &state.theme;
+    let banner_area = Rect::new(area.x, area.y, area.width, 1);
+    let style = Style::default()
+        .fg(theme.bg_primary)
+        .bg(theme.status_error)
+        .add_modifier(Modifier::BOLD);
+
+    let hint_width = vm
+        .dismiss_hint
+        .as_ref()
+        .map(|hint| hint.chars().count())
+        .unwrap_or(0);
+    let text_width = (area.width as usize).saturating_sub(hint_width);
+    let text = if vm.text.chars().count() > text_width {
+        let truncated: String = vm.text.chars().take(text_width.saturating_sub(1)).collect();
+        format!("{}…", truncated)
+    } else {
+        format!("{:<text_width$}", vm.text)
+    };
+
+    let mut spans = vec![Span::styled(text, style)];
+    if let Some(hint) = vm.dismiss_hint {
+        spans.push(Span::styled(hint, style.remove_modifier(Modifier::BOLD)));
+    }
+
+    f.render_widget(Clear, banner_area);
+    f.render_widget(Paragraph::new(Line::from(spans)).style(style), banner_area);
+}
@@ -36,7 +36,9 @@ impl View for KeyBindingsView {
 
     fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
         // Key bindings panel supports vertical scrolling with vim bindings
-        PanelCapabilities::SCROLL_VERTICAL | PanelCapabilities::VIM_NAVIGATION_BINDINGS
+        PanelCapabilities::SCROLL_VERTICAL
+            | PanelCapabilities::VIM_NAVIGATION_BINDINGS
+            | PanelCapabilities::DISMISSIBLE
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -50,7 +52,12 @@ impl View for KeyBindingsView {
             NavigationAction::ToTop => KeyBindingsAction::NavigateToTop,
             NavigationAction::ToBottom => KeyBindingsAction::NavigateToBottom,
             // Key bindings panel doesn't use horizontal navigation
-            NavigationAction::Left | NavigationAction::Right => return None,
+            NavigationAction::Left
+            | NavigationAction::Right
+            | NavigationAction::PageDown
+            | NavigationAction::PageUp
+            | NavigationAction::HalfPageDown
+            | NavigationAction::HalfPageUp => return None,
         };
         Some(Action::KeyBindings(action))
     }
@@ -4,27 +4,37 @@ use crate::state::AppState;
 use ratatui::{layout::Rect, Frame};
 
 // New view modules (concrete view types)
+pub mod action_history_view;
 pub mod add_repository_view;
 pub mod build_log_view;
 pub mod command_palette_view;
+pub mod commit_list_view;
 pub mod confirmation_popup_view;
 pub mod debug_console_view;
 pub mod diff_viewer_view;
+pub mod error_banner;
 pub mod key_bindings_view;
 pub mod pull_request_view;
 pub mod repository_tabs_view;
+pub mod setup_wizard_view;
+pub mod shortcut_bar;
 pub mod splash_view;
 pub mod status_bar;
+pub mod toast;
+pub mod which_key_popup;
 
 // Re-export concrete view types for convenience
+pub use action_history_view::ActionHistoryView;
 pub use add_repository_view::AddRepositoryView;
 pub use build_log_view::BuildLogView;
 pub use command_palette_view::CommandPaletteView;
+pub use commit_list_view::CommitListView;
 pub use confirmation_popup_view::ConfirmationPopupView;
 pub use debug_console_view::DebugConsoleView;
 pub use diff_viewer_view::DiffViewerView;
 pub use key_bindings_view::KeyBindingsView;
 pub use pull_request_view::PullRequestView;
+pub use setup_wizard_view::SetupWizardView;
 pub use splash_view::SplashView;
 
 /// View identifier - allows comparing which view is active
@@ -33,12 +43,15 @@ pub enum ViewId {
     Splash,
     PullRequestView,
     DebugConsole,
+    ActionHistory,
     CommandPalette,
     AddRepository,
     KeyBindings,
     BuildLog,
     ConfirmationPopup,
     DiffViewer,
+    CommitList,
+    SetupWizard,
 }
 
 /// View trait - defines the interface that all views must implement
@@ -132,6 +145,22 @@ pub trait View: std::fmt::Debug + Send + Sync {
         None // Default: view doesn't handle context actions
     }
 
+    /// Translate a mouse click at (column, row) into this view's specific action.
+    ///
+    /// The view hit-tests the click against its own layout, computed from the
+    /// terminal size in `state.terminal_size`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn translate_mouse_click(&self, col: u16, row: u16, state: &AppState) -> Option<Action> {
+    ///     let tab = tabs_vm.tab_at(col)?;
+    ///     Some(Action::PullRequest(PullRequestAction::RepositorySelect(tab)))
+    /// }
+    /// ```
+    fn translate_mouse_click(&self, _col: u16, _row: u16, _state: &AppState) -> Option<Action> {
+        None // Default: view doesn't handle mouse clicks
+    }
+
     /// Check if this view accepts/handles a given action.
     ///
     /// This is used by the keyboard middleware for action gating.
@@ -184,9 +213,14 @@ impl Clone for Box<dyn View> {
 /// Rendering strategy:
 /// - Render all views in the stack from bottom to top
 /// - Views using `Clear` widget will preserve portions of underlying views
+/// - Transient hints (which-key, error banner, toasts) render on top of everything
 pub fn render(state: &AppState, area: Rect, f: &mut Frame) {
     // Render each view bottom-up so views on top render last
     for view in &state.view_stack {
         view.render(state, area, f);
     }
+
+    which_key_popup::render(state, area, f);
+    error_banner::render(state, area, f);
+    toast::render(state, area, f);
 }
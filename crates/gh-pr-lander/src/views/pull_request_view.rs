@@ -3,24 +3,27 @@
 //! Renders the repository tabs and PR table.
 
 use crate::actions::{
-    Action, AvailableAction, BuildLogAction, ContextAction, DiffViewerAction, NavigationAction,
-    PullRequestAction,
+    Action, AvailableAction, BuildLogAction, CommitListAction, ContextAction, DiffViewerAction,
+    NavigationAction, PullRequestAction, TextInputAction,
 };
 use crate::capabilities::PanelCapabilities;
 use crate::command_id::CommandId;
-use crate::state::AppState;
+use crate::state::{AppState, PrColumn, RowDensity};
 use crate::view_models::{
-    determine_main_content, MainContentViewModel, PrTableViewModel, RepositoryTabsViewModel,
-    StatusBarViewModel,
+    determine_main_content, MainContentViewModel, PrDescriptionViewModel, PrTableViewModel,
+    RepositoryTabsViewModel, ShortcutBarViewModel, StatusBarViewModel,
 };
 use crate::views::repository_tabs_view::RepositoryTabsWidget;
+use crate::views::shortcut_bar::ShortcutBarWidget;
 use crate::views::status_bar::StatusBarWidget;
 use crate::views::View;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    text::{Line, Span},
-    widgets::{Block, Cell, Paragraph, Row, Table},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, Wrap,
+    },
     Frame,
 };
 
@@ -43,8 +46,25 @@ impl View for PullRequestView {
         render(state, area, f);
     }
 
-    fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
-        PanelCapabilities::VIM_NAVIGATION_BINDINGS
+    fn capabilities(&self, state: &AppState) -> PanelCapabilities {
+        // While the "go to PR number" input is open, keys are typed into it
+        if state.main_view.goto_pr_input.is_some() {
+            PanelCapabilities::TEXT_INPUT
+        } else {
+            PanelCapabilities::VIM_NAVIGATION_BINDINGS
+        }
+    }
+
+    fn translate_text_input(&self, input: TextInputAction) -> Option<Action> {
+        // Only reached while the "go to PR number" input is open
+        let action = match input {
+            TextInputAction::Char(c) => PullRequestAction::GotoPrChar(c),
+            TextInputAction::Backspace => PullRequestAction::GotoPrBackspace,
+            TextInputAction::ClearLine => PullRequestAction::GotoPrClear,
+            TextInputAction::Confirm => PullRequestAction::GotoPrConfirm,
+            TextInputAction::Escape => PullRequestAction::GotoPrCancel,
+        };
+        Some(Action::PullRequest(action))
     }
 
     fn clone_box(&self) -> Box<dyn View> {
@@ -57,7 +77,12 @@ impl View for PullRequestView {
             NavigationAction::Previous => PullRequestAction::NavigatePrevious,
             NavigationAction::ToTop => PullRequestAction::NavigateToTop,
             NavigationAction::ToBottom => PullRequestAction::NavigateToBottom,
-            NavigationAction::Left | NavigationAction::Right => return None,
+            NavigationAction::Left => PullRequestAction::ScrollTitleLeft,
+            NavigationAction::Right => PullRequestAction::ScrollTitleRight,
+            NavigationAction::PageDown => PullRequestAction::NavigatePageDown,
+            NavigationAction::PageUp => PullRequestAction::NavigatePageUp,
+            NavigationAction::HalfPageDown => PullRequestAction::NavigateHalfPageDown,
+            NavigationAction::HalfPageUp => PullRequestAction::NavigateHalfPageUp,
         };
         Some(Action::PullRequest(action))
     }
@@ -72,12 +97,53 @@ impl View for PullRequestView {
         Some(Action::PullRequest(pr_action))
     }
 
+    fn translate_mouse_click(&self, col: u16, row: u16, state: &AppState) -> Option<Action> {
+        let (width, height) = state.terminal_size;
+        let chunks = layout(Rect::new(0, 0, width, height));
+        let (tabs_area, content_area) = (chunks[0], chunks[1]);
+
+        if tabs_area.contains((col, row).into()) {
+            let tabs_vm = RepositoryTabsViewModel::from_state(state);
+            let tab = tabs_vm.tab_at(col - tabs_area.x, tabs_area.width)?;
+            return Some(Action::PullRequest(PullRequestAction::RepositorySelect(
+                tab,
+            )));
+        }
+
+        let (table_area, _) = split_content(state, content_area);
+        if table_area.contains((col, row).into())
+            && matches!(determine_main_content(state), MainContentViewModel::PrTable)
+        {
+            let repo_idx = state.main_view.selected_repository;
+            let repo_data = state.main_view.repo_data.get(&repo_idx)?;
+            let visible = repo_data.visible_pr_indices();
+            let selected_index = visible
+                .iter()
+                .position(|&idx| idx == repo_data.selected_pr)
+                .unwrap_or(0);
+            let table_row = pr_table_row_at(
+                table_area,
+                row,
+                state.row_density.row_height(),
+                selected_index,
+                visible.len(),
+            )?;
+            return Some(Action::PullRequest(PullRequestAction::NavigateToRow(
+                table_row,
+            )));
+        }
+
+        None
+    }
+
     fn accepts_action(&self, action: &Action) -> bool {
         matches!(
             action,
             Action::PullRequest(_)
+                | Action::Repository(_)
                 | Action::DiffViewer(DiffViewerAction::Open)
                 | Action::BuildLog(BuildLogAction::Open)
+                | Action::CommitList(CommitListAction::Open)
                 | Action::ViewContext(_)
                 | Action::Navigate(_)
                 | Action::Global(_)
@@ -87,27 +153,111 @@ impl View for PullRequestView {
 
     fn available_actions(&self, _state: &AppState) -> Vec<AvailableAction> {
         vec![
-            AvailableAction::primary(CommandId::Confirm, "Open"),
+            AvailableAction::primary(CommandId::PrOpenInBrowser, "Open"),
             AvailableAction::primary(CommandId::PrMerge, "Merge"),
             AvailableAction::primary(CommandId::PrOpenBuildLogs, "Build Logs"),
             AvailableAction::primary(CommandId::DiffViewerOpen, "Diffs"),
-            AvailableAction::selection(CommandId::ToggleSelect, "Select"),
+            AvailableAction::selection(CommandId::PrToggleSelection, "Select"),
             AvailableAction::navigation(CommandId::RepositoryNext, "Next Repo"),
+            AvailableAction::primary(CommandId::CommandPaletteOpen, "Commands"),
+            AvailableAction::primary(CommandId::KeyBindingsToggleView, "Help"),
         ]
     }
 }
 
-/// Render the main view
-fn render(state: &AppState, area: Rect, f: &mut Frame) {
-    // Split into repository tabs, content area, and status bar
-    let chunks = Layout::default()
+/// Split into repository tabs, content area, shortcut bar, and status bar
+fn layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // Repository tab bar (single row)
             Constraint::Min(0),    // Content area
+            Constraint::Length(1), // Shortcut bar (single row)
             Constraint::Length(1), // Status bar (single row)
         ])
+        .split(area)
+}
+
+/// Split the content area into the PR table and, if shown, the description panel
+fn split_content(state: &AppState, area: Rect) -> (Rect, Option<Rect>) {
+    if !state.main_view.show_description {
+        return (area, None);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
+    (chunks[0], Some(chunks[1]))
+}
+
+/// Number of PR rows that fit the table, minus borders and the header row
+fn visible_pr_rows(area: Rect, row_height: u16) -> usize {
+    (area.height.saturating_sub(3) / row_height.max(1)) as usize
+}
+
+/// Row of the PR table at terminal row `row`, if it hits a data row
+///
+/// The table is rendered with a fresh `TableState`, so ratatui scrolls just
+/// enough to keep the cursor row visible at the bottom. Every data row is
+/// `row_height` lines tall.
+fn pr_table_row_at(
+    area: Rect,
+    row: u16,
+    row_height: u16,
+    selected_index: usize,
+    row_count: usize,
+) -> Option<usize> {
+    // Data rows start below the top border and the header row
+    let first_row = area.y + 2;
+    let visible_rows = visible_pr_rows(area, row_height);
+    if row < first_row || visible_rows == 0 {
+        return None;
+    }
+    let offset = (selected_index + 1).saturating_sub(visible_rows);
+    let index = offset + ((row - first_row) / row_height.max(1)) as usize;
+    (index < row_count && index < offset + visible_rows).then_some(index)
+}
+
+/// Render a scrollbar over the right border of the PR table's data rows
+///
+/// Only shown when there are more rows than fit. Drawn on the border, so it
+/// takes no width from the columns.
+fn render_pr_table_scrollbar(
+    area: Rect,
+    row_height: u16,
+    selected_index: usize,
+    row_count: usize,
+    theme: &gh_pr_lander_theme::Theme,
+    f: &mut Frame,
+) {
+    let visible_rows = visible_pr_rows(area, row_height);
+    if visible_rows == 0 || row_count <= visible_rows {
+        return;
+    }
+
+    // Same offset ratatui uses for a fresh TableState (see `pr_table_row_at`)
+    let offset = (selected_index + 1).saturating_sub(visible_rows);
+    let mut scrollbar_state = ScrollbarState::new(row_count - visible_rows)
+        .position(offset)
+        .viewport_content_length(visible_rows);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_symbol(None)
+        .thumb_style(Style::default().fg(theme.accent_primary));
+
+    let scrollbar_area = Rect {
+        x: area.x,
+        y: area.y + 2,
+        width: area.width,
+        height: area.height.saturating_sub(3),
+    };
+    f.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+}
+
+/// Render the main view
+fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let chunks = layout(area);
 
     // Build and render tabs view model
     let tabs_vm = RepositoryTabsViewModel::from_state(state);
@@ -119,13 +269,21 @@ fn render(state: &AppState, area: Rect, f: &mut Frame) {
             render_empty_state(&empty_vm, chunks[1], f);
         }
         MainContentViewModel::PrTable => {
-            render_pr_table(state, chunks[1], f);
+            let (table_area, description_area) = split_content(state, chunks[1]);
+            render_pr_table(state, table_area, f);
+            if let Some(description_area) = description_area {
+                render_description(state, description_area, f);
+            }
         }
     }
 
+    // Shortcuts of the top-most view, so popups advertise their own keys
+    let shortcut_vm = ShortcutBarViewModel::from_state(state);
+    f.render_widget(ShortcutBarWidget(&shortcut_vm), chunks[2]);
+
     // Render status bar at the bottom
     let status_vm = StatusBarViewModel::from_state(state);
-    f.render_widget(StatusBarWidget(&status_vm), chunks[2]);
+    f.render_widget(StatusBarWidget(&status_vm), chunks[3]);
 }
 
 /// Render the PR table for the currently selected repository
@@ -133,38 +291,86 @@ fn render_pr_table(state: &AppState, area: Rect, f: &mut Frame) {
     let theme = &state.theme;
     let repo_idx = state.main_view.selected_repository;
 
-    let repo = &state.main_view.repositories[repo_idx];
-    let repo_data = state.main_view.repo_data.get(&repo_idx).unwrap();
+    let (Some(repo), Some(repo_data)) = (
+        state.main_view.repositories.get(repo_idx),
+        state.main_view.repo_data.get(&repo_idx),
+    ) else {
+        return;
+    };
 
     // Build view model
-    let vm = PrTableViewModel::from_repo_data(repo_data, repo, theme);
+    let vm = PrTableViewModel::from_repo_data(
+        repo_data,
+        repo,
+        state.row_density,
+        state.app_config.pr.bulk_include_hidden,
+        theme,
+    );
 
     // Build block with header
     let status_line = Line::from(vm.header.status_text.clone())
         .style(ratatui::style::Style::default().fg(vm.header.status_color))
         .right_aligned();
 
-    let block = Block::bordered()
+    let mut block = Block::bordered()
         .border_type(ratatui::widgets::BorderType::QuadrantOutside)
         .border_style(ratatui::style::Style::default().fg(theme.accent_primary))
         .title(vm.header.title.clone())
         .title(status_line);
 
+    // "Go to PR number" input
+    if let Some(input) = &state.main_view.goto_pr_input {
+        block = block.title_bottom(
+            Line::from(format!(" Go to PR #{}▏ ", input))
+                .style(theme.accent())
+                .right_aligned(),
+        );
+    }
+
+    // The list stopped at the PR limit, so bulk actions don't cover every PR
+    if let Some(truncation) = &vm.header.truncation {
+        let mut spans = vec![Span::styled(format!(" ⚠ {}", truncation), theme.warning())];
+        if let Some(hint) = state.keymap.hint_for_command(CommandId::PrLoadMore) {
+            spans.push(Span::styled(" · ", theme.muted()));
+            spans.push(Span::styled(hint.to_string(), theme.key_hint()));
+            spans.push(Span::styled(" load more", theme.muted()));
+        }
+        spans.push(Span::raw(" "));
+        block = block.title(Line::from(spans).centered());
+    }
+
+    // Selection summary, so partial bulk-merge failures are obvious up front
+    if let Some(summary) = &vm.header.selection_summary {
+        block = block.title_bottom(
+            Line::from(summary.clone())
+                .style(ratatui::style::Style::default().fg(vm.header.selection_summary_color)),
+        );
+    }
+
     // Build header row
     let header_style = theme.table_header();
+    // Detailed rows move author, age and status to their second line
+    let detailed = vm.density == RowDensity::Detailed;
+    let columns: Vec<PrColumn> = state
+        .pr_columns
+        .iter()
+        .copied()
+        .filter(|column| !(detailed && column.on_details_line()))
+        .collect();
 
-    // Column widths: Delta=12, Maturity=6, Review=6
-    let header_cells = [
-        "  #PR".to_string(),
-        "Title".to_string(),
-        "Author".to_string(),
-        format!("{:^12}", "Delta"),
-        format!("{:^6}", "Draft?"),
-        format!("{:^6}", "Rev."),
-        "Status".to_string(),
-    ]
-    .into_iter()
-    .map(|h| Cell::from(h).style(header_style));
+    let header_cells = columns.iter().map(|column| {
+        let title = match column {
+            PrColumn::Number => "  #PR".to_string(),
+            PrColumn::Title => "Title".to_string(),
+            PrColumn::Author => "Author".to_string(),
+            PrColumn::Delta => format!("{:^12}", "Delta"),
+            PrColumn::Draft => format!("{:^6}", "Draft?"),
+            PrColumn::Review => format!("{:^6}", "Rev."),
+            PrColumn::Age => format!("{:>4}", "Age"),
+            PrColumn::Status => "Status".to_string(),
+        };
+        Cell::from(title).style(header_style)
+    });
 
     let header = Row::new(header_cells).style(header_style).height(1);
 
@@ -175,30 +381,36 @@ fn render_pr_table(state: &AppState, area: Rect, f: &mut Frame) {
         .map(|row_vm| {
             let style = Style::default().fg(row_vm.fg_color).bg(row_vm.bg_color);
 
-            // Build delta cell with colored additions (green) and deletions (red)
-            // Right-align additions, space, left-align deletions within 12-char column
-            let add_str = format!("+{}", row_vm.additions);
-            let del_str = format!("-{}", row_vm.deletions);
-            let delta_line = Line::from(vec![
-                Span::styled(format!("{:>5}", add_str), Style::default().fg(Color::Green)),
-                Span::raw(" "),
-                Span::styled(format!("{:<6}", del_str), Style::default().fg(Color::Red)),
-            ]);
-
-            Row::new(vec![
-                Cell::from(row_vm.pr_number.clone()),
-                Cell::from(row_vm.title.clone()),
-                Cell::from(row_vm.author.clone()),
-                Cell::from(delta_line),
-                Cell::from(format!("{:^6}", row_vm.maturity_text))
+            let cells = columns.iter().map(|column| match column {
+                PrColumn::Number => Cell::from(row_vm.pr_number.clone()),
+                PrColumn::Title if detailed => Cell::from(Text::from(vec![
+                    Line::from(row_vm.title.clone()),
+                    Line::styled(row_vm.details.clone(), theme.muted()),
+                ])),
+                PrColumn::Title => Cell::from(row_vm.title.clone()),
+                PrColumn::Author => Cell::from(row_vm.author.clone()),
+                PrColumn::Delta => {
+                    // Colored additions (green) and deletions (red): right-align
+                    // additions, space, left-align deletions within 12-char column
+                    let add_str = format!("+{}", row_vm.additions);
+                    let del_str = format!("-{}", row_vm.deletions);
+                    Cell::from(Line::from(vec![
+                        Span::styled(format!("{:>5}", add_str), Style::default().fg(Color::Green)),
+                        Span::raw(" "),
+                        Span::styled(format!("{:<6}", del_str), Style::default().fg(Color::Red)),
+                    ]))
+                }
+                PrColumn::Draft => Cell::from(format!("{:^6}", row_vm.maturity_text))
                     .style(Style::default().fg(row_vm.maturity_color)),
-                Cell::from(format!("{:^6}", row_vm.review_text))
+                PrColumn::Review => Cell::from(format!("{:^6}", row_vm.review_text))
                     .style(Style::default().fg(row_vm.review_color)),
-                Cell::from(row_vm.status_text.clone())
+                PrColumn::Age => Cell::from(format!("{:>4}", row_vm.age_text))
+                    .style(Style::default().fg(row_vm.age_color)),
+                PrColumn::Status => Cell::from(row_vm.status_text.clone())
                     .style(Style::default().fg(row_vm.status_color)),
-            ])
-            .style(style)
-            .height(1)
+            });
+
+            Row::new(cells).style(style).height(vm.density.row_height())
         })
         .collect();
 
@@ -212,15 +424,21 @@ fn render_pr_table(state: &AppState, area: Rect, f: &mut Frame) {
         .unwrap_or(6) // fallback to 6 if no rows
         .max(5) as u16; // minimum width for "  #PR" header
 
-    let widths = [
-        Constraint::Length(pr_number_width), // #PR - dynamic width
-        Constraint::Percentage(35),          // Title (reduced)
-        Constraint::Percentage(10),          // Author
-        Constraint::Length(12),              // Delta (+123 -456)
-        Constraint::Length(6),               // Maturity
-        Constraint::Length(6),               // Review
-        Constraint::Percentage(15),          // Status
-    ];
+    // Fixed-width columns keep their size, the text columns share the rest
+    // in proportion, so hiding columns widens the remaining ones
+    let widths: Vec<Constraint> = columns
+        .iter()
+        .map(|column| match column {
+            PrColumn::Number => Constraint::Length(pr_number_width), // dynamic width
+            PrColumn::Title => Constraint::Fill(7),
+            PrColumn::Author => Constraint::Fill(2),
+            PrColumn::Delta => Constraint::Length(12), // +123 -456
+            PrColumn::Draft => Constraint::Length(6),
+            PrColumn::Review => Constraint::Length(6),
+            PrColumn::Age => Constraint::Length(4),
+            PrColumn::Status => Constraint::Fill(3),
+        })
+        .collect();
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -233,6 +451,38 @@ fn render_pr_table(state: &AppState, area: Rect, f: &mut Frame) {
     table_state.select(Some(vm.selected_index));
 
     f.render_stateful_widget(table, area, &mut table_state);
+
+    render_pr_table_scrollbar(
+        area,
+        vm.density.row_height(),
+        vm.selected_index,
+        vm.rows.len(),
+        theme,
+        f,
+    );
+}
+
+/// Render the description panel of the focused PR
+fn render_description(state: &AppState, area: Rect, f: &mut Frame) {
+    let theme = &state.theme;
+    let vm = PrDescriptionViewModel::from_state(state);
+
+    let mut block = Block::bordered()
+        .border_type(ratatui::widgets::BorderType::QuadrantOutside)
+        .border_style(theme.panel_border())
+        .title(vm.title.clone())
+        .title_style(theme.panel_title());
+    if let Some(branch) = &vm.branch {
+        block = block.title_bottom(Line::styled(format!(" ⎇ {} ", branch), theme.muted()));
+    }
+    let scroll = vm.clamped_scroll(block.inner(area).width);
+
+    let paragraph = Paragraph::new(vm.body)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+
+    f.render_widget(paragraph, area);
 }
 
 /// Render empty/loading state
@@ -241,7 +491,25 @@ fn render_empty_state(vm: &crate::view_models::EmptyStateViewModel, area: Rect,
         .border_type(ratatui::widgets::BorderType::QuadrantOutside)
         .border_style(ratatui::style::Style::default().fg(vm.border_color));
 
-    let paragraph = Paragraph::new(vm.message.clone())
+    let mut lines = vec![Line::from(vm.message.clone())];
+    if !vm.hints.is_empty() {
+        lines.push(Line::default());
+    }
+    lines.extend(vm.hints.iter().map(|(key, description)| {
+        Line::from(vec![
+            Span::raw("Press "),
+            Span::styled(key.clone(), vm.key_style),
+            Span::raw(format!(" {}", description)),
+        ])
+    }));
+
+    // Center the text vertically inside the borders
+    let inner_height = block.inner(area).height as usize;
+    let padding = inner_height.saturating_sub(lines.len()) / 2;
+    let mut padded = vec![Line::default(); padding];
+    padded.extend(lines);
+
+    let paragraph = Paragraph::new(padded)
         .block(block)
         .style(vm.text_style)
         .alignment(Alignment::Center);
@@ -0,0 +1,194 @@
+//! Setup Wizard View
+//!
+//! Shown on startup when no GitHub token is found. Explains the ways to
+//! provide one and can run `gh auth login` right away.
+
+use crate::actions::{Action, GlobalAction, NavigationAction, SetupWizardAction, TextInputAction};
+use crate::capabilities::PanelCapabilities;
+use crate::state::AppState;
+use crate::views::{View, ViewId};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+/// First-run setup wizard view
+#[derive(Debug, Clone)]
+pub struct SetupWizardView;
+
+impl SetupWizardView {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SetupWizardView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for SetupWizardView {
+    fn view_id(&self) -> ViewId {
+        ViewId::SetupWizard
+    }
+
+    fn render(&self, state: &AppState, area: Rect, f: &mut Frame) {
+        render(state, area, f);
+    }
+
+    fn capabilities(&self, _state: &AppState) -> PanelCapabilities {
+        // Single-key choices, without the global keymap (nothing works without a client)
+        PanelCapabilities::TEXT_INPUT
+    }
+
+    fn clone_box(&self) -> Box<dyn View> {
+        Box::new(self.clone())
+    }
+
+    fn translate_navigation(&self, _nav: NavigationAction) -> Option<Action> {
+        None
+    }
+
+    fn translate_text_input(&self, input: TextInputAction) -> Option<Action> {
+        match input {
+            TextInputAction::Char('g') => {
+                Some(Action::SetupWizard(SetupWizardAction::RunGhAuthLogin))
+            }
+            TextInputAction::Char('r') | TextInputAction::Confirm => {
+                Some(Action::SetupWizard(SetupWizardAction::Retry))
+            }
+            // The app can't do anything without a token
+            TextInputAction::Char('q') | TextInputAction::Escape => {
+                Some(Action::Global(GlobalAction::Quit))
+            }
+            _ => None,
+        }
+    }
+
+    fn accepts_action(&self, action: &Action) -> bool {
+        matches!(
+            action,
+            Action::SetupWizard(_) | Action::TextInput(_) | Action::Global(_)
+        )
+    }
+}
+
+/// Render the setup wizard as a centered panel
+fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let theme = &state.theme;
+    let wizard = &state.setup_wizard;
+
+    let panel_width = (area.width * 70 / 100).clamp(60, 84).min(area.width);
+    let panel_height = (18 + wizard.attempts.len() as u16 + 1).min(area.height);
+    let panel_area = Rect {
+        x: area.x + (area.width - panel_width) / 2,
+        y: area.y + (area.height - panel_height) / 2,
+        width: panel_width,
+        height: panel_height,
+    };
+
+    // Render dimmed overlay over the entire screen (the splash screen)
+    let overlay = Block::default().style(
+        Style::default()
+            .bg(ratatui::style::Color::Black)
+            .add_modifier(Modifier::DIM),
+    );
+    f.render_widget(overlay, area);
+    f.render_widget(Clear, panel_area);
+
+    let key = |k: &'static str| Span::styled(k, theme.key_hint().bold());
+    let code = |c: String| Span::styled(c, theme.accent());
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!("No GitHub token was found for {}.", wizard.host),
+        theme.text(),
+    ))];
+
+    // Why each token source came up empty
+    if !wizard.attempts.is_empty() {
+        lines.push(Line::from(Span::styled("Tried:", theme.text_secondary())));
+        lines.extend(wizard.attempts.iter().map(|attempt| {
+            Line::from(vec![
+                Span::styled("  • ", theme.muted()),
+                Span::styled(attempt.clone(), theme.muted()),
+            ])
+        }));
+    }
+
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "Provide one in any of these ways:",
+            theme.text(),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("1. ", theme.accent()),
+            Span::styled("Log in with the GitHub CLI: press ", theme.text()),
+            key("g"),
+            Span::styled(" to run ", theme.text()),
+            code(format!("gh auth login --hostname {}", wizard.host)),
+        ]),
+        Line::from(vec![
+            Span::styled("2. ", theme.accent()),
+            Span::styled("Export ", theme.text()),
+            code("GITHUB_TOKEN".to_string()),
+            Span::styled(" (or ", theme.text()),
+            code(wizard.env_key.clone()),
+            Span::styled(") and restart the app", theme.text()),
+        ]),
+        Line::from(vec![
+            Span::styled("3. ", theme.accent()),
+            Span::styled("Add ", theme.text()),
+            code("GITHUB_TOKEN=<token>".to_string()),
+            Span::styled(" to a ", theme.text()),
+            code(".env".to_string()),
+            Span::styled(" file in the current directory", theme.text()),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Done? Press ", theme.text_secondary()),
+            key("r"),
+            Span::styled(" to look for the token again.", theme.text_secondary()),
+        ]),
+        Line::from(""),
+    ]);
+
+    if wizard.checking {
+        lines.push(Line::from(Span::styled(
+            "Looking for a token...",
+            theme.muted(),
+        )));
+    } else if let Some(error) = &wizard.error {
+        lines.push(Line::from(Span::styled(error.clone(), theme.error())));
+    }
+
+    let footer_hint = Line::from(vec![
+        Span::styled(" g", theme.key_hint().bold()),
+        Span::styled(" gh auth login  ", theme.muted()),
+        Span::styled("r", theme.key_hint().bold()),
+        Span::styled(" retry  ", theme.muted()),
+        Span::styled("q", theme.key_hint().bold()),
+        Span::styled(" quit ", theme.muted()),
+    ]);
+
+    let block = Block::default()
+        .title(" Welcome to GitHub PR Lander ")
+        .borders(Borders::ALL)
+        .border_style(theme.panel_border())
+        .title_style(theme.panel_title())
+        .title_alignment(Alignment::Center)
+        .title_bottom(footer_hint)
+        .padding(Padding::uniform(1));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(theme.panel_background())
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, panel_area);
+}
@@ -0,0 +1,41 @@
+//! Shortcut Bar Widget
+//!
+//! Renders the shortcuts of the active view as `key label` chips in a single row.
+//! Chips that don't fit are left out rather than cut off.
+
+use crate::view_models::ShortcutBarViewModel;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// Spacing between two chips
+const CHIP_GAP: u16 = 2;
+
+/// Widget for rendering the shortcut bar
+pub struct ShortcutBarWidget<'a>(pub &'a ShortcutBarViewModel);
+
+impl Widget for ShortcutBarWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let vm = self.0;
+
+        if area.height < 1 {
+            return;
+        }
+
+        // Fill entire row with background
+        for x in area.x..area.x + area.width {
+            buf[(x, area.y)].set_bg(vm.bg_color).set_char(' ');
+        }
+
+        let right = area.x + area.width;
+        let mut x = area.x + 1; // 1 char padding
+        for chip in &vm.chips {
+            let keys_width = chip.keys.chars().count() as u16;
+            let chip_width = keys_width + 1 + chip.label.chars().count() as u16;
+            if x + chip_width >= right {
+                break;
+            }
+            buf.set_string(x, area.y, &chip.keys, vm.key_style);
+            buf.set_string(x + keys_width + 1, area.y, chip.label, vm.label_style);
+            x += chip_width + CHIP_GAP;
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! Status Bar Widget
 //!
 //! Renders the status bar at the bottom of the screen.
-//! Format: `[timestamp] emoji message                     [source]`
+//! Format: `[timestamp] emoji message      g… 1.4s [source] ↻ 3m ago · 14:32`
 
 use crate::view_models::StatusBarViewModel;
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 
+/// Minimum status bar width for showing the clock, so narrow terminals keep
+/// the room for the message
+const MIN_WIDTH_FOR_CLOCK: u16 = 60;
+
 /// Widget for rendering the status bar
 pub struct StatusBarWidget<'a>(pub &'a StatusBarViewModel);
 
@@ -22,6 +26,15 @@ impl Widget for StatusBarWidget<'_> {
             buf[(x, area.y)].set_bg(vm.bg_color).set_char(' ');
         }
 
+        // Clock in the right corner; the rest of the bar renders left of it
+        let mut area = area;
+        if !vm.clock.is_empty() && area.width >= MIN_WIDTH_FOR_CLOCK {
+            let clock_width = vm.clock.chars().count() as u16;
+            let clock_x = area.x + area.width - clock_width - 1;
+            buf.set_string(clock_x, area.y, &vm.clock, vm.metadata_style);
+            area.width -= clock_width + 2;
+        }
+
         if !vm.has_message {
             return;
         }
@@ -47,6 +60,14 @@ impl Widget for StatusBarWidget<'_> {
             0
         };
 
+        // Pending key sequence indicator, left of the source
+        let pending_width = vm
+            .pending_sequence
+            .as_ref()
+            .map(|pending| pending.chars().count() + 1)
+            .unwrap_or(0);
+        let source_width = source_width + pending_width;
+
         // Message (truncate if needed)
         let available_width = area
             .width
@@ -62,6 +83,11 @@ impl Widget for StatusBarWidget<'_> {
             buf.set_string(x, area.y, &vm.message, vm.message_style);
         }
 
+        if let Some(pending) = &vm.pending_sequence {
+            let pending_x = (area.x + area.width).saturating_sub(source_width as u16 + 1);
+            buf.set_string(pending_x, area.y, pending, vm.pending_sequence_style);
+        }
+
         // Source on the right side
         if !vm.source.is_empty() {
             let source_str = format!("[{}]", vm.source);
@@ -0,0 +1,48 @@
+//! Toast
+//!
+//! Transient notification in the bottom-right corner, above the status bar,
+//! showing the result of the most recent operation. Like the error banner,
+//! it is not part of the view stack and never captures input.
+
+use crate::state::AppState;
+use crate::view_models::ToastViewModel;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Render the visible toast, if any
+pub fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let Some(vm) = ToastViewModel::from_state(state) else {
+        return;
+    };
+    // Keep the status bar (last row) visible
+    if area.height < 3 || area.width < 4 {
+        return;
+    }
+
+    let max_width = area.width.saturating_sub(2) as usize;
+    let text = if vm.text.chars().count() > max_width {
+        let truncated: String = vm.text.chars().take(max_width.saturating_sub(1)).collect();
+        format!("{}…", truncated)
+    } else {
+        vm.text
+    };
+    let width = text.chars().count() as u16;
+    let toast_area = Rect::new(
+        area.x + area.width - width - 1,
+        area.y + area.height - 2,
+        width,
+        1,
+    );
+
+    let style = Style::default()
+        .fg(vm.fg)
+        .bg(vm.bg)
+        .add_modifier(Modifier::BOLD);
+    f.render_widget(Clear, toast_area);
+    f.render_widget(Paragraph::new(Line::from(text)).style(style), toast_area);
+}
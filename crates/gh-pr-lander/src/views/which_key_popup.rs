@@ -0,0 +1,62 @@
+//! Which-Key Popup
+//!
+//! Transient hint listing the possible second keys while a two-key sequence
+//! is pending. It is not part of the view stack, so it never captures input.
+
+use crate::state::AppState;
+use crate::view_models::WhichKeyViewModel;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the which-key popup in the bottom right corner, above the status bar
+pub fn render(state: &AppState, area: Rect, f: &mut Frame) {
+    let Some(vm) = WhichKeyViewModel::from_state(state) else {
+        return;
+    };
+    let theme = &state.theme;
+
+    let content_width = vm
+        .rows
+        .iter()
+        .map(|row| row.title.chars().count() + 4)
+        .max()
+        .unwrap_or(0);
+    let width = (content_width as u16 + 4).min(area.width);
+    let height = (vm.rows.len() as u16 + 2).min(area.height.saturating_sub(1));
+    let popup_area = Rect::new(
+        area.x + area.width - width,
+        (area.y + area.height).saturating_sub(height + 1),
+        width,
+        height,
+    );
+
+    let lines: Vec<Line> = vm
+        .rows
+        .iter()
+        .map(|row| {
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(format!("{:<3}", row.key), theme.key_hint()),
+                Span::styled(row.title.clone(), theme.key_description()),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(vm.title)
+        .borders(Borders::ALL)
+        .border_style(theme.panel_border())
+        .title_style(theme.panel_title());
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .style(theme.panel_background()),
+        popup_area,
+    );
+}